@@ -0,0 +1,36 @@
+use aoc_util::error::{self, Error};
+use std::collections::HashSet;
+
+fn parse_changes(input: &str) -> error::Result<Vec<i32>> {
+    input
+        .lines()
+        .map(|line| line.parse::<i32>().map_err(|e| Error::parse(e.to_string())))
+        .collect()
+}
+
+fn part1(input: &str) -> error::Result<i32> {
+    Ok(parse_changes(input)?.into_iter().sum())
+}
+
+fn part2(input: &str) -> error::Result<i32> {
+    let changes_vec = parse_changes(input)?;
+    let mut changes = changes_vec.iter().cycle();
+    let mut freqs = HashSet::new();
+    let mut freq = 0i32;
+    while freqs.insert(freq) {
+        freq += changes.next().expect("Can't get None from non-empty cycle");
+    }
+    Ok(freq)
+}
+
+aoc_util::solution! {
+    year: 2018,
+    day: 1,
+    title: "Chronal Calibration",
+    part1: part1,
+    part2: part2,
+    examples: [
+        part1 => 3,
+        part2 => 2,
+    ],
+}
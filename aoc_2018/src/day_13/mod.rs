@@ -0,0 +1,19 @@
+use aoc_util::error::{self, Error};
+
+fn part1(_input: &str) -> error::Result<u32> {
+    Err(Error::Unimplemented {
+        year: 2018,
+        day: 13,
+        part: Some(1),
+    })
+}
+
+fn part2(_input: &str) -> error::Result<u32> {
+    Err(Error::Unimplemented {
+        year: 2018,
+        day: 13,
+        part: Some(2),
+    })
+}
+
+aoc_util::register_day!(2018, 13, part1, part2, "Mine Cart Madness");
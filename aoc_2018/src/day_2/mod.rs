@@ -0,0 +1,71 @@
+use std::collections::HashMap;
+
+use aoc_util::error::{self, Error};
+
+fn part1(input: &str) -> error::Result<u32> {
+    let mut double = 0u32;
+    let mut triple = 0u32;
+    for id in input.lines() {
+        let mut byte_freqs = HashMap::new();
+        for byte in id.bytes() {
+            *byte_freqs.entry(byte).or_default() += 1;
+        }
+        let mut counted_double = false;
+        let mut counted_triple = false;
+        for (_, freq) in byte_freqs {
+            match freq {
+                2 if !counted_double => {
+                    double += 1;
+                    counted_double = true;
+                }
+                3 if !counted_triple => {
+                    triple += 1;
+                    counted_triple = true;
+                }
+                _ => {}
+            }
+            if counted_double && counted_triple {
+                break;
+            }
+        }
+    }
+    Ok(double * triple)
+}
+
+fn part2(input: &str) -> error::Result<String> {
+    let ids = input.lines().map(str::as_bytes).collect::<Vec<_>>();
+    for i in 0..ids.len() {
+        'lv1: for j in 0..i {
+            let a = ids[i];
+            let b = ids[j];
+            let mut diff = None;
+            for i in 0..a.len() {
+                if a[i] != b[i] {
+                    if diff.is_some() {
+                        continue 'lv1;
+                    }
+                    diff = Some(i);
+                }
+            }
+            if let Some(i) = diff {
+                let common = [&a[..i], &a[(i + 1)..]].concat();
+                return Ok(String::from_utf8_lossy(&common).into_owned());
+            }
+        }
+    }
+    Err(Error::UnsolvableInput(
+        "No two IDs differ by exactly one letter".to_string(),
+    ))
+}
+
+aoc_util::solution! {
+    year: 2018,
+    day: 2,
+    title: "Inventory Management System",
+    part1: part1,
+    part2: part2,
+    examples: [
+        part1 => 12,
+        part2("common") => "fgij",
+    ],
+}
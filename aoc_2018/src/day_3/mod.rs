@@ -3,14 +3,12 @@ use nom::{
     IResult,
 };
 
-use std::{
-    cmp::Ordering,
-    fs::File,
-    io::{self, BufRead, BufReader},
-    iter::FromIterator,
-};
+use std::{cmp::Ordering, iter::FromIterator};
 
-use aoc_util::nom_extended::NomParse;
+use aoc_util::{
+    error::{self, Error},
+    nom_extended::NomParse,
+};
 
 #[derive(PartialEq, Eq, Clone)]
 struct Rect {
@@ -382,24 +380,37 @@ impl IntoIterator for RectSet {
     }
 }
 
-pub fn run() -> io::Result<()> {
-    fn get_claims() -> io::Result<RectSet> {
-        BufReader::new(File::open("2018_03.txt")?)
-            .lines()
-            .map(|line| {
-                line?
-                    .parse()
-                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
-            })
-            .collect()
+fn get_claims(input: &str) -> error::Result<RectSet> {
+    input
+        .lines()
+        .map(|line| {
+            line.parse::<Rect>()
+                .map_err(|e| Error::parse(e.to_string()))
+        })
+        .collect()
+}
+
+fn part1(input: &str) -> error::Result<u32> {
+    Ok(get_claims(input)?.overlap().area())
+}
+
+fn part2(input: &str) -> error::Result<u32> {
+    match get_claims(input)?.non_overlap_ids()[..] {
+        [id] => Ok(id),
+        ref ids => Err(Error::UnsolvableInput(format!(
+            "Expected exactly one claim without overlap but found {ids:?}"
+        ))),
     }
+}
 
-    // Part 1
-    println!("Overlap area: {}", get_claims()?.overlap().area());
-    // Part 2
-    println!(
-        "Non-overlapping claim: {:?}",
-        get_claims()?.non_overlap_ids()
-    );
-    Ok(())
+aoc_util::solution! {
+    year: 2018,
+    day: 3,
+    title: "No Matter How You Slice It",
+    part1: part1,
+    part2: part2,
+    examples: [
+        part1 => 4,
+        part2 => 3,
+    ],
 }
@@ -7,12 +7,13 @@ use std::{
     collections::HashMap,
     convert::TryFrom,
     fmt::{self, Display, Formatter},
-    fs::File,
-    io::{self, BufRead, BufReader},
     ops::Range,
 };
 
-use aoc_util::nom_extended::NomParse;
+use aoc_util::{
+    error::{self, Error},
+    nom_extended::NomParse,
+};
 
 #[derive(PartialOrd, Ord, PartialEq, Eq, Clone, Copy)]
 struct Date {
@@ -259,21 +260,20 @@ impl IntoIterator for ReposeRecord {
     }
 }
 
-fn get_entries() -> io::Result<Vec<Day4Entry>> {
-    let mut ret = BufReader::new(File::open("2018_04.txt")?)
+fn get_entries(input: &str) -> error::Result<Vec<Day4Entry>> {
+    let mut ret = input
         .lines()
         .map(|line| {
-            line?
-                .parse::<Day4Entry>()
-                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+            line.parse::<Day4Entry>()
+                .map_err(|e| Error::parse(e.to_string()))
         })
-        .collect::<io::Result<Vec<_>>>()?;
+        .collect::<error::Result<Vec<_>>>()?;
     ret.sort_by_key(|entry| entry.datetime());
     Ok(ret)
 }
 
-fn build_repose_record() -> io::Result<ReposeRecord> {
-    let entries = get_entries()?;
+fn build_repose_record(input: &str) -> error::Result<ReposeRecord> {
+    let entries = get_entries(input)?;
     let mut repose_record = ReposeRecord::new();
     let mut guard: Option<u32> = None;
     let mut sleep_time: Option<Time> = None;
@@ -305,8 +305,8 @@ fn build_repose_record() -> io::Result<ReposeRecord> {
     Ok(repose_record)
 }
 
-fn build_counts() -> io::Result<HashMap<u32, HashMap<u16, u32>>> {
-    let repose_record = build_repose_record()?;
+fn build_counts(input: &str) -> error::Result<HashMap<u32, HashMap<u16, u32>>> {
+    let repose_record = build_repose_record(input)?;
     let mut counts: HashMap<_, HashMap<_, _>> = HashMap::new();
     for (guard, sleep_ranges) in repose_record {
         let freqs = counts.entry(guard).or_default();
@@ -319,39 +319,43 @@ fn build_counts() -> io::Result<HashMap<u32, HashMap<u16, u32>>> {
     Ok(counts)
 }
 
-pub fn run() -> io::Result<()> {
-    {
-        // Part 1
-        let (guard, guard_counts) = build_counts()?
-            .into_iter()
-            .max_by_key(|(_, guard_counts)| {
-                guard_counts
-                    .iter()
-                    .map(|(_, &count)| count as u64)
-                    .sum::<u64>()
-            })
-            .unwrap();
-        let (minute, count) = guard_counts
-            .into_iter()
-            .max_by_key(|&(_, count)| count)
-            .unwrap();
-        println!("Guard #{guard} slept the most with {count} minutes at minute {minute}");
-        println!("Key is {}", guard * minute as u32);
-    }
-    {
-        // Part 2
-        let (guard, minute, count) = build_counts()?
-            .into_iter()
-            .map(|(guard, counts)| {
-                let (minute, count) = counts.into_iter().max_by_key(|&(_, count)| count).unwrap();
-                (guard, minute, count)
-            })
-            .max_by_key(|&(_, _, count)| count)
-            .unwrap();
-        println!(
-            "Guard #{guard} slept the most consistently with {count} minutes at minute {minute}"
-        );
-        println!("Key is {}", guard * minute as u32);
-    }
-    Ok(())
+fn part1(input: &str) -> error::Result<u32> {
+    let (guard, guard_counts) = build_counts(input)?
+        .into_iter()
+        .max_by_key(|(_, guard_counts)| {
+            guard_counts
+                .iter()
+                .map(|(_, &count)| count as u64)
+                .sum::<u64>()
+        })
+        .unwrap();
+    let (minute, _) = guard_counts
+        .into_iter()
+        .max_by_key(|&(_, count)| count)
+        .unwrap();
+    Ok(guard * minute as u32)
+}
+
+fn part2(input: &str) -> error::Result<u32> {
+    let (guard, minute, _) = build_counts(input)?
+        .into_iter()
+        .map(|(guard, counts)| {
+            let (minute, count) = counts.into_iter().max_by_key(|&(_, count)| count).unwrap();
+            (guard, minute, count)
+        })
+        .max_by_key(|&(_, _, count)| count)
+        .unwrap();
+    Ok(guard * minute as u32)
+}
+
+aoc_util::solution! {
+    year: 2018,
+    day: 4,
+    title: "Repose Record",
+    part1: part1,
+    part2: part2,
+    examples: [
+        part1 => 240,
+        part2 => 4455,
+    ],
 }
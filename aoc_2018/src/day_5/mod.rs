@@ -0,0 +1,96 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_util::error::{self, Error};
+
+fn invert_case(c: char) -> char {
+    if c.is_ascii_lowercase() {
+        c.to_ascii_uppercase()
+    } else if c.is_ascii_uppercase() {
+        c.to_ascii_lowercase()
+    } else {
+        c
+    }
+}
+
+#[derive(Clone)]
+struct Braid {
+    crossings: Vec<char>,
+}
+
+impl Braid {
+    fn new() -> Braid {
+        Braid {
+            crossings: Vec::new(),
+        }
+    }
+
+    fn add(&mut self, crossing: char) {
+        match self.crossings.pop() {
+            None => self.crossings.push(crossing),
+            Some(last_crossing) if invert_case(last_crossing) == crossing => {}
+            Some(last_crossing) => {
+                self.crossings.push(last_crossing);
+                self.crossings.push(crossing);
+            }
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.crossings.len()
+    }
+
+    fn strip(self, c: char) -> Braid {
+        let mut ret = Braid::new();
+        let c = c.to_ascii_lowercase();
+        for crossing in self.crossings {
+            if crossing.to_ascii_lowercase() != c {
+                ret.add(crossing);
+            }
+        }
+        ret
+    }
+}
+
+fn get_polymer(input: &str) -> error::Result<&str> {
+    input
+        .lines()
+        .next()
+        .ok_or_else(|| Error::parse("Missing polymer"))
+}
+
+fn part1(input: &str) -> error::Result<usize> {
+    let mut polymer = Braid::new();
+    for c in get_polymer(input)?.chars() {
+        polymer.add(c);
+    }
+    Ok(polymer.len())
+}
+
+fn part2(input: &str) -> error::Result<usize> {
+    let mut polymer = Braid::new();
+    let mut components = HashSet::new();
+    for c in get_polymer(input)?.chars() {
+        polymer.add(c);
+        components.insert(c.to_ascii_lowercase());
+    }
+    let mut stripped_lengths = HashMap::new();
+    for c in components {
+        stripped_lengths.insert(c, polymer.clone().strip(c).len());
+    }
+    stripped_lengths
+        .into_values()
+        .min()
+        .ok_or_else(|| Error::parse("Empty polymer"))
+}
+
+aoc_util::solution! {
+    year: 2018,
+    day: 5,
+    title: "Alchemical Reduction",
+    part1: part1,
+    part2: part2,
+    examples: [
+        part1 => 10,
+        part2 => 4,
+    ],
+}
@@ -0,0 +1,42 @@
+use aoc_util::error::{self, Error};
+
+fn parse_masses(input: &str) -> error::Result<Vec<u32>> {
+    input
+        .lines()
+        .map(|line| line.parse::<u32>().map_err(|e| Error::parse(e.to_string())))
+        .collect()
+}
+
+fn part1(input: &str) -> error::Result<u32> {
+    Ok(parse_masses(input)?
+        .into_iter()
+        .map(|mass| mass / 3 - 2)
+        .sum())
+}
+
+fn part2(input: &str) -> error::Result<u32> {
+    Ok(parse_masses(input)?
+        .into_iter()
+        .map(|mass| {
+            let mut ret = 0;
+            let mut next = (mass / 3).saturating_sub(2);
+            while next > 0 {
+                ret += next;
+                next = (next / 3).saturating_sub(2);
+            }
+            ret
+        })
+        .sum())
+}
+
+aoc_util::solution! {
+    year: 2019,
+    day: 1,
+    title: "The Tyranny of the Rocket Equation",
+    part1: part1,
+    part2: part2,
+    examples: [
+        part1 => 34_241,
+        part2 => 51_316,
+    ],
+}
@@ -0,0 +1,125 @@
+use std::{cmp::Ordering, collections::BTreeMap};
+
+use aoc_util::{
+    error::{self, Error},
+    geometry::Point2D,
+    nom_extended::NomParse,
+};
+use nom::{branch, character::complete as character, combinator as comb, multi, IResult};
+
+#[derive(Clone, Debug)]
+struct AsteroidField {
+    asteroids: Vec<Vec<bool>>,
+}
+
+impl AsteroidField {
+    fn positions(&self) -> impl Iterator<Item = Point2D<i64>> + '_ {
+        self.asteroids.iter().zip(0..).flat_map(|(row, y)| {
+            row.iter()
+                .zip(0..)
+                .filter(|(&asteroid, _)| asteroid)
+                .map(move |(_, x)| Point2D::at(x, y))
+        })
+    }
+
+    /// Groups the other asteroids by the direction in which they are from `station`, reflected so
+    /// that the y-axis points up, and orders each group from nearest to farthest.
+    fn lines_of_sight(&self, station: Point2D<i64>) -> BTreeMap<Direction, Vec<Point2D<i64>>> {
+        let mut lines = BTreeMap::<_, Vec<_>>::new();
+        for asteroid in self.positions().filter(|&asteroid| asteroid != station) {
+            let offset = (asteroid - station).reflect_y();
+            lines
+                .entry(Direction(offset.reduced()))
+                .or_default()
+                .push(asteroid);
+        }
+        for line in lines.values_mut() {
+            line.sort_by_key(|asteroid| asteroid.manhattan_distance(&station));
+        }
+        lines
+    }
+
+    /// Finds the asteroid from which the most other asteroids can be seen and returns the
+    /// asteroids that can be seen from it.
+    fn best_station(&self) -> error::Result<BTreeMap<Direction, Vec<Point2D<i64>>>> {
+        self.positions()
+            .map(|station| self.lines_of_sight(station))
+            .max_by_key(BTreeMap::len)
+            .ok_or_else(|| Error::UnsolvableInput("There are no asteroids".to_string()))
+    }
+}
+
+/// A direction from the monitoring station, ordered clockwise from straight up.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct Direction(Point2D<i64>);
+
+impl Ord for Direction {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.clockwise_cmp(&other.0)
+    }
+}
+
+impl PartialOrd for Direction {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'s> NomParse<&'s str> for AsteroidField {
+    fn nom_parse(s: &'s str) -> IResult<&'s str, Self> {
+        let asteroid = comb::value(true, character::char('#'));
+        let blank = comb::value(false, character::char('.'));
+        let row_parser = multi::many1(branch::alt((asteroid, blank)));
+        let field_parser = multi::separated_list1(character::line_ending, row_parser);
+        comb::map(field_parser, |asteroids| Self { asteroids })(s)
+    }
+}
+
+aoc_util::impl_from_str_for_nom_parse!(AsteroidField);
+
+fn parse_field(input: &str) -> error::Result<AsteroidField> {
+    input.trim().parse().map_err(|e: String| Error::parse(e))
+}
+
+fn part1(field: &AsteroidField) -> error::Result<usize> {
+    Ok(field.best_station()?.len())
+}
+
+fn part2(field: &AsteroidField) -> error::Result<i64> {
+    let mut lines = field
+        .best_station()?
+        .into_values()
+        .map(Vec::into_iter)
+        .collect::<Vec<_>>();
+    // The laser sweeps clockwise from straight up, vaporizing the nearest asteroid in each
+    // direction on every rotation.
+    let mut vaporized = vec![];
+    loop {
+        let rotation = lines
+            .iter_mut()
+            .filter_map(Iterator::next)
+            .collect::<Vec<_>>();
+        if rotation.is_empty() {
+            break;
+        }
+        vaporized.extend(rotation);
+    }
+    let asteroid = vaporized.get(199).ok_or_else(|| {
+        Error::UnsolvableInput("Fewer than 200 asteroids are vaporized".to_string())
+    })?;
+    Ok(asteroid.x() * 100 + asteroid.y())
+}
+
+aoc_util::solution! {
+    year: 2019,
+    day: 10,
+    title: "Monitoring Station",
+    parse: parse_field,
+    part1: part1,
+    part2: part2,
+    examples: [
+        part1 => 210,
+        part1("small") => 8,
+        part2 => 802,
+    ],
+}
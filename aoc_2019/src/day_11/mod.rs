@@ -0,0 +1,42 @@
+use std::thread;
+
+use crate::{
+    intcode_interpreter::IntcodeInterpreter,
+    robot::{Color, Robot},
+};
+
+use aoc_util::error;
+
+use extended_io::{
+    self as eio,
+    pipe::{self, PipeRead, PipeWrite},
+};
+
+/// Runs the painting program in `input` on a robot which starts on a panel of color `start` and
+/// returns the robot once the program halts.
+fn paint(input: &str, start: Color) -> error::Result<Robot> {
+    let prog = IntcodeInterpreter::<PipeRead, PipeWrite>::parse_program(input)?;
+    let (robot_to_prog_read, robot_to_prog_write) = pipe::mk_pipe();
+    let (prog_to_robot_read, mut prog_to_robot_write) = pipe::mk_pipe();
+    let prog = prog.dup_with(robot_to_prog_read, prog_to_robot_write.clone());
+    let mut robot = Robot::new(prog_to_robot_read, robot_to_prog_write);
+    let prog_thread = thread::spawn(move || prog.run_piped());
+    let robot_thread = thread::spawn(move || {
+        robot.set(Default::default(), start);
+        robot.run();
+        robot
+    });
+    prog_thread.join().unwrap();
+    eio::write_i64(&mut prog_to_robot_write, 2)?;
+    Ok(robot_thread.join().unwrap())
+}
+
+fn part1(input: &str) -> error::Result<usize> {
+    Ok(paint(input, Color::Black)?.num_panels())
+}
+
+fn part2(input: &str) -> error::Result<String> {
+    Ok(paint(input, Color::White)?.field())
+}
+
+aoc_util::register_day!(2019, 11, part1, part2, "Space Police");
@@ -0,0 +1,237 @@
+use aoc_util::{
+    error::{self, Error},
+    math,
+    nom_extended::{coords, NomParse},
+};
+
+use std::{
+    cmp::Ordering,
+    ops::{Add, AddAssign},
+};
+
+use nom::{character::complete as character, combinator as comb, IResult};
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+struct Vec3 {
+    x: i16,
+    y: i16,
+    z: i16,
+}
+
+impl Vec3 {
+    fn new(x: i16, y: i16, z: i16) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl Add for Vec3 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        Self::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl<'a> Add<&'a Self> for Vec3 {
+    type Output = Self;
+
+    fn add(self, other: &'a Self) -> Self::Output {
+        self + *other
+    }
+}
+
+impl<'a> Add<&'a mut Self> for Vec3 {
+    type Output = Self;
+
+    fn add(self, other: &'a mut Self) -> Self::Output {
+        self + *other
+    }
+}
+
+impl<'a> Add<Vec3> for &'a Vec3 {
+    type Output = Vec3;
+
+    fn add(self, other: Vec3) -> Self::Output {
+        other + self
+    }
+}
+
+impl<'a, 'b> Add<&'b Vec3> for &'a Vec3 {
+    type Output = Vec3;
+
+    fn add(self, other: &'b Vec3) -> Self::Output {
+        *self + other
+    }
+}
+
+impl<'a, 'b> Add<&'b mut Vec3> for &'a Vec3 {
+    type Output = Vec3;
+
+    fn add(self, other: &'b mut Vec3) -> Self::Output {
+        *self + other
+    }
+}
+
+impl<'a> Add<Vec3> for &'a mut Vec3 {
+    type Output = Vec3;
+
+    fn add(self, other: Vec3) -> Self::Output {
+        other + self
+    }
+}
+
+impl<'a, 'b> Add<&'b Vec3> for &'a mut Vec3 {
+    type Output = Vec3;
+
+    fn add(self, other: &'b Vec3) -> Self::Output {
+        *self + other
+    }
+}
+
+impl<'a, 'b> Add<&'b mut Vec3> for &'a mut Vec3 {
+    type Output = Vec3;
+
+    fn add(self, other: &'b mut Vec3) -> Self::Output {
+        *self + other
+    }
+}
+
+impl AddAssign for Vec3 {
+    fn add_assign(&mut self, other: Self) {
+        self.x += other.x;
+        self.y += other.y;
+        self.z += other.z;
+    }
+}
+
+impl<'a> AddAssign<&'a Self> for Vec3 {
+    fn add_assign(&mut self, other: &'a Self) {
+        *self += *other;
+    }
+}
+
+impl<'a> AddAssign<&'a mut Self> for Vec3 {
+    fn add_assign(&mut self, other: &'a mut Self) {
+        *self += *other;
+    }
+}
+
+impl<'a> AddAssign<Vec3> for &'a mut Vec3 {
+    fn add_assign(&mut self, other: Vec3) {
+        **self += other;
+    }
+}
+
+impl<'a, 'b> AddAssign<&'b Vec3> for &'a mut Vec3 {
+    fn add_assign(&mut self, other: &'b Vec3) {
+        **self += other;
+    }
+}
+
+impl<'a, 'b> AddAssign<&'b mut Vec3> for &'a mut Vec3 {
+    fn add_assign(&mut self, other: &'b mut Vec3) {
+        **self += other;
+    }
+}
+
+impl<'s> NomParse<&'s str> for Vec3 {
+    fn nom_parse(s: &'s str) -> IResult<&'s str, Self> {
+        comb::map(coords::labeled_point3d(character::i16), |point| {
+            Vec3::new(*point.x(), *point.y(), *point.z())
+        })(s)
+    }
+}
+
+aoc_util::impl_from_str_for_nom_parse!(Vec3);
+
+type Moon = (Vec3, Vec3);
+
+fn parse_moons(input: &str) -> error::Result<Vec<Moon>> {
+    input
+        .lines()
+        .map(|line| line.parse::<Vec3>().map_err(Error::parse))
+        .map(|v| Ok((v?, Vec3::default())))
+        .collect()
+}
+
+/// Applies gravity between every pair of moons and then moves each moon by its new velocity.
+fn step(moons: &mut [Moon]) {
+    let positions = moons.iter().map(|&(x, _)| x).collect::<Vec<_>>();
+    for (moon1_x, moon1_v) in moons.iter_mut() {
+        for moon2_x in &positions {
+            match moon1_x.x.cmp(&moon2_x.x) {
+                Ordering::Less => moon1_v.x += 1,
+                Ordering::Equal => {}
+                Ordering::Greater => moon1_v.x -= 1,
+            }
+            match moon1_x.y.cmp(&moon2_x.y) {
+                Ordering::Less => moon1_v.y += 1,
+                Ordering::Equal => {}
+                Ordering::Greater => moon1_v.y -= 1,
+            }
+            match moon1_x.z.cmp(&moon2_x.z) {
+                Ordering::Less => moon1_v.z += 1,
+                Ordering::Equal => {}
+                Ordering::Greater => moon1_v.z -= 1,
+            }
+        }
+    }
+    for moon in moons.iter_mut() {
+        moon.0 += &moon.1;
+    }
+}
+
+fn part1(input: &str) -> error::Result<i16> {
+    fn potential_energy(moon_x: Vec3) -> i16 {
+        moon_x.x.abs() + moon_x.y.abs() + moon_x.z.abs()
+    }
+    fn kinetic_energy(moon_v: Vec3) -> i16 {
+        moon_v.x.abs() + moon_v.y.abs() + moon_v.z.abs()
+    }
+    fn total_energy((moon_x, moon_v): Moon) -> i16 {
+        potential_energy(moon_x) * kinetic_energy(moon_v)
+    }
+    let mut moons = parse_moons(input)?;
+    for _ in 0..1000 {
+        step(&mut moons);
+    }
+    Ok(moons.into_iter().map(total_energy).sum())
+}
+
+fn part2(input: &str) -> error::Result<u64> {
+    let initial = parse_moons(input)?;
+    // The axes don't affect each other, so each of them cycles on its own and the whole system
+    // returns to its initial state once all three cycles line up.
+    let axes: [fn(&Vec3) -> i16; 3] = [|v| v.x, |v| v.y, |v| v.z];
+    let mut moons = initial.clone();
+    let mut periods = [None; 3];
+    let mut steps = 0u64;
+    while periods.iter().any(Option::is_none) {
+        step(&mut moons);
+        steps += 1;
+        for (period, axis) in periods.iter_mut().zip(axes) {
+            if period.is_none()
+                && moons
+                    .iter()
+                    .zip(&initial)
+                    .all(|((x1, v1), (x2, v2))| axis(x1) == axis(x2) && axis(v1) == axis(v2))
+            {
+                *period = Some(steps);
+            }
+        }
+    }
+    Ok(periods.into_iter().flatten().fold(1, math::lcm))
+}
+
+aoc_util::solution! {
+    year: 2019,
+    day: 12,
+    title: "The N-Body Problem",
+    part1: part1,
+    part2: part2,
+    examples: [
+        part1 => 183,
+        part2 => 2772,
+        part2("long") => 4_686_774_924,
+    ],
+}
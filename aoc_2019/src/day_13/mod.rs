@@ -0,0 +1,23 @@
+use crate::intcode_interpreter::IntcodeInterpreter;
+
+use aoc_util::error::{self, Error};
+
+use extended_io::pipe::{PipeRead, PipeWrite};
+
+fn part1(input: &str) -> error::Result<usize> {
+    let outputs =
+        IntcodeInterpreter::<PipeRead, PipeWrite>::parse_program(input)?.run_with_inputs(&[]);
+    // The game draws each tile as its x coordinate, its y coordinate, and its tile id, and the id
+    // of a block is 2.
+    Ok(outputs.chunks_exact(3).filter(|tile| tile[2] == 2).count())
+}
+
+fn part2(_input: &str) -> error::Result<u64> {
+    Err(Error::Unimplemented {
+        year: 2019,
+        day: 13,
+        part: Some(2),
+    })
+}
+
+aoc_util::register_day!(2019, 13, part1, part2, "Care Package");
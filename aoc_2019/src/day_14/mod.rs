@@ -0,0 +1,266 @@
+use std::{
+    cmp::Ordering,
+    collections::HashMap,
+    fmt::{self, Display, Formatter},
+    ops::Mul,
+};
+
+use nom::{
+    bytes::complete as bytes, character::complete as character, combinator as comb, multi,
+    sequence, IResult,
+};
+
+use aoc_util::{
+    error::{self, Error},
+    nom_extended::NomParse,
+};
+
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+struct Material(u64, String);
+
+impl Material {
+    fn amount(&self) -> u64 {
+        self.0
+    }
+
+    fn chemical(&self) -> &String {
+        &self.1
+    }
+}
+
+impl Display for Material {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.amount(), self.chemical())
+    }
+}
+
+impl Mul<u64> for Material {
+    type Output = Self;
+
+    fn mul(self, rhs: u64) -> Self::Output {
+        Self(self.amount() * rhs, self.chemical().clone())
+    }
+}
+
+impl<'a> Mul<&'a u64> for Material {
+    type Output = Self;
+
+    fn mul(self, rhs: &'a u64) -> Self::Output {
+        Self(self.amount() * rhs, self.chemical().clone())
+    }
+}
+
+impl<'a> Mul<u64> for &'a Material {
+    type Output = Material;
+
+    fn mul(self, rhs: u64) -> Self::Output {
+        Material(self.amount() * rhs, self.chemical().clone())
+    }
+}
+
+impl<'a, 'b> Mul<&'b u64> for &'a Material {
+    type Output = Material;
+
+    fn mul(self, rhs: &'b u64) -> Self::Output {
+        Material(self.amount() * rhs, self.chemical().clone())
+    }
+}
+
+impl<'s> NomParse<&'s str> for Material {
+    fn nom_parse(s: &'s str) -> IResult<&'s str, Self> {
+        comb::map(
+            sequence::separated_pair(
+                character::u64,
+                bytes::tag(" "),
+                multi::many1(character::one_of("ABCDEFGHIJKLMNOPQRSTUVWXYZ")),
+            ),
+            |(n, name)| Material(n, name.into_iter().collect()),
+        )(s)
+    }
+}
+
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+struct Reaction(Vec<Material>, Material);
+
+impl Reaction {
+    fn ingredients(&self) -> &[Material] {
+        &self.0[..]
+    }
+
+    fn result(&self) -> &Material {
+        &self.1
+    }
+}
+
+impl Display for Reaction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let ingredients = self
+            .ingredients()
+            .iter()
+            .map(<Material as ToString>::to_string)
+            .collect::<Vec<_>>()
+            .join(", ");
+        write!(f, "{} => {}", ingredients, self.result())
+    }
+}
+
+impl Mul<u64> for Reaction {
+    type Output = Self;
+
+    fn mul(self, rhs: u64) -> Self::Output {
+        Self(
+            self.ingredients().iter().map(|mat| mat * rhs).collect(),
+            self.result() * rhs,
+        )
+    }
+}
+
+impl<'a> Mul<&'a u64> for Reaction {
+    type Output = Self;
+
+    fn mul(self, rhs: &'a u64) -> Self::Output {
+        Self(
+            self.ingredients().iter().map(|mat| mat * rhs).collect(),
+            self.result() * rhs,
+        )
+    }
+}
+
+impl<'a> Mul<u64> for &'a Reaction {
+    type Output = Reaction;
+
+    fn mul(self, rhs: u64) -> Self::Output {
+        Reaction(
+            self.ingredients().iter().map(|mat| mat * rhs).collect(),
+            self.result() * rhs,
+        )
+    }
+}
+
+impl<'a, 'b> Mul<&'b u64> for &'a Reaction {
+    type Output = Reaction;
+
+    fn mul(self, rhs: &'b u64) -> Self::Output {
+        Reaction(
+            self.ingredients().iter().map(|mat| mat * rhs).collect(),
+            self.result() * rhs,
+        )
+    }
+}
+
+impl<'s> NomParse<&'s str> for Reaction {
+    fn nom_parse(s: &'s str) -> IResult<&'s str, Self> {
+        comb::map(
+            sequence::separated_pair(
+                multi::separated_list1(bytes::tag(", "), Material::nom_parse),
+                bytes::tag(" => "),
+                Material::nom_parse,
+            ),
+            |(ingredients, result)| Reaction(ingredients, result),
+        )(s)
+    }
+}
+
+aoc_util::impl_from_str_for_nom_parse!(Reaction);
+
+type Reactions = HashMap<String, Reaction>;
+
+fn parse_reactions(input: &str) -> error::Result<Reactions> {
+    input
+        .lines()
+        .map(|line| line.parse::<Reaction>().map_err(Error::parse))
+        .try_fold(Reactions::new(), |mut acc, x| {
+            let x = x?;
+            let chemical = x.result().chemical().clone();
+            if acc.insert(chemical.clone(), x).is_some() {
+                return Err(Error::parse(format!(
+                    "{chemical} is produced by two reactions"
+                )));
+            }
+            Ok(acc)
+        })
+}
+
+/// The amount of ORE that's required to produce `fuel` FUEL.
+fn ore_required(reactions: &Reactions, fuel: u64) -> error::Result<u64> {
+    let mut num_ore = 0;
+    let mut materials = HashMap::<String, _>::new();
+    let mut leftovers = HashMap::<String, _>::new();
+    materials.insert("FUEL".to_string(), fuel);
+    while let Some(producing) = materials.keys().next().cloned() {
+        let producing = Material(materials.remove(&producing).unwrap(), producing);
+        let reaction = reactions.get(producing.chemical()).ok_or_else(|| {
+            Error::parse(format!(
+                "FUEL requires unproducable material {}",
+                producing.chemical()
+            ))
+        })?;
+        // If the required amount of the chemical can be produced by a whole number of
+        // occurrences of the reaction, perform the reaction that many times. Otherwise, perform
+        // the reaction as many times as possible without producing more of the chemical than
+        // required then perform the reaction one more time.
+        let repeats = producing.amount().div_ceil(reaction.result().amount());
+        let reaction = reaction * repeats;
+        for ingredient in reaction.ingredients() {
+            let chemical = ingredient.chemical().clone();
+            let material_count = leftovers.remove(&chemical).unwrap_or(0);
+            if &chemical == "ORE" {
+                num_ore += ingredient.amount();
+                continue;
+            }
+            match material_count.cmp(&ingredient.amount()) {
+                // More of the chemical is required for the reaction than previous reactions have
+                // left over.
+                Ordering::Less => {
+                    let entry = materials.entry(chemical).or_insert(0);
+                    *entry += ingredient.amount() - material_count;
+                }
+                // Previous reactions have left exactly enough of the chemical for the reaction.
+                Ordering::Equal => {}
+                // Previous reactions have left more than enough of the chemical for the reaction.
+                Ordering::Greater => {
+                    leftovers.insert(chemical, material_count - ingredient.amount());
+                }
+            }
+        }
+        // `reaction` is the smallest multiple of the canonical reaction that produces at least the
+        // required amount of the chemical, so anything beyond the required amount is left over.
+        let extra = reaction.result().amount() - producing.amount();
+        if extra > 0 {
+            *leftovers.entry(producing.chemical().clone()).or_insert(0) += extra;
+        }
+    }
+    Ok(num_ore)
+}
+
+fn part1(reactions: &Reactions) -> error::Result<u64> {
+    ore_required(reactions, 1)
+}
+
+fn part2(reactions: &Reactions) -> error::Result<u64> {
+    const ORE: u64 = 1_000_000_000_000;
+    // Every FUEL takes at least 1 ORE, so the answer is at most `ORE`.
+    let (mut low, mut high) = (0, ORE);
+    while low < high {
+        let mid = low + (high - low + 1) / 2;
+        if ore_required(reactions, mid)? <= ORE {
+            low = mid;
+        } else {
+            high = mid - 1;
+        }
+    }
+    Ok(low)
+}
+
+aoc_util::solution! {
+    year: 2019,
+    day: 14,
+    title: "Space Stoichiometry",
+    parse: parse_reactions,
+    part1: part1,
+    part2: part2,
+    examples: [
+        part1 => 13_312,
+        part2 => 82_892_753,
+    ],
+}
@@ -0,0 +1,19 @@
+use aoc_util::error::{self, Error};
+
+fn part1(_input: &str) -> error::Result<u32> {
+    Err(Error::Unimplemented {
+        year: 2019,
+        day: 15,
+        part: Some(1),
+    })
+}
+
+fn part2(_input: &str) -> error::Result<u32> {
+    Err(Error::Unimplemented {
+        year: 2019,
+        day: 15,
+        part: Some(2),
+    })
+}
+
+aoc_util::register_day!(2019, 15, part1, part2, "Oxygen System");
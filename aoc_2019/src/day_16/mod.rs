@@ -0,0 +1,98 @@
+use std::hint::unreachable_unchecked;
+
+use aoc_util::error::{self, Error};
+
+// O(1)
+fn calc_fft(i: usize, j: usize) -> Option<i32> {
+    match j / i {
+        e if e % 2 == 0 => None,
+        e if e % 4 == 1 => Some(1),
+        e if e % 4 == 3 => Some(-1),
+        _ => unsafe { unreachable_unchecked() },
+    }
+}
+
+// O(n**2)
+fn run_fft(digits: &[i32]) -> Vec<i32> {
+    (1..=digits.len())
+        .map(|i| {
+            // if (i - 1) % 50 == 0 {
+            //     println!("Producing digit {}", i);
+            // }
+            // O(n - i)
+            (i..=digits.len())
+                .filter_map(|j| Some(digits[j - 1] * calc_fft(i, j)?))
+                .sum::<i32>()
+                .abs()
+                % 10
+        })
+        .collect()
+}
+
+fn parse_digits(input: &str) -> error::Result<Vec<i32>> {
+    let digits = input
+        .trim()
+        .chars()
+        .map(|c| {
+            c.to_digit(10)
+                .map(|digit| digit as i32)
+                .ok_or_else(|| Error::parse(format!("Invalid digit {c:?}")))
+        })
+        .collect::<error::Result<Vec<_>>>()?;
+    if digits.len() < 8 {
+        return Err(Error::parse(
+            "The signal is shorter than the 8 digit message",
+        ));
+    }
+    Ok(digits)
+}
+
+fn message(digits: &[i32]) -> String {
+    digits[..8].iter().map(|digit| digit.to_string()).collect()
+}
+
+fn part1(input: &str) -> error::Result<String> {
+    let digits = (0..100).fold(parse_digits(input)?, |digits, _| run_fft(&digits));
+    Ok(message(&digits))
+}
+
+fn part2(input: &str) -> error::Result<String> {
+    let digits = parse_digits(input)?;
+    let offset = digits[..7]
+        .iter()
+        .copied()
+        .fold(0usize, |acc, x| acc * 10 + x as usize);
+    let len = digits.len() * 10_000;
+    if offset <= len / 2 || offset + 8 > len {
+        return Err(Error::UnsolvableInput(format!(
+            "The offset {offset} isn't in the second half of the {len} digits"
+        )));
+    }
+    // In the second half of the signal, every digit of the pattern from the digit being produced
+    // onward is 1, so each phase replaces each digit with the last digit of the sum of it and all
+    // of the digits after it. This method by "paul2718" on Reddit:
+    // <https://old.reddit.com/r/adventofcode/comments/ebf5cy/2019_day_16_part_2_understanding_how_to_come_up/fb4bvw4/>
+    let mut digits = aoc_iter::cycle_bounded(10_000, digits.into_iter())
+        .skip(offset)
+        .collect::<Vec<_>>();
+    for _ in 0..100 {
+        let mut sum = 0;
+        for digit in digits.iter_mut().rev() {
+            sum = (sum + *digit) % 10;
+            *digit = sum;
+        }
+    }
+    Ok(message(&digits))
+}
+
+aoc_util::solution! {
+    year: 2019,
+    day: 16,
+    title: "Flawed Frequency Transmission",
+    part1: part1,
+    part2: part2,
+    examples: [
+        part1 => "24176176",
+        part2("offset") => "84462026",
+    ],
+}
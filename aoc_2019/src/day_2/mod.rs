@@ -0,0 +1,43 @@
+use super::intcode_interpreter::IntcodeInterpreter;
+
+use aoc_util::error::{self, Error};
+
+use extended_io::pipe::{PipeRead, PipeWrite};
+
+fn parse_program(input: &str) -> error::Result<Vec<i64>> {
+    input
+        .trim()
+        .split(',')
+        .map(|s| {
+            s.parse()
+                .map_err(|e| Error::parse(format!("Invalid line {s:?}: {e:?}")))
+        })
+        .collect()
+}
+
+fn run_with(prog: &[i64], noun: i64, verb: i64) -> i64 {
+    let mut prog = prog.to_vec();
+    prog[1] = noun;
+    prog[2] = verb;
+    IntcodeInterpreter::<PipeRead, PipeWrite>::from(prog).run()
+}
+
+fn part1(input: &str) -> error::Result<i64> {
+    Ok(run_with(&parse_program(input)?, 12, 2))
+}
+
+fn part2(input: &str) -> error::Result<i64> {
+    let prog = parse_program(input)?;
+    for noun in 0..100 {
+        for verb in 0..100 {
+            if run_with(&prog, noun, verb) == 19690720 {
+                return Ok(100 * noun + verb);
+            }
+        }
+    }
+    Err(Error::UnsolvableInput(
+        "No noun and verb produce 19690720".to_string(),
+    ))
+}
+
+aoc_util::register_day!(2019, 2, part1, part2, "1202 Program Alarm");
@@ -1,9 +1,10 @@
-use aoc_util::nom_extended::NomParse;
+use aoc_util::{
+    error::{self, Error},
+    nom_extended::NomParse,
+};
 
 use std::{
     collections::{HashMap, HashSet},
-    fs::File,
-    io::{self, BufRead, BufReader},
     mem,
 };
 
@@ -142,44 +143,50 @@ impl<'s> NomParse<&'s str> for Wire {
 
 aoc_util::impl_from_str_for_nom_parse!(Wire);
 
-pub(super) fn run() -> io::Result<()> {
-    {
-        // Part 1
-        let mut wires = BufReader::new(File::open("2019_3.txt")?)
-            .lines()
-            .map(|line| {
-                line?
-                    .parse::<Wire>()
-                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
-            });
-        let wire1 = wires.next().expect("Missing first wire")?;
-        let wire2 = wires.next().expect("Missing second wire")?;
-        let mut intersections = wire1
-            .intersections(&wire2)
-            .into_iter()
-            .map(|(p, _)| p.manhattan_distance_o())
-            .collect::<Vec<_>>();
-        intersections.sort_unstable();
-        println!("Minimum intersection distance is {}", intersections[0]);
-    }
-    {
-        // Part 2
-        let mut wires = BufReader::new(File::open("2019_3.txt")?)
-            .lines()
-            .map(|line| {
-                line?
-                    .parse::<Wire>()
-                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
-            });
-        let wire1 = wires.next().expect("Missing first wire")?;
-        let wire2 = wires.next().expect("Missing second wire")?;
-        let mut intersections = wire1
-            .intersections(&wire2)
-            .into_iter()
-            .map(|x| x.1)
-            .collect::<Vec<_>>();
-        intersections.sort_unstable();
-        println!("Minimum combined steps is {}", intersections[0]);
-    }
-    Ok(())
+fn parse_wires(input: &str) -> error::Result<(Wire, Wire)> {
+    let mut wires = input.lines().map(|line| {
+        line.parse::<Wire>()
+            .map_err(|e| Error::parse(e.to_string()))
+    });
+    let wire1 = wires
+        .next()
+        .ok_or_else(|| Error::parse("Missing first wire"))??;
+    let wire2 = wires
+        .next()
+        .ok_or_else(|| Error::parse("Missing second wire"))??;
+    Ok((wire1, wire2))
+}
+
+fn part1(input: &str) -> error::Result<u64> {
+    let (wire1, wire2) = parse_wires(input)?;
+    wire1
+        .intersections(&wire2)
+        .into_iter()
+        .map(|(p, _)| p.manhattan_distance_o())
+        .min()
+        .ok_or_else(|| Error::UnsolvableInput("The wires don't cross".to_string()))
+}
+
+fn part2(input: &str) -> error::Result<u32> {
+    let (wire1, wire2) = parse_wires(input)?;
+    wire1
+        .intersections(&wire2)
+        .into_iter()
+        .map(|(_, steps)| steps)
+        .min()
+        .ok_or_else(|| Error::UnsolvableInput("The wires don't cross".to_string()))
+}
+
+aoc_util::solution! {
+    year: 2019,
+    day: 3,
+    title: "Crossed Wires",
+    part1: part1,
+    part2: part2,
+    examples: [
+        part1 => 159,
+        part1("far") => 135,
+        part2 => 610,
+        part2("far") => 410,
+    ],
 }
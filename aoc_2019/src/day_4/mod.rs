@@ -0,0 +1,51 @@
+use aoc_util::{
+    error::{self, Error},
+    math,
+};
+use std::ops::Range;
+
+use nom::{
+    bytes::complete as bytes, character::complete as character, combinator as comb, sequence,
+    IResult,
+};
+
+fn parse_range(s: &str) -> IResult<&str, Range<u32>> {
+    comb::map(
+        sequence::separated_pair(character::u32, bytes::tag("-"), character::u32),
+        |(least, most)| least..most,
+    )(s)
+}
+
+fn possible_pw(pw: u32) -> bool {
+    let digits = math::digits(pw.into());
+    let is_valid_len = digits.len() == 6;
+    let has_pair = digits.windows(2).any(|pair| pair[0] == pair[1]);
+    let is_mono_inc = digits.windows(2).all(|pair| pair[0] <= pair[1]);
+    is_valid_len && has_pair && is_mono_inc
+}
+
+fn possible_pw_modified(pw: u32) -> bool {
+    let is_valid_old = possible_pw(pw);
+    let has_pair = math::digits(pw.into())
+        .chunk_by(|a, b| a == b)
+        .any(|run| run.len() == 2);
+    is_valid_old && has_pair
+}
+
+fn get_range(input: &str) -> error::Result<Range<u32>> {
+    parse_range(input.trim())
+        .map(|(_, range)| range)
+        .map_err(|e| Error::parse(e.to_string()))
+}
+
+fn part1(input: &str) -> error::Result<usize> {
+    Ok(get_range(input)?.filter(|&pw| possible_pw(pw)).count())
+}
+
+fn part2(input: &str) -> error::Result<usize> {
+    Ok(get_range(input)?
+        .filter(|&pw| possible_pw_modified(pw))
+        .count())
+}
+
+aoc_util::register_day!(2019, 4, part1, part2, "Secure Container");
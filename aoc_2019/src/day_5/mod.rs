@@ -0,0 +1,31 @@
+use crate::intcode_interpreter::IntcodeInterpreter;
+
+use aoc_util::error::{self, Error};
+
+use extended_io::pipe::{PipeRead, PipeWrite};
+
+/// Runs the diagnostic program for the system with ID `system_id` and returns the diagnostic code
+/// that it outputs after all of its tests have passed.
+fn diagnose(input: &str, system_id: i64) -> error::Result<i64> {
+    let outputs = IntcodeInterpreter::<PipeRead, PipeWrite>::parse_program(input)?
+        .run_with_inputs(&[system_id]);
+    match outputs.split_last() {
+        Some((&code, tests)) if tests.iter().all(|&test| test == 0) => Ok(code),
+        Some((_, tests)) => Err(Error::UnsolvableInput(format!(
+            "Diagnostic tests failed: {tests:?}"
+        ))),
+        None => Err(Error::UnsolvableInput(
+            "The program didn't output a diagnostic code".to_string(),
+        )),
+    }
+}
+
+fn part1(input: &str) -> error::Result<i64> {
+    diagnose(input, 1)
+}
+
+fn part2(input: &str) -> error::Result<i64> {
+    diagnose(input, 5)
+}
+
+aoc_util::register_day!(2019, 5, part1, part2, "Sunny with a Chance of Asteroids");
@@ -1,8 +1,6 @@
-use std::{
-    fs::File,
-    io::{self, BufRead, BufReader},
-};
+use aoc_util::error::{self, Error};
 
+#[derive(Debug)]
 struct Body {
     name: String,
     orbiters: Vec<Body>,
@@ -91,50 +89,46 @@ impl Default for Body {
     }
 }
 
-fn get_orbits() -> io::Result<Body> {
-    let mut orbits: Box<dyn Iterator<Item = (String, String)>> = Box::new(
-        BufReader::new(File::open("2019_6.txt")?)
-            .lines()
-            .map(|s| {
-                let s = s?;
-                let mut strs = s.split(')').map(|s| s.to_owned());
-                let parent = strs.next().unwrap();
-                let child = strs.next().unwrap();
-                Ok((parent, child))
-            })
-            .collect::<io::Result<Vec<_>>>()?
-            .into_iter(),
-    );
+fn get_orbits(input: &str) -> error::Result<Body> {
+    let mut orbits = input
+        .lines()
+        .map(|s| {
+            let (parent, child) = s
+                .split_once(')')
+                .ok_or_else(|| Error::parse(format!("Invalid orbit {s:?}")))?;
+            Ok((parent.to_owned(), child.to_owned()))
+        })
+        .collect::<error::Result<Vec<_>>>()?;
     let mut com = Body::default();
-    loop {
-        let mut missed = vec![];
-        for (parent, child) in orbits {
-            if !com.add(parent.clone(), child.clone()) {
-                missed.push((parent, child));
-            }
-        }
-        if missed.is_empty() {
-            break;
-        } else {
-            orbits = Box::new(missed.into_iter());
+    while !orbits.is_empty() {
+        let num_orbits = orbits.len();
+        orbits.retain(|(parent, child)| !com.add(parent.clone(), child.clone()));
+        if orbits.len() == num_orbits {
+            let msg = format!("Orbits {orbits:?} aren't connected to COM");
+            return Err(Error::parse(msg));
         }
     }
     Ok(com)
 }
 
-pub(super) fn run() -> io::Result<()> {
-    println!("Building map...");
-    let com = get_orbits()?;
-    {
-        println!("Year 2019 Day 6 Part 1");
-        println!("There are {} orbits", com.num_orbits());
-    }
-    {
-        println!("Year 2019 Day 6 Part 2");
-        println!(
-            "You are {} transfers away from Santa",
-            com.distance_from("SAN").unwrap(),
-        );
-    }
-    Ok(())
+fn part1(com: &Body) -> error::Result<u32> {
+    Ok(com.num_orbits())
+}
+
+fn part2(com: &Body) -> error::Result<u32> {
+    com.distance_from("SAN")
+        .ok_or_else(|| Error::UnsolvableInput("Couldn't find both YOU and SAN".to_string()))
+}
+
+aoc_util::solution! {
+    year: 2019,
+    day: 6,
+    title: "Universal Orbit Map",
+    parse: get_orbits,
+    part1: part1,
+    part2: part2,
+    examples: [
+        part1 => 42,
+        part2("transfer") => 4,
+    ],
 }
@@ -0,0 +1,95 @@
+use crate::intcode_interpreter::IntcodeInterpreter;
+
+use std::thread;
+
+use aoc_util::{
+    error::{self, Error},
+    math,
+};
+
+use extended_io::{
+    self as eio,
+    pipe::{self, PipeRead, PipeWrite},
+};
+
+type Amplifier = IntcodeInterpreter<PipeRead, PipeWrite>;
+
+/// Runs the amplifiers in series once with the phase settings `phases` and returns the signal that
+/// the last amplifier sends to the thrusters.
+fn run_series(amplifier_controller: &Amplifier, phases: &[i64]) -> error::Result<i64> {
+    phases.iter().try_fold(0, |signal, &phase| {
+        match amplifier_controller.run_with_inputs(&[phase, signal])[..] {
+            [signal] => Ok(signal),
+            ref outputs => Err(Error::UnsolvableInput(format!(
+                "Amplifier sent {outputs:?} instead of a single signal"
+            ))),
+        }
+    })
+}
+
+/// Runs the amplifiers in a feedback loop with the phase settings `phases` until they all halt and
+/// returns the last signal that the last amplifier sends to the thrusters.
+fn run_feedback_loop(amplifier_controller: &Amplifier, phases: &[i64]) -> error::Result<i64> {
+    let (mut e_to_a_read, mut e_to_a_write) = pipe::mk_pipe();
+    let (a_to_b_read, mut a_to_b_write) = pipe::mk_pipe();
+    let (b_to_c_read, mut b_to_c_write) = pipe::mk_pipe();
+    let (c_to_d_read, mut c_to_d_write) = pipe::mk_pipe();
+    let (d_to_e_read, mut d_to_e_write) = pipe::mk_pipe();
+
+    eio::write_i64(&mut e_to_a_write, phases[0])?;
+    eio::write_i64(&mut e_to_a_write, 0)?;
+    eio::write_i64(&mut a_to_b_write, phases[1])?;
+    eio::write_i64(&mut b_to_c_write, phases[2])?;
+    eio::write_i64(&mut c_to_d_write, phases[3])?;
+    eio::write_i64(&mut d_to_e_write, phases[4])?;
+
+    let amplifiers = [
+        amplifier_controller.dup_with(e_to_a_read.clone(), a_to_b_write),
+        amplifier_controller.dup_with(a_to_b_read, b_to_c_write),
+        amplifier_controller.dup_with(b_to_c_read, c_to_d_write),
+        amplifier_controller.dup_with(c_to_d_read, d_to_e_write),
+        amplifier_controller.dup_with(d_to_e_read, e_to_a_write),
+    ];
+    let threads = amplifiers
+        .into_iter()
+        .zip('a'..)
+        .map(|(amplifier, name)| {
+            thread::Builder::new()
+                .name(format!("2019::7::thread_{name}"))
+                .spawn(move || amplifier.run_piped())
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    for thread in threads {
+        let name = thread.thread().name().unwrap_or_default().to_string();
+        if let Err(e) = thread.join() {
+            match e.downcast_ref::<String>() {
+                Some(message) => panic!("[{name}] {message}"),
+                None => panic!("[{name}] {e:?}"),
+            }
+        }
+    }
+    Ok(eio::read_i64(&mut e_to_a_read)?)
+}
+
+/// Returns the highest thrust that the amplifiers in `input` can produce when run by `run` with
+/// some ordering of the phase settings `phases`.
+fn max_thrust(
+    input: &str,
+    phases: [i64; 5],
+    run: fn(&Amplifier, &[i64]) -> error::Result<i64>,
+) -> error::Result<i64> {
+    let amplifier_controller = Amplifier::parse_program(input)?;
+    math::permutations(phases)
+        .map(|perm| run(&amplifier_controller, &perm))
+        .try_fold(i64::MIN, |max, thrust| Ok(max.max(thrust?)))
+}
+
+fn part1(input: &str) -> error::Result<i64> {
+    max_thrust(input, [0, 1, 2, 3, 4], run_series)
+}
+
+fn part2(input: &str) -> error::Result<i64> {
+    max_thrust(input, [5, 6, 7, 8, 9], run_feedback_loop)
+}
+
+aoc_util::register_day!(2019, 7, part1, part2, "Amplification Circuit");
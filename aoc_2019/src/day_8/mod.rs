@@ -0,0 +1,101 @@
+use aoc_util::{
+    error::{self, Error},
+    nom_extended::NomParse,
+};
+
+use nom::{character::complete as character, combinator as comb, multi, IResult};
+
+#[derive(Clone, Copy, Debug)]
+struct SIFLayer {
+    pixels: [[u8; 25]; 6],
+}
+
+impl<'s> NomParse<&'s str> for SIFLayer {
+    fn nom_parse(s: &'s str) -> IResult<&'s str, Self> {
+        comb::map(
+            multi::count(multi::count(character::one_of("0123456789"), 25), 6),
+            |pixels_vec| {
+                let mut pixels = [[0; 25]; 6];
+                for row in 0..6 {
+                    for col in 0..25 {
+                        let mut s = String::new();
+                        s.push(pixels_vec[row][col]);
+                        pixels[row][col] = s.parse().unwrap();
+                    }
+                }
+                Self { pixels }
+            },
+        )(s)
+    }
+}
+
+#[derive(Clone, Debug)]
+struct SpaceImageFormat {
+    layers: Vec<SIFLayer>,
+}
+
+impl<'s> NomParse<&'s str> for SpaceImageFormat {
+    fn nom_parse(s: &'s str) -> IResult<&'s str, Self> {
+        comb::map(multi::many1(SIFLayer::nom_parse), |layers| Self { layers })(s)
+    }
+}
+
+aoc_util::impl_from_str_for_nom_parse!(SpaceImageFormat);
+
+fn parse_image(input: &str) -> error::Result<SpaceImageFormat> {
+    input.trim().parse().map_err(|e: String| Error::parse(e))
+}
+
+fn part1(pic: &SpaceImageFormat) -> error::Result<u32> {
+    let layer = pic
+        .layers
+        .iter()
+        .min_by_key(|layer| {
+            layer
+                .pixels
+                .iter()
+                .flatten()
+                .filter(|&&pixel| pixel == 0)
+                .count()
+        })
+        .ok_or_else(|| Error::parse("The image has no layers"))?;
+    let mut num_ones = 0;
+    let mut num_twos = 0;
+    for row in &layer.pixels {
+        for &pixel in row {
+            match pixel {
+                1 => num_ones += 1,
+                2 => num_twos += 1,
+                _ => {}
+            }
+        }
+    }
+    Ok(num_ones * num_twos)
+}
+
+fn part2(pic: &SpaceImageFormat) -> error::Result<String> {
+    let mut result = [[2; 25]; 6];
+    for layer in &pic.layers {
+        for (result_row, layer_row) in result.iter_mut().zip(&layer.pixels) {
+            result_row
+                .iter_mut()
+                .zip(layer_row)
+                .filter(|(result_cell, _)| **result_cell == 2)
+                .for_each(|(result_cell, layer_cell)| *result_cell = *layer_cell)
+        }
+    }
+    let mut image = String::new();
+    for row in &result {
+        for pixel in row {
+            match pixel {
+                0 => image.push(' '),
+                1 => image.push('X'),
+                _ => return Err(Error::UnsolvableInput(format!("Invalid pixel: {pixel}"))),
+            }
+        }
+        image.push('\n');
+    }
+    Ok(image)
+}
+
+aoc_util::register_day!(2019, 8, parse_image, part1, part2, "Space Image Format");
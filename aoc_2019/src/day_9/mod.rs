@@ -0,0 +1,27 @@
+use aoc_util::error::{self, Error};
+
+use extended_io::pipe::{PipeRead, PipeWrite};
+
+use super::intcode_interpreter::IntcodeInterpreter;
+
+/// Runs the BOOST program in mode `mode` and returns the single value that it outputs.
+fn boost(input: &str, mode: i64) -> error::Result<i64> {
+    match IntcodeInterpreter::<PipeRead, PipeWrite>::parse_program(input)?.run_with_inputs(&[mode])
+        [..]
+    {
+        [value] => Ok(value),
+        ref outputs => Err(Error::UnsolvableInput(format!(
+            "Expected a single output but got {outputs:?}"
+        ))),
+    }
+}
+
+fn part1(input: &str) -> error::Result<i64> {
+    boost(input, 1)
+}
+
+fn part2(input: &str) -> error::Result<i64> {
+    boost(input, 2)
+}
+
+aoc_util::register_day!(2019, 9, part1, part2, "Sensor Boost");
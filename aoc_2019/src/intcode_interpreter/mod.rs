@@ -2,11 +2,13 @@ use std::{
     convert::{TryFrom, TryInto},
     io::{self, BufRead, Write},
     ops::{Index, IndexMut},
-    path::Path,
     str::FromStr,
 };
 
-use aoc_util::nom_extended::NomParse;
+use aoc_util::{
+    error::{self, Error},
+    nom_extended::NomParse,
+};
 
 use nom::{
     bytes::complete as bytes, character::complete as character, combinator as comb, multi, IResult,
@@ -186,6 +188,7 @@ where
 
 impl IntcodeInterpreter<PipeRead, PipeWrite> {
     pub fn run_piped(mut self) -> i64 {
+        let _span = tracing::info_span!("intcode").entered();
         loop {
             let instr = self.prog[self.pc];
             if self.debug {
@@ -310,13 +313,8 @@ where
         }
     }
 
-    pub fn read_from_file<P>(path: P) -> io::Result<Self>
-    where
-        P: AsRef<Path>,
-    {
-        std::fs::read_to_string(path)?
-            .parse()
-            .map_err(|e| io::Error::new(io::ErrorKind::Other, e))
+    pub fn parse_program(input: &str) -> error::Result<Self> {
+        input.trim().parse().map_err(Error::parse)
     }
 
     pub fn dup<R1, W1>(&self) -> IntcodeInterpreter<R1, W1>
@@ -340,6 +338,22 @@ where
         ret
     }
 
+    /// Runs a copy of the program which reads `inputs` instead of stdin and returns everything that
+    /// it writes.
+    pub fn run_with_inputs(&self, inputs: &[i64]) -> Vec<i64> {
+        let input = inputs
+            .iter()
+            .map(|value| value.to_string() + "\n")
+            .collect::<String>();
+        let mut output = Vec::new();
+        self.dup_with(input.as_bytes(), &mut output).run();
+        String::from_utf8(output)
+            .expect("Intcode output is always ASCII")
+            .lines()
+            .map(|line| line.parse().expect("Intcode output is always a number"))
+            .collect()
+    }
+
     pub fn get_program(&self) -> IntcodeProgram {
         self.prog.clone()
     }
@@ -387,6 +401,7 @@ where
     }
 
     pub fn run(mut self) -> i64 {
+        let _span = tracing::info_span!("intcode").entered();
         loop {
             let instr = self.prog[self.pc];
             if self.debug {
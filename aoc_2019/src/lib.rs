@@ -0,0 +1,37 @@
+use aoc_util::registry::Day;
+
+mod day_1;
+mod day_2;
+mod day_3;
+mod day_4;
+mod day_5;
+mod day_6;
+mod day_7;
+mod day_8;
+mod day_9;
+
+mod day_10;
+mod day_11;
+mod day_12;
+mod day_13;
+mod day_14;
+mod day_15;
+mod day_16;
+mod day_17;
+mod day_18;
+mod day_19;
+mod day_20;
+mod day_21;
+mod day_22;
+mod day_23;
+mod day_24;
+mod day_25;
+
+/// An interpreter for the intcode programs that many of the puzzles of 2019 are built around.
+pub mod intcode_interpreter;
+mod robot;
+
+/// Every day of 2019 which has a registered solution, sorted by day.
+pub fn days() -> Vec<&'static Day> {
+    aoc_util::registry::days_in(2019)
+}
@@ -173,8 +173,8 @@ impl Robot {
         self.painted.len()
     }
 
-    pub fn print_field(&self) {
-        print!("{}", self.field.to_string());
+    pub fn field(&self) -> String {
+        self.field.to_string()
     }
 
     fn try_read<T>(&mut self) -> io::Result<T>
@@ -1,25 +1,18 @@
-use std::{
-    cmp::Ordering,
-    fs::File,
-    io::{self, BufRead, BufReader},
-    path::Path,
-};
+use aoc_util::error::{self, Error};
+use std::cmp::Ordering;
 
+#[derive(Debug)]
 struct Expenses {
     ends: Vec<Vec<u32>>,
 }
 
 impl Expenses {
-    fn read_from_file(filename: impl AsRef<Path>) -> io::Result<Self> {
+    fn parse(input: &str) -> error::Result<Self> {
         let mut ends = vec![vec![]; 10];
-        BufReader::new(File::open(filename)?)
+        input
             .lines()
-            .map(|line| {
-                line?
-                    .parse()
-                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
-            })
-            .try_for_each::<_, io::Result<_>>(|value| {
+            .map(|line| line.parse::<u32>().map_err(|e| Error::parse(e.to_string())))
+            .try_for_each::<_, error::Result<_>>(|value| {
                 let value = value?;
                 ends[(value % 10) as usize].push(value);
                 Ok(())
@@ -143,25 +136,33 @@ impl Expenses {
     }
 }
 
-pub(super) fn run() -> io::Result<()> {
-    let expenses = Expenses::read_from_file("2020_01.txt")?;
-    {
-        println!("2020 Day 1 Part 1");
-        if let Some((v1, v2)) = expenses.find_pair_sum(2020) {
-            println!("Values are {} and {}. Their product is {}", v1, v2, v1 * v2);
-        }
-    }
-    {
-        println!("2020 Day 1 Part 2");
-        if let Some((v1, v2, v3)) = expenses.find_triple_sum(2020) {
-            println!(
-                "Values are {}, {}, and {}. Their product is {}",
-                v1,
-                v2,
-                v3,
-                v1 * v2 * v3
-            );
-        }
-    }
-    Ok(())
+fn no_solution(count: &str) -> Error {
+    Error::UnsolvableInput(format!("No {count} of expenses sums to 2020"))
+}
+
+fn part1(expenses: &Expenses) -> error::Result<u32> {
+    let (v1, v2) = expenses
+        .find_pair_sum(2020)
+        .ok_or_else(|| no_solution("pair"))?;
+    Ok(v1 * v2)
+}
+
+fn part2(expenses: &Expenses) -> error::Result<u32> {
+    let (v1, v2, v3) = expenses
+        .find_triple_sum(2020)
+        .ok_or_else(|| no_solution("triple"))?;
+    Ok(v1 * v2 * v3)
+}
+
+aoc_util::solution! {
+    year: 2020,
+    day: 1,
+    title: "Report Repair",
+    parse: Expenses::parse,
+    part1: part1,
+    part2: part2,
+    examples: [
+        part1 => 514_579,
+        part2 => 241_861_950,
+    ],
 }
@@ -1,8 +1,5 @@
-use std::{
-    collections::HashMap,
-    fs::File,
-    io::{self, BufRead, BufReader},
-};
+use aoc_util::error::{self, Error};
+use std::collections::HashMap;
 
 fn count_arrangements(adapters: &[u32]) -> u64 {
     fn delegate(adapters: &[u32], memoizer: &mut HashMap<usize, u64>) -> u64 {
@@ -41,43 +38,50 @@ fn count_arrangements(adapters: &[u32]) -> u64 {
     delegate(adapters, &mut HashMap::new())
 }
 
-pub(super) fn run() -> io::Result<()> {
-    let adapters = {
-        let mut res = BufReader::new(File::open("2020_10.txt")?)
-            .lines()
-            .map(|line| {
-                line?
-                    .parse::<u32>()
-                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
-            })
-            .chain([Ok(0)])
-            .collect::<io::Result<Vec<_>>>()?;
-        res.sort_unstable();
-        res.push(res.last().unwrap() + 3);
-        res
-    };
-    {
-        println!("Year 2020 Day 10 Part 1");
-        let (num_ones, num_threes) = adapters.windows(2)
-            .fold((0, 0), |(num_ones, num_threes), window| {
+/// Parses the adapters in `input` and orders them along with the outlet and the device.
+fn parse_adapters(input: &str) -> error::Result<Vec<u32>> {
+    let mut res = input
+        .lines()
+        .map(|line| line.parse::<u32>().map_err(|e| Error::parse(e.to_string())))
+        .chain([Ok(0)])
+        .collect::<error::Result<Vec<_>>>()?;
+    res.sort_unstable();
+    res.push(res.last().unwrap() + 3);
+    Ok(res)
+}
+
+fn part1(input: &str) -> error::Result<u32> {
+    let (num_ones, num_threes) =
+        parse_adapters(input)?
+            .windows(2)
+            .try_fold((0, 0), |(num_ones, num_threes), window| {
                 match window[1] - window[0] {
-                    1 => (num_ones + 1, num_threes),
-                    2 => (num_ones, num_threes),
-                    3 => (num_ones, num_threes + 1),
-                    delta => unreachable!("Can't create a working chain if there is a difference of {} jolts between two consecutive adapters", delta),
+                    1 => Ok((num_ones + 1, num_threes)),
+                    2 => Ok((num_ones, num_threes)),
+                    3 => Ok((num_ones, num_threes + 1)),
+                    delta => Err(Error::UnsolvableInput(format!(
+                "Can't create a working chain if there is a difference of {delta} jolts between \
+                 two consecutive adapters"
+            ))),
                 }
-            });
-        println!(
-            "There are {} 1-jolt differences and {} 3-jolt differences. Their product is {}",
-            num_ones,
-            num_threes,
-            num_ones * num_threes,
-        );
-    }
-    {
-        println!("Year 2020 Day 10 Part 2");
-        let num_sets = count_arrangements(&adapters);
-        println!("There are {num_sets} sets of adapters which can charge the device");
-    }
-    Ok(())
+            })?;
+    Ok(num_ones * num_threes)
+}
+
+fn part2(input: &str) -> error::Result<u64> {
+    Ok(count_arrangements(&parse_adapters(input)?))
+}
+
+aoc_util::solution! {
+    year: 2020,
+    day: 10,
+    title: "Adapter Array",
+    part1: part1,
+    part2: part2,
+    examples: [
+        part1 => 35,
+        part1("large") => 220,
+        part2 => 8,
+        part2("large") => 19_208,
+    ],
 }
@@ -1,10 +1,12 @@
-use aoc_util::nom_extended::NomParse;
-use nom::{branch, character::complete as character, combinator as comb, multi, sequence, IResult};
-use std::{
-    fmt::{self, Debug, Formatter},
-    fs, io,
-    ops::Deref,
+use aoc_util::{
+    automata,
+    error::{self, Error},
+    geometry::Point2D,
+    grid::{Grid, ALL_AROUND},
+    nom_extended::NomParse,
 };
+use nom::{branch, character::complete as character, combinator as comb, IResult};
+use std::fmt::{self, Debug, Formatter};
 
 #[derive(Clone, Copy, Eq, PartialEq)]
 enum Tile {
@@ -27,26 +29,6 @@ impl Tile {
             Self::Floor => false,
         }
     }
-
-    fn leave(&mut self) -> bool {
-        match *self {
-            Self::Floor | Self::EmptyChair => false,
-            Self::OccupiedChair => {
-                *self = Self::EmptyChair;
-                true
-            }
-        }
-    }
-
-    fn occupy(&mut self) -> bool {
-        match *self {
-            Self::Floor | Self::OccupiedChair => false,
-            Self::EmptyChair => {
-                *self = Self::OccupiedChair;
-                true
-            }
-        }
-    }
 }
 
 impl Debug for Tile {
@@ -73,148 +55,83 @@ impl<'s> NomParse<&'s str> for Tile {
     }
 }
 
-trait OccupationBehavior<TileRow>
-where
-    Self: Debug,
-    TileRow: Deref<Target = [Tile]>,
-{
-    /// Determine whether the tile at `tiles[row][column]` should switch from `Tile::OccupiedChair`
-    /// to `Tile::UnoccupiedChair` or vice versa.
-    fn update_tile(&self, row: usize, column: usize, tiles: &[TileRow]) -> bool;
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum OccupationBehavior {
+    /// The basic occupation behavior for part 1, where people look at the eight seats around them.
+    Basic,
+    /// The line-of-sight occupation behavior for part 2, where people look at the first seat they
+    /// can see in each of the eight directions.
+    LineOfSight,
 }
 
-/// The basic occupation behavior for part 1.
-#[derive(Clone, Copy, Debug)]
-struct BasicOccupationBehavior;
-
-impl OccupationBehavior<Vec<Tile>> for BasicOccupationBehavior {
-    fn update_tile(&self, row: usize, column: usize, tiles: &[Vec<Tile>]) -> bool {
-        if !tiles[row][column].is_seat() {
-            false
-        } else {
-            let left_column = column.checked_sub(1);
-            let upper_row = row.checked_sub(1);
-            let right_column = column
-                .checked_add(1)
-                .filter(|&column| column < tiles[0].len());
-            let lower_row = row.checked_add(1).filter(|&row| row < tiles.len());
-
-            let neighbors = [
-                left_column.map(|column| (row, column)),
-                upper_row.and_then(|row| Some((row, left_column?))),
-                upper_row.map(|row| (row, column)),
-                upper_row.and_then(|row| Some((row, right_column?))),
-                right_column.map(|column| (row, column)),
-                lower_row.and_then(|row| Some((row, right_column?))),
-                lower_row.map(|row| (row, column)),
-                lower_row.and_then(|row| Some((row, left_column?))),
-            ];
-
-            let num_occupied_neighbors = neighbors
-                .iter()
-                .copied()
-                .flatten()
-                .map(|(row, column)| tiles[row][column])
-                .filter(Tile::is_occupied)
-                .count();
-            match num_occupied_neighbors {
-                0 => !tiles[row][column].is_occupied(),
-                1..=3 => false,
-                4..=usize::MAX => tiles[row][column].is_occupied(),
-                _ => unreachable!(),
-            }
+impl OccupationBehavior {
+    /// The positions of the tiles that the person in the seat at `seat` looks at.
+    fn neighbors(self, tiles: &Grid<Tile>, seat: Point2D<usize>) -> Vec<Point2D<usize>> {
+        match self {
+            Self::Basic => tiles
+                .neighbors8(seat)
+                .map(|(neighbor, _)| neighbor)
+                .collect(),
+            Self::LineOfSight => ALL_AROUND
+                .into_iter()
+                .filter_map(|direction| {
+                    let mut neighbor = seat;
+                    loop {
+                        neighbor = neighbor.checked_add_signed(direction)?;
+                        if tiles.get(&neighbor)?.is_seat() {
+                            return Some(neighbor);
+                        }
+                    }
+                })
+                .collect(),
         }
     }
-}
-
-/// The line-of-sight occupation behavior for part 2.
-#[derive(Clone, Copy, Debug)]
-struct LosOccupationBehavior;
-
-impl OccupationBehavior<Vec<Tile>> for LosOccupationBehavior {
-    fn update_tile(&self, row: usize, column: usize, tiles: &[Vec<Tile>]) -> bool {
-        if !tiles[row][column].is_seat() {
-            false
-        } else {
-            let max_left_distance = column;
-            let max_up_distance = row;
-            let max_right_distance = tiles[0].len() - 1 - column;
-            let max_down_distance = tiles.len() - 1 - row;
 
-            let mut left_los: Box<dyn FnMut(_) -> _> =
-                Box::new(|distance| tiles[row][column - distance]);
-            let mut upper_left_los: Box<dyn FnMut(usize) -> _> =
-                Box::new(|distance| tiles[row - distance][column - distance]);
-            let mut upper_los: Box<dyn FnMut(usize) -> _> =
-                Box::new(|distance| tiles[row - distance][column]);
-            let mut upper_right_los: Box<dyn FnMut(usize) -> _> =
-                Box::new(|distance| tiles[row - distance][column + distance]);
-            let mut right_los: Box<dyn FnMut(_) -> _> =
-                Box::new(|distance| tiles[row][column + distance]);
-            let mut lower_right_los: Box<dyn FnMut(usize) -> _> =
-                Box::new(|distance| tiles[row + distance][column + distance]);
-            let mut lower_los: Box<dyn FnMut(usize) -> _> =
-                Box::new(|distance| tiles[row + distance][column]);
-            let mut lower_left_los: Box<dyn FnMut(usize) -> _> =
-                Box::new(|distance| tiles[row + distance][column - distance]);
-
-            let mut lines_of_sight = [
-                (1..=max_left_distance).map(&mut left_los),
-                (1..=Ord::min(max_left_distance, max_up_distance)).map(&mut upper_left_los),
-                (1..=max_up_distance).map(&mut upper_los),
-                (1..=Ord::min(max_right_distance, max_up_distance)).map(&mut upper_right_los),
-                (1..=max_right_distance).map(&mut right_los),
-                (1..=Ord::min(max_right_distance, max_down_distance)).map(&mut lower_right_los),
-                (1..=max_down_distance).map(&mut lower_los),
-                (1..=Ord::min(max_left_distance, max_down_distance)).map(&mut lower_left_los),
-            ];
-
-            let num_lines_of_sight_occupied = lines_of_sight
-                .iter_mut()
-                .flat_map(|iter| iter.find(Tile::is_seat))
-                .filter(Tile::is_occupied)
-                .count();
-            match num_lines_of_sight_occupied {
-                0 => !tiles[row][column].is_occupied(),
-                1..=4 => false,
-                5..=usize::MAX => tiles[row][column].is_occupied(),
-                _ => unreachable!(),
-            }
+    /// The number of occupied seats that it takes for a person to leave their seat.
+    fn tolerance(self) -> usize {
+        match self {
+            Self::Basic => 4,
+            Self::LineOfSight => 5,
         }
     }
 }
 
 #[derive(Clone, Debug)]
-struct GameOfLife<'behavior> {
-    tiles: Vec<Vec<Tile>>,
-    occupation_behavior: &'behavior dyn OccupationBehavior<Vec<Tile>>,
+struct GameOfLife {
+    tiles: Grid<Tile>,
+    occupation_behavior: OccupationBehavior,
 }
 
-impl<'behavior> GameOfLife<'behavior> {
+impl GameOfLife {
     fn num_occupied_seats(&self) -> usize {
         self.tiles
             .iter()
-            .flat_map(|iter| iter.iter())
-            .copied()
-            .filter(Tile::is_occupied)
+            .filter(|(_, tile)| tile.is_occupied())
             .count()
     }
 
     fn step(&mut self) -> bool {
-        let mut new_tiles = self.tiles.clone();
-        let mut changed = false;
-        for (i, (new_row, old_row)) in new_tiles.iter_mut().zip(self.tiles.iter()).enumerate() {
-            for (j, (new_tile, old_tile)) in new_row.iter_mut().zip(old_row.iter()).enumerate() {
-                if self.occupation_behavior.update_tile(i, j, &self.tiles) {
-                    if old_tile.is_occupied() {
-                        new_tile.leave();
-                    } else {
-                        new_tile.occupy();
+        let occupation_behavior = self.occupation_behavior;
+        let new_tiles = automata::step_dense(
+            &self.tiles,
+            |seat| occupation_behavior.neighbors(&self.tiles, seat),
+            |&tile, neighbors| {
+                let num_occupied_neighbors = neighbors
+                    .iter()
+                    .filter(|neighbor| neighbor.is_occupied())
+                    .count();
+                match tile {
+                    Tile::EmptyChair if num_occupied_neighbors == 0 => Tile::OccupiedChair,
+                    Tile::OccupiedChair
+                        if num_occupied_neighbors >= occupation_behavior.tolerance() =>
+                    {
+                        Tile::EmptyChair
                     }
-                    changed = true;
+                    tile => tile,
                 }
-            }
-        }
+            },
+        );
+        let changed = new_tiles != self.tiles;
         self.tiles = new_tiles;
         changed
     }
@@ -224,99 +141,80 @@ impl<'behavior> GameOfLife<'behavior> {
     }
 }
 
-impl<'behavior> Eq for GameOfLife<'behavior> {}
+impl Eq for GameOfLife {}
 
-impl<'s> NomParse<&'s str> for GameOfLife<'static> {
+impl<'s> NomParse<&'s str> for GameOfLife {
     fn nom_parse(s: &'s str) -> IResult<&'s str, Self> {
-        let (s, first_line) =
-            sequence::terminated(multi::many0(Tile::nom_parse), character::line_ending)(s)?;
-        let (s, mut remaining_lines) = sequence::terminated(
-            multi::separated_list0(
-                character::line_ending,
-                multi::many_m_n(first_line.len(), first_line.len(), Tile::nom_parse),
-            ),
-            comb::opt(character::line_ending),
-        )(s)?;
-        remaining_lines.insert(0, first_line);
-        Ok((
-            s,
-            Self {
-                tiles: remaining_lines,
-                occupation_behavior: &BasicOccupationBehavior,
-            },
-        ))
+        comb::map(Grid::nom_parse, |tiles| Self {
+            tiles,
+            occupation_behavior: OccupationBehavior::Basic,
+        })(s)
     }
 }
 
-aoc_util::impl_from_str_for_nom_parse!(GameOfLife<'static>);
+aoc_util::impl_from_str_for_nom_parse!(GameOfLife);
 
-impl<'behavior> PartialEq for GameOfLife<'behavior> {
+impl PartialEq for GameOfLife {
     fn eq(&self, rhs: &Self) -> bool {
         self.tiles.eq(&rhs.tiles)
     }
 }
 
-pub(super) fn run() -> io::Result<()> {
-    let seating_area = fs::read_to_string("2020_11.txt")?
-        .parse::<GameOfLife<'_>>()
-        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
-    {
-        println!("Year 2020 Day 11 Part 1");
-        let mut seating_area = seating_area.clone();
-        seating_area.run_to_stasis();
-        println!(
-            "When the seating area reaches equilibrium, there are {} occupied seats",
-            seating_area.num_occupied_seats(),
-        );
-    }
-    {
-        println!("Year 2020 Day 11 Part 2");
-        let mut seating_area = seating_area;
-        seating_area.occupation_behavior = &LosOccupationBehavior;
-        seating_area.run_to_stasis();
-        println!(
-            "When the seating area reaches equilibrium this time, there are {} occupied seats",
-            seating_area.num_occupied_seats(),
-        );
-    }
-    Ok(())
+fn parse_seating_area(input: &str) -> error::Result<GameOfLife> {
+    input
+        .parse::<GameOfLife>()
+        .map_err(|e| Error::parse(e.to_string()))
+}
+
+/// Counts the occupied seats once the seating area reaches equilibrium with `occupation_behavior`.
+fn count_stable_seats(seating_area: &GameOfLife, occupation_behavior: OccupationBehavior) -> usize {
+    let mut seating_area = seating_area.clone();
+    seating_area.occupation_behavior = occupation_behavior;
+    seating_area.run_to_stasis();
+    seating_area.num_occupied_seats()
+}
+
+fn part1(seating_area: &GameOfLife) -> error::Result<usize> {
+    Ok(count_stable_seats(seating_area, OccupationBehavior::Basic))
+}
+
+fn part2(seating_area: &GameOfLife) -> error::Result<usize> {
+    Ok(count_stable_seats(
+        seating_area,
+        OccupationBehavior::LineOfSight,
+    ))
+}
+
+aoc_util::solution! {
+    year: 2020,
+    day: 11,
+    title: "Seating System",
+    parse: parse_seating_area,
+    part1: part1,
+    part2: part2,
+    examples: [
+        part1 => 37,
+        part2 => 26,
+    ],
 }
 
 #[cfg(test)]
 mod test {
+    use aoc_util::samples;
+
     use super::*;
 
     #[ignore]
     #[test]
     fn runs_correctly() {
-        let expected = concat!(
-            "#.##.##.##\n",
-            "#######.##\n",
-            "#.#.#..#..\n",
-            "####.##.##\n",
-            "#.##.##.##\n",
-            "#.#####.##\n",
-            "..#.#.....\n",
-            "##########\n",
-            "#.######.#\n",
-            "#.#####.##\n",
-        )
-        .parse::<GameOfLife<'_>>()
-        .unwrap();
-        let mut actual = concat!(
-            "L.LL.LL.LL\n",
-            "LLLLLLL.LL\n",
-            "L.L.L..L..\n",
-            "LLLL.LL.LL\n",
-            "L.LL.LL.LL\n",
-            "L.LLLLL.LL\n",
-            "..L.L.....\n",
-            "LLLLLLLLLL\n",
-            "L.LLLLLL.L\n",
-            "L.LLLLL.LL\n",
-        )
-        .parse::<GameOfLife<'_>>()
-        .unwrap();
+        let expected = samples::get_named(2020, 11, "step")
+            .unwrap()
+            .parse::<GameOfLife>()
+            .unwrap();
+        let mut actual = samples::get(2020, 11)
+            .unwrap()
+            .parse::<GameOfLife>()
+            .unwrap();
         actual.step();
         assert_eq!(expected, actual);
     }
@@ -324,34 +222,14 @@ mod test {
     #[ignore]
     #[test]
     fn terminates_correctly() {
-        let expected = concat!(
-            "#.#L.L#.##\n",
-            "#LLL#LL.L#\n",
-            "L.#.L..#..\n",
-            "#L##.##.L#\n",
-            "#.#L.LL.LL\n",
-            "#.#L#L#.##\n",
-            "..L.L.....\n",
-            "#L#L##L#L#\n",
-            "#.LLLLLL.L\n",
-            "#.#L#L#.##\n",
-        )
-        .parse::<GameOfLife<'_>>()
-        .unwrap();
-        let mut actual = concat!(
-            "L.LL.LL.LL\n",
-            "LLLLLLL.LL\n",
-            "L.L.L..L..\n",
-            "LLLL.LL.LL\n",
-            "L.LL.LL.LL\n",
-            "L.LLLLL.LL\n",
-            "..L.L.....\n",
-            "LLLLLLLLLL\n",
-            "L.LLLLLL.L\n",
-            "L.LLLLL.LL\n",
-        )
-        .parse::<GameOfLife<'_>>()
-        .unwrap();
+        let expected = samples::get_named(2020, 11, "stable")
+            .unwrap()
+            .parse::<GameOfLife>()
+            .unwrap();
+        let mut actual = samples::get(2020, 11)
+            .unwrap()
+            .parse::<GameOfLife>()
+            .unwrap();
         actual.run_to_stasis();
         assert_eq!(expected, actual);
     }
@@ -360,38 +238,18 @@ mod test {
     #[test]
     fn los_runs_correctly() {
         let expected = {
-            let mut res = concat!(
-                "#.##.##.##\n",
-                "#######.##\n",
-                "#.#.#..#..\n",
-                "####.##.##\n",
-                "#.##.##.##\n",
-                "#.#####.##\n",
-                "..#.#.....\n",
-                "##########\n",
-                "#.######.#\n",
-                "#.#####.##\n",
-            )
-            .parse::<GameOfLife<'_>>()
-            .unwrap();
-            res.occupation_behavior = &LosOccupationBehavior;
+            let mut res = samples::get_named(2020, 11, "step")
+                .unwrap()
+                .parse::<GameOfLife>()
+                .unwrap();
+            res.occupation_behavior = OccupationBehavior::LineOfSight;
             res
         };
-        let mut actual = concat!(
-            "L.LL.LL.LL\n",
-            "LLLLLLL.LL\n",
-            "L.L.L..L..\n",
-            "LLLL.LL.LL\n",
-            "L.LL.LL.LL\n",
-            "L.LLLLL.LL\n",
-            "..L.L.....\n",
-            "LLLLLLLLLL\n",
-            "L.LLLLLL.L\n",
-            "L.LLLLL.LL\n",
-        )
-        .parse::<GameOfLife<'_>>()
-        .unwrap();
-        actual.occupation_behavior = &LosOccupationBehavior;
+        let mut actual = samples::get(2020, 11)
+            .unwrap()
+            .parse::<GameOfLife>()
+            .unwrap();
+        actual.occupation_behavior = OccupationBehavior::LineOfSight;
         actual.step();
         assert_eq!(expected, actual);
     }
@@ -400,38 +258,18 @@ mod test {
     #[test]
     fn los_terminates_correctly() {
         let expected = {
-            let mut res = concat!(
-                "#.L#.L#.L#\n",
-                "#LLLLLL.LL\n",
-                "L.L.L..#..\n",
-                "##L#.#L.L#\n",
-                "L.L#.LL.L#\n",
-                "#.LLLL#.LL\n",
-                "..#.L.....\n",
-                "LLL###LLL#\n",
-                "#.LLLLL#.L\n",
-                "#.L#LL#.L#\n",
-            )
-            .parse::<GameOfLife<'_>>()
-            .unwrap();
-            res.occupation_behavior = &LosOccupationBehavior;
+            let mut res = samples::get_named(2020, 11, "los_stable")
+                .unwrap()
+                .parse::<GameOfLife>()
+                .unwrap();
+            res.occupation_behavior = OccupationBehavior::LineOfSight;
             res
         };
-        let mut actual = concat!(
-            "L.LL.LL.LL\n",
-            "LLLLLLL.LL\n",
-            "L.L.L..L..\n",
-            "LLLL.LL.LL\n",
-            "L.LL.LL.LL\n",
-            "L.LLLLL.LL\n",
-            "..L.L.....\n",
-            "LLLLLLLLLL\n",
-            "L.LLLLLL.L\n",
-            "L.LLLLL.LL\n",
-        )
-        .parse::<GameOfLife<'_>>()
-        .unwrap();
-        actual.occupation_behavior = &LosOccupationBehavior;
+        let mut actual = samples::get(2020, 11)
+            .unwrap()
+            .parse::<GameOfLife>()
+            .unwrap();
+        actual.occupation_behavior = OccupationBehavior::LineOfSight;
         actual.run_to_stasis();
         assert_eq!(expected, actual);
     }
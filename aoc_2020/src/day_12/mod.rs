@@ -1,9 +1,9 @@
-use aoc_util::{geometry::Point2D as Point, nom_extended::NomParse};
-use nom::{character::complete as character, combinator as comb, sequence, IResult};
-use std::{
-    fs::File,
-    io::{self, BufRead, BufReader},
+use aoc_util::{
+    error::{self, Error},
+    geometry::Point2D as Point,
+    nom_extended::NomParse,
 };
+use nom::{character::complete as character, combinator as comb, sequence, IResult};
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 enum Facing {
@@ -169,35 +169,42 @@ impl<'s> NomParse<&'s str> for Instruction {
     }
 }
 
-pub(super) fn run() -> io::Result<()> {
-    let directions = BufReader::new(File::open("2020_12.txt")?)
+fn parse_directions(input: &str) -> error::Result<Vec<Instruction>> {
+    input
         .lines()
         .map(|line| {
-            line?
-                .parse()
-                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+            line.parse::<Instruction>()
+                .map_err(|e| Error::parse(e.to_string()))
         })
-        .collect::<io::Result<Vec<_>>>()?;
-    {
-        println!("Year 2020 Day 12 Part 1");
-        let mut ship = Ship::default();
-        ship.execute(&directions);
-        println!(
-            "The manhattan distance that the ship covers is {}",
-            ship.location.manhattan_distance(&Point::at(0, 0)),
-        );
-    }
-    {
-        println!("Year 2020 Day 12 Part 2");
-        let mut ship = Ship::default();
-        let mut waypoint = Waypoint::new(&mut ship);
-        waypoint.execute(&directions);
-        println!(
-            "The manhattan distance that the ship covers is {}",
-            ship.location.manhattan_distance(&Point::at(0, 0)),
-        );
-    }
-    Ok(())
+        .collect()
+}
+
+/// The manhattan distance that the ship covers when it moves itself.
+fn part1(input: &str) -> error::Result<i32> {
+    let mut ship = Ship::default();
+    ship.execute(&parse_directions(input)?);
+    Ok(ship.location.manhattan_distance(&Point::at(0, 0)))
+}
+
+/// The manhattan distance that the ship covers when it moves the waypoint.
+fn part2(input: &str) -> error::Result<i32> {
+    let directions = parse_directions(input)?;
+    let mut ship = Ship::default();
+    let mut waypoint = Waypoint::new(&mut ship);
+    waypoint.execute(&directions);
+    Ok(ship.location.manhattan_distance(&Point::at(0, 0)))
+}
+
+aoc_util::solution! {
+    year: 2020,
+    day: 12,
+    title: "Rain Risk",
+    part1: part1,
+    part2: part2,
+    examples: [
+        part1 => 25,
+        part2 => 286,
+    ],
 }
 
 #[cfg(test)]
@@ -1,31 +1,15 @@
-use aoc_util::nom_extended::NomParse;
+use aoc_util::{
+    error::{self, Error},
+    math,
+    nom_extended::NomParse,
+};
 use nom::{branch, character::complete as character, combinator as comb, multi, IResult};
 use std::{
     convert::TryFrom,
     fmt::{self, Display, Formatter},
-    fs, io, mem,
     ops::{Add, AddAssign, Rem, Sub},
 };
 
-fn gcd(mut a: u128, mut b: u128) -> u128 {
-    while b > 0 && a != b {
-        a %= b;
-        mem::swap(&mut a, &mut b);
-    }
-    a
-}
-
-fn lcm(a: u128, b: u128) -> u128 {
-    if a == 0 {
-        b
-    } else if b == 0 {
-        a
-    } else {
-        let gcd = gcd(a, b);
-        a * (b / gcd)
-    }
-}
-
 #[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
 struct Duration(u128);
 
@@ -128,58 +112,39 @@ impl<'s> NomParse<&'s str> for BusNumber {
     }
 }
 
+#[derive(Debug)]
 struct BusSchedule {
     buses: Vec<BusNumber>,
 }
 
 impl BusSchedule {
     /// Gets a pair of `(next_bus, delay)` for the least delay.
-    fn next_bus(&self, time: Timestamp) -> (BusNumber, Duration) {
+    fn next_bus(&self, time: Timestamp) -> Option<(BusNumber, Duration)> {
         self.buses
             .iter()
             .filter(|&&bus| bus != BusNumber(0))
             .map(|&bus| (bus, bus.wait(time)))
             .min_by_key(|&(_, delay)| delay)
-            .expect("No buses")
     }
 
-    fn first_diagonal(&self) -> Timestamp {
-        let bus_offsets = self
+    fn first_diagonal(&self) -> error::Result<Timestamp> {
+        // Bus `bus_number` leaves `offset` minutes after the diagonal starts exactly when the
+        // start time is congruent to `-offset` modulo `bus_number`.
+        let congruences = self
             .buses
             .iter()
             .enumerate()
             .filter(|&(_, &bus_number)| bus_number != BusNumber(0))
-            .map(|(offset, &bus_number)| (Duration::try_from(offset).unwrap(), bus_number))
-            .collect::<Vec<_>>();
-        let max_step = bus_offsets
-            .iter()
-            .map(|&(_, bus_number)| bus_number.0)
-            .fold(1, lcm);
-        println!("The time between diagonals is {max_step}");
-        let mut buses_satisfied = 1;
-        let mut step = self.buses[0].0;
-        let mut time = Timestamp(0u128);
-        loop {
-            if let Some((new_idx, extra_skip)) = bus_offsets[buses_satisfied..]
-                .iter()
-                .zip(buses_satisfied..)
-                .take_while(|&(&(offset, bus_number), _)| bus_number.runs_at(time + offset))
-                .fold(None, |acc, (&(_, bus_number), idx)| {
-                    acc.or(Some((idx, 1)))
-                        .map(|(_, base)| (idx, lcm(base, bus_number.0)))
-                })
-            {
-                if new_idx == bus_offsets.len() - 1 {
-                    return time;
-                } else if new_idx > buses_satisfied {
-                    buses_satisfied = new_idx;
-                    let new_step = lcm(step, extra_skip);
-                    step = new_step;
-                }
-            }
-
-            time += Duration(step);
-        }
+            .map(|(offset, &bus_number)| {
+                let offset = i128::try_from(offset).expect("Offset is too large");
+                let bus_number = i128::try_from(bus_number.0).expect("Bus number is too large");
+                (-offset, bus_number)
+            });
+        let (time, _) = math::crt(congruences)
+            .ok_or_else(|| Error::UnsolvableInput("The buses never line up".to_string()))?;
+        Ok(Timestamp(
+            u128::try_from(time).expect("Diagonals never start at a negative time"),
+        ))
     }
 }
 
@@ -199,32 +164,52 @@ impl<'s> NomParse<&'s str> for BusSchedule {
     }
 }
 
-#[allow(unreachable_code)]
-pub(super) fn run() -> io::Result<()> {
-    let notes = fs::read_to_string("2020_13.txt")?;
-    let mut lines = notes.lines();
+#[derive(Debug)]
+struct Notes {
+    time: Timestamp,
+    schedule: BusSchedule,
+}
+
+fn parse_notes(input: &str) -> error::Result<Notes> {
+    let mut lines = input.lines();
     let time = lines
         .next()
-        .expect("Missing time")
+        .ok_or_else(|| Error::parse("Missing time"))?
         .parse::<Timestamp>()
-        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        .map_err(|e| Error::parse(e.to_string()))?;
     let schedule = lines
         .next()
-        .expect("Missing schedule")
+        .ok_or_else(|| Error::parse("Missing schedule"))?
         .parse::<BusSchedule>()
-        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
-    {
-        println!("Year 2020 Day 13 Part 1");
-        let (first_bus, delay) = schedule.next_bus(time);
-        println!("The first available bus is {first_bus}");
-        println!("The result is {}", delay.0 * first_bus.0);
-    }
-    {
-        println!("Year 2020 Day 13 Part 2");
-        let first_diagonal = schedule.first_diagonal();
-        println!("The first time that starts a diagonal is {first_diagonal}");
-    }
-    Ok(())
+        .map_err(|e| Error::parse(e.to_string()))?;
+    Ok(Notes { time, schedule })
+}
+
+/// The ID of the first available bus multiplied by the number of minutes to wait for it.
+fn part1(notes: &Notes) -> error::Result<u128> {
+    let (first_bus, delay) = notes
+        .schedule
+        .next_bus(notes.time)
+        .ok_or_else(|| Error::parse("No buses"))?;
+    Ok(delay.0 * first_bus.0)
+}
+
+/// The first time at which each bus leaves as many minutes later as its position in the list.
+fn part2(notes: &Notes) -> error::Result<u128> {
+    Ok(notes.schedule.first_diagonal()?.0)
+}
+
+aoc_util::solution! {
+    year: 2020,
+    day: 13,
+    title: "Shuttle Search",
+    parse: parse_notes,
+    part1: part1,
+    part2: part2,
+    examples: [
+        part1 => 295,
+        part2 => 1_068_781,
+    ],
 }
 
 #[cfg(test)]
@@ -242,7 +227,7 @@ mod test {
                 .collect(),
         };
         let expected = Timestamp(1068781);
-        let actual = schedule.first_diagonal();
+        let actual = schedule.first_diagonal().unwrap();
         assert_eq!(expected, actual);
     }
 }
@@ -1,4 +1,7 @@
-use aoc_util::nom_extended::NomParse;
+use aoc_util::{
+    error::{self, Error},
+    nom_extended::NomParse,
+};
 use nom::{
     branch, bytes::complete as bytes, character::complete as character, combinator as comb, multi,
     sequence, IResult,
@@ -6,7 +9,7 @@ use nom::{
 use std::{
     collections::HashMap,
     convert::{TryFrom, TryInto},
-    fs, io, iter,
+    iter,
     ops::{BitAnd, BitOr, BitXor, Not},
 };
 
@@ -245,29 +248,39 @@ impl<'s> NomParse<&'s str> for Program {
 
 aoc_util::impl_from_str_for_nom_parse!(Program);
 
-pub(super) fn run() -> io::Result<()> {
-    let program = fs::read_to_string("2020_14.txt")?
+fn parse_program(input: &str) -> error::Result<Program> {
+    input
         .parse::<Program>()
-        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
-    {
-        println!("Year 2020 Day 14 Part 1");
-        let total = program.clone().run().total();
-        println!(
-            "The total of all values remaining after running the initialization program is {total}",
-        );
-    }
-    {
-        println!("Year 2020 Day 14 Part 2");
-        let total = program.run_v2().total();
-        println!(
-            "The total of all values remaining after running the initialization program v2 is {total}",
-        );
-    }
-    Ok(())
+        .map_err(|e| Error::parse(e.to_string()))
+}
+
+/// The total of all values remaining after running the initialization program.
+fn part1(program: &Program) -> error::Result<u64> {
+    Ok(program.clone().run().total())
+}
+
+/// The total of all values remaining after running the initialization program v2.
+fn part2(program: &Program) -> error::Result<u64> {
+    Ok(program.clone().run_v2().total())
+}
+
+aoc_util::solution! {
+    year: 2020,
+    day: 14,
+    title: "Docking Data",
+    parse: parse_program,
+    part1: part1,
+    part2: part2,
+    examples: [
+        part1 => 165,
+        part2("v2") => 208,
+    ],
 }
 
 #[cfg(test)]
 mod test {
+    use aoc_util::samples;
+
     use super::*;
 
     #[ignore]
@@ -315,13 +328,7 @@ mod test {
                 },
             ],
         });
-        let actual = concat!(
-            "mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X\n",
-            "mem[8] = 11\n",
-            "mem[7] = 101\n",
-            "mem[8] = 0\n",
-        )
-        .parse::<Program>();
+        let actual = samples::get(2020, 14).unwrap().parse::<Program>();
         assert_eq!(expected, actual);
     }
 
@@ -1,11 +1,13 @@
-use aoc_util::nom_extended::NomParse;
+use aoc_util::{
+    error::{self, Error},
+    nom_extended::NomParse,
+};
 use nom::{
     bytes::complete as bytes, character::complete as character, combinator as comb, multi,
     sequence, IResult,
 };
 use std::{
     collections::HashMap,
-    fs, io,
     ops::{Add, Sub},
 };
 
@@ -90,23 +92,30 @@ impl<'s> NomParse<&'s str> for History {
     }
 }
 
-#[allow(unreachable_code)]
-pub(super) fn run() -> io::Result<()> {
-    let initial_values = fs::read_to_string("2020_15.txt")?
+fn parse_history(input: &str) -> error::Result<History> {
+    input
         .parse::<History>()
-        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
-    {
-        println!("Year 2020 Day 15 Part 1");
-        let value = initial_values.clone().run_to(Turn(2020));
-        println!("The 2020th number is {value}");
-    }
-    {
-        println!("Year 2020 Day 15 Part 2");
-        let mut initial_values = initial_values;
-        let value = initial_values.run_to(Turn(30_000_000));
-        println!("The 30,000,000th number is {value}");
-    }
-    Ok(())
+        .map_err(|e| Error::parse(e.to_string()))
+}
+
+fn part1(initial_values: &History) -> error::Result<u64> {
+    Ok(initial_values.clone().run_to(Turn(2020)))
+}
+
+fn part2(initial_values: &History) -> error::Result<u64> {
+    Ok(initial_values.clone().run_to(Turn(30_000_000)))
+}
+
+aoc_util::solution! {
+    year: 2020,
+    day: 15,
+    title: "Rambunctious Recitation",
+    parse: parse_history,
+    part1: part1,
+    part2: part2,
+    examples: [
+        part1 => 436,
+    ],
 }
 
 #[cfg(test)]
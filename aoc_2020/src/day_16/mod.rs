@@ -1,11 +1,13 @@
-use aoc_util::nom_extended::NomParse;
+use aoc_util::{
+    error::{self, Error},
+    nom_extended::NomParse,
+};
 use nom::{
     bytes::complete as bytes, character::complete as character, combinator as comb, multi,
     sequence, Finish, IResult,
 };
 use std::{
     collections::{HashMap, HashSet},
-    fs, io,
     ops::RangeInclusive,
 };
 
@@ -164,38 +166,51 @@ fn error_rate(tickets: &[Ticket], rules: &TicketRules<'_>) -> u64 {
         .sum::<u64>()
 }
 
-pub(super) fn run() -> io::Result<()> {
-    let file_contents = fs::read_to_string("2020_16.txt")?;
-    let (rules, (my_ticket, nearby_tickets)) = parse_rules_and_tickets(&file_contents)
+type Notes<'s> = (TicketRules<'s>, (Ticket, Vec<Ticket>));
+
+fn parse_notes(input: &str) -> error::Result<Notes<'_>> {
+    Ok(parse_rules_and_tickets(input)
         .finish()
-        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, format!("{e}")))?
-        .1;
-    {
-        println!("Year 2020 Day 16 Part 1");
-        println!(
-            "The ticket-scanning error rate is {}",
-            error_rate(&nearby_tickets, &rules)
-        );
-    }
-    {
-        println!("Year 2020 Day 16 Part 2");
-        let nearby_tickets = nearby_tickets
-            .into_iter()
-            .filter(|ticket| rules.error(ticket) == 0)
-            .collect::<Vec<_>>();
-        let named_fields = rules.find_fields(&nearby_tickets);
-        let result = named_fields
-            .into_iter()
-            .filter(|&(field, _)| field.starts_with("departure"))
-            .map(|(_, idx)| my_ticket.fields[idx])
-            .product::<u64>();
-        println!("The product of the six departure fields is {result}");
-    }
-    Ok(())
+        .map_err(|e| Error::parse(format!("{e}")))?
+        .1)
+}
+
+/// The ticket-scanning error rate.
+fn part1(input: &str) -> error::Result<u64> {
+    let (rules, (_, nearby_tickets)) = parse_notes(input)?;
+    Ok(error_rate(&nearby_tickets, &rules))
+}
+
+/// The product of the six departure fields of my ticket.
+fn part2(input: &str) -> error::Result<u64> {
+    let (rules, (my_ticket, nearby_tickets)) = parse_notes(input)?;
+    let nearby_tickets = nearby_tickets
+        .into_iter()
+        .filter(|ticket| rules.error(ticket) == 0)
+        .collect::<Vec<_>>();
+    let named_fields = rules.find_fields(&nearby_tickets);
+    Ok(named_fields
+        .into_iter()
+        .filter(|&(field, _)| field.starts_with("departure"))
+        .map(|(_, idx)| my_ticket.fields[idx])
+        .product())
+}
+
+aoc_util::solution! {
+    year: 2020,
+    day: 16,
+    title: "Ticket Translation",
+    part1: part1,
+    part2: part2,
+    examples: [
+        part1 => 71,
+    ],
 }
 
 #[cfg(test)]
 mod test {
+    use aoc_util::samples;
+
     use super::*;
 
     #[ignore]
@@ -211,32 +226,15 @@ mod test {
             .cloned()
             .collect(),
         });
-        let actual = TicketRules::nom_parse(concat!(
-            "class: 1-3 or 5-7\n",
-            "row: 6-11 or 33-44\n",
-            "seat: 13-40 or 45-50\n",
-        ))
-        .map(|(_, res)| res);
+        let rules = samples::get_named(2020, 16, "rules").unwrap();
+        let actual = TicketRules::nom_parse(&rules).map(|(_, res)| res);
         assert_eq!(expected, actual);
     }
 
     #[ignore]
     #[test]
     fn parses_ticket_rules_and_tickets() {
-        let notes = concat!(
-            "class: 1-3 or 5-7\n",
-            "row: 6-11 or 33-44\n",
-            "seat: 13-40 or 45-50\n",
-            "\n",
-            "your ticket:\n",
-            "7,1,14\n",
-            "\n",
-            "nearby tickets:\n",
-            "7,3,47\n",
-            "40,4,50\n",
-            "55,2,20\n",
-            "38,6,12\n",
-        );
+        let notes = samples::get(2020, 16).unwrap();
         let expected = Ok((
             TicketRules {
                 rules: [
@@ -268,7 +266,7 @@ mod test {
                 ],
             ),
         ));
-        let actual = parse_rules_and_tickets(notes).map(|(_, res)| res);
+        let actual = parse_rules_and_tickets(&notes).map(|(_, res)| res);
         assert_eq!(expected, actual);
     }
 
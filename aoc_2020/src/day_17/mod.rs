@@ -1,23 +1,19 @@
-use aoc_util::nom_extended::NomParse;
+use aoc_util::{
+    automata,
+    error::{self, Error},
+    geometry::Point,
+    grid::SparseGrid,
+    nom_extended::NomParse,
+};
 use nom::{branch, character::complete as character, combinator as comb, multi, sequence, IResult};
 use std::{
-    collections::HashSet,
     convert::TryFrom,
     fmt::{self, Debug, Formatter},
-    fs, io,
 };
 
 #[derive(Clone, Default, Eq, PartialEq)]
 struct ConwayCubes {
-    active: HashSet<(i64, i64, i64, i64)>,
-    min_x: i64,
-    max_x: i64,
-    min_y: i64,
-    max_y: i64,
-    min_z: i64,
-    max_z: i64,
-    min_w: i64,
-    max_w: i64,
+    active: SparseGrid<(), 4>,
     use_w: bool,
 }
 
@@ -26,52 +22,17 @@ impl ConwayCubes {
     where
         V: IntoIterator<Item = (i64, i64)>,
     {
-        let mut res = Self {
-            active: values.into_iter().map(|(x, y)| (x, y, 0, 0)).collect(),
+        Self {
+            active: values
+                .into_iter()
+                .map(|(x, y)| (Point::at([x, y, 0, 0]), ()))
+                .collect(),
             ..Default::default()
-        };
-        res.update_bounds();
-        res
+        }
     }
 
     fn count_active(&self) -> u64 {
-        self.active.iter().fold(0, |acc, _| acc + 1)
-    }
-
-    fn count_active_neighbors(&self, x: i64, y: i64, z: i64, w: i64) -> usize {
-        if x < self.min_x - 1
-            || self.max_x + 1 < x
-            || y < self.min_y - 1
-            || self.max_y + 1 < y
-            || z < self.min_z - 1
-            || self.max_z + 1 < z
-            || (self.use_w && (w < self.min_w - 1 || self.max_w + 1 < w))
-            || (!self.use_w && w != self.min_w)
-        {
-            0
-        } else {
-            let mut res = 0;
-            let w_range = if self.use_w { (w - 1)..=(w + 1) } else { w..=w };
-            let pos = (x, y, z, w);
-            for l in w_range {
-                for i in (x - 1)..=(x + 1) {
-                    for j in (y - 1)..=(y + 1) {
-                        for k in (z - 1)..=(z + 1) {
-                            let adjacent = (i, j, k, l);
-                            if pos == adjacent {
-                                continue;
-                            } else if self.active.contains(&adjacent) {
-                                res += 1;
-                                // if res > 3 {
-                                //     return 4;
-                                // }
-                            }
-                        }
-                    }
-                }
-            }
-            res
-        }
+        self.active.len() as u64
     }
 
     fn boot(&mut self) -> &mut Self {
@@ -82,97 +43,37 @@ impl ConwayCubes {
     }
 
     fn step(&mut self) {
-        let mut delta = HashSet::new();
-        let w_range = if self.use_w {
-            (self.min_w - 1)..=(self.max_w + 1)
-        } else {
-            self.min_w..=self.min_w
-        };
-        for w in w_range {
-            for x in (self.min_x - 1)..=(self.max_x + 1) {
-                for y in (self.min_y - 1)..=(self.max_y + 1) {
-                    for z in (self.min_z - 1)..=(self.max_z + 1) {
-                        let num_active_neighbors = self.count_active_neighbors(x, y, z, w);
-                        let pos = (x, y, z, w);
-                        let is_active = self.active.contains(&pos);
-                        match (num_active_neighbors, is_active) {
-                            (3, false) => delta.insert(pos),
-                            (2, true) | (3, true) | (_, false) => false,
-                            (_, true) => delta.insert(pos),
-                        };
-                    }
-                }
-            }
-        }
-        for pos in delta {
-            if self.active.contains(&pos) {
-                self.active.remove(&pos);
-            } else {
-                self.active.insert(pos);
-            }
-        }
-        self.update_bounds();
-    }
-
-    fn update_bounds(&mut self) {
-        assert_ne!(self.active.len(), 0);
-        let (min_x, max_x, min_y, max_y, min_z, max_z, min_w, max_w) = self.active.iter().fold(
-            (None, None, None, None, None, None, None, None),
-            |acc, pos| {
-                (
-                    Some(acc.0.filter(|&min_x| min_x <= pos.0).unwrap_or(pos.0)),
-                    Some(acc.1.filter(|&max_x| max_x >= pos.0).unwrap_or(pos.0)),
-                    Some(acc.2.filter(|&min_y| min_y <= pos.1).unwrap_or(pos.1)),
-                    Some(acc.3.filter(|&max_y| max_y >= pos.1).unwrap_or(pos.1)),
-                    Some(acc.4.filter(|&min_z| min_z <= pos.2).unwrap_or(pos.2)),
-                    Some(acc.5.filter(|&max_z| max_z >= pos.2).unwrap_or(pos.2)),
-                    Some(
-                        acc.6
-                            .filter(|&min_w| {
-                                if self.use_w {
-                                    min_w <= pos.3
-                                } else {
-                                    assert_eq!(
-                                        min_w, pos.3,
-                                        "All values of w must be the same if w is disabled",
-                                    );
-                                    true
-                                }
-                            })
-                            .unwrap_or(pos.3),
-                    ),
-                    Some(
-                        acc.7
-                            .filter(|&max_w| {
-                                if self.use_w {
-                                    max_w >= pos.3
-                                } else {
-                                    assert_eq!(
-                                        max_w, pos.3,
-                                        "All values of w must be the same if w is disabled",
-                                    );
-                                    true
-                                }
-                            })
-                            .unwrap_or(pos.3),
-                    ),
-                )
+        // If w is disabled then every active cube has the same w, so the neighbors with other
+        // values of w are never active.
+        let use_w = self.use_w;
+        self.active = automata::step_sparse(
+            self.active.iter().map(|(&point, _)| point),
+            |point| {
+                let w = point[3];
+                point
+                    .neighbors()
+                    .into_iter()
+                    .filter(move |neighbor| use_w || neighbor[3] == w)
+            },
+            |is_active, num_active_neighbors| {
+                matches!((num_active_neighbors, is_active), (3, _) | (2, true))
             },
-        );
-        self.min_x = min_x.unwrap();
-        self.max_x = max_x.unwrap();
-        self.min_y = min_y.unwrap();
-        self.max_y = max_y.unwrap();
-        self.min_z = min_z.unwrap();
-        self.max_z = max_z.unwrap();
-        self.min_w = min_w.unwrap();
-        self.max_w = max_w.unwrap();
+        )
+        .map(|point| (point, ()))
+        .collect();
     }
 }
 
 impl Debug for ConwayCubes {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        let mut active = self.active.iter().copied().collect::<Vec<_>>();
+        let mut active = self
+            .active
+            .iter()
+            .map(|(point, _)| {
+                let [x, y, z, w] = point.into_coords();
+                (x, y, z, w)
+            })
+            .collect::<Vec<_>>();
         active.sort_unstable();
         let mut debug_struct = f.debug_struct("ConwayCubes");
         if self.use_w {
@@ -184,12 +85,15 @@ impl Debug for ConwayCubes {
                 .collect::<Vec<_>>();
             debug_struct.field("active", &active);
         }
+        let bounds = self.active.bounds().unwrap_or_default();
+        let [min_x, min_y, min_z, min_w] = bounds.min.into_coords();
+        let [max_x, max_y, max_z, max_w] = bounds.max.into_coords();
         debug_struct
-            .field("bounds_x", &(self.min_x, self.max_x))
-            .field("bounds_y", &(self.min_y, self.max_y))
-            .field("bounds_z", &(self.min_z, self.max_z));
+            .field("bounds_x", &(min_x, max_x))
+            .field("bounds_y", &(min_y, max_y))
+            .field("bounds_z", &(min_z, max_z));
         if self.use_w {
-            debug_struct.field("bounds_w", &(self.min_w, self.max_w));
+            debug_struct.field("bounds_w", &(min_w, max_w));
         }
         debug_struct.finish()
     }
@@ -225,60 +129,67 @@ impl<'s> NomParse<&'s str> for ConwayCubes {
     }
 }
 
-#[allow(unreachable_code)]
-pub(super) fn run() -> io::Result<()> {
-    let state = fs::read_to_string("2020_17.txt")?
+fn parse_state(input: &str) -> error::Result<ConwayCubes> {
+    input
         .parse::<ConwayCubes>()
-        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
-    {
-        println!("Year 2020 Day 17 Part 1");
-        let mut state = state.clone();
-        println!(
-            "After the boot sequence, there are {} active cubes",
-            state.boot().count_active()
-        );
-    }
-    {
-        println!("Year 2020 Day 17 Part 2");
-        let mut state = state;
-        state.use_w = true;
-        println!(
-            "After the boot sequence, there are {} active cubes",
-            state.boot().count_active()
-        );
-    }
-    Ok(())
+        .map_err(|e| Error::parse(e.to_string()))
+}
+
+/// Counts the active cubes after the boot sequence, with a fourth dimension if `use_w` is set.
+fn count_booted(state: &ConwayCubes, use_w: bool) -> u64 {
+    let mut state = state.clone();
+    state.use_w = use_w;
+    state.boot().count_active()
+}
+
+fn part1(state: &ConwayCubes) -> error::Result<u64> {
+    Ok(count_booted(state, false))
+}
+
+fn part2(state: &ConwayCubes) -> error::Result<u64> {
+    Ok(count_booted(state, true))
+}
+
+aoc_util::solution! {
+    year: 2020,
+    day: 17,
+    title: "Conway Cubes",
+    parse: parse_state,
+    part1: part1,
+    part2: part2,
+    examples: [
+        part1 => 112,
+        part2 => 848,
+    ],
 }
 
 #[cfg(test)]
 mod test {
+    use aoc_util::samples;
+
     use super::*;
 
+    fn cubes(active: &[(i64, i64, i64, i64)]) -> SparseGrid<(), 4> {
+        active
+            .iter()
+            .map(|&(x, y, z, w)| (Point::at([x, y, z, w]), ()))
+            .collect()
+    }
+
     #[ignore]
     #[test]
     fn parses_layout() {
         let expected = Ok(ConwayCubes {
-            active: [
+            active: cubes(&[
                 (0, 1, 0, 0),
                 (1, 2, 0, 0),
                 (2, 0, 0, 0),
                 (2, 1, 0, 0),
                 (2, 2, 0, 0),
-            ]
-            .iter()
-            .copied()
-            .collect(),
-            min_x: 0,
-            max_x: 2,
-            min_y: 0,
-            max_y: 2,
-            min_z: 0,
-            max_z: 0,
-            min_w: 0,
-            max_w: 0,
+            ]),
             use_w: false,
         });
-        let actual = concat!(".#.\n", "..#\n", "###\n",).parse::<ConwayCubes>();
+        let actual = samples::get(2020, 17).unwrap().parse::<ConwayCubes>();
         assert_eq!(expected, actual);
     }
 
@@ -286,7 +197,7 @@ mod test {
     #[test]
     fn steps_correctly_in_3d() {
         let expected = ConwayCubes {
-            active: [
+            active: cubes(&[
                 (1, 0, -1, 0),
                 (2, 2, -1, 0),
                 (3, 1, -1, 0),
@@ -298,39 +209,17 @@ mod test {
                 (1, 0, 1, 0),
                 (2, 2, 1, 0),
                 (3, 1, 1, 0),
-            ]
-            .iter()
-            .copied()
-            .collect(),
-            min_x: 1,
-            max_x: 3,
-            min_y: 0,
-            max_y: 2,
-            min_z: -1,
-            max_z: 1,
-            min_w: 0,
-            max_w: 0,
+            ]),
             use_w: false,
         };
         let mut actual = ConwayCubes {
-            active: [
+            active: cubes(&[
                 (0, 1, 0, 0),
                 (1, 2, 0, 0),
                 (2, 0, 0, 0),
                 (2, 1, 0, 0),
                 (2, 2, 0, 0),
-            ]
-            .iter()
-            .copied()
-            .collect(),
-            min_x: 0,
-            max_x: 2,
-            min_y: 0,
-            max_y: 2,
-            min_z: 0,
-            max_z: 0,
-            min_w: 0,
-            max_w: 0,
+            ]),
             use_w: false,
         };
         actual.step();
@@ -342,24 +231,13 @@ mod test {
     fn boots_correctly_in_3d() {
         let expected = 112;
         let actual = ConwayCubes {
-            active: [
+            active: cubes(&[
                 (0, 1, 0, 0),
                 (1, 2, 0, 0),
                 (2, 0, 0, 0),
                 (2, 1, 0, 0),
                 (2, 2, 0, 0),
-            ]
-            .iter()
-            .copied()
-            .collect(),
-            min_x: 0,
-            max_x: 2,
-            min_y: 0,
-            max_y: 2,
-            min_z: 0,
-            max_z: 0,
-            min_w: 0,
-            max_w: 0,
+            ]),
             use_w: false,
         }
         .boot()
@@ -371,7 +249,7 @@ mod test {
     #[test]
     fn steps_correctly_in_4d() {
         let expected = ConwayCubes {
-            active: [
+            active: cubes(&[
                 (1, 0, -1, -1),
                 (2, 2, -1, -1),
                 (3, 1, -1, -1),
@@ -401,39 +279,17 @@ mod test {
                 (1, 0, 1, 1),
                 (2, 2, 1, 1),
                 (3, 1, 1, 1),
-            ]
-            .iter()
-            .copied()
-            .collect(),
-            min_x: 1,
-            max_x: 3,
-            min_y: 0,
-            max_y: 2,
-            min_z: -1,
-            max_z: 1,
-            min_w: -1,
-            max_w: 1,
+            ]),
             use_w: true,
         };
         let mut actual = ConwayCubes {
-            active: [
+            active: cubes(&[
                 (0, 1, 0, 0),
                 (1, 2, 0, 0),
                 (2, 0, 0, 0),
                 (2, 1, 0, 0),
                 (2, 2, 0, 0),
-            ]
-            .iter()
-            .copied()
-            .collect(),
-            min_x: 0,
-            max_x: 2,
-            min_y: 0,
-            max_y: 2,
-            min_z: 0,
-            max_z: 0,
-            min_w: 0,
-            max_w: 0,
+            ]),
             use_w: true,
         };
         actual.step();
@@ -444,7 +300,7 @@ mod test {
     #[test]
     fn steps_twice_correctly_in_4d() {
         let expected = ConwayCubes {
-            active: [
+            active: cubes(&[
                 (2, 1, -2, -2),
                 (0, -1, 0, -2),
                 (0, 0, 0, -2),
@@ -505,39 +361,17 @@ mod test {
                 (4, 1, 0, 2),
                 (4, 2, 0, 2),
                 (2, 1, 2, 2),
-            ]
-            .iter()
-            .copied()
-            .collect(),
-            min_x: 0,
-            max_x: 4,
-            min_y: -1,
-            max_y: 3,
-            min_z: -2,
-            max_z: 2,
-            min_w: -2,
-            max_w: 2,
+            ]),
             use_w: true,
         };
         let mut actual = ConwayCubes {
-            active: [
+            active: cubes(&[
                 (0, 1, 0, 0),
                 (1, 2, 0, 0),
                 (2, 0, 0, 0),
                 (2, 1, 0, 0),
                 (2, 2, 0, 0),
-            ]
-            .iter()
-            .copied()
-            .collect(),
-            min_x: 0,
-            max_x: 2,
-            min_y: 0,
-            max_y: 2,
-            min_z: 0,
-            max_z: 0,
-            min_w: 0,
-            max_w: 0,
+            ]),
             use_w: true,
         };
         actual.step();
@@ -550,24 +384,13 @@ mod test {
     fn boots_correctly_in_4d() {
         let expected = 848;
         let actual = ConwayCubes {
-            active: [
+            active: cubes(&[
                 (0, 1, 0, 0),
                 (1, 2, 0, 0),
                 (2, 0, 0, 0),
                 (2, 1, 0, 0),
                 (2, 2, 0, 0),
-            ]
-            .iter()
-            .copied()
-            .collect(),
-            min_x: 0,
-            max_x: 2,
-            min_y: 0,
-            max_y: 2,
-            min_z: 0,
-            max_z: 0,
-            min_w: 0,
-            max_w: 0,
+            ]),
             use_w: true,
         }
         .boot()
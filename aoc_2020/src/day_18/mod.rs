@@ -1,8 +1,11 @@
-use aoc_util::nom_extended::NomParse;
+use aoc_util::{
+    error::{self, Error},
+    nom_extended::NomParse,
+};
 use nom::{branch, character::complete as character, combinator as comb, sequence, IResult};
 use std::{
     fmt::{self, Display, Formatter},
-    fs, io, iter,
+    iter,
     str::FromStr,
 };
 
@@ -256,31 +259,44 @@ impl FromStr for Expr {
     }
 }
 
-pub(super) fn run() -> io::Result<()> {
-    let contents = fs::read_to_string("2020_18.txt")?;
-    let token_streams = contents
+fn parse_token_streams(input: &str) -> Vec<Vec<ExprToken>> {
+    input
         .lines()
         .map(ExprTokens::of)
         .map(Iterator::collect::<Vec<_>>)
-        .collect::<Vec<_>>();
-    {
-        println!("Year 2020 Day 18 Part 1");
-        let total = token_streams
-            .iter()
-            .filter_map(|line| Expr::from_tokens(line))
-            .map(|expr| expr.eval())
-            .sum::<u64>();
-        println!("The total of all expressions is {total}");
-    }
-    {
-        println!("Year 2020 Day 18 Part 2");
-        let total = token_streams
-            .into_iter()
-            .map(Expr::eval_advanced)
-            .sum::<u64>();
-        println!("The total of all expressions is {total}");
-    }
-    Ok(())
+        .collect()
+}
+
+/// The total of all expressions when addition and multiplication have the same precedence.
+fn part1(input: &str) -> error::Result<u64> {
+    parse_token_streams(input)
+        .iter()
+        .map(|line| {
+            Expr::from_tokens(line)
+                .map(|expr| expr.eval())
+                .ok_or_else(|| Error::parse(format!("Invalid expression {line:?}")))
+        })
+        .sum()
+}
+
+/// The total of all expressions when addition takes precedence over multiplication.
+fn part2(input: &str) -> error::Result<u64> {
+    Ok(parse_token_streams(input)
+        .into_iter()
+        .map(Expr::eval_advanced)
+        .sum())
+}
+
+aoc_util::solution! {
+    year: 2020,
+    day: 18,
+    title: "Operation Order",
+    part1: part1,
+    part2: part2,
+    examples: [
+        part1 => 26_335,
+        part2 => 693_891,
+    ],
 }
 
 #[cfg(test)]
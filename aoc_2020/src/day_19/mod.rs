@@ -1,4 +1,7 @@
-use aoc_util::nom_extended::NomParse;
+use aoc_util::{
+    error::{self, Error},
+    nom_extended::NomParse,
+};
 use nom::{
     branch, bytes::complete as bytes, character::complete as character, combinator as comb,
     error::ParseError, multi, sequence, AsChar, Finish, IResult, InputIter, InputLength, Offset,
@@ -6,7 +9,7 @@ use nom::{
 };
 use std::{
     collections::{HashMap, HashSet},
-    fs, io, iter,
+    iter,
     ops::{RangeFrom, RangeTo},
 };
 
@@ -230,6 +233,7 @@ impl<'s> NomParse<&'s str> for Rules {
     }
 }
 
+#[derive(Debug)]
 struct RulesAndStrings {
     rules: HashMap<RuleId, Rule>,
     strings: Vec<String>,
@@ -257,158 +261,103 @@ impl<'s> NomParse<&'s str> for RulesAndStrings {
     }
 }
 
-#[allow(unreachable_code)]
-pub(super) fn run() -> io::Result<()> {
-    fn build_lengths(
-        rule_0: &Rule,
-        rules: &HashMap<RuleId, Rule>,
-        max_length: usize,
-    ) -> HashMap<RuleId, HashSet<usize>> {
-        let mut res = HashMap::new();
-        rule_0.length(rules, &mut res, max_length);
-        res
-    }
-    let RulesAndStrings { rules, strings } =
-        RulesAndStrings::nom_parse(&fs::read_to_string("2020_19.txt")?)
-            .finish()
-            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, format!("{e:?}")))?
-            .1;
-    {
-        println!("Year 2020 Day 19 Part 1");
-        let rule_0 = &rules[&RuleId(0)];
-        let mut lengths = build_lengths(
-            rule_0,
-            &rules,
-            strings.iter().map(|s| s.len()).max().unwrap(),
-        );
-        let num_matches = strings
-            .iter()
-            .filter(|s| rule_0.matches(s, &rules, &mut lengths))
-            .count();
-        println!("There are {num_matches} strings that match rule 0");
-    }
-    {
-        println!("Year 2020 Day 19 Part 2");
-        let mut rules = rules;
-        assert_eq!(
-            rules.insert(
-                RuleId(8),
-                Rule {
-                    id: RuleId(8),
-                    inner: UnnamedRule::Branch(Box::new([
-                        UnnamedRule::Proxy(RuleId(42)),
-                        UnnamedRule::Sequence(Box::new([
-                            UnnamedRule::Proxy(RuleId(42)),
-                            UnnamedRule::Proxy(RuleId(8)),
-                        ])),
-                    ])),
-                },
-            ),
-            Some(Rule {
+fn parse_notes(input: &str) -> error::Result<RulesAndStrings> {
+    Ok(RulesAndStrings::nom_parse(input)
+        .finish()
+        .map_err(|e| Error::parse(format!("{e:?}")))?
+        .1)
+}
+
+/// Counts the strings that match rule 0 of `rules`.
+fn count_matches(rules: &HashMap<RuleId, Rule>, strings: &[String]) -> usize {
+    let rule_0 = &rules[&RuleId(0)];
+    let max_length = strings.iter().map(|s| s.len()).max().unwrap_or(0);
+    let mut lengths = HashMap::new();
+    rule_0.length(rules, &mut lengths, max_length);
+    strings
+        .iter()
+        .filter(|s| rule_0.matches(s, rules, &mut lengths))
+        .count()
+}
+
+fn part1(notes: &RulesAndStrings) -> error::Result<usize> {
+    Ok(count_matches(&notes.rules, &notes.strings))
+}
+
+fn part2(notes: &RulesAndStrings) -> error::Result<usize> {
+    let mut rules = notes.rules.clone();
+    assert_eq!(
+        rules.insert(
+            RuleId(8),
+            Rule {
                 id: RuleId(8),
-                inner: UnnamedRule::Proxy(RuleId(42)),
-            }),
-        );
-        assert_eq!(
-            rules.insert(
-                RuleId(11),
-                Rule {
-                    id: RuleId(11),
-                    inner: UnnamedRule::Branch(Box::new([
-                        UnnamedRule::Sequence(Box::new([
-                            UnnamedRule::Proxy(RuleId(42)),
-                            UnnamedRule::Proxy(RuleId(31)),
-                        ])),
-                        UnnamedRule::Sequence(Box::new([
-                            UnnamedRule::Proxy(RuleId(42)),
-                            UnnamedRule::Proxy(RuleId(11)),
-                            UnnamedRule::Proxy(RuleId(31)),
-                        ])),
+                inner: UnnamedRule::Branch(Box::new([
+                    UnnamedRule::Proxy(RuleId(42)),
+                    UnnamedRule::Sequence(Box::new([
+                        UnnamedRule::Proxy(RuleId(42)),
+                        UnnamedRule::Proxy(RuleId(8)),
                     ])),
-                },
-            ),
-            Some(Rule {
+                ])),
+            },
+        ),
+        Some(Rule {
+            id: RuleId(8),
+            inner: UnnamedRule::Proxy(RuleId(42)),
+        }),
+    );
+    assert_eq!(
+        rules.insert(
+            RuleId(11),
+            Rule {
                 id: RuleId(11),
-                inner: UnnamedRule::Sequence(Box::new([
-                    UnnamedRule::Proxy(RuleId(42)),
-                    UnnamedRule::Proxy(RuleId(31)),
+                inner: UnnamedRule::Branch(Box::new([
+                    UnnamedRule::Sequence(Box::new([
+                        UnnamedRule::Proxy(RuleId(42)),
+                        UnnamedRule::Proxy(RuleId(31)),
+                    ])),
+                    UnnamedRule::Sequence(Box::new([
+                        UnnamedRule::Proxy(RuleId(42)),
+                        UnnamedRule::Proxy(RuleId(11)),
+                        UnnamedRule::Proxy(RuleId(31)),
+                    ])),
                 ])),
-            })
-        );
-        let rule_0 = &rules[&RuleId(0)];
-        let mut lengths = build_lengths(
-            rule_0,
-            &rules,
-            strings.iter().map(|s| s.len()).max().unwrap(),
-        );
-        let num_matches = strings
-            .iter()
-            .filter(|s| rule_0.matches(s, &rules, &mut lengths))
-            .count();
-        println!("There are {num_matches} strings that match rule 0");
-    }
-    Ok(())
+            },
+        ),
+        Some(Rule {
+            id: RuleId(11),
+            inner: UnnamedRule::Sequence(Box::new([
+                UnnamedRule::Proxy(RuleId(42)),
+                UnnamedRule::Proxy(RuleId(31)),
+            ])),
+        })
+    );
+    Ok(count_matches(&rules, &notes.strings))
+}
+
+aoc_util::solution! {
+    year: 2020,
+    day: 19,
+    title: "Monster Messages",
+    parse: parse_notes,
+    part1: part1,
+    part2: part2,
+    examples: [
+        part1 => 2,
+        part1("advanced") => 3,
+        part2("advanced") => 12,
+    ],
 }
 
 #[cfg(test)]
 mod test {
+    use aoc_util::samples;
+
     use super::*;
 
     fn get_advanced() -> (HashMap<RuleId, Rule>, Vec<String>) {
-        let rules_str = concat!(
-            "42: 9 14 | 10 1\n",
-            "9: 14 27 | 1 26\n",
-            "10: 23 14 | 28 1\n",
-            "1: \"a\"\n",
-            "11: 42 31\n",
-            "5: 1 14 | 15 1\n",
-            "19: 14 1 | 14 14\n",
-            "12: 24 14 | 19 1\n",
-            "16: 15 1 | 14 14\n",
-            "31: 14 17 | 1 13\n",
-            "6: 14 14 | 1 14\n",
-            "2: 1 24 | 14 4\n",
-            "0: 8 11\n",
-            "13: 14 3 | 1 12\n",
-            "15: 1 | 14\n",
-            "17: 14 2 | 1 7\n",
-            "23: 25 1 | 22 14\n",
-            "28: 16 1\n",
-            "4: 1 1\n",
-            "20: 14 14 | 1 15\n",
-            "3: 5 14 | 16 1\n",
-            "27: 1 6 | 14 18\n",
-            "14: \"b\"\n",
-            "21: 14 1 | 1 14\n",
-            "25: 1 1 | 1 14\n",
-            "22: 14 14\n",
-            "8: 42\n",
-            "26: 14 22 | 1 20\n",
-            "18: 15 15\n",
-            "7: 14 5 | 1 21\n",
-            "24: 14 1\n",
-        );
-        let strings = [
-            "abbbbbabbbaaaababbaabbbbabababbbabbbbbbabaaaa",
-            "bbabbbbaabaabba",
-            "babbbbaabbbbbabbbbbbaabaaabaaa",
-            "aaabbbbbbaaaabaababaabababbabaaabbababababaaa",
-            "bbbbbbbaaaabbbbaaabbabaaa",
-            "bbbababbbbaaaaaaaabbababaaababaabab",
-            "ababaaaaaabaaab",
-            "ababaaaaabbbaba",
-            "baabbaaaabbaaaababbaababb",
-            "abbbbabbbbaaaababbbbbbaaaababb",
-            "aaaaabbaabaaaaababaa",
-            "aaaabbaaaabbaaa",
-            "aaaabbaabbaaaaaaabbbabbbaaabbaabaaa",
-            "babaaabbbaaabaababbaabababaaab",
-            "aabbbbbaabbbaaaaaabbbbbababaaaaabbaaabba",
-        ];
-        (
-            Rules::nom_parse(rules_str).unwrap().1 .0,
-            strings.iter().copied().map(String::from).collect(),
-        )
+        let input = samples::get_named(2020, 19, "advanced").unwrap();
+        let RulesAndStrings { rules, strings } = RulesAndStrings::nom_parse(&input).unwrap().1;
+        (rules, strings)
     }
 
     #[test]
@@ -432,14 +381,7 @@ mod test {
     #[test]
     #[ignore]
     fn parses_rules() {
-        let rule_str = concat!(
-            "0: 4 1 5\n",
-            "1: 2 3 | 3 2\n",
-            "2: 4 4 | 5 5\n",
-            "3: 4 5 | 5 4\n",
-            "4: \"a\"\n",
-            "5: \"b\"\n",
-        );
+        let rule_str = samples::get_named(2020, 19, "rules").unwrap();
         let expected = Ok([
             Rule {
                 id: RuleId(0),
@@ -500,7 +442,7 @@ mod test {
         .iter()
         .map(|rule| (rule.id, rule.clone()))
         .collect::<HashMap<_, _>>());
-        let actual = Rules::nom_parse(rule_str).map(|(_, actual)| actual.0);
+        let actual = Rules::nom_parse(&rule_str).map(|(_, actual)| actual.0);
         assert_eq!(expected, actual);
     }
 
@@ -1,13 +1,12 @@
-use aoc_util::nom_extended::NomParse;
+use aoc_util::{
+    error::{self, Error},
+    nom_extended::NomParse,
+};
 use nom::{
     bytes::complete as bytes, character::complete as character, combinator, combinator as comb,
     sequence, IResult,
 };
-use std::{
-    fs::File,
-    io::{self, BufRead, BufReader},
-    iter,
-};
+use std::iter;
 
 enum PasswordPolicy {
     SingleLetterCount {
@@ -130,33 +129,37 @@ impl FromIterator<PasswordDatabaseEntry> for PasswordDatabase {
     }
 }
 
-#[allow(unreachable_code)]
-pub(super) fn run() -> io::Result<()> {
-    let mut password_database = BufReader::new(File::open("2020_02.txt")?)
+fn parse_database(input: &str) -> error::Result<PasswordDatabase> {
+    input
         .lines()
         .map(|line| {
-            line?
-                .parse()
-                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+            line.parse::<PasswordDatabaseEntry>()
+                .map_err(|e| Error::parse(e.to_string()))
         })
-        .collect::<io::Result<PasswordDatabase>>()?;
-    {
-        println!("Year 2020 Day 2 Part 1");
-        println!(
-            "There are {} valid passwords in the database",
-            password_database.count_valid()
-        );
-    }
-    {
-        println!("Year 2020 Day 2 Part 2");
-        password_database
-            .0
-            .iter_mut()
-            .for_each(|entry| entry.policy.switch_to_multi_letter_check());
-        println!(
-            "There are {} valid passwords in the database",
-            password_database.count_valid()
-        );
-    }
-    Ok(())
+        .collect()
+}
+
+fn part1(input: &str) -> error::Result<usize> {
+    Ok(parse_database(input)?.count_valid())
+}
+
+fn part2(input: &str) -> error::Result<usize> {
+    let mut password_database = parse_database(input)?;
+    password_database
+        .0
+        .iter_mut()
+        .for_each(|entry| entry.policy.switch_to_multi_letter_check());
+    Ok(password_database.count_valid())
+}
+
+aoc_util::solution! {
+    year: 2020,
+    day: 2,
+    title: "Password Philosophy",
+    part1: part1,
+    part2: part2,
+    examples: [
+        part1 => 2,
+        part2 => 1,
+    ],
 }
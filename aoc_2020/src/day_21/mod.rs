@@ -1,8 +1,5 @@
-use std::{
-    collections::{BTreeSet, HashMap, HashSet},
-    fs::File,
-    io::{self, BufRead, BufReader},
-};
+use aoc_util::error::{self, Error};
+use std::collections::{BTreeSet, HashMap, HashSet};
 
 struct IntersperseIter<I, T> {
     iter: I,
@@ -76,20 +73,17 @@ where
     }
 }
 
-fn read_allergens(
-    input: &mut dyn BufRead,
-) -> io::Result<HashMap<BTreeSet<String>, HashSet<String>>> {
+fn read_allergens(input: &str) -> error::Result<HashMap<BTreeSet<String>, HashSet<String>>> {
     input.lines().try_fold(HashMap::new(), |mut acc, line| {
-        // `line?` is of the form `"mxmxvkd kfcds sqjhc nhms (contains dairy, fish)"` where
+        // `line` is of the form `"mxmxvkd kfcds sqjhc nhms (contains dairy, fish)"` where
         // `mxmxvkd`, `kfcds`, `sqjhc`, and `nhms` are the ingredients and `dairy` and `fish` are
         // the marked allergens.
         //
         // Thus `ingredients` is of the form `"mxmxvkd kfcds sqjhc nhms" and `allergens` is of the
         // form `"dairy, fish)"`.
-        let line = line?;
         let (ingredients, allergens) = line
             .split_once(" (contains ")
-            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "Missing allergen list"))?;
+            .ok_or_else(|| Error::parse("Missing allergen list"))?;
         // This can't be a HashSet because HashSet doesn't implement Hash for some reason.
         let ingredients = ingredients
             .split_whitespace()
@@ -108,7 +102,7 @@ fn read_allergens(
 /// ingredient that contains it.
 fn separate_allergens(
     ingredients: &HashMap<BTreeSet<String>, HashSet<String>>,
-) -> io::Result<(HashSet<String>, HashMap<String, String>)> {
+) -> error::Result<(HashSet<String>, HashMap<String, String>)> {
     let known_allergens = ingredients;
     let mut potential_sources = known_allergens.iter().fold(
         HashMap::<_, HashSet<String>>::new(),
@@ -133,12 +127,10 @@ fn separate_allergens(
         for (allergen, possible_ingredients) in &potential_sources {
             match possible_ingredients.len() {
                 0 => {
-                    println!("{allergen} has no more potential sources, removing it");
                     allergens_to_remove.insert(allergen.to_owned());
                 }
                 1 => {
                     let ingredient = possible_ingredients.iter().next().unwrap().to_owned();
-                    println!("Found out that {ingredient} contains {allergen}");
                     actual_sources.insert(allergen.clone(), ingredient.clone());
                     ingredients_to_remove.insert(ingredient);
                 }
@@ -172,7 +164,7 @@ fn separate_allergens(
     Ok((ingredients, actual_sources))
 }
 
-fn part1(input: &mut dyn BufRead) -> io::Result<usize> {
+fn part1(input: &str) -> error::Result<usize> {
     let ingredients = read_allergens(input)?;
     let (clean_ingredients, _) = separate_allergens(&ingredients)?;
     Ok(ingredients
@@ -185,7 +177,7 @@ fn part1(input: &mut dyn BufRead) -> io::Result<usize> {
         .count())
 }
 
-fn part2(input: &mut dyn BufRead) -> io::Result<String> {
+fn part2(input: &str) -> error::Result<String> {
     let ingredients = read_allergens(input)?;
     let (_, allergens) = separate_allergens(&ingredients)?;
     let mut allergens = allergens.into_iter().collect::<Vec<_>>();
@@ -197,44 +189,26 @@ fn part2(input: &mut dyn BufRead) -> io::Result<String> {
         .collect())
 }
 
-pub(super) fn run() -> io::Result<()> {
-    {
-        println!("Year 2020 Day 21 Part 1");
-        println!(
-            "There are {} occurrences of ingredients that definitely do not contain any relevant allergens",
-            part1(&mut BufReader::new(File::open("2020_21.txt")?))?,
-        );
-    }
-    {
-        println!("Year 2020 Day 21 Part 2");
-        println!(
-            "{}",
-            part2(&mut BufReader::new(File::open("2020_21.txt")?))?
-        );
-    }
-    Ok(())
-}
+aoc_util::register_day!(2020, 21, part1, part2, "Allergen Assessment");
 
 #[cfg(test)]
 mod test {
-    use std::io::Cursor;
-
     use super::*;
 
     #[test]
-    fn test_part1() -> io::Result<()> {
+    fn test_part1() -> error::Result<()> {
         let s = "mxmxvkd kfcds sqjhc nhms (contains dairy, fish)\ntrh fvjkl sbzzf mxmxvkd (contains dairy)\nsqjhc fvjkl (contains soy)\nsqjhc mxmxvkd sbzzf (contains fish)";
         let expected = 5;
-        let actual = part1(&mut Cursor::new(s))?;
+        let actual = part1(s)?;
         assert_eq!(expected, actual);
         Ok(())
     }
 
     #[test]
-    fn test_part2() -> io::Result<()> {
+    fn test_part2() -> error::Result<()> {
         let s = "mxmxvkd kfcds sqjhc nhms (contains dairy, fish)\ntrh fvjkl sbzzf mxmxvkd (contains dairy)\nsqjhc fvjkl (contains soy)\nsqjhc mxmxvkd sbzzf (contains fish)";
         let expected = "mxmxvkd,sqjhc,fvjkl";
-        let actual = part2(&mut Cursor::new(s))?;
+        let actual = part2(s)?;
         assert_eq!(expected, actual);
         Ok(())
     }
@@ -1,30 +1,19 @@
-use std::{
-    fs::File,
-    io::{self, BufRead, BufReader},
-};
+use aoc_util::error::{self, Error};
 
-fn part1(_input: &mut dyn BufRead) -> io::Result<()> {
-    todo!("Year 2020 Day 22 Part 1")
+fn part1(_input: &str) -> error::Result<u32> {
+    Err(Error::Unimplemented {
+        year: 2020,
+        day: 22,
+        part: Some(1),
+    })
 }
 
-fn part2(_input: &mut dyn BufRead) -> io::Result<()> {
-    todo!("Year 2020 Day 22 Part 2")
+fn part2(_input: &str) -> error::Result<u32> {
+    Err(Error::Unimplemented {
+        year: 2020,
+        day: 22,
+        part: Some(2),
+    })
 }
 
-pub(super) fn run() -> io::Result<()> {
-    {
-        println!("Year 2020 Day 22 Part 1");
-        println!(
-            "{:?}",
-            part1(&mut BufReader::new(File::open("2020_22.txt")?))?
-        );
-    }
-    {
-        println!("Year 2020 Day 22 Part 2");
-        println!(
-            "{:?}",
-            part2(&mut BufReader::new(File::open("2020_22.txt")?))?
-        );
-    }
-    Ok(())
-}
+aoc_util::register_day!(2020, 22, part1, part2, "Crab Combat");
@@ -1,8 +1,11 @@
-use aoc_util::nom_extended::NomParse;
+use aoc_util::{
+    error::{self, Error},
+    geometry::Point2D,
+    grid::Grid,
+    nom_extended::NomParse,
+};
 
-use std::{fs, io, iter};
-
-use nom::{branch, character::complete as character, combinator as comb, multi, sequence, IResult};
+use nom::{branch, character::complete as character, combinator as comb, IResult};
 
 #[derive(Clone, Copy, Debug)]
 enum Tile {
@@ -20,14 +23,14 @@ impl<'s> NomParse<&'s str> for Tile {
 }
 
 #[derive(Clone, Debug)]
-struct TreeMap(Vec<Vec<Tile>>);
+struct TreeMap(Grid<Tile>);
 
 impl TreeMap {
     fn count_trees(&self, delta_x: usize, delta_y: usize) -> usize {
-        (0..self.0.len())
+        (0..self.0.height())
             .step_by(delta_y)
-            .zip((0..).step_by(delta_x).map(|x| x % self.0[0].len()))
-            .fold(0, |acc, (y, x)| match self.0[y][x] {
+            .zip((0..).step_by(delta_x).map(|x| x % self.0.width()))
+            .fold(0, |acc, (y, x)| match self.0[Point2D::at(x, y)] {
                 Tile::Snow => acc,
                 Tile::Tree => acc + 1,
             })
@@ -36,38 +39,36 @@ impl TreeMap {
 
 impl<'s> NomParse<&'s str> for TreeMap {
     fn nom_parse(s: &'s str) -> IResult<&'s str, Self> {
-        let (_, first_line) =
-            sequence::terminated(multi::many1(Tile::nom_parse), character::line_ending)(s)?;
-        comb::map(
-            multi::many1(sequence::terminated(
-                multi::many_m_n(first_line.len(), first_line.len(), Tile::nom_parse),
-                character::line_ending,
-            )),
-            Self,
-        )(s)
+        comb::map(Grid::nom_parse, Self)(s)
     }
 }
 
-#[allow(unreachable_code)]
-pub(super) fn run() -> io::Result<()> {
-    let (_, tree_map) =
-        TreeMap::nom_parse(&fs::read_to_string("2020_03.txt")?).expect("Couldn't parse tree map");
-    let three = {
-        println!("Year 2020 Day 3 Part 1");
-        let three = tree_map.count_trees(3, 1);
-        println!("There are {three} trees on the path with slope -1/3");
-        three
-    };
-    {
-        println!("Year 2020 Day 3 Part 2");
-        let total = iter::once(three)
-            .chain(
-                [(1usize, 1usize), (5, 1), (7, 1), (1, 2)]
-                    .iter()
-                    .map(|&(delta_x, delta_y)| tree_map.count_trees(delta_x, delta_y)),
-            )
-            .product::<usize>();
-        println!("The product is {total} trees**5");
-    }
-    Ok(())
+fn parse_map(input: &str) -> error::Result<TreeMap> {
+    TreeMap::nom_parse(input)
+        .map(|(_, tree_map)| tree_map)
+        .map_err(|e| Error::parse(e.to_string()))
+}
+
+fn part1(tree_map: &TreeMap) -> error::Result<usize> {
+    Ok(tree_map.count_trees(3, 1))
+}
+
+fn part2(tree_map: &TreeMap) -> error::Result<usize> {
+    Ok([(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)]
+        .into_iter()
+        .map(|(delta_x, delta_y)| tree_map.count_trees(delta_x, delta_y))
+        .product())
+}
+
+aoc_util::solution! {
+    year: 2020,
+    day: 3,
+    title: "Toboggan Trajectory",
+    parse: parse_map,
+    part1: part1,
+    part2: part2,
+    examples: [
+        part1 => 7,
+        part2 => 336,
+    ],
 }
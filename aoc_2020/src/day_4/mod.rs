@@ -1,10 +1,10 @@
-use aoc_util::nom_extended::NomParse;
-
-use std::{
-    collections::{HashMap, HashSet},
-    fs, io,
+use aoc_util::{
+    error::{self, Error},
+    nom_extended::NomParse,
 };
 
+use std::collections::{HashMap, HashSet};
+
 use nom::{
     branch, bytes::complete as bytes, character::complete as character, combinator as comb,
     sequence, IResult,
@@ -236,34 +236,40 @@ impl<'s> NomParse<&'s str> for Passport<'s> {
     }
 }
 
-pub(super) fn run() -> io::Result<()> {
-    let passport_text = fs::read_to_string("2020_04.txt")?;
-    let passports = passport_text
+/// Counts the passports in `input` which satisfy `predicate`.
+fn count_passports<'s>(
+    input: &'s str,
+    predicate: fn(&Passport<'s>) -> bool,
+) -> error::Result<usize> {
+    input
         .split("\n\n")
         .map(|s| {
-            // println!("Parsing {:?}", s);
             Passport::nom_parse(s)
                 .map(|(_, res)| res)
-                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, format!("{e:?}")))
+                .map_err(|e| Error::parse(format!("{e:?}")))
         })
-        .collect::<Result<Vec<Passport<'_>>, _>>()?;
-    {
-        println!("Year 2020 Day 4 Part 1");
-        println!(
-            "There are {} valid passports",
-            passports
-                .iter()
-                .copied()
-                .filter(Passport::is_filled)
-                .count(),
-        );
-    }
-    {
-        println!("Year 2020 Day 4 Part 2");
-        println!(
-            "There are {} valid passports",
-            passports.iter().copied().filter(Passport::is_valid).count(),
-        );
-    }
-    Ok(())
+        .try_fold(0, |count, passport| {
+            Ok(count + usize::from(predicate(&passport?)))
+        })
+}
+
+fn part1(input: &str) -> error::Result<usize> {
+    count_passports(input, Passport::is_filled)
+}
+
+fn part2(input: &str) -> error::Result<usize> {
+    count_passports(input, Passport::is_valid)
+}
+
+aoc_util::solution! {
+    year: 2020,
+    day: 4,
+    title: "Passport Processing",
+    part1: part1,
+    part2: part2,
+    examples: [
+        part1 => 2,
+        part2("invalid") => 0,
+        part2("valid") => 4,
+    ],
 }
@@ -1,10 +1,9 @@
-use aoc_util::nom_extended::NomParse;
-use nom::{bytes::complete as bytes, combinator as comb, sequence, IResult};
-use std::{
-    cmp::Ordering,
-    fs::File,
-    io::{self, BufRead, BufReader},
+use aoc_util::{
+    error::{self, Error},
+    nom_extended::NomParse,
 };
+use nom::{bytes::complete as bytes, combinator as comb, sequence, IResult};
+use std::cmp::Ordering;
 
 #[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
 #[repr(transparent)]
@@ -79,39 +78,50 @@ impl<'s> NomParse<&'s str> for Seat {
 
 aoc_util::impl_from_str_for_nom_parse!(Row Column Seat);
 
-#[allow(unreachable_code)]
-pub(super) fn run() -> io::Result<()> {
-    let mut seats = BufReader::new(File::open("2020_05.txt")?)
+/// Parses the boarding passes in `input`, ordered by seat ID.
+fn parse_seats(input: &str) -> error::Result<Vec<Seat>> {
+    let mut seats = input
         .lines()
         .map(|line| {
-            line?
-                .parse::<Seat>()
-                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+            line.parse::<Seat>()
+                .map_err(|e| Error::parse(e.to_string()))
         })
-        .collect::<io::Result<Vec<_>>>()?;
+        .collect::<error::Result<Vec<_>>>()?;
     seats.sort();
-    {
-        println!("Year 2020 Day 5 Part 1");
-        println!(
-            "The highest seat ID is {}",
-            seats.iter().last().unwrap().seat_id()
-        );
-    }
-    {
-        println!("Year 2020 Day 5 Part 2");
-        let seat = seats
-            .windows(2)
-            .map(|window| match window {
-                &[left, right] => [left.seat_id(), right.seat_id()],
-                _ => unreachable!("Windows are of width 2"),
-            })
-            .find_map(|[left_seat, right_seat]| {
-                Some(left_seat + 1).filter(|&seat| seat == right_seat - 1)
-            })
-            .expect("No pair of seats with exactly one seat between them");
-        println!("The only empty seat is ID {seat}");
-    }
-    Ok(())
+    Ok(seats)
+}
+
+fn part1(input: &str) -> error::Result<u32> {
+    parse_seats(input)?
+        .last()
+        .map(Seat::seat_id)
+        .ok_or_else(|| Error::parse("There are no boarding passes"))
+}
+
+fn part2(input: &str) -> error::Result<u32> {
+    parse_seats(input)?
+        .windows(2)
+        .map(|window| match window {
+            &[left, right] => [left.seat_id(), right.seat_id()],
+            _ => unreachable!("Windows are of width 2"),
+        })
+        .find_map(|[left_seat, right_seat]| {
+            Some(left_seat + 1).filter(|&seat| seat == right_seat - 1)
+        })
+        .ok_or_else(|| {
+            Error::UnsolvableInput("No pair of seats has exactly one seat between them".to_string())
+        })
+}
+
+aoc_util::solution! {
+    year: 2020,
+    day: 5,
+    title: "Binary Boarding",
+    part1: part1,
+    part2: part2,
+    examples: [
+        part1 => 820,
+    ],
 }
 
 #[cfg(test)]
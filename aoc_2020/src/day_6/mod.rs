@@ -1,8 +1,10 @@
-use aoc_util::nom_extended::NomParse;
+use aoc_util::{
+    error::{self, Error},
+    nom_extended::NomParse,
+};
 
 use std::{
     convert::TryFrom,
-    fs, io,
     iter::{FromIterator, Product, Sum},
     ops::{Add, Index, Mul},
 };
@@ -125,33 +127,42 @@ impl<'s> NomParse<&'s str> for GroupAnswers {
 
 aoc_util::impl_from_str_for_nom_parse!(GroupAnswers);
 
-pub(super) fn run() -> io::Result<()> {
-    let group_answers = fs::read_to_string("2020_06.txt")?
+fn parse_groups(input: &str) -> error::Result<Vec<GroupAnswers>> {
+    input
+        .trim_end()
         .split("\n\n")
         .map(|s| s.parse::<GroupAnswers>())
         .collect::<Result<Vec<_>, _>>()
-        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
-    {
-        println!("Year 2020 Day 6 Part 1");
-        let distinct_answers = group_answers
-            .iter()
-            .map(GroupAnswers::count_distinct_answers)
-            .sum::<usize>();
-        println!(
-            "The total number of answers, counting each answer only once within each group, is {distinct_answers}",
-        );
-    }
-    {
-        println!("Year 2020 Day 6 Part 2");
-        let shared_answers = group_answers
-            .iter()
-            .map(GroupAnswers::count_shared_answers)
-            .sum::<usize>();
-        println!(
-            "The total number of answers, counting each answer for a group only if all members of that group answered that question, is {shared_answers}",
-        );
-    }
-    Ok(())
+        .map_err(|e| Error::parse(e.to_string()))
+}
+
+/// Counts the answers in each group, counting each answer only once within each group.
+fn part1(input: &str) -> error::Result<usize> {
+    Ok(parse_groups(input)?
+        .iter()
+        .map(GroupAnswers::count_distinct_answers)
+        .sum())
+}
+
+/// Counts the answers in each group, counting an answer only if every member of the group gave
+/// it.
+fn part2(input: &str) -> error::Result<usize> {
+    Ok(parse_groups(input)?
+        .iter()
+        .map(GroupAnswers::count_shared_answers)
+        .sum())
+}
+
+aoc_util::solution! {
+    year: 2020,
+    day: 6,
+    title: "Custom Customs",
+    part1: part1,
+    part2: part2,
+    examples: [
+        part1 => 11,
+        part2 => 6,
+    ],
 }
 
 #[cfg(test)]
@@ -1,4 +1,7 @@
-use aoc_util::nom_extended::NomParse;
+use aoc_util::{
+    error::{self, Error},
+    nom_extended::NomParse,
+};
 use nom::{
     branch, bytes::complete as bytes, character::complete as character, combinator,
     combinator as comb, multi, sequence, Finish, IResult,
@@ -6,7 +9,6 @@ use nom::{
 use std::{
     collections::{HashMap, HashSet},
     fmt::{self, Display, Formatter},
-    fs, io,
 };
 
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
@@ -150,35 +152,42 @@ where
     }
 }
 
-pub(super) fn run() -> io::Result<()> {
-    let file_contents = fs::read_to_string("2020_07.txt")?;
-    let bag_rules = BagRules::nom_parse(&file_contents)
+fn parse_rules(input: &str) -> error::Result<BagRules<'_>> {
+    Ok(BagRules::nom_parse(input)
         .finish()
-        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, format!("{e:?}")))?
-        .1;
-    {
-        println!("Year 2020 Day 7 Part 1");
-        let inner = "shiny gold";
-        println!(
-            "There are {} types of bags that can contain a {} bag at some level of nesting",
-            bag_rules.get_wrapper_types(BagColor(inner)).len(),
-            inner,
-        );
-    }
-    {
-        println!("Year 2020 Day 7 Part 2");
-        let outer = "shiny gold";
-        println!(
-            "A {} bag must contain {} distinct bags at some level of nesting",
-            outer,
-            bag_rules.requires_contained(BagColor(outer)),
-        );
-    }
-    Ok(())
+        .map_err(|e| Error::parse(format!("{e:?}")))?
+        .1)
+}
+
+/// Counts the types of bags that can contain a shiny gold bag at some level of nesting.
+fn part1(input: &str) -> error::Result<usize> {
+    Ok(parse_rules(input)?
+        .get_wrapper_types(BagColor("shiny gold"))
+        .len())
+}
+
+/// Counts the bags that a shiny gold bag must contain at some level of nesting.
+fn part2(input: &str) -> error::Result<usize> {
+    Ok(parse_rules(input)?.requires_contained(BagColor("shiny gold")))
+}
+
+aoc_util::solution! {
+    year: 2020,
+    day: 7,
+    title: "Handy Haversacks",
+    part1: part1,
+    part2: part2,
+    examples: [
+        part1 => 4,
+        part2 => 32,
+        part2("deep") => 126,
+    ],
 }
 
 #[cfg(test)]
 mod test {
+    use aoc_util::samples;
+
     use super::*;
 
     #[ignore]
@@ -286,18 +295,8 @@ mod test {
             .cloned()
             .collect(),
         ));
-        let actual = BagRules::nom_parse(concat!(
-            "light red bags contain 1 bright white bag, 2 muted yellow bags.\n",
-            "dark orange bags contain 3 bright white bags, 4 muted yellow bags.\n",
-            "bright white bags contain 1 shiny gold bag.\n",
-            "muted yellow bags contain 2 shiny gold bags, 9 faded blue bags.\n",
-            "shiny gold bags contain 1 dark olive bag, 2 vibrant plum bags.\n",
-            "dark olive bags contain 3 faded blue bags, 4 dotted black bags.\n",
-            "vibrant plum bags contain 5 faded blue bags, 6 dotted black bags.\n",
-            "faded blue bags contain no other bags.\n",
-            "dotted black bags contain no other bags.\n",
-        ))
-        .map(|res| res.1);
+        let input = samples::get(2020, 7).unwrap();
+        let actual = BagRules::nom_parse(&input).map(|res| res.1);
         assert_eq!(expected, actual);
     }
 }
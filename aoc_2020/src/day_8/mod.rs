@@ -1,9 +1,12 @@
-use aoc_util::nom_extended::NomParse;
+use aoc_util::{
+    error::{self, Error},
+    nom_extended::NomParse,
+};
 use nom::{
     branch, bytes::complete as bytes, character::complete as character, combinator as comb,
     sequence, IResult,
 };
-use std::{collections::HashSet, convert::TryFrom, fs, io};
+use std::{collections::HashSet, convert::TryFrom};
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 enum Instruction {
@@ -139,42 +142,59 @@ impl<'instructions> State<'instructions> {
     }
 }
 
-#[allow(unreachable_code)]
-pub(super) fn run() -> io::Result<()> {
-    let instructions = fs::read_to_string("2020_08.txt")?
+fn parse_instructions(input: &str) -> error::Result<Vec<Instruction>> {
+    input
         .lines()
-        .map(str::parse)
+        .map(str::parse::<Instruction>)
         .collect::<Result<Vec<_>, _>>()
-        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
-    let state = State::new(&instructions);
-    {
-        println!("Year 2020 Day 8 Part 1");
-        println!(
-            "Immediately before an instruction is first executed for the second time, the value of the accumulator is {}",
-            state.run().expect_err("Program ran out of instructions before looping"),
-        );
-    }
-    {
-        println!("Year 2020 Day 8 Part 2");
-        let mut local_instructions = instructions.clone();
-        let res = (0..instructions.len())
-            .filter_map(|idx| match instructions[idx] {
-                Instruction::NoOp(delta) => Some((idx, Instruction::Jump(delta))),
-                Instruction::Accumulate(_) => None,
-                Instruction::Jump(delta) => Some((idx, Instruction::NoOp(delta))),
-            })
-            .fold(None, |acc, (idx, replacement)| {
-                acc.or_else(|| {
-                    local_instructions[idx] = replacement;
-                    let res = State::new(&local_instructions).run().ok();
-                    local_instructions[idx] = instructions[idx];
-                    res
-                })
-            })
-            .expect("No single no-op or jump instruction found to remove the infinite loop");
-        println!("The program terminates with {res} in the accumulator");
+        .map_err(|e| Error::parse(e.to_string()))
+}
+
+/// The value of the accumulator immediately before an instruction is first executed for the
+/// second time.
+fn part1(input: &str) -> error::Result<i32> {
+    match State::new(&parse_instructions(input)?).run() {
+        Ok(_) => Err(Error::UnsolvableInput(
+            "Program ran out of instructions before looping".to_string(),
+        )),
+        Err(accumulator) => Ok(accumulator),
     }
-    Ok(())
+}
+
+/// The value of the accumulator once the program terminates after swapping a single no-op or
+/// jump instruction.
+fn part2(input: &str) -> error::Result<i32> {
+    let instructions = parse_instructions(input)?;
+    let mut local_instructions = instructions.clone();
+    (0..instructions.len())
+        .filter_map(|idx| match instructions[idx] {
+            Instruction::NoOp(delta) => Some((idx, Instruction::Jump(delta))),
+            Instruction::Accumulate(_) => None,
+            Instruction::Jump(delta) => Some((idx, Instruction::NoOp(delta))),
+        })
+        .find_map(|(idx, replacement)| {
+            local_instructions[idx] = replacement;
+            let res = State::new(&local_instructions).run().ok();
+            local_instructions[idx] = instructions[idx];
+            res
+        })
+        .ok_or_else(|| {
+            Error::UnsolvableInput(
+                "No single no-op or jump instruction found to remove the infinite loop".to_string(),
+            )
+        })
+}
+
+aoc_util::solution! {
+    year: 2020,
+    day: 8,
+    title: "Handheld Halting",
+    part1: part1,
+    part2: part2,
+    examples: [
+        part1 => 5,
+        part2 => 8,
+    ],
 }
 
 #[cfg(test)]
@@ -1,9 +1,5 @@
-use std::{
-    cmp::Ordering,
-    fmt::Display,
-    fs::File,
-    io::{self, BufRead, BufReader},
-};
+use aoc_util::error::{self, Error};
+use std::cmp::Ordering;
 
 enum SumResult {
     Incomplete,
@@ -12,73 +8,94 @@ enum SumResult {
 }
 
 impl SumResult {
-    fn expect<Message>(self, message: Message) -> u64
-    where
-        Message: Display,
-    {
+    fn weakness(self) -> Option<u64> {
         match self {
-            Self::Weakness(res) => res,
-            Self::Incomplete | Self::Overflow => panic!("{}", message),
+            Self::Weakness(res) => Some(res),
+            Self::Incomplete | Self::Overflow => None,
         }
     }
 }
 
 use SumResult::{Incomplete, Overflow, Weakness};
 
-pub(super) fn run() -> io::Result<()> {
-    const PREAMBLE_LENGTH: usize = 25;
-    let xmas_stream = BufReader::new(File::open("2020_09.txt")?)
+const PREAMBLE_LENGTH: usize = 25;
+
+fn parse_stream(input: &str) -> error::Result<Vec<u64>> {
+    input
         .lines()
-        .map(|line| {
-            line?
-                .parse::<u64>()
-                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
-        })
-        .collect::<io::Result<Vec<_>>>()?;
-    let invalid_follower = {
-        println!("Year 2020 Day 9 Part 1");
-        let invalid_follower = xmas_stream
-            .windows(PREAMBLE_LENGTH + 1)
-            .find_map(|window| {
-                for (idx, &first_value) in window[..PREAMBLE_LENGTH].iter().enumerate() {
-                    if window[(idx + 1)..PREAMBLE_LENGTH]
-                        .iter()
-                        .any(|&second_value| first_value + second_value == window[PREAMBLE_LENGTH])
-                    {
-                        return None;
-                    }
+        .map(|line| line.parse::<u64>().map_err(|e| Error::parse(e.to_string())))
+        .collect()
+}
+
+/// Finds the first number in `xmas_stream` which isn't the sum of two of the `preamble_length`
+/// numbers before it.
+fn find_invalid_follower(xmas_stream: &[u64], preamble_length: usize) -> error::Result<u64> {
+    xmas_stream
+        .windows(preamble_length + 1)
+        .find_map(|window| {
+            for (idx, &first_value) in window[..preamble_length].iter().enumerate() {
+                if window[(idx + 1)..preamble_length]
+                    .iter()
+                    .any(|&second_value| first_value + second_value == window[preamble_length])
+                {
+                    return None;
                 }
-                Some(window[PREAMBLE_LENGTH])
-            })
-            .expect("All values in XMAS stream are valid");
-        println!("The first invalid number in the XMAS stream is {invalid_follower}");
-        invalid_follower
-    };
-    {
-        println!("Year 2020 Day 9 Part 2");
-        let encryption_weakness = (0..xmas_stream.len())
-            .fold(Incomplete, |acc, start| match acc {
-                Weakness(_) => acc,
-                Incomplete | Overflow => {
-                    ((start + 2)..=xmas_stream.len()).fold(Incomplete, |acc, end| match acc {
-                        Weakness(_) | Overflow => acc,
-                        Incomplete => {
-                            let window = &xmas_stream[start..end];
-                            match window.iter().sum::<u64>().cmp(&invalid_follower) {
-                                Ordering::Less => Incomplete,
-                                Ordering::Equal => {
-                                    let least = window.iter().min().unwrap();
-                                    let most = window.iter().max().unwrap();
-                                    Weakness(least + most)
-                                }
-                                Ordering::Greater => Overflow,
+            }
+            Some(window[preamble_length])
+        })
+        .ok_or_else(|| Error::UnsolvableInput("All values in XMAS stream are valid".to_string()))
+}
+
+/// Finds the contiguous run of at least two numbers in `xmas_stream` which sum to the first
+/// invalid number and adds the least and greatest numbers in it together.
+fn find_encryption_weakness(xmas_stream: &[u64], preamble_length: usize) -> error::Result<u64> {
+    let invalid_follower = find_invalid_follower(xmas_stream, preamble_length)?;
+    (0..xmas_stream.len())
+        .fold(Incomplete, |acc, start| match acc {
+            Weakness(_) => acc,
+            Incomplete | Overflow => {
+                ((start + 2)..=xmas_stream.len()).fold(Incomplete, |acc, end| match acc {
+                    Weakness(_) | Overflow => acc,
+                    Incomplete => {
+                        let window = &xmas_stream[start..end];
+                        match window.iter().sum::<u64>().cmp(&invalid_follower) {
+                            Ordering::Less => Incomplete,
+                            Ordering::Equal => {
+                                let least = window.iter().min().unwrap();
+                                let most = window.iter().max().unwrap();
+                                Weakness(least + most)
                             }
+                            Ordering::Greater => Overflow,
                         }
-                    })
-                }
-            })
-            .expect("Couldn't find weakness");
-        println!("Weakness is {encryption_weakness}");
+                    }
+                })
+            }
+        })
+        .weakness()
+        .ok_or_else(|| Error::UnsolvableInput("Couldn't find weakness".to_string()))
+}
+
+fn part1(input: &str) -> error::Result<u64> {
+    find_invalid_follower(&parse_stream(input)?, PREAMBLE_LENGTH)
+}
+
+fn part2(input: &str) -> error::Result<u64> {
+    find_encryption_weakness(&parse_stream(input)?, PREAMBLE_LENGTH)
+}
+
+aoc_util::register_day!(2020, 9, part1, part2, "Encoding Error");
+
+#[cfg(test)]
+mod test {
+    use aoc_util::samples;
+
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        // The example only has a preamble of 5 numbers.
+        let xmas_stream = parse_stream(&samples::get(2020, 9).unwrap()).unwrap();
+        assert_eq!(127, find_invalid_follower(&xmas_stream, 5).unwrap());
+        assert_eq!(62, find_encryption_weakness(&xmas_stream, 5).unwrap());
     }
-    Ok(())
 }
@@ -1,7 +1,4 @@
-use std::{
-    fs::File,
-    io::{self, BufRead, BufReader},
-};
+use aoc_util::error::{self, Error};
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 enum Delimiter {
@@ -42,7 +39,7 @@ impl Delimiter {
 }
 
 impl TryFrom<char> for Delimiter {
-    type Error = io::Error;
+    type Error = Error;
 
     fn try_from(c: char) -> Result<Self, Self::Error> {
         match c {
@@ -54,20 +51,16 @@ impl TryFrom<char> for Delimiter {
             '}' => Ok(Self::Brace(true)),
             '<' => Ok(Self::Angle(false)),
             '>' => Ok(Self::Angle(true)),
-            c => Err(io::Error::new(
-                io::ErrorKind::InvalidData,
-                format!("Invalid chunk delimiter {c:?}"),
-            )),
+            c => Err(Error::parse(format!("Invalid chunk delimiter {c:?}"))),
         }
     }
 }
 
-fn part1(input: &mut dyn BufRead) -> io::Result<u32> {
+fn part1(input: &str) -> error::Result<u32> {
     use Delimiter::{Angle, Brace, Bracket, Parenthesis};
     input
         .lines()
         .map(|line| {
-            let line = line?;
             let mut stack = vec![];
             for c in line.chars().map(Delimiter::try_from) {
                 let c = c?;
@@ -84,12 +77,11 @@ fn part1(input: &mut dyn BufRead) -> io::Result<u32> {
         .sum()
 }
 
-fn part2(input: &mut dyn BufRead) -> io::Result<u64> {
+fn part2(input: &str) -> error::Result<u64> {
     use Delimiter::{Angle, Brace, Bracket, Parenthesis};
     let mut scores = input
         .lines()
         .map(|line| {
-            let line = line?;
             let mut stack = vec![];
             for c in line.chars().map(Delimiter::try_from) {
                 let c = c?;
@@ -108,70 +100,20 @@ fn part2(input: &mut dyn BufRead) -> io::Result<u64> {
                 .try_fold(0, |acc, points| Ok(acc * 5 + points))
         })
         .filter(|score| !matches!(score, Ok(0)))
-        .collect::<io::Result<Vec<_>>>()?;
+        .collect::<error::Result<Vec<_>>>()?;
     scores.sort_unstable();
     Ok(scores[scores.len() / 2])
 }
 
-pub(super) fn run() -> io::Result<()> {
-    {
-        println!("Year 2021 Day 10 Part 1");
-        println!(
-            "The total syntax error score is {}",
-            part1(&mut BufReader::new(File::open("2021_10.txt")?))?
-        );
-    }
-    {
-        println!("Year 2021 Day 10 Part 2");
-        println!(
-            "The middle autocomplete score is {}",
-            part2(&mut BufReader::new(File::open("2021_10.txt")?))?
-        );
-    }
-    Ok(())
-}
-
-#[cfg(test)]
-mod tests {
-    use std::io::Cursor;
-
-    use super::*;
-
-    #[test]
+aoc_util::solution! {
+    year: 2021,
+    day: 10,
+    title: "Syntax Scoring",
+    part1: part1,
+    part2: part2,
     #[ignore]
-    fn test_part1() -> io::Result<()> {
-        let s = r"[({(<(())[]>[[{[]{<()<>>
-[(()[<>])]({[<{<<[]>>(
-{([(<{}[<>[]}>{[]{[(<()>
-(((({<>}<{<{<>}{[]{[]{}
-[[<[([]))<([[{}[[()]]]
-[{[{({}]{}}([{[{{{}}([]
-{<[[]]>}<{[{[{[]{()[[[]
-[<(<(<(<{}))><([]([]()
-<{([([[(<>()){}]>(<<{{
-<{([{{}}[<[[[<>{}]]]>[]]";
-        let expected = 26_397;
-        let actual = part1(&mut Cursor::new(s))?;
-        assert_eq!(expected, actual);
-        Ok(())
-    }
-
-    #[test]
-    #[ignore]
-    fn test_part2() -> io::Result<()> {
-        let s = r"[({(<(())[]>[[{[]{<()<>>
-[(()[<>])]({[<{<<[]>>(
-{([(<{}[<>[]}>{[]{[(<()>
-(((({<>}<{<{<>}{[]{[]{}
-[[<[([]))<([[{}[[()]]]
-[{[{({}]{}}([{[{{{}}([]
-{<[[]]>}<{[{[{[]{()[[[]
-[<(<(<(<{}))><([]([]()
-<{([([[(<>()){}]>(<<{{
-<{([{{}}[<[[[<>{}]]]>[]]";
-        let expected = 288_957;
-        let actual = part2(&mut Cursor::new(s))?;
-        assert_eq!(expected, actual);
-        Ok(())
-    }
+    examples: [
+        part1 => 26_397,
+        part2 => 288_957,
+    ],
 }
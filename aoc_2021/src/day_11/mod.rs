@@ -1,9 +1,5 @@
-use std::{
-    collections::HashSet,
-    fs::File,
-    io::{self, BufRead, BufReader},
-    mem,
-};
+use aoc_util::error::{self, Error};
+use std::{collections::HashSet, mem};
 
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
 struct Octopuses {
@@ -35,17 +31,13 @@ impl Octopuses {
 }
 
 impl Octopuses {
-    fn read(input: &mut dyn BufRead) -> io::Result<Self> {
+    fn read(input: &str) -> error::Result<Self> {
         let mut ret = Self::default();
         for (row_idx, line) in input.lines().enumerate() {
             let row = &mut ret.energy_levels[row_idx];
-            let line = line?;
             for (col_idx, c) in line.char_indices() {
                 row[col_idx] = c.to_digit(10).ok_or_else(|| {
-                    io::Error::new(
-                        io::ErrorKind::InvalidData,
-                        format!("Invalid energy level in row {row_idx}: {c:?}"),
-                    )
+                    Error::parse(format!("Invalid energy level in row {row_idx}: {c:?}"))
                 })?;
             }
         }
@@ -88,57 +80,25 @@ impl Octopuses {
     }
 }
 
-fn part1(input: &mut dyn BufRead) -> io::Result<usize> {
+fn part1(input: &str) -> error::Result<usize> {
     let mut octopuses = Octopuses::read(input)?;
     Ok((0..100).map(|_| octopuses.update()).sum())
 }
 
-fn part2(input: &mut dyn BufRead) -> io::Result<usize> {
+fn part2(input: &str) -> error::Result<usize> {
     let mut octopuses = Octopuses::read(input)?;
     Ok((1..).find(|_: &usize| octopuses.update() == 100).unwrap())
 }
 
-pub(super) fn run() -> io::Result<()> {
-    {
-        println!("Year 2021 Day 11 Part 1");
-        println!(
-            "There are {} flashes in the first 100 steps",
-            part1(&mut BufReader::new(File::open("2021_11.txt")?))?
-        );
-    }
-    {
-        println!("Year 2021 Day 11 Part 2");
-        println!(
-            "The first step where all 100 octopuses flash is {}",
-            part2(&mut BufReader::new(File::open("2021_11.txt")?))?
-        );
-    }
-    Ok(())
-}
-
-#[cfg(test)]
-mod tests {
-    use std::io::Cursor;
-
-    use super::*;
-
-    #[test]
+aoc_util::solution! {
+    year: 2021,
+    day: 11,
+    title: "Dumbo Octopus",
+    part1: part1,
+    part2: part2,
     #[ignore]
-    fn test_part1() -> io::Result<()> {
-        let s = "5483143223\n2745854711\n5264556173\n6141336146\n6357385478\n4167524645\n2176841721\n6882881134\n4846848554\n5283751526";
-        let expected = 1656;
-        let actual = part1(&mut Cursor::new(s))?;
-        assert_eq!(expected, actual);
-        Ok(())
-    }
-
-    #[test]
-    #[ignore]
-    fn test_part2() -> io::Result<()> {
-        let s = "5483143223\n2745854711\n5264556173\n6141336146\n6357385478\n4167524645\n2176841721\n6882881134\n4846848554\n5283751526";
-        let expected = 195;
-        let actual = part2(&mut Cursor::new(s))?;
-        assert_eq!(expected, actual);
-        Ok(())
-    }
+    examples: [
+        part1 => 1656,
+        part2 => 195,
+    ],
 }
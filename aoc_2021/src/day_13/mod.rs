@@ -1,8 +1,8 @@
+use aoc_util::error::{self, Error};
 use std::{
     collections::HashSet,
     fmt::{self, Display, Formatter},
-    fs::File,
-    io::{self, BufRead, BufReader},
+    str::Lines,
 };
 
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
@@ -11,34 +11,21 @@ struct Dots {
 }
 
 impl Dots {
-    fn read(input: &mut dyn BufRead) -> io::Result<Self> {
+    fn read<'a>(lines: &mut impl Iterator<Item = &'a str>) -> error::Result<Self> {
         let mut ret = Self::default();
-        let mut buf = String::new();
-        loop {
-            buf.clear();
-            input.read_line(&mut buf)?;
-            if buf.trim().is_empty() {
+        for line in lines.by_ref() {
+            if line.trim().is_empty() {
                 break;
             }
-            let (x, y) = buf.trim().split_once(',').ok_or_else(|| {
-                io::Error::new(
-                    io::ErrorKind::InvalidData,
-                    format!("Invalid point: {buf:?}"),
-                )
-            })?;
+            let (x, y) = line
+                .trim()
+                .split_once(',')
+                .ok_or_else(|| Error::parse(format!("Invalid point: {line:?}")))?;
             ret.positions.insert((
-                x.parse().map_err(|e| {
-                    io::Error::new(
-                        io::ErrorKind::InvalidData,
-                        format!("Invalid x-coordinate: {x:?}: {e:?}"),
-                    )
-                })?,
-                y.parse().map_err(|e| {
-                    io::Error::new(
-                        io::ErrorKind::InvalidData,
-                        format!("Invalid y-coordinate: {y:?}: {e:?}"),
-                    )
-                })?,
+                x.parse()
+                    .map_err(|e| Error::parse(format!("Invalid x-coordinate: {x:?}: {e:?}")))?,
+                y.parse()
+                    .map_err(|e| Error::parse(format!("Invalid y-coordinate: {y:?}: {e:?}")))?,
             ));
         }
         Ok(ret)
@@ -102,41 +89,32 @@ enum Axis {
     Y,
 }
 
-fn folds(input: &mut dyn BufRead) -> impl Iterator<Item = io::Result<(Axis, usize)>> + '_ {
-    input.lines().map(|fold| {
-        let fold = fold?;
-        let line = fold.strip_prefix("fold along ").ok_or_else(|| {
-            io::Error::new(
-                io::ErrorKind::InvalidData,
-                format!("Invalid fold direction: {fold:?}"),
-            )
-        })?;
-        let (axis, value) = line.trim().split_once('=').ok_or_else(|| {
-            io::Error::new(
-                io::ErrorKind::InvalidData,
-                format!("Missing {:?} in fold {:?}", '=', fold),
-            )
-        })?;
+fn folds(lines: Lines<'_>) -> impl Iterator<Item = error::Result<(Axis, usize)>> + '_ {
+    lines.map(|fold| {
+        let line = fold
+            .strip_prefix("fold along ")
+            .ok_or_else(|| Error::parse(format!("Invalid fold direction: {fold:?}")))?;
+        let (axis, value) = line
+            .trim()
+            .split_once('=')
+            .ok_or_else(|| Error::parse(format!("Missing {:?} in fold {:?}", '=', fold)))?;
         let value = value.parse().map_err(|e| {
-            io::Error::new(
-                io::ErrorKind::InvalidData,
-                format!("Invalid position {value:?} in fold{fold:?}: {e:?}"),
-            )
+            Error::parse(format!("Invalid position {value:?} in fold{fold:?}: {e:?}"))
         })?;
         match axis {
             "x" => Ok((Axis::X, value)),
             "y" => Ok((Axis::Y, value)),
-            axis => Err(io::Error::new(
-                io::ErrorKind::InvalidData,
-                format!("Invalid axis {axis:?} in fold {fold:?}"),
-            )),
+            axis => Err(Error::parse(format!(
+                "Invalid axis {axis:?} in fold {fold:?}"
+            ))),
         }
     })
 }
 
-fn part1(input: &mut dyn BufRead) -> io::Result<usize> {
-    let mut page_1 = Dots::read(&mut *input)?;
-    let mut folds = folds(input);
+fn part1(input: &str) -> error::Result<usize> {
+    let mut lines = input.lines();
+    let mut page_1 = Dots::read(&mut lines)?;
+    let mut folds = folds(lines);
     if let Some(fold) = folds.next() {
         let (axis, value) = fold?;
         match axis {
@@ -145,13 +123,14 @@ fn part1(input: &mut dyn BufRead) -> io::Result<usize> {
         }
         Ok(page_1.num_dots())
     } else {
-        Err(io::Error::new(io::ErrorKind::InvalidData, "Missing folds"))
+        Err(Error::parse("Missing folds"))
     }
 }
 
-fn part2(input: &mut dyn BufRead) -> io::Result<String> {
-    let mut page_1 = Dots::read(&mut *input)?;
-    for fold in folds(input) {
+fn part2(input: &str) -> error::Result<String> {
+    let mut lines = input.lines();
+    let mut page_1 = Dots::read(&mut lines)?;
+    for fold in folds(lines) {
         match fold? {
             (Axis::X, value) => page_1.fold_left(value),
             (Axis::Y, value) => page_1.fold_up(value),
@@ -160,70 +139,15 @@ fn part2(input: &mut dyn BufRead) -> io::Result<String> {
     Ok(format!("{page_1}"))
 }
 
-pub(super) fn run() -> io::Result<()> {
-    {
-        println!("Year 2021 Day 13 Part 1");
-        println!(
-            "There are {} visible dots after the first fold",
-            part1(&mut BufReader::new(File::open("2021_13.txt")?))?
-        );
-    }
-    {
-        println!("Year 2021 Day 13 Part 2");
-        println!("The code is");
-        println!(
-            "{}",
-            part2(&mut BufReader::new(File::open("2021_13.txt")?))?
-        );
-    }
-    Ok(())
-}
-
-#[cfg(test)]
-mod tests {
-    use std::io::Cursor;
-
-    use super::*;
-
-    const TEST_DATA: &str = concat!(
-        "6,10\n",
-        "0,14\n",
-        "9,10\n",
-        "0,3\n",
-        "10,4\n",
-        "4,11\n",
-        "6,0\n",
-        "6,12\n",
-        "4,1\n",
-        "0,13\n",
-        "10,12\n",
-        "3,4\n",
-        "3,0\n",
-        "8,4\n",
-        "1,10\n",
-        "2,14\n",
-        "8,10\n",
-        "9,0\n",
-        "\n",
-        "fold along y=7\n",
-        "fold along x=5\n"
-    );
-
-    #[test]
+aoc_util::solution! {
+    year: 2021,
+    day: 13,
+    title: "Transparent Origami",
+    part1: part1,
+    part2: part2,
     #[ignore]
-    fn test_part1() -> io::Result<()> {
-        let expected = 17;
-        let actual = part1(&mut Cursor::new(TEST_DATA))?;
-        assert_eq!(expected, actual);
-        Ok(())
-    }
-
-    #[test]
-    #[ignore]
-    fn test_part2() -> io::Result<()> {
-        let expected = "█████\n█   █\n█   █\n█   █\n█████\n";
-        let actual = part2(&mut Cursor::new(TEST_DATA))?;
-        assert_eq!(expected, actual);
-        Ok(())
-    }
+    examples: [
+        part1 => 17,
+        part2 => "█████\n█   █\n█   █\n█   █\n█████\n",
+    ],
 }
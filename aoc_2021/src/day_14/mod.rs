@@ -1,10 +1,5 @@
-use std::{
-    collections::HashMap,
-    fs::File,
-    hash::Hash,
-    io::{self, BufRead, BufReader},
-    mem,
-};
+use aoc_util::error::{self, Error};
+use std::{collections::HashMap, hash::Hash, mem};
 
 struct Polymer {
     len: u64,
@@ -14,12 +9,14 @@ struct Polymer {
 }
 
 impl Polymer {
-    fn read(input: &mut dyn BufRead) -> io::Result<Self> {
-        let mut buf = String::new();
-        let elements = {
-            input.read_line(&mut buf)?;
-            buf.drain(..).filter(|&c| c != '\n').collect::<Vec<_>>()
-        };
+    fn read(input: &str) -> error::Result<Self> {
+        let mut lines = input.lines();
+        let elements = lines
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .chars()
+            .collect::<Vec<_>>();
         let len = elements.len() as u64;
         let pairs = elements.windows(2).fold(HashMap::new(), |mut acc, pair| {
             *acc.entry((pair[0], pair[1])).or_default() += 1;
@@ -31,18 +28,14 @@ impl Polymer {
                 *acc.entry(element).or_default() += 1;
                 acc
             });
-        input.read_line(&mut buf)?;
-        assert_eq!(buf, "\n");
-        let rules = input
-            .lines()
+        if !lines.next().unwrap_or_default().trim().is_empty() {
+            return Err(Error::parse(
+                "Expected a blank line after the polymer template",
+            ));
+        }
+        let rules = lines
             .map(|line| {
-                let line = line?;
-                let mk_error = || {
-                    io::Error::new(
-                        io::ErrorKind::InvalidData,
-                        format!("Invalid pair insertion rule: {line:?}"),
-                    )
-                };
+                let mk_error = || Error::parse(format!("Invalid pair insertion rule: {line:?}"));
                 let (pair, result) = line.trim().split_once(" -> ").ok_or_else(mk_error)?;
                 match (pair.len(), result.len()) {
                     (2, 1) => {}
@@ -54,7 +47,7 @@ impl Polymer {
                 let result = result.chars().next().ok_or_else(mk_error)?;
                 Ok(((left, right), result))
             })
-            .collect::<io::Result<_>>()?;
+            .collect::<error::Result<_>>()?;
         Ok(Self {
             len,
             pairs,
@@ -94,7 +87,7 @@ impl Polymer {
     }
 }
 
-fn part1(input: &mut dyn BufRead) -> io::Result<u64> {
+fn part1(input: &str) -> error::Result<u64> {
     let mut polymer = Polymer::read(input)?;
     for _ in 0..10 {
         polymer.polymerize();
@@ -102,7 +95,7 @@ fn part1(input: &mut dyn BufRead) -> io::Result<u64> {
     Ok(polymer.counts().values().max().unwrap() - polymer.counts().values().min().unwrap())
 }
 
-fn part2(input: &mut dyn BufRead) -> io::Result<u64> {
+fn part2(input: &str) -> error::Result<u64> {
     let mut polymer = Polymer::read(input)?;
     for _ in 0..40 {
         polymer.polymerize();
@@ -110,65 +103,28 @@ fn part2(input: &mut dyn BufRead) -> io::Result<u64> {
     Ok(polymer.counts().values().max().unwrap() - polymer.counts().values().min().unwrap())
 }
 
-pub(super) fn run() -> io::Result<()> {
-    {
-        println!("Year 2021 Day 14 Part 1");
-        println!(
-            "{}",
-            part1(&mut BufReader::new(File::open("2021_14.txt")?))?
-        );
-    }
-    {
-        println!("Year 2021 Day 14 Part 2");
-        println!(
-            "{}",
-            part2(&mut BufReader::new(File::open("2021_14.txt")?))?
-        );
-    }
-    Ok(())
-}
+aoc_util::register_day!(2021, 14, part1, part2, "Extended Polymerization");
 
 #[cfg(test)]
 mod tests {
-    use std::io::Cursor;
+    use aoc_util::samples;
 
     use super::*;
 
-    const TEST_DATA: &str = concat!(
-        "NNCB\n",
-        "\n",
-        "CH -> B\n",
-        "HH -> N\n",
-        "CB -> H\n",
-        "NH -> C\n",
-        "HB -> C\n",
-        "HC -> B\n",
-        "HN -> C\n",
-        "NN -> C\n",
-        "BH -> H\n",
-        "NC -> B\n",
-        "NB -> B\n",
-        "BN -> B\n",
-        "BB -> N\n",
-        "BC -> B\n",
-        "CC -> N\n",
-        "CN -> C\n",
-    );
-
     #[test]
     #[ignore]
-    fn test_part1() -> io::Result<()> {
+    fn test_part1() -> error::Result<()> {
         let expected = 1588;
-        let actual = part1(&mut Cursor::new(TEST_DATA))?;
+        let actual = part1(&samples::get(2021, 14)?)?;
         assert_eq!(expected, actual);
         Ok(())
     }
 
     #[test]
     #[ignore]
-    fn test_part2() -> io::Result<()> {
+    fn test_part2() -> error::Result<()> {
         let expected = 2_188_189_693_529;
-        let actual = part2(&mut Cursor::new(TEST_DATA))?;
+        let actual = part2(&samples::get(2021, 14)?)?;
         assert_eq!(expected, actual);
         Ok(())
     }
@@ -1,7 +1,9 @@
-use std::{
-    collections::HashSet,
-    fs::File,
-    io::{self, BufRead, BufReader},
+use std::collections::HashSet;
+
+use aoc_util::{
+    error::{self, Error},
+    geometry::Point2D,
+    grid::Grid,
 };
 
 struct PathNode {
@@ -10,46 +12,35 @@ struct PathNode {
 }
 
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
-struct Grid {
-    risk: Vec<u32>,
-    width: usize,
-    height: usize,
+struct Cave {
+    risk: Grid<u32>,
     expanded: bool,
 }
 
-impl Grid {
-    fn read(input: &mut dyn BufRead) -> io::Result<Self> {
-        input.lines().try_fold(Self::default(), |mut acc, line| {
-            let line = line?;
-            if acc.is_empty() {
-                acc.width = line.len();
-            } else if acc.width != line.len() {
-                return Err(io::Error::new(
-                    io::ErrorKind::InvalidData,
-                    format!("Line {:?} incorrect length, expected {}", line, acc.width),
-                ));
-            }
-            acc.reserve();
-            line.chars()
-                .map(|c| {
-                    c.to_digit(10).ok_or_else(|| {
-                        io::Error::new(
-                            io::ErrorKind::InvalidData,
-                            format!("Invalid risk level {c:?} in line {line:?}"),
-                        )
+impl Cave {
+    fn read(input: &str) -> error::Result<Self> {
+        let rows = input
+            .lines()
+            .map(|line| {
+                line.chars()
+                    .map(|c| {
+                        c.to_digit(10).ok_or_else(|| {
+                            Error::parse(format!("Invalid risk level {c:?} in line {line:?}"))
+                        })
                     })
-                })
-                .try_for_each(|risk| {
-                    acc.risk.push(risk?);
-                    io::Result::Ok(())
-                })?;
-            acc.height += 1;
-            Ok(acc)
+                    .collect::<error::Result<Vec<_>>>()
+            })
+            .collect::<error::Result<Vec<_>>>()?;
+        let risk = Grid::from_rows(rows)
+            .ok_or_else(|| Error::parse("Lines must all be the same length"))?;
+        Ok(Self {
+            risk,
+            expanded: false,
         })
     }
 }
 
-impl Grid {
+impl Cave {
     fn neighbors(&self, (x, y): (usize, usize)) -> impl Iterator<Item = (usize, usize)> {
         [
             x.checked_sub(1).map(|x| (x, y)),
@@ -61,43 +52,34 @@ impl Grid {
         .flatten()
     }
 
-    fn is_empty(&self) -> bool {
-        self.risk.is_empty()
-    }
-
     fn risk_at(&self, (x, y): (usize, usize)) -> Option<u32> {
-        if self.expanded {
-            let mega_x = (x / self.width) as u32;
-            let mega_y = (y / self.height) as u32;
-            let x = x % self.width;
-            let y = y % self.height;
-            let risk = self.risk[y * self.width + x];
-            let risk = risk + mega_x + mega_y;
-            if risk > 9 {
-                Some(risk - 9)
-            } else {
-                Some(risk)
-            }
-        } else if x < self.width && y < self.height {
-            Some(self.risk[y * self.width + x])
-        } else {
-            None
+        if x >= self.width() || y >= self.height() {
+            return None;
         }
+        // Each tile of the expanded map is one riskier than the tile to its left or above it,
+        // with risks above 9 wrapping back around to 1. The tile at (0, 0) is the original map.
+        let expanded = self
+            .risk
+            .wrapping()
+            .transformed(|&risk, tile: Point2D<isize>| {
+                (risk - 1 + (tile.x() + tile.y()) as u32) % 9 + 1
+            });
+        Some(expanded.get(Point2D::at(x as isize, y as isize)))
     }
 
     fn width(&self) -> usize {
         if self.expanded {
-            5 * self.width
+            5 * self.risk.width()
         } else {
-            self.width
+            self.risk.width()
         }
     }
 
     fn height(&self) -> usize {
         if self.expanded {
-            5 * self.height
+            5 * self.risk.height()
         } else {
-            self.height
+            self.risk.height()
         }
     }
 
@@ -152,78 +134,45 @@ impl Grid {
     }
 }
 
-impl Grid {
-    fn reserve(&mut self) {
-        self.risk.reserve(self.width)
-    }
-
+impl Cave {
     fn expand_map(&mut self) {
         self.expanded = true;
     }
 }
 
-fn part1(input: &mut dyn BufRead) -> io::Result<u32> {
-    let grid = Grid::read(input)?;
-    Ok(grid.lowest_risk())
+fn part1(input: &str) -> error::Result<u32> {
+    let cave = Cave::read(input)?;
+    Ok(cave.lowest_risk())
 }
 
-fn part2(input: &mut dyn BufRead) -> io::Result<u32> {
-    let mut grid = Grid::read(input)?;
-    grid.expand_map();
-    Ok(grid.lowest_risk())
+fn part2(input: &str) -> error::Result<u32> {
+    let mut cave = Cave::read(input)?;
+    cave.expand_map();
+    Ok(cave.lowest_risk())
 }
 
-pub(super) fn run() -> io::Result<()> {
-    {
-        println!("Year 2021 Day 15 Part 1");
-        println!(
-            "{}",
-            part1(&mut BufReader::new(File::open("2021_15.txt")?))?
-        );
-    }
-    {
-        println!("Year 2021 Day 15 Part 2");
-        println!(
-            "{}",
-            part2(&mut BufReader::new(File::open("2021_15.txt")?))?
-        );
-    }
-    Ok(())
-}
+aoc_util::register_day!(2021, 15, part1, part2, "Chiton");
 
 #[cfg(test)]
 mod tests {
-    use std::io::Cursor;
+    use aoc_util::samples;
 
     use super::*;
 
-    const TEST_DATA: &str = concat!(
-        "1163751742\n",
-        "1381373672\n",
-        "2136511328\n",
-        "3694931569\n",
-        "7463417111\n",
-        "1319128137\n",
-        "1359912421\n",
-        "3125421639\n",
-        "1293138521\n",
-        "2311944581\n"
-    );
-
     #[test]
     #[ignore]
-    fn test_part1() -> io::Result<()> {
+    fn test_part1() -> error::Result<()> {
         let expected = 40;
-        let actual = part1(&mut Cursor::new(TEST_DATA))?;
+        let actual = part1(&samples::get(2021, 15)?)?;
         assert_eq!(expected, actual);
         Ok(())
     }
 
     #[test]
     #[ignore]
-    fn test_part2() -> io::Result<()> {
+    fn test_part2() -> error::Result<()> {
         let expected = 315;
-        let actual = part2(&mut Cursor::new(TEST_DATA))?;
+        let actual = part2(&samples::get(2021, 15)?)?;
         assert_eq!(expected, actual);
         Ok(())
     }
@@ -1,8 +1,8 @@
+use aoc_util::error::{self, Error};
 use std::{
     fmt::{self, Display, Formatter},
-    fs::File,
-    io::{self, BufRead, BufReader},
     ops::Index,
+    str::Bytes,
     sync::atomic::AtomicUsize,
 };
 
@@ -53,7 +53,7 @@ impl Index<usize> for LeftoverBits {
 }
 
 impl TryFrom<u8> for LeftoverBits {
-    type Error = io::Error;
+    type Error = Error;
 
     fn try_from(value: u8) -> Result<Self, Self::Error> {
         let mut ret = Self::default();
@@ -74,12 +74,7 @@ impl TryFrom<u8> for LeftoverBits {
             b'D' => ret.bits = [true, true, false, true],
             b'E' => ret.bits = [true, true, true, false],
             b'F' => ret.bits = [true, true, true, true],
-            _ => {
-                return Err(io::Error::new(
-                    io::ErrorKind::InvalidData,
-                    format!("Invalid hex digit {:?}", value),
-                ))
-            }
+            _ => return Err(Error::parse(format!("Invalid hex digit {:?}", value))),
         }
         ret.idx = 0;
         Ok(ret)
@@ -187,11 +182,11 @@ struct Packet {
 }
 
 impl Packet {
-    fn read(input: &mut dyn BufRead) -> io::Result<Self> {
+    fn read(input: &str) -> error::Result<Self> {
         fn read_impl(
             mut bits: LeftoverBits,
-            input: &mut dyn BufRead,
-        ) -> io::Result<(Packet, LeftoverBits, usize)> {
+            input: &mut Bytes<'_>,
+        ) -> error::Result<(Packet, LeftoverBits, usize)> {
             static DEPTH: AtomicUsize = AtomicUsize::new(0);
 
             macro_rules! deepen {
@@ -218,15 +213,14 @@ impl Packet {
                 };
                 ($print:expr) => {{
                     let print = $print;
-                    let mut buf = [0];
-                    if 0 != input.read(&mut buf)? {
-                        let new_bits = LeftoverBits::try_from(buf[0]);
+                    if let Some(byte) = input.next() {
+                        let new_bits = LeftoverBits::try_from(byte);
                         if print {
                             print!("{:?}", new_bits);
                         }
                         new_bits
                     } else {
-                        Err(io::Error::new(io::ErrorKind::UnexpectedEof, ""))
+                        Err(Error::parse("Unexpected end of input"))
                     }
                 }};
             }
@@ -308,7 +302,7 @@ impl Packet {
                             |(mut acc, bits, width), _| {
                                 let (packet, bits, packet_width) = read_impl(bits, input)?;
                                 acc.push(packet);
-                                io::Result::Ok((acc, bits, width + packet_width))
+                                Ok::<_, Error>((acc, bits, width + packet_width))
                             },
                         )?;
                         bits = leftovers;
@@ -348,7 +342,7 @@ impl Packet {
             Ok((Packet { version, payload }, bits, 6 + payload_width))
         }
 
-        Ok(read_impl(LeftoverBits::default(), input)?.0)
+        Ok(read_impl(LeftoverBits::default(), &mut input.trim().bytes())?.0)
     }
 }
 
@@ -368,172 +362,151 @@ impl Display for Packet {
     }
 }
 
-fn part1(input: &mut dyn BufRead) -> io::Result<u32> {
-    let root = Packet::read(input)?;
-    println!("{root}");
+fn part1(root: &Packet) -> error::Result<u32> {
     Ok(root.version_sum())
 }
 
-fn part2(input: &mut dyn BufRead) -> io::Result<u64> {
-    let root = Packet::read(input)?;
+fn part2(root: &Packet) -> error::Result<u64> {
     Ok(root.value())
 }
 
-pub(super) fn run() -> io::Result<()> {
-    {
-        println!("Year 2021 Day 16 Part 1");
-        println!(
-            "{}",
-            part1(&mut BufReader::new(File::open("2021_16.txt")?))?
-        );
-    }
-    {
-        println!("Year 2021 Day 16 Part 2");
-        println!(
-            "{}",
-            part2(&mut BufReader::new(File::open("2021_16.txt")?))?
-        );
-    }
-    Ok(())
-}
+aoc_util::register_day!(2021, 16, Packet::read, part1, part2, "Packet Decoder");
 
 #[cfg(test)]
 mod tests {
-    use std::io::Cursor;
-
     use super::*;
 
     #[test]
     #[ignore]
-    fn test_part1_a() -> io::Result<()> {
+    fn test_part1_a() -> error::Result<()> {
         let expected = 6;
-        let actual = part1(&mut Cursor::new("D2FE28"))?;
+        let actual = part1(&Packet::read("D2FE28")?)?;
         assert_eq!(expected, actual);
         Ok(())
     }
 
     #[test]
     #[ignore]
-    fn test_part1_b() -> io::Result<()> {
+    fn test_part1_b() -> error::Result<()> {
         let expected = 9;
-        let actual = part1(&mut Cursor::new("38006F45291200"))?;
+        let actual = part1(&Packet::read("38006F45291200")?)?;
         assert_eq!(expected, actual);
         Ok(())
     }
 
     #[test]
     #[ignore]
-    fn test_part1_c() -> io::Result<()> {
+    fn test_part1_c() -> error::Result<()> {
         let expected = 14;
-        let actual = part1(&mut Cursor::new("EE00D40C823060"))?;
+        let actual = part1(&Packet::read("EE00D40C823060")?)?;
         assert_eq!(expected, actual);
         Ok(())
     }
 
     #[test]
     #[ignore]
-    fn test_part1_d() -> io::Result<()> {
+    fn test_part1_d() -> error::Result<()> {
         let expected = 16;
-        let actual = part1(&mut Cursor::new("8A004A801A8002F478"))?;
+        let actual = part1(&Packet::read("8A004A801A8002F478")?)?;
         assert_eq!(expected, actual);
         Ok(())
     }
 
     #[test]
     #[ignore]
-    fn test_part1_e() -> io::Result<()> {
+    fn test_part1_e() -> error::Result<()> {
         let expected = 12;
-        let actual = part1(&mut Cursor::new("620080001611562C8802118E34"))?;
+        let actual = part1(&Packet::read("620080001611562C8802118E34")?)?;
         assert_eq!(expected, actual);
         Ok(())
     }
 
     #[test]
     #[ignore]
-    fn test_part1_f() -> io::Result<()> {
+    fn test_part1_f() -> error::Result<()> {
         let expected = 23;
-        let actual = part1(&mut Cursor::new("C0015000016115A2E0802F182340"))?;
+        let actual = part1(&Packet::read("C0015000016115A2E0802F182340")?)?;
         assert_eq!(expected, actual);
         Ok(())
     }
 
     #[test]
     #[ignore]
-    fn test_part1_g() -> io::Result<()> {
+    fn test_part1_g() -> error::Result<()> {
         let expected = 31;
-        let actual = part1(&mut Cursor::new("A0016C880162017C3686B18A3D4780"))?;
+        let actual = part1(&Packet::read("A0016C880162017C3686B18A3D4780")?)?;
         assert_eq!(expected, actual);
         Ok(())
     }
 
     #[test]
     #[ignore]
-    fn test_part2_a() -> io::Result<()> {
+    fn test_part2_a() -> error::Result<()> {
         let expected = 3;
-        let actual = part2(&mut Cursor::new("C200B40A82"))?;
+        let actual = part2(&Packet::read("C200B40A82")?)?;
         assert_eq!(expected, actual);
         Ok(())
     }
 
     #[test]
     #[ignore]
-    fn test_part2_b() -> io::Result<()> {
+    fn test_part2_b() -> error::Result<()> {
         let expected = 54;
-        let actual = part2(&mut Cursor::new("04005AC33890"))?;
+        let actual = part2(&Packet::read("04005AC33890")?)?;
         assert_eq!(expected, actual);
         Ok(())
     }
 
     #[test]
     #[ignore]
-    fn test_part2_c() -> io::Result<()> {
+    fn test_part2_c() -> error::Result<()> {
         let expected = 7;
-        let actual = part2(&mut Cursor::new("880086C3E88112"))?;
+        let actual = part2(&Packet::read("880086C3E88112")?)?;
         assert_eq!(expected, actual);
         Ok(())
     }
 
     #[test]
     #[ignore]
-    fn test_part2_d() -> io::Result<()> {
+    fn test_part2_d() -> error::Result<()> {
         let expected = 9;
-        let actual = part2(&mut Cursor::new("CE00C43D881120"))?;
+        let actual = part2(&Packet::read("CE00C43D881120")?)?;
         assert_eq!(expected, actual);
         Ok(())
     }
 
     #[test]
     #[ignore]
-    fn test_part2_e() -> io::Result<()> {
+    fn test_part2_e() -> error::Result<()> {
         let expected = 1;
-        let actual = part2(&mut Cursor::new("D8005AC2A8F0"))?;
+        let actual = part2(&Packet::read("D8005AC2A8F0")?)?;
         assert_eq!(expected, actual);
         Ok(())
     }
 
     #[test]
     #[ignore]
-    fn test_part2_f() -> io::Result<()> {
+    fn test_part2_f() -> error::Result<()> {
         let expected = 0;
-        let actual = part2(&mut Cursor::new("F600BC2D8F"))?;
+        let actual = part2(&Packet::read("F600BC2D8F")?)?;
         assert_eq!(expected, actual);
         Ok(())
     }
 
     #[test]
     #[ignore]
-    fn test_part2_g() -> io::Result<()> {
+    fn test_part2_g() -> error::Result<()> {
         let expected = 0;
-        let actual = part2(&mut Cursor::new("9C005AC2F8F0"))?;
+        let actual = part2(&Packet::read("9C005AC2F8F0")?)?;
         assert_eq!(expected, actual);
         Ok(())
     }
 
     #[test]
     #[ignore]
-    fn test_part2_h() -> io::Result<()> {
+    fn test_part2_h() -> error::Result<()> {
         let expected = 1;
-        let actual = part2(&mut Cursor::new("9C0141080250320F1802104A08"))?;
+        let actual = part2(&Packet::read("9C0141080250320F1802104A08")?)?;
         assert_eq!(expected, actual);
         Ok(())
     }
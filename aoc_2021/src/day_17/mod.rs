@@ -1,9 +1,5 @@
-use std::{
-    fs::File,
-    io::{self, BufRead, BufReader},
-    num::ParseIntError,
-    ops::RangeInclusive,
-};
+use aoc_util::error::{self, Error};
+use std::{num::ParseIntError, ops::RangeInclusive};
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 struct Target {
@@ -12,51 +8,40 @@ struct Target {
 }
 
 impl Target {
-    fn read(input: &mut dyn BufRead) -> io::Result<Self> {
-        let mut buf = String::new();
-        let _ = input.read_line(&mut buf)?;
-        let coordinates = buf
+    fn read(input: &str) -> error::Result<Self> {
+        let coordinates = input
+            .lines()
+            .next()
+            .unwrap_or_default()
             .trim()
             .strip_prefix("target area: ")
-            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "Missing prefix"))?;
-        let (x_coord, y_coord) = coordinates.split_once(", ").ok_or_else(|| {
-            io::Error::new(io::ErrorKind::InvalidData, "Missing coordinate separator")
-        })?;
+            .ok_or_else(|| Error::parse("Missing prefix"))?;
+        let (x_coord, y_coord) = coordinates
+            .split_once(", ")
+            .ok_or_else(|| Error::parse("Missing coordinate separator"))?;
         let x_coord = x_coord
             .strip_prefix("x=")
-            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "Missing x"))?;
-        let (min_x, max_x) = x_coord.split_once("..").ok_or_else(|| {
-            io::Error::new(io::ErrorKind::InvalidData, "Format for x range invalid")
-        })?;
+            .ok_or_else(|| Error::parse("Missing x"))?;
+        let (min_x, max_x) = x_coord
+            .split_once("..")
+            .ok_or_else(|| Error::parse("Format for x range invalid"))?;
         let min_x = min_x.parse().map_err(|e: ParseIntError| {
-            io::Error::new(
-                io::ErrorKind::InvalidData,
-                format!("Error parsing minimum x: {:?}", e.to_string()),
-            )
+            Error::parse(format!("Error parsing minimum x: {:?}", e.to_string()))
         })?;
         let max_x = max_x.parse().map_err(|e: ParseIntError| {
-            io::Error::new(
-                io::ErrorKind::InvalidData,
-                format!("Error parsing maximum x: {:?}", e.to_string()),
-            )
+            Error::parse(format!("Error parsing maximum x: {:?}", e.to_string()))
         })?;
         let y_coord = y_coord
             .strip_prefix("y=")
-            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "Missing y"))?;
-        let (min_y, max_y) = y_coord.split_once("..").ok_or_else(|| {
-            io::Error::new(io::ErrorKind::InvalidData, "Format for y range invalid")
-        })?;
+            .ok_or_else(|| Error::parse("Missing y"))?;
+        let (min_y, max_y) = y_coord
+            .split_once("..")
+            .ok_or_else(|| Error::parse("Format for y range invalid"))?;
         let min_y = min_y.parse().map_err(|e: ParseIntError| {
-            io::Error::new(
-                io::ErrorKind::InvalidData,
-                format!("Error parsing minimum y: {:?}", e.to_string()),
-            )
+            Error::parse(format!("Error parsing minimum y: {:?}", e.to_string()))
         })?;
         let max_y = max_y.parse().map_err(|e: ParseIntError| {
-            io::Error::new(
-                io::ErrorKind::InvalidData,
-                format!("Error parsing maximum y: {:?}", e.to_string()),
-            )
+            Error::parse(format!("Error parsing maximum y: {:?}", e.to_string()))
         })?;
         Ok(Self {
             target_x: min_x..=max_x,
@@ -82,7 +67,7 @@ impl Target {
     }
 }
 
-fn part1(input: &mut dyn BufRead) -> io::Result<u32> {
+fn part1(input: &str) -> error::Result<u32> {
     fn vertical_finds(target_y: RangeInclusive<i32>, up: i32) -> bool {
         let mut velocity = -up;
         let mut position = up;
@@ -114,7 +99,7 @@ fn part1(input: &mut dyn BufRead) -> io::Result<u32> {
     Ok((max_up * (max_up + 1)) / 2)
 }
 
-fn part2(input: &mut dyn BufRead) -> io::Result<usize> {
+fn part2(input: &str) -> error::Result<usize> {
     let target = Target::read(input)?;
     // T_n = (n * (n + 1)) / 2
     // T_n >= k => n**2 + n >= 2k
@@ -142,47 +127,15 @@ fn part2(input: &mut dyn BufRead) -> io::Result<usize> {
         .count())
 }
 
-pub(super) fn run() -> io::Result<()> {
-    {
-        println!("Year 2021 Day 17 Part 1");
-        println!(
-            "The highest y position is {}",
-            part1(&mut BufReader::new(File::open("2021_17.txt")?))?
-        );
-    }
-    {
-        println!("Year 2021 Day 17 Part 2");
-        println!(
-            "{}",
-            part2(&mut BufReader::new(File::open("2021_17.txt")?))?
-        );
-    }
-    Ok(())
-}
-
-#[cfg(test)]
-mod tests {
-    use std::io::Cursor;
-
-    use super::*;
-
-    #[test]
-    #[ignore]
-    fn test_part1() -> io::Result<()> {
-        let s = "target area: x=20..30, y=-10..-5";
-        let expected = 45;
-        let actual = part1(&mut Cursor::new(s))?;
-        assert_eq!(expected, actual);
-        Ok(())
-    }
-
-    #[test]
+aoc_util::solution! {
+    year: 2021,
+    day: 17,
+    title: "Trick Shot",
+    part1: part1,
+    part2: part2,
     #[ignore]
-    fn test_part2() -> io::Result<()> {
-        let s = "target area: x=20..30, y=-10..-5";
-        let expected = 112;
-        let actual = part2(&mut Cursor::new(s))?;
-        assert_eq!(expected, actual);
-        Ok(())
-    }
+    examples: [
+        part1 => 45,
+        part2 => 112,
+    ],
 }
@@ -1,7 +1,6 @@
+use aoc_util::error::{self, Error};
 use std::{
     fmt::{self, Display, Formatter},
-    fs::File,
-    io::{self, BufRead, BufReader, Cursor},
     iter::Sum,
     mem,
     ops::{Add, Index, IndexMut},
@@ -97,12 +96,10 @@ enum Branch {
 struct SnailfishNumber(Number, Number);
 
 impl SnailfishNumber {
-    fn read(input: &mut dyn BufRead) -> io::Result<Self> {
-        let mut buf = String::new();
-        input.read_line(&mut buf)?;
-        let (_, this) = comb::all_consuming(Self::nom_parse)(buf.trim())
+    fn read(input: &str) -> error::Result<Self> {
+        let (_, this) = comb::all_consuming(Self::nom_parse)(input.trim())
             .finish()
-            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?;
+            .map_err(|e| Error::parse(e.to_string()))?;
         Ok(this)
     }
 
@@ -350,20 +347,20 @@ impl Sum<SnailfishNumber> for Option<SnailfishNumber> {
     }
 }
 
-fn part1(input: &mut dyn BufRead) -> io::Result<u32> {
+fn part1(input: &str) -> error::Result<u32> {
     let sum = input
         .lines()
-        .map(|line| SnailfishNumber::read(&mut Cursor::new(line?)))
-        .sum::<io::Result<Option<SnailfishNumber>>>()?
-        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "No input"))?;
+        .map(SnailfishNumber::read)
+        .sum::<error::Result<Option<SnailfishNumber>>>()?
+        .ok_or_else(|| Error::parse("No input"))?;
     Ok(sum.magnitude())
 }
 
-fn part2(input: &mut dyn BufRead) -> io::Result<u32> {
+fn part2(input: &str) -> error::Result<u32> {
     let numbers = input
         .lines()
-        .map(|line| SnailfishNumber::read(&mut Cursor::new(line?)))
-        .collect::<io::Result<Vec<_>>>()?;
+        .map(SnailfishNumber::read)
+        .collect::<error::Result<Vec<_>>>()?;
     (0..numbers.len())
         .flat_map(|i| (0..numbers.len()).map(move |j| (i, j)))
         .filter(|(i, j)| i != j)
@@ -372,30 +369,14 @@ fn part2(input: &mut dyn BufRead) -> io::Result<u32> {
             sum.magnitude()
         })
         .reduce(u32::max)
-        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "Missing input"))
+        .ok_or_else(|| Error::parse("Missing input"))
 }
 
-pub(super) fn run() -> io::Result<()> {
-    {
-        println!("Year 2021 Day 18 Part 1");
-        println!(
-            "{}",
-            part1(&mut BufReader::new(File::open("2021_18.txt")?))?
-        );
-    }
-    {
-        println!("Year 2021 Day 18 Part 2");
-        println!(
-            "{}",
-            part2(&mut BufReader::new(File::open("2021_18.txt")?))?
-        );
-    }
-    Ok(())
-}
+aoc_util::register_day!(2021, 18, part1, part2, "Snailfish");
 
 #[cfg(test)]
 mod tests {
-    use std::io::Cursor;
+    use aoc_util::samples;
 
     use super::*;
 
@@ -581,7 +562,7 @@ mod tests {
 
     #[test]
     #[ignore]
-    fn test_parse() -> io::Result<()> {
+    fn test_parse() -> error::Result<()> {
         let s = "[[[[4,3],4],4],[7,[[8,4],9]]]";
         let expected = SnailfishNumber(
             Number::from(SnailfishNumber(
@@ -599,54 +580,41 @@ mod tests {
                 )),
             )),
         );
-        let actual = SnailfishNumber::read(&mut Cursor::new(s))?;
+        let actual = SnailfishNumber::read(s)?;
         assert_eq!(expected, actual);
         Ok(())
     }
 
     #[test]
     #[ignore]
-    fn test_addition() -> io::Result<()> {
+    fn test_addition() -> error::Result<()> {
         let x = "[[[[4,3],4],4],[7,[[8,4],9]]]";
-        let x = SnailfishNumber::read(&mut Cursor::new(x))?;
+        let x = SnailfishNumber::read(x)?;
         let y = "[1,1]";
-        let y = SnailfishNumber::read(&mut Cursor::new(y))?;
+        let y = SnailfishNumber::read(y)?;
         let expected = "[[[[0,7],4],[[7,8],[6,0]]],[8,1]]";
-        let expected = SnailfishNumber::read(&mut Cursor::new(expected))?;
+        let expected = SnailfishNumber::read(expected)?;
         let actual = x + y;
         assert_eq!(expected, actual);
         Ok(())
     }
 
-    const TEST_DATA: &str = concat!(
-        "[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]\n",
-        "[[[5,[2,8]],4],[5,[[9,9],0]]]\n",
-        "[6,[[[6,2],[5,6]],[[7,6],[4,7]]]]\n",
-        "[[[6,[0,7]],[0,9]],[4,[9,[9,0]]]]\n",
-        "[[[7,[6,4]],[3,[1,3]]],[[[5,5],1],9]]\n",
-        "[[6,[[7,3],[3,2]]],[[[3,8],[5,7]],4]]\n",
-        "[[[[5,4],[7,7]],8],[[8,3],8]]\n",
-        "[[9,3],[[9,9],[6,[4,9]]]]\n",
-        "[[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]\n",
-        "[[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]\n"
-    );
-
     #[test]
     #[ignore]
-    fn test_part1() -> io::Result<()> {
-        let s = TEST_DATA;
+    fn test_part1() -> error::Result<()> {
+        let s = samples::get(2021, 18)?;
         let expected = 4140;
-        let actual = part1(&mut Cursor::new(s))?;
+        let actual = part1(&s)?;
         assert_eq!(expected, actual);
         Ok(())
     }
 
     #[test]
     #[ignore]
-    fn test_part2() -> io::Result<()> {
-        let s = TEST_DATA;
+    fn test_part2() -> error::Result<()> {
+        let s = samples::get(2021, 18)?;
         let expected = 3993;
-        let actual = part2(&mut Cursor::new(s))?;
+        let actual = part2(&s)?;
         assert_eq!(expected, actual);
         Ok(())
     }
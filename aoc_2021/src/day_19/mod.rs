@@ -1,14 +1,19 @@
-use std::io;
+use aoc_util::error::{self, Error};
 
-#[allow(unreachable_code)]
-pub(super) fn run() -> io::Result<()> {
-    {
-        println!("Year 2021 Day 19 Part 1");
-        todo!("Year 2021 Day 19 Part 1");
-    }
-    {
-        println!("Year 2021 Day 19 Part 2");
-        todo!("Year 2021 Day 19 Part 2");
-    }
-    Ok(())
+fn part1(_input: &str) -> error::Result<u32> {
+    Err(Error::Unimplemented {
+        year: 2021,
+        day: 19,
+        part: Some(1),
+    })
 }
+
+fn part2(_input: &str) -> error::Result<u32> {
+    Err(Error::Unimplemented {
+        year: 2021,
+        day: 19,
+        part: Some(2),
+    })
+}
+
+aoc_util::register_day!(2021, 19, part1, part2, "Beacon Scanner");
@@ -1,9 +1,5 @@
-use std::{
-    fs::File,
-    io::{self, BufRead, BufReader},
-    num::ParseIntError,
-    str::FromStr,
-};
+use aoc_util::error::{self, Error};
+use std::{num::ParseIntError, str::FromStr};
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 enum Motion {
@@ -49,14 +45,13 @@ impl FromIterator<Motion> for Position {
     }
 }
 
-fn part1(input: &mut dyn BufRead) -> io::Result<Position> {
-    input
-        .lines()
-        .map(|e| {
-            e?.parse::<Motion>()
-                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
-        })
-        .collect()
+fn read_motions(input: &str) -> impl Iterator<Item = error::Result<Motion>> + '_ {
+    input.lines().map(|line| line.parse().map_err(Error::parse))
+}
+
+fn part1(input: &str) -> error::Result<u32> {
+    let final_position = read_motions(input).collect::<error::Result<Position>>()?;
+    Ok(final_position.x * final_position.depth)
 }
 
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
@@ -88,67 +83,20 @@ impl FromIterator<Motion> for Ray {
     }
 }
 
-fn part2(input: &mut dyn BufRead) -> io::Result<Position> {
-    Ok(input
-        .lines()
-        .map(|s| {
-            s?.parse::<Motion>()
-                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
-        })
-        .collect::<io::Result<Ray>>()?
-        .pos)
-}
-
-#[allow(unreachable_code)]
-pub(super) fn run() -> io::Result<()> {
-    {
-        println!("Year 2021 Day 2 Part 1");
-        let mut input = BufReader::new(File::open("2021_02.txt")?);
-        let final_position = part1(&mut input)?;
-        println!(
-            "Final position is {} units forward by {} units deep ({})",
-            final_position.x,
-            final_position.depth,
-            final_position.x * final_position.depth
-        );
-    }
-    {
-        println!("Year 2021 Day 2 Part 2");
-        let mut input = BufReader::new(File::open("2021_02.txt")?);
-        let final_position = part2(&mut input)?;
-        println!(
-            "Final position is {} units forward by {} units deep ({})",
-            final_position.x,
-            final_position.depth,
-            final_position.x * final_position.depth
-        );
-    }
-    Ok(())
+fn part2(input: &str) -> error::Result<u32> {
+    let final_position = read_motions(input).collect::<error::Result<Ray>>()?.pos;
+    Ok(final_position.x * final_position.depth)
 }
 
-#[cfg(test)]
-mod test {
-    use std::io::Cursor;
-
-    use super::*;
-
-    #[test]
+aoc_util::solution! {
+    year: 2021,
+    day: 2,
+    title: "Dive!",
+    part1: part1,
+    part2: part2,
     #[ignore]
-    fn test_part1() -> io::Result<()> {
-        let s = "forward 5\ndown 5\nforward 8\nup 3\ndown 8\nforward 2\n";
-        let expected = Position { x: 15, depth: 10 };
-        let actual = part1(&mut Cursor::new(s))?;
-        assert_eq!(expected, actual);
-        Ok(())
-    }
-
-    #[test]
-    #[ignore]
-    fn test_part2() -> io::Result<()> {
-        let s = "forward 5\ndown 5\nforward 8\nup 3\ndown 8\nforward 2\n";
-        let expected = Position { x: 15, depth: 60 };
-        let actual = part2(&mut Cursor::new(s))?;
-        assert_eq!(expected, actual);
-        Ok(())
-    }
+    examples: [
+        part1 => 150,
+        part2 => 900,
+    ],
 }
@@ -1,8 +1,11 @@
 use std::{
     collections::HashSet,
     fmt::{self, Display, Formatter},
-    fs::File,
-    io::{self, BufRead, BufReader},
+};
+
+use aoc_util::{
+    error::{self, Error},
+    progress::Progress,
 };
 
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
@@ -27,13 +30,13 @@ impl From<PixelIntensity> for usize {
 }
 
 impl TryFrom<char> for PixelIntensity {
-    type Error = io::Error;
+    type Error = Error;
 
     fn try_from(this: char) -> Result<Self, Self::Error> {
         match this {
             '.' => Ok(Self::Dark),
             '#' => Ok(Self::Light),
-            c => Err(io::Error::new(io::ErrorKind::InvalidData, c.to_string())),
+            c => Err(Error::parse(c.to_string())),
         }
     }
 }
@@ -44,11 +47,9 @@ struct ImageEnhancementAlgorithm {
 }
 
 impl ImageEnhancementAlgorithm {
-    fn read(input: &mut dyn BufRead) -> io::Result<Self> {
-        let mut buf = String::new();
-        input.read_line(&mut buf)?;
+    fn read(line: &str) -> error::Result<Self> {
         Ok(Self {
-            light_indices: buf
+            light_indices: line
                 .trim()
                 .chars()
                 .map(PixelIntensity::try_from)
@@ -58,7 +59,7 @@ impl ImageEnhancementAlgorithm {
                     Ok(PixelIntensity::Dark) => None,
                     Err(e) => Some(Err(e)),
                 })
-                .collect::<io::Result<_>>()?,
+                .collect::<error::Result<_>>()?,
         })
     }
 }
@@ -84,16 +85,11 @@ struct Image {
 }
 
 impl Image {
-    fn read(input: &mut dyn BufRead) -> io::Result<Self> {
-        let mut buf = String::new();
+    fn read<'a>(lines: impl Iterator<Item = &'a str>) -> error::Result<Self> {
         let mut this = Self::default();
-        for row in 0.. {
-            buf.clear();
-            if 0 == input.read_line(&mut buf)? {
-                break;
-            }
-            let buf = buf.trim();
-            let added_pixel = buf
+        for (line, row) in lines.zip(0..) {
+            let added_pixel = line
+                .trim()
                 .chars()
                 .zip(0..)
                 .filter_map(|(c, col)| match PixelIntensity::try_from(c) {
@@ -107,7 +103,7 @@ impl Image {
                         this.max_col = col;
                     }
                     this.light_indices.insert((col, row));
-                    io::Result::Ok(true)
+                    Ok::<_, Error>(true)
                 })?;
             if added_pixel {
                 this.max_row = row;
@@ -215,21 +211,21 @@ impl FromIterator<(isize, isize)> for Image {
     }
 }
 
-fn read_ieai(input: &mut dyn BufRead) -> io::Result<(ImageEnhancementAlgorithm, Image)> {
-    let iea = ImageEnhancementAlgorithm::read(&mut *input)?;
-    let mut buf = String::new();
-    input.read_line(&mut buf)?;
-    if !buf.trim().is_empty() {
-        return Err(io::Error::new(
-            io::ErrorKind::InvalidData,
-            format!("Missing blank line before image: {:?}", buf.trim()),
-        ));
+fn read_ieai(input: &str) -> error::Result<(ImageEnhancementAlgorithm, Image)> {
+    let mut lines = input.lines();
+    let iea = ImageEnhancementAlgorithm::read(lines.next().unwrap_or_default())?;
+    let blank = lines.next().unwrap_or_default();
+    if !blank.trim().is_empty() {
+        return Err(Error::parse(format!(
+            "Missing blank line before image: {:?}",
+            blank.trim()
+        )));
     }
-    let image = Image::read(input)?;
+    let image = Image::read(lines)?;
     Ok((iea, image))
 }
 
-fn part1(input: &mut dyn BufRead) -> io::Result<usize> {
+fn part1(input: &str) -> error::Result<usize> {
     let (iea, image) = read_ieai(input)?;
     let enhance = |image: Image| image.apply_filter(|value| iea.intensity_at(value));
     let enhanced = enhance(image);
@@ -237,66 +233,40 @@ fn part1(input: &mut dyn BufRead) -> io::Result<usize> {
     Ok(double_enhanced.light_indices.len())
 }
 
-fn part2(input: &mut dyn BufRead) -> io::Result<usize> {
+fn part2(input: &str) -> error::Result<usize> {
     let (iea, image) = read_ieai(input)?;
     let enhance = |image: Image| image.apply_filter(|value| iea.intensity_at(value));
-    let enhanced = (0..50).fold(image, |image, _| enhance(image));
+    let mut progress = Progress::new("Enhancing", Some(50));
+    let enhanced = (0..50).fold(image, |image, _| {
+        let enhanced = enhance(image);
+        progress.inc();
+        enhanced
+    });
     Ok(enhanced.light_indices.len())
 }
 
-pub(super) fn run() -> io::Result<()> {
-    {
-        println!("Year 2021 Day 20 Part 1");
-        println!(
-            "{}",
-            part1(&mut BufReader::new(File::open("2021_20.txt")?))?
-        );
-    }
-    {
-        println!("Year 2021 Day 20 Part 2");
-        println!(
-            "{}",
-            part2(&mut BufReader::new(File::open("2021_20.txt")?))?
-        );
-    }
-    Ok(())
-}
+aoc_util::register_day!(2021, 20, part1, part2, "Trench Map");
 
 #[cfg(test)]
 mod tests {
-    use std::io::Cursor;
+    use aoc_util::samples;
 
     use super::*;
 
-    const TEST_DATA: &str = concat!(
-        "..#.#..#####.#.#.#.###.##.....###.##.#..###.####..#####..#....#..#..##..###..######.###..",
-        ".####..#..#####..##..#.#####...##.#.#..#.##..#.#......#.###.######.###.####...#.##.##..#.",
-        ".#..#####.....#.#....###..#.##......#.....#..#..#..##..#...##.######.####.####.#.#...#...",
-        "....#..#.#.#...####.##.#......#..#...##.#.##..#...##.#.##..###.#......#.#.......#.#.#.###",
-        "#.###.##...#.....####.#..#..#.##.#....##..#.####....##...##..#...#......#.#.......#......",
-        ".##..####..#...#.#.#...##..#.#..###..#####........#..####......#..#\n",
-        "\n",
-        "#..#.\n",
-        "#....\n",
-        "##..#\n",
-        "..#..\n",
-        "..###\n",
-    );
-
     #[test]
     #[ignore]
-    fn test_part1() -> io::Result<()> {
+    fn test_part1() -> error::Result<()> {
         let expected = 35;
-        let actual = part1(&mut Cursor::new(TEST_DATA))?;
+        let actual = part1(&samples::get(2021, 20)?)?;
         assert_eq!(expected, actual);
         Ok(())
     }
 
     #[test]
     #[ignore]
-    fn test_part2() -> io::Result<()> {
+    fn test_part2() -> error::Result<()> {
         let expected = 3351;
-        let actual = part2(&mut Cursor::new(TEST_DATA))?;
+        let actual = part2(&samples::get(2021, 20)?)?;
         assert_eq!(expected, actual);
         Ok(())
     }
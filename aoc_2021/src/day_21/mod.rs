@@ -1,9 +1,5 @@
-use std::{
-    collections::HashMap,
-    fs::File,
-    io::{self, BufRead, BufReader},
-    mem,
-};
+use aoc_util::error::{self, Error};
+use std::{collections::HashMap, mem};
 
 use nom::{
     bytes::complete as bytes, character::complete as character, combinator as comb, sequence,
@@ -179,13 +175,10 @@ fn parse_players(s: &str) -> IResult<&str, (Player, Player)> {
     )(s)
 }
 
-fn part1(input: &mut dyn BufRead) -> io::Result<u32> {
-    let mut buf = String::new();
-    input.read_line(&mut buf)?;
-    input.read_line(&mut buf)?;
-    let (p1, p2) = parse_players(&buf)
+fn part1(input: &str) -> error::Result<u32> {
+    let (p1, p2) = parse_players(input)
         .map(|(_, players)| players)
-        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?;
+        .map_err(|e| Error::parse(e.to_string()))?;
     let mut game = DeterministicGameState {
         p1,
         p2,
@@ -201,13 +194,10 @@ fn part1(input: &mut dyn BufRead) -> io::Result<u32> {
     Ok(loser_score * game.die.num_rolls)
 }
 
-fn part2(input: &mut dyn BufRead) -> io::Result<u64> {
-    let mut buf = String::new();
-    input.read_line(&mut buf)?;
-    input.read_line(&mut buf)?;
-    let (p1, p2) = parse_players(&buf)
+fn part2(input: &str) -> error::Result<u64> {
+    let (p1, p2) = parse_players(input)
         .map(|(_, players)| players)
-        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?;
+        .map_err(|e| Error::parse(e.to_string()))?;
     let mut game = DiracGameState::starting_at(p1, p2);
     let mut scores = HashMap::new();
     for i in 0.. {
@@ -237,46 +227,28 @@ fn part2(input: &mut dyn BufRead) -> io::Result<u64> {
     Ok(*game.completed_games.values().max().unwrap())
 }
 
-pub(super) fn run() -> io::Result<()> {
-    {
-        println!("Year 2021 Day 21 Part 1");
-        println!(
-            "{}",
-            part1(&mut BufReader::new(File::open("2021_21.txt")?))?
-        );
-    }
-    {
-        println!("Year 2021 Day 21 Part 2");
-        println!(
-            "{}",
-            part2(&mut BufReader::new(File::open("2021_21.txt")?))?
-        );
-    }
-    Ok(())
-}
+aoc_util::register_day!(2021, 21, part1, part2, "Dirac Dice");
 
 #[cfg(test)]
 mod tests {
-    use std::io::Cursor;
+    use aoc_util::samples;
 
     use super::*;
 
-    const TEST_DATA: &str = "Player 1 starting position: 4\nPlayer 2 starting position: 8";
-
     #[test]
     #[ignore]
-    fn test_part1() -> io::Result<()> {
+    fn test_part1() -> error::Result<()> {
         let expected = 739_785;
-        let actual = part1(&mut Cursor::new(TEST_DATA))?;
+        let actual = part1(&samples::get(2021, 21)?)?;
         assert_eq!(expected, actual);
         Ok(())
     }
 
     #[test]
     #[ignore]
-    fn test_part2() -> io::Result<()> {
+    fn test_part2() -> error::Result<()> {
         let expected = 444_356_092_776_315;
-        let actual = part2(&mut Cursor::new(TEST_DATA))?;
+        let actual = part2(&samples::get(2021, 21)?)?;
         assert_eq!(expected, actual);
         Ok(())
     }
@@ -1,12 +1,13 @@
 use std::{
     fmt::{self, Display, Formatter},
-    fs::File,
-    io::{self, BufRead, BufReader},
     mem,
     ops::Sub,
 };
 
-use aoc_util::a_star::{self, AStarState};
+use aoc_util::{
+    a_star::{self, AStarState},
+    error::{self, Error},
+};
 use nom::{branch, bytes::complete as bytes, combinator as comb, multi, sequence, Finish, IResult};
 
 fn abs_sub<T>(x: T, y: T) -> T
@@ -145,36 +146,26 @@ struct State {
 }
 
 impl State {
-    fn read(input: &mut dyn BufRead) -> io::Result<Self> {
-        fn read_line(input: &mut dyn BufRead, buf: &mut String) -> io::Result<usize> {
-            buf.clear();
-            input.read_line(buf)
-        }
-
-        let mut buf = String::new();
-        let _ = read_line(input, &mut buf)?;
-        let _ = read_line(input, &mut buf)?;
-        let _ = read_line(input, &mut buf)?;
+    fn read(input: &str) -> error::Result<Self> {
+        let mut lines = input.lines().skip(2);
         let upper = comb::all_consuming(sequence::delimited(
             bytes::tag("###"),
             multi::separated_list1(bytes::tag("#"), Amphipod::nom_parse),
             bytes::tag("###"),
-        ))(buf.trim_end())
+        ))(lines.next().unwrap_or_default().trim_end())
         .finish()
-        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?
+        .map_err(|e| Error::parse(e.to_string()))?
         .1;
         assert_eq!(upper.len(), 4);
-        let _ = read_line(input, &mut buf)?;
         let lower = comb::all_consuming(sequence::delimited(
             bytes::tag("  #"),
             multi::separated_list1(bytes::tag("#"), Amphipod::nom_parse),
             bytes::tag("#"),
-        ))(buf.trim_end())
+        ))(lines.next().unwrap_or_default().trim_end())
         .finish()
-        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?
+        .map_err(|e| Error::parse(e.to_string()))?
         .1;
         assert_eq!(lower.len(), 4);
-        let _ = read_line(input, &mut buf)?;
         let mut state = State {
             rooms: [
                 Room {
@@ -471,46 +462,28 @@ fn amphipod_heuristic(s: &State) -> u64 {
     }
 }
 
-fn part1(input: &mut dyn BufRead) -> io::Result<u64> {
-    a_star::run_a_star_for_distance::<_, u64, _, _>(State::read(input)?, amphipod_heuristic)
-        .ok_or_else(|| io::Error::new(io::ErrorKind::Other, "Couldn't find path to sorted state"))
+fn part1(input: &str) -> error::Result<u64> {
+    a_star::run_a_star_for_distance::<_, u64, _, _>(State::read(input)?, amphipod_heuristic)?
+        .ok_or_else(|| Error::UnsolvableInput("Couldn't find path to sorted state".to_string()))
 }
 
-fn part2(_input: &mut dyn BufRead) -> io::Result<u64> {
-    todo!("Year 2021 Day 23 Part 2")
+fn part2(_input: &str) -> error::Result<u64> {
+    Err(Error::Unimplemented {
+        year: 2021,
+        day: 23,
+        part: Some(2),
+    })
 }
 
-pub(super) fn run() -> io::Result<()> {
-    {
-        println!("Year 2021 Day 23 Part 1");
-        println!(
-            "{}",
-            part1(&mut BufReader::new(File::open("2021_23.txt")?))?
-        );
-    }
-    {
-        println!("Year 2021 Day 23 Part 2");
-        println!(
-            "{}",
-            part2(&mut BufReader::new(File::open("2021_23.txt")?))?
-        );
-    }
-    Ok(())
-}
+aoc_util::register_day!(2021, 23, part1, part2, "Amphipod");
 
 #[cfg(test)]
 mod tests {
-    use std::{collections::HashMap, io::Cursor};
+    use std::collections::HashMap;
 
-    use super::*;
+    use aoc_util::samples;
 
-    const TEST_DATA: &str = concat!(
-        "#############\n",
-        "#...........#\n",
-        "###B#C#B#D###\n",
-        "  #A#D#C#A#\n",
-        "  #########",
-    );
+    use super::*;
 
     #[test]
     #[ignore]
@@ -554,7 +527,7 @@ mod tests {
 
     #[test]
     #[ignore]
-    fn test_read_state() -> io::Result<()> {
+    fn test_read_state() -> error::Result<()> {
         let expected = State {
             rooms: [
                 Room {
@@ -588,7 +561,7 @@ mod tests {
             ],
             hallway: [None; 11],
         };
-        let actual = State::read(&mut Cursor::new(TEST_DATA))?;
+        let actual = State::read(&samples::get(2021, 23)?)?;
         assert_eq!(expected, actual);
         Ok(())
     }
@@ -907,9 +880,9 @@ mod tests {
 
     #[test]
     #[ignore = "A* not implemented correctly"]
-    fn test_part1() -> io::Result<()> {
+    fn test_part1() -> error::Result<()> {
         let expected = 12_521;
-        let actual = part1(&mut Cursor::new(TEST_DATA))?;
+        let actual = part1(&samples::get(2021, 23)?)?;
         assert_eq!(expected, actual);
         Ok(())
     }
@@ -1,10 +1,10 @@
-use std::{
-    collections::HashMap,
-    fs::File,
-    io::{self, BufRead, BufReader},
-};
+use std::collections::HashMap;
 
-use aoc_util::{impl_from_str_for_nom_parse, nom_extended::NomParse};
+use aoc_util::{
+    error::{self, Error},
+    impl_from_str_for_nom_parse,
+    nom_extended::NomParse,
+};
 
 use nom::{
     branch, bytes::complete as bytes, character::complete as character, combinator as comb,
@@ -193,14 +193,13 @@ impl NomParse<&'_ str> for Instruction {
 
 impl_from_str_for_nom_parse!(Instruction);
 
-fn read_program(input: &mut dyn BufRead) -> io::Result<Vec<Instruction>> {
+fn read_program(input: &str) -> error::Result<Vec<Instruction>> {
     input
         .lines()
         .map(|line| {
-            line?
-                .trim()
+            line.trim()
                 .parse::<Instruction>()
-                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+                .map_err(|e| Error::parse(e.to_string()))
         })
         .collect()
 }
@@ -209,7 +208,20 @@ fn fold_num(digits: impl IntoIterator<Item = u64>) -> u64 {
     digits.into_iter().fold(0, |acc, digit| acc * 10 + digit)
 }
 
-fn part1(input: &mut dyn BufRead) -> io::Result<u64> {
+/// The model numbers used by both parts were found by manually stepping through the fourteen
+/// segments of the program and keeping track of exactly what the output would be for any possible
+/// input sequence, so they are only valid for the author's input.
+fn check_model_number(alu: &Alu) -> error::Result<()> {
+    if 0 == alu.read_variable(Variable::Z) {
+        Ok(())
+    } else {
+        Err(Error::UnsolvableInput(
+            "The hard-coded model number is not valid for this program".to_string(),
+        ))
+    }
+}
+
+fn part1(input: &str) -> error::Result<u64> {
     let mut alu = Alu::default();
     let program = read_program(input)?;
     let digits = [6, 9, 9, 1, 4, 9, 9, 9, 9, 7, 5, 3, 6, 9];
@@ -217,11 +229,11 @@ fn part1(input: &mut dyn BufRead) -> io::Result<u64> {
         program.iter().cloned(),
         &mut digits.iter().copied().map(i128::from),
     );
-    assert_eq!(0, alu.read_variable(Variable::Z));
+    check_model_number(&alu)?;
     Ok(fold_num(digits))
 }
 
-fn part2(input: &mut dyn BufRead) -> io::Result<u64> {
+fn part2(input: &str) -> error::Result<u64> {
     let mut alu = Alu::default();
     let program = read_program(input)?;
     let digits = [1, 4, 9, 1, 1, 6, 7, 5, 3, 1, 1, 1, 1, 4];
@@ -229,47 +241,26 @@ fn part2(input: &mut dyn BufRead) -> io::Result<u64> {
         program.iter().cloned(),
         &mut digits.iter().copied().map(i128::from),
     );
-    assert_eq!(0, alu.read_variable(Variable::Z));
+    check_model_number(&alu)?;
     Ok(fold_num(digits))
 }
 
-#[allow(unreachable_code)]
-pub(super) fn run() -> io::Result<()> {
-    println!("This problem was solved by manually stepping through the fourteen segments of the program and keeping track of exactly what the output would be for any possible input sequence. As such, this \"solution\" works only for my specific input");
-    {
-        println!("Year 2021 Day 24 Part 1");
-        println!(
-            "{}",
-            part1(&mut BufReader::new(File::open("2021_24.txt")?))?
-        );
-    }
-    {
-        println!("Year 2021 Day 24 Part 2");
-        println!(
-            "{}",
-            part2(&mut BufReader::new(File::open("2021_24.txt")?))?
-        );
-    }
-    Ok(())
-}
+aoc_util::register_day!(2021, 24, part1, part2, "Arithmetic Logic Unit");
 
 #[cfg(test)]
 mod tests {
-    use std::io::Cursor;
+    use aoc_util::samples;
 
     use super::*;
 
     #[test]
     #[ignore]
-    fn test_negation() -> io::Result<()> {
+    fn test_negation() -> error::Result<()> {
         let program = "inp x\nmul x -1\n";
         let input = [3];
         let expected = -3;
         let mut alu = Alu::default();
-        alu.run_program(
-            read_program(&mut Cursor::new(program))?,
-            &mut input.into_iter(),
-        );
+        alu.run_program(read_program(program)?, &mut input.into_iter());
         let actual = alu.read_variable(Variable::X);
         assert_eq!(expected, actual);
         Ok(())
@@ -277,15 +268,12 @@ mod tests {
 
     #[test]
     #[ignore]
-    fn test_comparison() -> io::Result<()> {
+    fn test_comparison() -> error::Result<()> {
         let program = "inp z\ninp x\nmul z 3\neql z x\n";
         let input = [3, 9];
         let expected = 1;
         let mut alu = Alu::default();
-        alu.run_program(
-            read_program(&mut Cursor::new(program))?,
-            &mut input.into_iter(),
-        );
+        alu.run_program(read_program(program)?, &mut input.into_iter());
         let actual = alu.read_variable(Variable::Z);
         assert_eq!(expected, actual);
         Ok(())
@@ -293,27 +281,12 @@ mod tests {
 
     #[test]
     #[ignore]
-    fn test_bit_storage() -> io::Result<()> {
-        let program = concat!(
-            "inp w\n",
-            "add z w\n",
-            "mod z 2\n",
-            "div w 2\n",
-            "add y w\n",
-            "mod y 2\n",
-            "div w 2\n",
-            "add x w\n",
-            "mod x 2\n",
-            "div w 2\n",
-            "mod w 2\n",
-        );
+    fn test_bit_storage() -> error::Result<()> {
+        let program = &samples::get_named(2021, 24, "bits")?;
         let input = [3, 9];
         let expected = 1;
         let mut alu = Alu::default();
-        alu.run_program(
-            read_program(&mut Cursor::new(program))?,
-            &mut input.into_iter(),
-        );
+        alu.run_program(read_program(program)?, &mut input.into_iter());
         let actual = alu.read_variable(Variable::Z);
         assert_eq!(expected, actual);
         Ok(())
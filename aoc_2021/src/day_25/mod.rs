@@ -1,12 +1,14 @@
 use std::{
     fmt::{self, Display, Formatter},
-    fs::File,
-    io::{self, BufRead, BufReader},
-    mem,
-    ops::{Index, IndexMut},
+    iter, mem,
 };
 
-use aoc_util::nom_extended::NomParse;
+use aoc_util::{
+    error::{self, Error},
+    geometry::Point2D,
+    grid::Grid,
+    nom_extended::NomParse,
+};
 use nom::{
     branch, bytes::complete as bytes, character::complete as character, combinator as comb, multi,
     sequence, IResult,
@@ -40,9 +42,7 @@ impl NomParse<&str> for Cell {
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 struct Seafloor {
-    cells: Vec<Option<Direction>>,
-    num_rows: usize,
-    num_columns: usize,
+    cells: Grid<Option<Direction>>,
 }
 
 impl Seafloor {
@@ -51,31 +51,30 @@ impl Seafloor {
         println!("Before step: {}", self);
         let mut changed = false;
         let mut new_cells = self.clone();
-        new_cells.cells.iter_mut().for_each(|cell| *cell = None);
-        for row in 0..self.num_rows {
-            for column in 0..self.num_columns {
-                let current_space = (row, column);
-                let current_value = self[current_space];
-                match current_value {
-                    None => {}
-                    Some(Direction::South) => new_cells[current_space] = current_value,
-                    Some(Direction::East) => {
-                        let next_space = if column + 1 == self.num_columns {
-                            (row, 0)
-                        } else {
-                            (row, column + 1)
-                        };
-                        #[cfg(test)]
-                        println!(
-                            "Found east-facing sea cucumber at {current_space:?} facing {next_space:?}"
-                        );
-                        if self[next_space].is_none() {
-                            changed = true;
-                            new_cells[current_space] = None;
-                            new_cells[next_space] = current_value;
-                        } else {
-                            new_cells[current_space] = current_value;
-                        }
+        new_cells
+            .cells
+            .iter_mut()
+            .for_each(|(_, cell)| *cell = None);
+        for (current_space, &current_value) in self.cells.iter() {
+            match current_value {
+                None => {}
+                Some(Direction::South) => new_cells.cells[current_space] = current_value,
+                Some(Direction::East) => {
+                    let next_space = if current_space.x() + 1 == self.cells.width() {
+                        Point2D::at(0, *current_space.y())
+                    } else {
+                        Point2D::at(current_space.x() + 1, *current_space.y())
+                    };
+                    #[cfg(test)]
+                    println!(
+                        "Found east-facing sea cucumber at {current_space:?} facing {next_space:?}"
+                    );
+                    if self.cells[next_space].is_none() {
+                        changed = true;
+                        new_cells.cells[current_space] = None;
+                        new_cells.cells[next_space] = current_value;
+                    } else {
+                        new_cells.cells[current_space] = current_value;
                     }
                 }
             }
@@ -83,31 +82,30 @@ impl Seafloor {
         mem::swap(self, &mut new_cells);
         #[cfg(test)]
         println!("After east step: {}", self);
-        new_cells.cells.iter_mut().for_each(|cell| *cell = None);
-        for row in 0..self.num_rows {
-            for column in 0..self.num_columns {
-                let current_space = (row, column);
-                let current_value = self[current_space];
-                match current_value {
-                    None => {}
-                    Some(Direction::East) => new_cells[current_space] = current_value,
-                    Some(Direction::South) => {
-                        let next_space = if row + 1 == self.num_rows {
-                            (0, column)
-                        } else {
-                            (row + 1, column)
-                        };
-                        #[cfg(test)]
-                        println!(
-                            "Found south-facing sea cucumber at {current_space:?} facing {next_space:?}"
-                        );
-                        if self[next_space].is_none() {
-                            changed = true;
-                            new_cells[current_space] = None;
-                            new_cells[next_space] = current_value;
-                        } else {
-                            new_cells[current_space] = current_value;
-                        }
+        new_cells
+            .cells
+            .iter_mut()
+            .for_each(|(_, cell)| *cell = None);
+        for (current_space, &current_value) in self.cells.iter() {
+            match current_value {
+                None => {}
+                Some(Direction::East) => new_cells.cells[current_space] = current_value,
+                Some(Direction::South) => {
+                    let next_space = if current_space.y() + 1 == self.cells.height() {
+                        Point2D::at(*current_space.x(), 0)
+                    } else {
+                        Point2D::at(*current_space.x(), current_space.y() + 1)
+                    };
+                    #[cfg(test)]
+                    println!(
+                        "Found south-facing sea cucumber at {current_space:?} facing {next_space:?}"
+                    );
+                    if self.cells[next_space].is_none() {
+                        changed = true;
+                        new_cells.cells[current_space] = None;
+                        new_cells.cells[next_space] = current_value;
+                    } else {
+                        new_cells.cells[current_space] = current_value;
                     }
                 }
             }
@@ -122,51 +120,12 @@ impl Seafloor {
 impl Display for Seafloor {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         writeln!(f)?;
-        for row in 0..self.num_rows {
-            for column in 0..self.num_columns {
-                match self.cells[row * self.num_columns + column] {
-                    None => write!(f, ".")?,
-                    Some(Direction::East) => write!(f, ">")?,
-                    Some(Direction::South) => write!(f, "v")?,
-                }
-            }
-            writeln!(f)?;
-        }
-        Ok(())
-    }
-}
-
-impl Index<(usize, usize)> for Seafloor {
-    type Output = Option<Direction>;
-
-    fn index(&self, (row, column): (usize, usize)) -> &Self::Output {
-        if row >= self.num_rows {
-            panic!("Tried to access row {} out of {}", row, self.num_rows);
-        }
-        if column >= self.num_columns {
-            panic!(
-                "Tried to access column {} out of {}",
-                column, self.num_columns
-            );
-        }
-        &self.cells[row * self.num_columns + column]
-    }
-}
-
-impl IndexMut<(usize, usize)> for Seafloor {
-    fn index_mut(&mut self, (row, column): (usize, usize)) -> &mut Self::Output {
-        #[cfg(test)]
-        println!("Attempting to access ({row}, {column}) mutably");
-        if row >= self.num_rows {
-            panic!("Tried to access row {} out of {}", row, self.num_rows);
-        }
-        if column >= self.num_columns {
-            panic!(
-                "Tried to access column {} out of {}",
-                column, self.num_columns
-            );
-        }
-        &mut self.cells[row * self.num_columns + column]
+        let rendered = self.cells.display(|cell| match cell {
+            None => '.',
+            Some(Direction::East) => '>',
+            Some(Direction::South) => 'v',
+        });
+        write!(f, "{rendered}")
     }
 }
 
@@ -181,162 +140,130 @@ impl NomParse<&str> for Seafloor {
             )),
             comb::opt(character::line_ending),
         )(input)?;
-        let num_rows = 1 + lines.len();
-        let mut cells = first_line.into_iter().map(Cell::unwrap).collect::<Vec<_>>();
-        cells.extend(lines.into_iter().flatten().map(Cell::unwrap));
-        assert_eq!(cells.len(), num_rows * num_columns);
-        Ok((
-            remainder,
-            Self {
-                cells,
-                num_rows,
-                num_columns,
-            },
-        ))
+        let cells = Grid::from_rows(
+            iter::once(first_line)
+                .chain(lines)
+                .map(|row| row.into_iter().map(Cell::unwrap).collect()),
+        )
+        .expect("Every row has the same number of columns");
+        Ok((remainder, Self { cells }))
     }
 }
 
 aoc_util::impl_from_str_for_nom_parse!(Seafloor);
 
-fn part1(input: &mut dyn BufRead) -> io::Result<u32> {
-    let mut buf = String::new();
-    input.read_to_string(&mut buf)?;
-    let mut seafloor = buf
+fn part1(input: &str) -> error::Result<u32> {
+    let mut seafloor = input
         .parse::<Seafloor>()
-        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        .map_err(|e| Error::parse(e.to_string()))?;
     for i in 1.. {
         if !seafloor.step() {
             return Ok(i);
         }
     }
-    Err(io::Error::new(io::ErrorKind::Other, "Ran out of numbers"))
+    Err(Error::UnsolvableInput("Ran out of numbers".to_string()))
 }
 
-pub(super) fn run() -> io::Result<()> {
-    {
-        println!("Year 2021 Day 25 Part 1");
-        println!(
-            "{}",
-            part1(&mut BufReader::new(File::open("2021_25.txt")?))?
-        );
-    }
-    Ok(())
+fn part2(_input: &str) -> error::Result<u32> {
+    Err(Error::Unimplemented {
+        year: 2021,
+        day: 25,
+        part: Some(2),
+    })
 }
 
+aoc_util::register_day!(2021, 25, part1, part2, "Sea Cucumber");
+
 #[cfg(test)]
 mod tests {
-    use std::io::Cursor;
+    use aoc_util::samples;
 
     use super::*;
 
-    const TEST_DATA: &str = concat!(
-        "...>...\n",
-        ".......\n",
-        "......>\n",
-        "v.....>\n",
-        "......>\n",
-        ".......\n",
-        "..vvv..\n",
-    );
-
-    const TEST_DATA_STEP1: &str = concat!(
-        "..vv>..\n",
-        ".......\n",
-        ">......\n",
-        "v.....>\n",
-        ">......\n",
-        ".......\n",
-        "....v..\n",
-    );
-
-    const PART1_TEST_DATA: &str = concat!(
-        "v...>>.vv>\n",
-        ".vv>>.vv..\n",
-        ">>.>v>...v\n",
-        ">>v>>.>.v.\n",
-        "v>v.vv.v..\n",
-        ">.>>..v...\n",
-        ".vv..>.>v.\n",
-        "v.v..>>v.v\n",
-        "....v..v.>\n",
-    );
-
     #[test]
     #[ignore]
     fn test_seafloor_parse() {
+        let cells = [
+            None,
+            None,
+            None,
+            Some(Direction::East),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(Direction::East),
+            Some(Direction::South),
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(Direction::East),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(Direction::East),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(Direction::South),
+            Some(Direction::South),
+            Some(Direction::South),
+            None,
+            None,
+        ];
         let expected = Seafloor {
-            cells: vec![
-                None,
-                None,
-                None,
-                Some(Direction::East),
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                Some(Direction::East),
-                Some(Direction::South),
-                None,
-                None,
-                None,
-                None,
-                None,
-                Some(Direction::East),
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                Some(Direction::East),
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                Some(Direction::South),
-                Some(Direction::South),
-                Some(Direction::South),
-                None,
-                None,
-            ],
-            num_rows: 7,
-            num_columns: 7,
+            cells: Grid::from_rows(cells.chunks(7).map(<[_]>::to_vec)).unwrap(),
         };
-        let actual = TEST_DATA.parse::<Seafloor>().unwrap();
+        let actual = samples::get_named(2021, 25, "small")
+            .unwrap()
+            .parse::<Seafloor>()
+            .unwrap();
         assert_eq!(expected, actual);
     }
 
     #[test]
     #[ignore]
     fn test_step() {
-        let expected = TEST_DATA_STEP1.parse::<Seafloor>().unwrap();
-        let mut actual = TEST_DATA.parse::<Seafloor>().unwrap();
+        let expected = samples::get_named(2021, 25, "step")
+            .unwrap()
+            .parse::<Seafloor>()
+            .unwrap();
+        let mut actual = samples::get_named(2021, 25, "small")
+            .unwrap()
+            .parse::<Seafloor>()
+            .unwrap();
         assert!(actual.step(), "Step failed to modify seafloor");
         assert_eq!(expected, actual);
     }
 
     #[test]
     #[ignore]
-    fn test_part1() -> io::Result<()> {
+    fn test_part1() -> error::Result<()> {
         let expected = 58;
-        let actual = part1(&mut Cursor::new(PART1_TEST_DATA))?;
+        let actual = part1(&samples::get(2021, 25)?)?;
         assert_eq!(expected, actual);
         Ok(())
     }
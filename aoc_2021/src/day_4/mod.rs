@@ -1,8 +1,5 @@
-use std::{
-    fs::File,
-    io::{self, BufRead, BufReader},
-    iter,
-};
+use aoc_util::error::{self, Error};
+use std::iter;
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 struct Space<N> {
@@ -63,45 +60,37 @@ impl BingoCard {
 }
 
 impl BingoCard {
-    fn from_lines(mut lines: impl Iterator<Item = io::Result<String>>) -> io::Result<Self> {
+    fn from_lines<'a>(mut lines: impl Iterator<Item = &'a str>) -> error::Result<Self> {
         let mut numbers = [[Space {
             number: 0,
             marked: false,
         }; 5]; 5];
         for (i, number_slot) in numbers.iter_mut().enumerate() {
-            let line = lines.next().ok_or_else(|| {
-                io::Error::new(
-                    io::ErrorKind::InvalidData,
-                    format!("Missing card line {}/5", i + 1),
-                )
-            })??;
+            let line = lines
+                .next()
+                .ok_or_else(|| Error::parse(format!("Missing card line {}/5", i + 1)))?;
             for (j, number) in (0..).zip(line.split_whitespace()) {
                 if j >= 5 {
-                    return Err(io::Error::new(
-                        io::ErrorKind::InvalidData,
-                        format!("Got too many numbers on line {}", i),
-                    ));
+                    return Err(Error::parse(format!("Got too many numbers on line {}", i)));
                 }
                 number_slot[j].number = number.parse().map_err(|e| {
-                    io::Error::new(
-                        io::ErrorKind::InvalidData,
-                        format!("Invalid number {number:?} at row {i} column {j}: {e}"),
-                    )
+                    Error::parse(format!(
+                        "Invalid number {number:?} at row {i} column {j}: {e}"
+                    ))
                 })?;
             }
         }
         Ok(Self { numbers })
     }
 
-    fn read_cards(mut lines: impl Iterator<Item = io::Result<String>>) -> io::Result<Vec<Self>> {
+    fn read_cards<'a>(mut lines: impl Iterator<Item = &'a str>) -> error::Result<Vec<Self>> {
         let mut cards = vec![];
         while let Some(line) = lines.next() {
-            let line = line?;
             if !line.is_empty() {
-                return Err(io::Error::new(
-                    io::ErrorKind::InvalidData,
-                    format!("Card {} is too tall", cards.len() + 1),
-                ));
+                return Err(Error::parse(format!(
+                    "Card {} is too tall",
+                    cards.len() + 1
+                )));
             }
             cards.push(BingoCard::from_lines(lines.by_ref().take(5))?);
         }
@@ -109,19 +98,16 @@ impl BingoCard {
     }
 }
 
-fn part1(input: &mut dyn BufRead) -> io::Result<u32> {
+fn part1(input: &str) -> error::Result<u32> {
     let mut lines = input.lines();
     let numbers = lines
         .next()
-        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "Missing number line"))??;
+        .ok_or_else(|| Error::parse("Missing number line"))?;
     let mut cards = BingoCard::read_cards(lines)?;
     for number in numbers.split(',') {
-        let number = number.parse().map_err(|e| {
-            io::Error::new(
-                io::ErrorKind::InvalidData,
-                format!("Invalid drawn number {number:?}: {e}"),
-            )
-        })?;
+        let number = number
+            .parse()
+            .map_err(|e| Error::parse(format!("Invalid drawn number {number:?}: {e}")))?;
         for card in cards.iter_mut() {
             if card.mark_number(number) {
                 let unmarked_numbers = card.unmarked_numbers();
@@ -129,26 +115,20 @@ fn part1(input: &mut dyn BufRead) -> io::Result<u32> {
             }
         }
     }
-    Err(io::Error::new(
-        io::ErrorKind::InvalidData,
-        "Ran out of numbers",
-    ))
+    Err(Error::UnsolvableInput("Ran out of numbers".to_string()))
 }
 
-fn part2(input: &mut dyn BufRead) -> io::Result<u32> {
+fn part2(input: &str) -> error::Result<u32> {
     let mut lines = input.lines();
     let numbers = lines
         .next()
-        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "Missing number line"))??;
+        .ok_or_else(|| Error::parse("Missing number line"))?;
     let mut cards = BingoCard::read_cards(lines)?;
     let mut done_cards = vec![];
     for number in numbers.split(',') {
-        let number = number.parse().map_err(|e| {
-            io::Error::new(
-                io::ErrorKind::InvalidData,
-                format!("Invalid drawn number {number:?}: {e}"),
-            )
-        })?;
+        let number = number
+            .parse()
+            .map_err(|e| Error::parse(format!("Invalid drawn number {number:?}: {e}")))?;
         for card_idx in 0..cards.len() {
             if cards[card_idx].mark_number(number) {
                 if cards.len() == 1 {
@@ -163,91 +143,21 @@ fn part2(input: &mut dyn BufRead) -> io::Result<u32> {
             cards.remove(done_card);
         }
     }
-    Err(io::Error::new(
-        io::ErrorKind::InvalidData,
-        format!("Ran out of numbers with {} cards remaining", cards.len()),
-    ))
+    Err(Error::UnsolvableInput(format!(
+        "Ran out of numbers with {} cards remaining",
+        cards.len()
+    )))
 }
 
-pub(super) fn run() -> io::Result<()> {
-    {
-        println!("Year 2021 Day 4 Part 1");
-        println!(
-            "The final score is {}",
-            part1(&mut BufReader::new(File::open("2021_04.txt")?))?
-        );
-    }
-    {
-        println!("Year 2021 Day 4 Part 2");
-        println!(
-            "The final FINAL score is {}",
-            part2(&mut BufReader::new(File::open("2021_04.txt")?))?,
-        );
-    }
-    Ok(())
-}
-
-#[cfg(test)]
-mod tests {
-    use std::io::Cursor;
-
-    use super::*;
-
-    #[test]
-    #[ignore]
-    fn test_part1() -> io::Result<()> {
-        let s = r"7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
-
-22 13 17 11 0
- 8 2 23 4 24
-21 9 14 16 7
- 6 10 3 18 5
- 1 12 20 15 19
-
- 3 15 0 2 22
- 9 18 13 17 5
-19 8 7 25 23
-20 11 10 24 4
-14 21 16 12 6
-
-14 21 17 24 4
-10 16 15 9 19
-18 8 23 26 20
-22 11 13 6 5
- 2 0 12 3 7
-";
-        let expected = 4512;
-        let actual = part1(&mut Cursor::new(s))?;
-        assert_eq!(expected, actual);
-        Ok(())
-    }
-
-    #[test]
+aoc_util::solution! {
+    year: 2021,
+    day: 4,
+    title: "Giant Squid",
+    part1: part1,
+    part2: part2,
     #[ignore]
-    fn test_part2() -> io::Result<()> {
-        let s = r"7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
-
-22 13 17 11 0
- 8 2 23 4 24
-21 9 14 16 7
- 6 10 3 18 5
- 1 12 20 15 19
-
- 3 15 0 2 22
- 9 18 13 17 5
-19 8 7 25 23
-20 11 10 24 4
-14 21 16 12 6
-
-14 21 17 24 4
-10 16 15 9 19
-18 8 23 26 20
-22 11 13 6 5
- 2 0 12 3 7
-";
-        let expected = 1924;
-        let actual = part2(&mut Cursor::new(s))?;
-        assert_eq!(expected, actual);
-        Ok(())
-    }
+    examples: [
+        part1 => 4512,
+        part2 => 1924,
+    ],
 }
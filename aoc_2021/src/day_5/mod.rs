@@ -1,9 +1,5 @@
-use std::{
-    collections::HashMap,
-    fs::File,
-    io::{self, BufRead, BufReader},
-    str::FromStr,
-};
+use aoc_util::error::{self, Error};
+use std::{collections::HashMap, str::FromStr};
 
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 struct Point {
@@ -12,27 +8,18 @@ struct Point {
 }
 
 impl FromStr for Point {
-    type Err = io::Error;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (x, y) = s.split_once(',').ok_or_else(|| {
-            io::Error::new(
-                io::ErrorKind::InvalidData,
-                format!("Point {:?} missing comma", s),
-            )
-        })?;
-        let x = x.parse().map_err(|e| {
-            io::Error::new(
-                io::ErrorKind::InvalidData,
-                format!("Invalid x coordinate {x:?}: {e}"),
-            )
-        })?;
-        let y = y.parse().map_err(|e| {
-            io::Error::new(
-                io::ErrorKind::InvalidData,
-                format!("Invalid y coordinate {y:?}: {e}"),
-            )
-        })?;
+        let (x, y) = s
+            .split_once(',')
+            .ok_or_else(|| Error::parse(format!("Point {:?} missing comma", s)))?;
+        let x = x
+            .parse()
+            .map_err(|e| Error::parse(format!("Invalid x coordinate {x:?}: {e}")))?;
+        let y = y
+            .parse()
+            .map_err(|e| Error::parse(format!("Invalid y coordinate {y:?}: {e}")))?;
         Ok(Self { x, y })
     }
 }
@@ -54,15 +41,12 @@ impl Line {
 }
 
 impl FromStr for Line {
-    type Err = io::Error;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (from, to) = s.split_once(" -> ").ok_or_else(|| {
-            io::Error::new(
-                io::ErrorKind::InvalidData,
-                format!("Line {:?} missing arrow", s),
-            )
-        })?;
+        let (from, to) = s
+            .split_once(" -> ")
+            .ok_or_else(|| Error::parse(format!("Line {:?} missing arrow", s)))?;
         let from = from.parse()?;
         let to = to.parse()?;
         let (from, to) = if to < from { (to, from) } else { (from, to) };
@@ -70,15 +54,14 @@ impl FromStr for Line {
     }
 }
 
-fn read_lines(input: &mut dyn BufRead) -> impl Iterator<Item = io::Result<Line>> + '_ {
+fn read_lines(input: &str) -> impl Iterator<Item = error::Result<Line>> + '_ {
     input.lines().map(|line| {
-        line?
-            .parse::<Line>()
-            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+        line.parse::<Line>()
+            .map_err(|e| Error::parse(e.to_string()))
     })
 }
 
-fn count_points_covered(lines: impl Iterator<Item = io::Result<Line>>) -> io::Result<usize> {
+fn count_points_covered(lines: impl Iterator<Item = error::Result<Line>>) -> error::Result<usize> {
     let mut points_covered = HashMap::<_, usize>::new();
     for line in lines {
         let line = line?;
@@ -122,7 +105,7 @@ fn count_points_covered(lines: impl Iterator<Item = io::Result<Line>>) -> io::Re
         .count())
 }
 
-fn part1(input: &mut dyn BufRead) -> io::Result<usize> {
+fn part1(input: &str) -> error::Result<usize> {
     count_points_covered(read_lines(input).filter_map(|line| {
         let line = match line {
             Err(e) => return Some(Err(e)),
@@ -134,51 +117,19 @@ fn part1(input: &mut dyn BufRead) -> io::Result<usize> {
     }))
 }
 
-fn part2(input: &mut dyn BufRead) -> io::Result<usize> {
+fn part2(input: &str) -> error::Result<usize> {
     count_points_covered(read_lines(input))
 }
 
-pub(super) fn run() -> io::Result<()> {
-    {
-        println!("Year 2021 Day 5 Part 1");
-        println!(
-            "There are {} points that are part of multiple vertical and horizontal lines",
-            part1(&mut BufReader::new(File::open("2021_05.txt")?))?
-        );
-    }
-    {
-        println!("Year 2021 Day 5 Part 2");
-        println!(
-            "There are {} points in multiple lines",
-            part2(&mut BufReader::new(File::open("2021_05.txt")?))?
-        );
-    }
-    Ok(())
-}
-
-#[cfg(test)]
-mod tests {
-    use std::io::Cursor;
-
-    use super::*;
-
-    #[test]
+aoc_util::solution! {
+    year: 2021,
+    day: 5,
+    title: "Hydrothermal Venture",
+    part1: part1,
+    part2: part2,
     #[ignore]
-    fn test_part1() -> io::Result<()> {
-        let s = "0,9 -> 5,9\n8,0 -> 0,8\n9,4 -> 3,4\n2,2 -> 2,1\n7,0 -> 7,4\n6,4 -> 2,0\n0,9 -> 2,9\n3,4 -> 1,4\n0,0 -> 8,8\n5,5 -> 8,2";
-        let expected = 5;
-        let actual = part1(&mut Cursor::new(s))?;
-        assert_eq!(expected, actual);
-        Ok(())
-    }
-
-    #[test]
-    #[ignore]
-    fn test_part2() -> io::Result<()> {
-        let s = "0,9 -> 5,9\n8,0 -> 0,8\n9,4 -> 3,4\n2,2 -> 2,1\n7,0 -> 7,4\n6,4 -> 2,0\n0,9 -> 2,9\n3,4 -> 1,4\n0,0 -> 8,8\n5,5 -> 8,2";
-        let expected = 12;
-        let actual = part2(&mut Cursor::new(s))?;
-        assert_eq!(expected, actual);
-        Ok(())
-    }
+    examples: [
+        part1 => 5,
+        part2 => 12,
+    ],
 }
@@ -1,8 +1,5 @@
-use std::{
-    fs::File,
-    io::{self, BufRead, BufReader},
-    mem,
-};
+use aoc_util::error::{self, Error};
+use std::mem;
 
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
 struct LanternfishTimers {
@@ -44,9 +41,9 @@ impl LanternfishTimers {
 }
 
 impl LanternfishTimers {
-    fn read(input: &mut dyn BufRead) -> io::Result<Self> {
+    fn read(input: &str) -> error::Result<Self> {
         let mut timers = LanternfishTimers::default();
-        for i in read_line(&mut *input)?.split(',') {
+        for i in input.lines().next().unwrap_or_default().split(',') {
             match i.trim().parse() {
                 Ok(0) => timers.zero_remaining += 1,
                 Ok(1) => timers.one_remaining += 1,
@@ -57,31 +54,15 @@ impl LanternfishTimers {
                 Ok(6) => timers.six_remaining += 1,
                 Ok(7) => timers.seven_remaining += 1,
                 Ok(8) => timers.eight_remaining += 1,
-                Ok(i) => {
-                    return Err(io::Error::new(
-                        io::ErrorKind::InvalidData,
-                        format!("Invalid timer: {}", i),
-                    ))
-                }
-                Err(e) => {
-                    return Err(io::Error::new(
-                        io::ErrorKind::InvalidData,
-                        format!("Invalid timer {:?}: {:?}", i, e),
-                    ))
-                }
+                Ok(i) => return Err(Error::parse(format!("Invalid timer: {}", i))),
+                Err(e) => return Err(Error::parse(format!("Invalid timer {:?}: {:?}", i, e))),
             }
         }
         Ok(timers)
     }
 }
 
-fn read_line(input: &mut dyn BufRead) -> io::Result<String> {
-    let mut buf = String::new();
-    input.read_line(&mut buf)?;
-    Ok(buf)
-}
-
-fn part1(input: &mut dyn BufRead) -> io::Result<u64> {
+fn part1(input: &str) -> error::Result<u64> {
     let mut timers = LanternfishTimers::read(input)?;
     for _ in 0..80 {
         timers.tick();
@@ -89,7 +70,7 @@ fn part1(input: &mut dyn BufRead) -> io::Result<u64> {
     Ok(timers.total_fish())
 }
 
-fn part2(input: &mut dyn BufRead) -> io::Result<u64> {
+fn part2(input: &str) -> error::Result<u64> {
     let mut timers = LanternfishTimers::read(input)?;
     for _ in 0..256 {
         timers.tick();
@@ -97,47 +78,15 @@ fn part2(input: &mut dyn BufRead) -> io::Result<u64> {
     Ok(timers.total_fish())
 }
 
-pub(super) fn run() -> io::Result<()> {
-    {
-        println!("Year 2021 Day 6 Part 1");
-        println!(
-            "After 80 days, the number of lanternfish would be {}",
-            part1(&mut BufReader::new(File::open("2021_06.txt")?))?,
-        );
-    }
-    {
-        println!("Year 2021 Day 6 Part 2");
-        println!(
-            "{:?}",
-            part2(&mut BufReader::new(File::open("2021_06.txt")?))?,
-        );
-    }
-    Ok(())
-}
-
-#[cfg(test)]
-mod tests {
-    use std::io::Cursor;
-
-    use super::*;
-
-    #[test]
-    #[ignore]
-    fn test_part1() -> io::Result<()> {
-        let s = "3,4,3,1,2";
-        let expected = 5934;
-        let actual = part1(&mut Cursor::new(s))?;
-        assert_eq!(expected, actual);
-        Ok(())
-    }
-
-    #[test]
+aoc_util::solution! {
+    year: 2021,
+    day: 6,
+    title: "Lanternfish",
+    part1: part1,
+    part2: part2,
     #[ignore]
-    fn test_part2() -> io::Result<()> {
-        let s = "3,4,3,1,2";
-        let expected = 26_984_457_539;
-        let actual = part2(&mut Cursor::new(s))?;
-        assert_eq!(expected, actual);
-        Ok(())
-    }
+    examples: [
+        part1 => 5934,
+        part2 => 26_984_457_539,
+    ],
 }
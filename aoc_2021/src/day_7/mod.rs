@@ -1,19 +1,12 @@
-use std::{
-    fs::File,
-    io::{self, BufRead, BufReader},
-};
+use aoc_util::error::{self, Error};
 
-fn read_positions(input: &mut dyn BufRead) -> io::Result<Vec<usize>> {
-    let line = {
-        let mut buf = String::new();
-        input.read_line(&mut buf)?;
-        buf
-    };
+fn read_positions(input: &str) -> error::Result<Vec<usize>> {
+    let line = input.lines().next().unwrap_or_default();
     line.split(',')
         .map(|s| {
             s.trim()
-                .parse()
-                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+                .parse::<usize>()
+                .map_err(|e| Error::parse(e.to_string()))
         })
         .collect()
 }
@@ -31,14 +24,14 @@ fn count_fuel(positions: &[usize], position: usize) -> usize {
         .sum()
 }
 
-fn part1(input: &mut dyn BufRead) -> io::Result<usize> {
+fn part1(input: &str) -> error::Result<usize> {
     let mut positions = read_positions(input)?;
     let num_positions = positions.len();
     positions.sort_unstable();
     Ok(count_fuel(&positions, positions[num_positions / 2]))
 }
 
-fn part2(input: &mut dyn BufRead) -> io::Result<usize> {
+fn part2(input: &str) -> error::Result<usize> {
     fn calculate_fuel(positions: &[usize], position: usize) -> usize {
         positions
             .iter()
@@ -90,47 +83,15 @@ fn part2(input: &mut dyn BufRead) -> io::Result<usize> {
     }
 }
 
-pub(super) fn run() -> io::Result<()> {
-    {
-        println!("Year 2021 Day 7 Part 1");
-        println!(
-            "Total fuel is {}",
-            part1(&mut BufReader::new(File::open("2021_07.txt")?))?
-        );
-    }
-    {
-        println!("Year 2021 Day 7 Part 2");
-        println!(
-            "{:?}",
-            part2(&mut BufReader::new(File::open("2021_07.txt")?))?
-        );
-    }
-    Ok(())
-}
-
-#[cfg(test)]
-mod tests {
-    use std::io::Cursor;
-
-    use super::*;
-
-    #[test]
+aoc_util::solution! {
+    year: 2021,
+    day: 7,
+    title: "The Treachery of Whales",
+    part1: part1,
+    part2: part2,
     #[ignore]
-    fn test_part1() -> io::Result<()> {
-        let s = "16,1,2,0,4,2,7,1,2,14";
-        let expected = 37;
-        let actual = part1(&mut Cursor::new(s))?;
-        assert_eq!(expected, actual);
-        Ok(())
-    }
-
-    #[test]
-    #[ignore]
-    fn test_part2() -> io::Result<()> {
-        let s = "16,1,2,0,4,2,7,1,2,14";
-        let expected = 168;
-        let actual = part2(&mut Cursor::new(s))?;
-        assert_eq!(expected, actual);
-        Ok(())
-    }
+    examples: [
+        part1 => 37,
+        part2 => 168,
+    ],
 }
@@ -1,30 +1,26 @@
-use std::{
-    fs::File,
-    io::{self, BufRead, BufReader},
-    mem,
-};
+use aoc_util::error::{self, Error};
+use std::mem;
 
-fn part1(input: &mut dyn BufRead) -> io::Result<u32> {
+fn part1(input: &str) -> error::Result<u32> {
     let mut snack_elf_calories = 0;
     let mut current_elf_calories = 0;
     let mut insert_calories = |current_elf_calories: &mut u32| {
         snack_elf_calories = snack_elf_calories.max(mem::take(current_elf_calories));
     };
     for line in input.lines() {
-        let line = line?;
         if line.trim().is_empty() {
             insert_calories(&mut current_elf_calories);
         } else {
             current_elf_calories += line
                 .parse::<u32>()
-                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+                .map_err(|e| Error::parse(e.to_string()))?;
         }
     }
     insert_calories(&mut current_elf_calories);
     Ok(snack_elf_calories)
 }
 
-fn part2(input: &mut dyn BufRead) -> io::Result<u32> {
+fn part2(input: &str) -> error::Result<u32> {
     let mut snack_elf_calories = [0; 3];
     let mut current_elf_calories = 0;
     let mut insert_calories = |current_elf_calories: &mut u32| {
@@ -36,33 +32,16 @@ fn part2(input: &mut dyn BufRead) -> io::Result<u32> {
         *current_elf_calories = 0;
     };
     for line in input.lines() {
-        let line = line?;
         if line.trim().is_empty() {
             insert_calories(&mut current_elf_calories);
         } else {
             current_elf_calories += line
                 .parse::<u32>()
-                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+                .map_err(|e| Error::parse(e.to_string()))?;
         }
     }
     insert_calories(&mut current_elf_calories);
     Ok(snack_elf_calories.into_iter().sum())
 }
 
-pub(super) fn run() -> io::Result<()> {
-    {
-        println!("Year 2022 Day 1 Part 1");
-        println!(
-            "{}",
-            part1(&mut BufReader::new(File::open("2022_01.txt")?))?
-        );
-    }
-    {
-        println!("Year 2022 Day 1 Part 2");
-        println!(
-            "{:?}",
-            part2(&mut BufReader::new(File::open("2022_01.txt")?))?
-        );
-    }
-    Ok(())
-}
+aoc_util::register_day!(2022, 1, part1, part2, "Calorie Counting");
@@ -1,8 +1,5 @@
-use std::{
-    fs::File,
-    io::{self, BufRead, BufReader},
-    str::FromStr,
-};
+use aoc_util::error::{self, Error};
+use std::str::FromStr;
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 enum Instruction {
@@ -11,15 +8,11 @@ enum Instruction {
 }
 
 impl FromStr for Instruction {
-    type Err = io::Error;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match &s[..4] {
-            "addx" => {
-                Ok(Self::Addx(s[5..].parse().map_err(|e| {
-                    io::Error::new(io::ErrorKind::InvalidData, e)
-                })?))
-            }
+            "addx" => Ok(Self::Addx(s[5..].parse()?)),
             "noop" => Ok(Self::Noop),
             _ => unreachable!(),
         }
@@ -119,225 +112,39 @@ impl Cpu {
     }
 }
 
-fn part1(input: &mut dyn BufRead) -> io::Result<i32> {
+fn part1(input: &str) -> error::Result<i32> {
     let instructions = input
         .lines()
-        .map(|line| {
-            line?
-                .parse()
-                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
-        })
-        .collect::<Result<Vec<_>, _>>()?;
+        .map(str::parse)
+        .collect::<error::Result<Vec<_>>>()?;
     let mut cpu = Cpu::new(instructions);
     Ok(cpu.run_program())
 }
 
-fn part2(input: &mut dyn BufRead) -> io::Result<String> {
+fn part2(input: &str) -> error::Result<String> {
     let instructions = input
         .lines()
-        .map(|line| {
-            line?
-                .parse()
-                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
-        })
-        .collect::<Result<Vec<_>, _>>()?;
+        .map(str::parse)
+        .collect::<error::Result<Vec<_>>>()?;
     let mut cpu = Cpu::new(instructions);
     Ok(cpu.draw_sprite())
 }
 
-pub(super) fn run() -> io::Result<()> {
-    {
-        println!("Year 2022 Day 10 Part 1");
-        println!(
-            "{}",
-            part1(&mut BufReader::new(File::open("2022_10.txt")?))?
-        );
-    }
-    {
-        println!("Year 2022 Day 10 Part 2");
-        println!(
-            "{}",
-            part2(&mut BufReader::new(File::open("2022_10.txt")?))?
-        );
-    }
-    Ok(())
-}
-
-#[cfg(test)]
-mod tests {
-    use std::io::Cursor;
-
-    use super::*;
-
-    const TEST_DATA: &str = concat!(
-        "addx 15\n",
-        "addx -11\n",
-        "addx 6\n",
-        "addx -3\n",
-        "addx 5\n",
-        "addx -1\n",
-        "addx -8\n",
-        "addx 13\n",
-        "addx 4\n",
-        "noop\n",
-        "addx -1\n",
-        "addx 5\n",
-        "addx -1\n",
-        "addx 5\n",
-        "addx -1\n",
-        "addx 5\n",
-        "addx -1\n",
-        "addx 5\n",
-        "addx -1\n",
-        "addx -35\n",
-        "addx 1\n",
-        "addx 24\n",
-        "addx -19\n",
-        "addx 1\n",
-        "addx 16\n",
-        "addx -11\n",
-        "noop\n",
-        "noop\n",
-        "addx 21\n",
-        "addx -15\n",
-        "noop\n",
-        "noop\n",
-        "addx -3\n",
-        "addx 9\n",
-        "addx 1\n",
-        "addx -3\n",
-        "addx 8\n",
-        "addx 1\n",
-        "addx 5\n",
-        "noop\n",
-        "noop\n",
-        "noop\n",
-        "noop\n",
-        "noop\n",
-        "addx -36\n",
-        "noop\n",
-        "addx 1\n",
-        "addx 7\n",
-        "noop\n",
-        "noop\n",
-        "noop\n",
-        "addx 2\n",
-        "addx 6\n",
-        "noop\n",
-        "noop\n",
-        "noop\n",
-        "noop\n",
-        "noop\n",
-        "addx 1\n",
-        "noop\n",
-        "noop\n",
-        "addx 7\n",
-        "addx 1\n",
-        "noop\n",
-        "addx -13\n",
-        "addx 13\n",
-        "addx 7\n",
-        "noop\n",
-        "addx 1\n",
-        "addx -33\n",
-        "noop\n",
-        "noop\n",
-        "noop\n",
-        "addx 2\n",
-        "noop\n",
-        "noop\n",
-        "noop\n",
-        "addx 8\n",
-        "noop\n",
-        "addx -1\n",
-        "addx 2\n",
-        "addx 1\n",
-        "noop\n",
-        "addx 17\n",
-        "addx -9\n",
-        "addx 1\n",
-        "addx 1\n",
-        "addx -3\n",
-        "addx 11\n",
-        "noop\n",
-        "noop\n",
-        "addx 1\n",
-        "noop\n",
-        "addx 1\n",
-        "noop\n",
-        "noop\n",
-        "addx -13\n",
-        "addx -19\n",
-        "addx 1\n",
-        "addx 3\n",
-        "addx 26\n",
-        "addx -30\n",
-        "addx 12\n",
-        "addx -1\n",
-        "addx 3\n",
-        "addx 1\n",
-        "noop\n",
-        "noop\n",
-        "noop\n",
-        "addx -9\n",
-        "addx 18\n",
-        "addx 1\n",
-        "addx 2\n",
-        "noop\n",
-        "noop\n",
-        "addx 9\n",
-        "noop\n",
-        "noop\n",
-        "noop\n",
-        "addx -1\n",
-        "addx 2\n",
-        "addx -37\n",
-        "addx 1\n",
-        "addx 3\n",
-        "noop\n",
-        "addx 15\n",
-        "addx -21\n",
-        "addx 22\n",
-        "addx -6\n",
-        "addx 1\n",
-        "noop\n",
-        "addx 2\n",
-        "addx 1\n",
-        "noop\n",
-        "addx -10\n",
-        "noop\n",
-        "noop\n",
-        "addx 20\n",
-        "addx 1\n",
-        "addx 2\n",
-        "addx 2\n",
-        "addx -6\n",
-        "addx -11\n",
-        "noop\n",
-        "noop\n",
-        "noop\n",
-    );
-
-    #[test]
-    fn test_part1() -> io::Result<()> {
-        let expected = 13_140;
-        let actual = part1(&mut Cursor::new(TEST_DATA))?;
-        assert_eq!(expected, actual);
-        Ok(())
-    }
-
-    #[test]
-    fn test_part2() -> io::Result<()> {
-        let expected = concat!(
+aoc_util::solution! {
+    year: 2022,
+    day: 10,
+    title: "Cathode-Ray Tube",
+    part1: part1,
+    part2: part2,
+    examples: [
+        part1 => 13_140,
+        part2 => concat!(
             "##..##..##..##..##..##..##..##..##..##..\n",
             "###...###...###...###...###...###...###.\n",
             "####....####....####....####....####....\n",
             "#####.....#####.....#####.....#####.....\n",
             "######......######......######......####\n",
             "#######.......#######.......#######.....\n",
-        );
-        let actual = part2(&mut Cursor::new(TEST_DATA))?;
-        assert_eq!(expected, actual);
-        Ok(())
-    }
+        ),
+    ],
 }
@@ -1,11 +1,10 @@
+use aoc_util::error::{self, Error};
 use std::{
     cmp::Reverse,
     collections::VecDeque,
     fmt::Debug,
-    fs::File,
-    io::{self, BufRead, BufReader},
     num::ParseIntError,
-    str::FromStr,
+    str::{FromStr, Lines},
 };
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -51,16 +50,13 @@ impl Operator {
 }
 
 impl FromStr for Operator {
-    type Err = io::Error;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             "+" => Ok(Self::Add),
             "*" => Ok(Self::Mul),
-            _ => Err(io::Error::new(
-                io::ErrorKind::InvalidData,
-                "Only + and * operators are supported",
-            )),
+            _ => Err(Error::parse("Only + and * operators are supported")),
         }
     }
 }
@@ -108,116 +104,85 @@ struct Monkey {
 }
 
 impl Monkey {
-    fn read(input: &mut dyn BufRead) -> io::Result<Self> {
-        let mut lines = input.lines();
+    fn read(lines: &mut Lines<'_>) -> error::Result<Self> {
         let worry_levels = {
-            let starting_items = lines.next().ok_or_else(|| {
-                io::Error::new(
-                    io::ErrorKind::InvalidData,
-                    "Ran out of input before first line",
-                )
-            })??;
+            let starting_items = lines
+                .next()
+                .ok_or_else(|| Error::parse("Ran out of input before first line"))?;
             if let Some(worry_levels) = starting_items.strip_prefix("  Starting items: ") {
                 worry_levels
                     .split(", ")
-                    .map(|level| {
-                        level
-                            .parse()
-                            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
-                    })
-                    .collect::<io::Result<_>>()?
+                    .map(|level| level.parse::<Worry>().map_err(Error::from))
+                    .collect::<error::Result<_>>()?
             } else {
-                return Err(io::Error::new(
-                    io::ErrorKind::InvalidData,
+                return Err(Error::parse(
                     r#"First line does not start with "Starting items""#,
                 ));
             }
         };
         let operation = {
-            let line = lines.next().ok_or_else(|| {
-                io::Error::new(io::ErrorKind::InvalidData, "Operation line missing")
-            })??;
+            let line = lines
+                .next()
+                .ok_or_else(|| Error::parse("Operation line missing"))?;
             if let Some(operation) = line.strip_prefix("  Operation: new = ") {
                 let mut bits = operation.split_whitespace();
                 let left = bits
                     .next()
-                    .ok_or_else(|| {
-                        io::Error::new(
-                            io::ErrorKind::InvalidData,
-                            "right side of operation missing",
-                        )
-                    })?
+                    .ok_or_else(|| Error::parse("right side of operation missing"))?
                     .parse::<Val>()
-                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+                    .map_err(|e| Error::parse(e.to_string()))?;
                 let operator = bits
                     .next()
-                    .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "operator missing"))?
+                    .ok_or_else(|| Error::parse("operator missing"))?
                     .parse::<Operator>()?;
                 let right = bits
                     .next()
-                    .ok_or_else(|| {
-                        io::Error::new(io::ErrorKind::InvalidData, "second argument missing")
-                    })?
+                    .ok_or_else(|| Error::parse("second argument missing"))?
                     .parse::<Val>()
-                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+                    .map_err(|e| Error::parse(e.to_string()))?;
                 Expr {
                     left,
                     operator,
                     right,
                 }
             } else {
-                return Err(io::Error::new(
-                    io::ErrorKind::InvalidData,
-                    "Invalid operation line",
-                ));
+                return Err(Error::parse("Invalid operation line"));
             }
         };
         let test_denominator = {
-            let line = lines.next().ok_or_else(|| {
-                io::Error::new(io::ErrorKind::InvalidData, "Missing monkey test")
-            })??;
+            let line = lines
+                .next()
+                .ok_or_else(|| Error::parse("Missing monkey test"))?;
             if let Some(next_monkey_test) = line.strip_prefix("  Test: divisible by ") {
                 next_monkey_test.parse::<Worry>().map_err(|e| {
-                    io::Error::new(
-                        io::ErrorKind::InvalidData,
-                        format!("Invalid test value {next_monkey_test:?}: {e:?}"),
-                    )
+                    Error::parse(format!("Invalid test value {next_monkey_test:?}: {e:?}"))
                 })?
             } else {
-                return Err(io::Error::new(
-                    io::ErrorKind::InvalidData,
-                    "Malformed monkey test",
-                ));
+                return Err(Error::parse("Malformed monkey test"));
             }
         };
         let success = {
-            let line = lines.next().ok_or_else(|| {
-                io::Error::new(io::ErrorKind::InvalidData, "Missing test success line")
-            })??;
+            let line = lines
+                .next()
+                .ok_or_else(|| Error::parse("Missing test success line"))?;
             if let Some(next_monkey_true) = line.strip_prefix("    If true: throw to monkey ") {
                 next_monkey_true
                     .parse::<MonkeyId>()
-                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?
+                    .map_err(|e| Error::parse(e.to_string()))?
             } else {
-                return Err(io::Error::new(
-                    io::ErrorKind::InvalidData,
-                    "Invalid test success line",
-                ));
+                return Err(Error::parse("Invalid test success line"));
             }
         };
         let failure = {
-            let line = lines.next().ok_or_else(|| {
-                io::Error::new(io::ErrorKind::InvalidData, "Missing test failure line")
-            })??;
+            let line = lines
+                .next()
+                .ok_or_else(|| Error::parse("Missing test failure line"))?;
             if let Some(next_monkey_false) = line.strip_prefix("    If false: throw to monkey ") {
                 next_monkey_false
                     .parse::<MonkeyId>()
-                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?
+                    .map_err(|e| Error::parse(e.to_string()))?
             } else {
-                return Err(io::Error::new(
-                    io::ErrorKind::InvalidData,
-                    "Invalid test failure line",
-                ));
+                return Err(Error::parse("Invalid test failure line"));
             }
         };
         let next_monkey = NextMonkey {
@@ -249,32 +214,28 @@ impl Monkey {
     }
 }
 
-fn part1(input: &mut dyn BufRead) -> io::Result<usize> {
+fn part1(input: &str) -> error::Result<usize> {
     let mut monkeys = vec![];
+    let mut lines = input.lines();
     loop {
-        if let Some(monkey_num) = input.lines().next() {
-            if let Some(monkey_num) = monkey_num?
+        if let Some(monkey_num) = lines.next() {
+            if let Some(monkey_num) = monkey_num
                 .strip_prefix("Monkey ")
                 .and_then(|monkey_num| monkey_num.strip_suffix(':'))
             {
                 if monkeys.len()
                     != monkey_num
                         .parse::<MonkeyId>()
-                        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?
+                        .map_err(|e| Error::parse(e.to_string()))?
                 {
-                    return Err(io::Error::new(
-                        io::ErrorKind::InvalidData,
-                        "Monkeys got scrambled!",
-                    ));
+                    return Err(Error::parse("Monkeys got scrambled!"));
                 } else {
-                    monkeys.push(Monkey::read(input)?);
-                    if let Some(empty_line) = input.lines().next() {
-                        let empty_line = empty_line?;
+                    monkeys.push(Monkey::read(&mut lines)?);
+                    if let Some(empty_line) = lines.next() {
                         if !empty_line.is_empty() {
-                            return Err(io::Error::new(
-                                io::ErrorKind::InvalidData,
-                                format!("Unexpected non-empty line after monkey: {empty_line:?}"),
-                            ));
+                            return Err(Error::parse(format!(
+                                "Unexpected non-empty line after monkey: {empty_line:?}"
+                            )));
                         } else {
                             continue;
                         }
@@ -301,32 +262,28 @@ fn part1(input: &mut dyn BufRead) -> io::Result<usize> {
     Ok(num_inspections.into_iter().take(2).product())
 }
 
-fn part2(input: &mut dyn BufRead) -> io::Result<usize> {
+fn part2(input: &str) -> error::Result<usize> {
     let mut monkeys = vec![];
+    let mut lines = input.lines();
     loop {
-        if let Some(monkey_num) = input.lines().next() {
-            if let Some(monkey_num) = monkey_num?
+        if let Some(monkey_num) = lines.next() {
+            if let Some(monkey_num) = monkey_num
                 .strip_prefix("Monkey ")
                 .and_then(|monkey_num| monkey_num.strip_suffix(':'))
             {
                 if monkeys.len()
                     != monkey_num
                         .parse::<MonkeyId>()
-                        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?
+                        .map_err(|e| Error::parse(e.to_string()))?
                 {
-                    return Err(io::Error::new(
-                        io::ErrorKind::InvalidData,
-                        "Monkeys got scrambled!",
-                    ));
+                    return Err(Error::parse("Monkeys got scrambled!"));
                 } else {
-                    monkeys.push(Monkey::read(input)?);
-                    if let Some(empty_line) = input.lines().next() {
-                        let empty_line = empty_line?;
+                    monkeys.push(Monkey::read(&mut lines)?);
+                    if let Some(empty_line) = lines.next() {
                         if !empty_line.is_empty() {
-                            return Err(io::Error::new(
-                                io::ErrorKind::InvalidData,
-                                format!("Unexpected non-empty line after monkey: {empty_line:?}"),
-                            ));
+                            return Err(Error::parse(format!(
+                                "Unexpected non-empty line after monkey: {empty_line:?}"
+                            )));
                         } else {
                             continue;
                         }
@@ -359,73 +316,14 @@ fn part2(input: &mut dyn BufRead) -> io::Result<usize> {
     Ok(num_inspections.into_iter().take(2).product())
 }
 
-pub(super) fn run() -> io::Result<()> {
-    {
-        println!("Year 2022 Day 11 Part 1");
-        println!(
-            "{}",
-            part1(&mut BufReader::new(File::open("2022_11.txt")?))?
-        );
-    }
-    {
-        println!("Year 2022 Day 11 Part 2");
-        println!(
-            "{}",
-            part2(&mut BufReader::new(File::open("2022_11.txt")?))?
-        );
-    }
-    Ok(())
-}
-
-#[cfg(test)]
-mod tests {
-    use std::io::Cursor;
-
-    use super::*;
-
-    const TEST_DATA: &str = concat!(
-        "Monkey 0:\n",
-        "  Starting items: 79, 98\n",
-        "  Operation: new = old * 19\n",
-        "  Test: divisible by 23\n",
-        "    If true: throw to monkey 2\n",
-        "    If false: throw to monkey 3\n",
-        "\n",
-        "Monkey 1:\n",
-        "  Starting items: 54, 65, 75, 74\n",
-        "  Operation: new = old + 6\n",
-        "  Test: divisible by 19\n",
-        "    If true: throw to monkey 2\n",
-        "    If false: throw to monkey 0\n",
-        "\n",
-        "Monkey 2:\n",
-        "  Starting items: 79, 60, 97\n",
-        "  Operation: new = old * old\n",
-        "  Test: divisible by 13\n",
-        "    If true: throw to monkey 1\n",
-        "    If false: throw to monkey 3\n",
-        "\n",
-        "Monkey 3:\n",
-        "  Starting items: 74\n",
-        "  Operation: new = old + 3\n",
-        "  Test: divisible by 17\n",
-        "    If true: throw to monkey 0\n",
-        "    If false: throw to monkey 1\n",
-    );
-
-    #[test]
-    fn test_part1() -> io::Result<()> {
-        let expected = 10_605;
-        let actual = part1(&mut Cursor::new(TEST_DATA))?;
-        assert_eq!(expected, actual);
-        Ok(())
-    }
-
-    #[test]
-    fn test_part2() -> io::Result<()> {
-        let expected = 2_713_310_158;
-        let actual = part2(&mut Cursor::new(TEST_DATA))?;
-        assert_eq!(expected, actual);
-        Ok(())
-    }
+aoc_util::solution! {
+    year: 2022,
+    day: 11,
+    title: "Monkey in the Middle",
+    part1: part1,
+    part2: part2,
+    examples: [
+        part1 => 10_605,
+        part2 => 2_713_310_158,
+    ],
 }
@@ -1,8 +1,8 @@
+use aoc_util::error::{self, Error};
 use std::{
     cmp::Reverse,
     collections::HashSet,
-    fs::File,
-    io::{self, BufRead, BufReader},
+    fmt::{self, Debug, Formatter},
     ops::Index,
 };
 
@@ -57,7 +57,7 @@ impl Default for Pos {
     }
 }
 
-#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[derive(Clone, Default, Eq, PartialEq)]
 struct Map {
     heights: Vec<Vec<u32>>,
     start: Pos,
@@ -65,12 +65,11 @@ struct Map {
 }
 
 impl Map {
-    fn read(input: &mut dyn BufRead) -> io::Result<Self> {
+    fn read(input: &str) -> error::Result<Self> {
         let ret = input
             .lines()
-            .fold(Ok(Self::default()), |acc: io::Result<_>, line| {
+            .fold(Ok(Self::default()), |acc: error::Result<_>, line| {
                 let mut acc = acc?;
-                let line = line?;
                 let bytes = line.as_bytes();
                 let row = bytes
                     .iter()
@@ -93,30 +92,19 @@ impl Map {
                                 b'z'
                             }
                             b'a'..=b'z' => c,
-                            _ => {
-                                return Err(io::Error::new(
-                                    io::ErrorKind::InvalidData,
-                                    format!("Invalid height {c:?}"),
-                                ))
-                            }
+                            _ => return Err(Error::parse(format!("Invalid height {c:?}"))),
                         } - b'a';
                         Ok(actual_height as u32)
                     })
-                    .collect::<io::Result<_>>()?;
+                    .collect::<error::Result<_>>()?;
                 acc.heights.push(row);
                 Ok(acc)
             })?;
         if ret.start.y >= ret.heights.len() || ret.start.x >= ret.heights[0].len() {
-            return Err(io::Error::new(
-                io::ErrorKind::InvalidData,
-                "Couldn't find start point",
-            ));
+            return Err(Error::parse("Couldn't find start point"));
         }
         if ret.end.y >= ret.heights.len() || ret.end.x >= ret.heights[0].len() {
-            return Err(io::Error::new(
-                io::ErrorKind::InvalidData,
-                "Couldn't find end point",
-            ));
+            return Err(Error::parse("Couldn't find end point"));
         }
         Ok(ret)
     }
@@ -126,6 +114,17 @@ impl Map {
     }
 }
 
+impl Debug for Map {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Map")
+            .field("width", &self.heights.first().map_or(0, Vec::len))
+            .field("height", &self.heights.len())
+            .field("start", &self.start)
+            .field("end", &self.end)
+            .finish()
+    }
+}
+
 impl Index<Pos> for Map {
     type Output = u32;
 
@@ -134,8 +133,7 @@ impl Index<Pos> for Map {
     }
 }
 
-fn part1(input: &mut dyn BufRead) -> io::Result<usize> {
-    let map = Map::read(input)?;
+fn part1(map: &Map) -> error::Result<usize> {
     let mut current_positions = vec![(map.start, 0)];
     let mut visited: HashSet<Pos> =
         HashSet::from_iter(current_positions.iter().map(|&(pos, _)| pos));
@@ -158,16 +156,12 @@ fn part1(input: &mut dyn BufRead) -> io::Result<usize> {
                     .map(|neighbor| (neighbor, steps_so_far + 1)),
             );
         } else {
-            return Err(io::Error::new(
-                io::ErrorKind::InvalidData,
-                "Couldn't reach end",
-            ));
+            return Err(Error::UnsolvableInput("Couldn't reach end".to_string()));
         }
     }
 }
 
-fn part2(input: &mut dyn BufRead) -> io::Result<usize> {
-    let map = Map::read(input)?;
+fn part2(map: &Map) -> error::Result<usize> {
     let mut current_positions = vec![(map.end, 0)];
     let mut visited: HashSet<Pos> =
         HashSet::from_iter(current_positions.iter().map(|&(pos, _)| pos));
@@ -190,59 +184,22 @@ fn part2(input: &mut dyn BufRead) -> io::Result<usize> {
                     .map(|neighbor| (neighbor, steps_so_far + 1)),
             );
         } else {
-            return Err(io::Error::new(
-                io::ErrorKind::InvalidData,
-                "Couldn't reach elevation 0",
+            return Err(Error::UnsolvableInput(
+                "Couldn't reach elevation 0".to_string(),
             ));
         }
     }
 }
 
-pub(super) fn run() -> io::Result<()> {
-    {
-        println!("Year 2022 Day 12 Part 1");
-        println!(
-            "{}",
-            part1(&mut BufReader::new(File::open("2022_12.txt")?))?
-        );
-    }
-    {
-        println!("Year 2022 Day 12 Part 2");
-        println!(
-            "{}",
-            part2(&mut BufReader::new(File::open("2022_12.txt")?))?
-        );
-    }
-    Ok(())
-}
-
-#[cfg(test)]
-mod tests {
-    use std::io::Cursor;
-
-    use super::*;
-
-    const TEST_DATA: &str = concat!(
-        "Sabqponm\n",
-        "abcryxxl\n",
-        "accszExk\n",
-        "acctuvwj\n",
-        "abdefghi\n",
-    );
-
-    #[test]
-    fn test_part1() -> io::Result<()> {
-        let expected = 31;
-        let actual = part1(&mut Cursor::new(TEST_DATA))?;
-        assert_eq!(expected, actual);
-        Ok(())
-    }
-
-    #[test]
-    fn test_part2() -> io::Result<()> {
-        let expected = 29;
-        let actual = part2(&mut Cursor::new(TEST_DATA))?;
-        assert_eq!(expected, actual);
-        Ok(())
-    }
+aoc_util::solution! {
+    year: 2022,
+    day: 12,
+    title: "Hill Climbing Algorithm",
+    parse: Map::read,
+    part1: part1,
+    part2: part2,
+    examples: [
+        part1 => 31,
+        part2 => 29,
+    ],
 }
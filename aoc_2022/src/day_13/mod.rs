@@ -1,8 +1,7 @@
+use aoc_util::error::{self, Error};
 use std::{
     cmp::Ordering,
     fmt::{self, Debug, Display, Formatter},
-    fs::File,
-    io::{self, BufRead, BufReader},
     num::ParseIntError,
     str::FromStr,
 };
@@ -89,34 +88,26 @@ impl PartialOrd for Packet {
     }
 }
 
-fn part1(input: &mut dyn BufRead) -> io::Result<i32> {
+fn part1(input: &str) -> error::Result<i32> {
     let mut total_correct = 0;
     let mut lines = input.lines();
     for i in 1.. {
         if let Some(line) = lines.next() {
-            let first = line?
-                .parse::<Packet>()
-                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+            let first = line.parse::<Packet>().map_err(Error::parse)?;
             let second = lines
                 .next()
-                .ok_or_else(|| {
-                    io::Error::new(io::ErrorKind::InvalidData, "Missing second packet")
-                })??
-                .parse()
-                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+                .ok_or_else(|| Error::parse("Missing second packet"))?
+                .parse::<Packet>()
+                .map_err(Error::parse)?;
             if first < second {
                 total_correct += i
             }
             match lines.next() {
                 None => return Ok(total_correct),
-                Some(Ok(line)) if line.is_empty() => {}
-                Some(Ok(line)) => {
-                    return Err(io::Error::new(
-                        io::ErrorKind::InvalidData,
-                        format!("Unexpected non-blank line {line:?}"),
-                    ))
+                Some("") => {}
+                Some(line) => {
+                    return Err(Error::parse(format!("Unexpected non-blank line {line:?}")))
                 }
-                Some(Err(e)) => return Err(e),
             }
         } else {
             return Ok(total_correct);
@@ -125,21 +116,15 @@ fn part1(input: &mut dyn BufRead) -> io::Result<i32> {
     unreachable!("Too many lines")
 }
 
-fn part2(input: &mut dyn BufRead) -> io::Result<usize> {
+fn part2(input: &str) -> error::Result<usize> {
     let mut packets = input
         .lines()
-        .filter_map(|line| {
-            let line = match line {
-                Ok(line) if line.is_empty() => return None,
-                Ok(line) => line,
-                Err(e) => return Some(Err(e)),
-            };
-            Some(
-                line.parse::<Packet>()
-                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
-            )
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            line.parse::<Packet>()
+                .map_err(|e| Error::parse(e.to_string()))
         })
-        .collect::<io::Result<Vec<_>>>()?;
+        .collect::<error::Result<Vec<_>>>()?;
     let first_divider = "[[2]]".parse::<Packet>().unwrap();
     let second_divider = "[[6]]".parse::<Packet>().unwrap();
     packets.extend([first_divider.clone(), second_divider.clone()]);
@@ -166,69 +151,14 @@ fn part2(input: &mut dyn BufRead) -> io::Result<usize> {
     Ok(first_divider * second_divider)
 }
 
-pub(super) fn run() -> io::Result<()> {
-    {
-        println!("Year 2022 Day 13 Part 1");
-        println!(
-            "{}",
-            part1(&mut BufReader::new(File::open("2022_13.txt")?))?
-        );
-    }
-    {
-        println!("Year 2022 Day 13 Part 2");
-        println!(
-            "{}",
-            part2(&mut BufReader::new(File::open("2022_13.txt")?))?
-        );
-    }
-    Ok(())
-}
-
-#[cfg(test)]
-mod tests {
-    use std::io::Cursor;
-
-    use super::*;
-
-    const TEST_DATA: &str = concat!(
-        "[1,1,3,1,1]\n",
-        "[1,1,5,1,1]\n",
-        "\n",
-        "[[1],[2,3,4]]\n",
-        "[[1],4]\n",
-        "\n",
-        "[9]\n",
-        "[[8,7,6]]\n",
-        "\n",
-        "[[4,4],4,4]\n",
-        "[[4,4],4,4,4]\n",
-        "\n",
-        "[7,7,7,7]\n",
-        "[7,7,7]\n",
-        "\n",
-        "[]\n",
-        "[3]\n",
-        "\n",
-        "[[[]]]\n",
-        "[[]]\n",
-        "\n",
-        "[1,[2,[3,[4,[5,6,7]]]],8,9]\n",
-        "[1,[2,[3,[4,[5,6,0]]]],8,9]\n",
-    );
-
-    #[test]
-    fn test_part1() -> io::Result<()> {
-        let expected = 13;
-        let actual = part1(&mut Cursor::new(TEST_DATA))?;
-        assert_eq!(expected, actual);
-        Ok(())
-    }
-
-    #[test]
-    fn test_part2() -> io::Result<()> {
-        let expected = 140;
-        let actual = part2(&mut Cursor::new(TEST_DATA))?;
-        assert_eq!(expected, actual);
-        Ok(())
-    }
+aoc_util::solution! {
+    year: 2022,
+    day: 13,
+    title: "Distress Signal",
+    part1: part1,
+    part2: part2,
+    examples: [
+        part1 => 13,
+        part2 => 140,
+    ],
 }
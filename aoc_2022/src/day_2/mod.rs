@@ -1,8 +1,5 @@
-use std::{
-    fs::File,
-    io::{self, BufRead, BufReader},
-    str::FromStr,
-};
+use aoc_util::error::{self, Error};
+use std::str::FromStr;
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 enum RpsChoice {
@@ -26,14 +23,14 @@ impl RpsChoice {
 }
 
 impl FromStr for RpsChoice {
-    type Err = io::Error;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             "A" | "X" => Ok(Self::Rock),
             "B" | "Y" => Ok(Self::Paper),
             "C" | "Z" => Ok(Self::Scissors),
-            _ => Err(io::Error::new(io::ErrorKind::InvalidData, s)),
+            _ => Err(Error::parse(s.to_string())),
         }
     }
 }
@@ -67,14 +64,14 @@ impl RpsResult {
 }
 
 impl FromStr for RpsResult {
-    type Err = io::Error;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             "X" => Ok(Self::Lose),
             "Y" => Ok(Self::Tie),
             "Z" => Ok(Self::Win),
-            _ => Err(io::Error::new(io::ErrorKind::InvalidData, s)),
+            _ => Err(Error::parse(s.to_string())),
         }
     }
 }
@@ -89,13 +86,12 @@ impl From<RpsResult> for u32 {
     }
 }
 
-fn part1(input: &mut dyn BufRead) -> io::Result<u32> {
+fn part1(input: &str) -> error::Result<u32> {
     let mut score = 0;
     for line in input.lines() {
-        let line = line?;
         let (opponent, me) = line
             .split_once(' ')
-            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, line.clone()))?;
+            .ok_or_else(|| Error::parse(line.to_string()))?;
         let opponent: RpsChoice = opponent.parse()?;
         let me: RpsChoice = me.parse()?;
         let result = me.r#match(opponent);
@@ -106,13 +102,12 @@ fn part1(input: &mut dyn BufRead) -> io::Result<u32> {
     Ok(score)
 }
 
-fn part2(input: &mut dyn BufRead) -> io::Result<u32> {
+fn part2(input: &str) -> error::Result<u32> {
     let mut score = 0;
     for line in input.lines() {
-        let line = line?;
         let (opponent, result) = line
             .split_once(' ')
-            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, line.clone()))?;
+            .ok_or_else(|| Error::parse(line.to_string()))?;
         let opponent: RpsChoice = opponent.parse()?;
         let result: RpsResult = result.parse()?;
         let me = result.my_choice(opponent);
@@ -123,45 +118,14 @@ fn part2(input: &mut dyn BufRead) -> io::Result<u32> {
     Ok(score)
 }
 
-pub(super) fn run() -> io::Result<()> {
-    {
-        println!("Year 2022 Day 2 Part 1");
-        println!(
-            "{}",
-            part1(&mut BufReader::new(File::open("2022_02.txt")?))?
-        );
-    }
-    {
-        println!("Year 2022 Day 2 Part 2");
-        println!(
-            "{}",
-            part2(&mut BufReader::new(File::open("2022_02.txt")?))?
-        );
-    }
-    Ok(())
-}
-
-#[cfg(test)]
-mod tests {
-    use std::io::Cursor;
-
-    use super::*;
-
-    const TEST_DATA: &str = "A Y\nB X\nC Z\n";
-
-    #[test]
-    fn test_part1() -> io::Result<()> {
-        let expected = 15;
-        let actual = part1(&mut Cursor::new(TEST_DATA))?;
-        assert_eq!(expected, actual);
-        Ok(())
-    }
-
-    #[test]
-    fn test_part2() -> io::Result<()> {
-        let expected = 12;
-        let actual = part2(&mut Cursor::new(TEST_DATA))?;
-        assert_eq!(expected, actual);
-        Ok(())
-    }
+aoc_util::solution! {
+    year: 2022,
+    day: 2,
+    title: "Rock Paper Scissors",
+    part1: part1,
+    part2: part2,
+    examples: [
+        part1 => 15,
+        part2 => 12,
+    ],
 }
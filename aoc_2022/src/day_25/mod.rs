@@ -1,30 +1,31 @@
-use std::{
-    fs::File,
-    io::{self, BufRead, BufReader},
+use aoc_util::{
+    error::{self, Error},
+    math::NumeralSystem,
 };
 
-fn part1(_input: &mut dyn BufRead) -> io::Result<()> {
-    todo!("Year 2022 Day 25 Part 1")
+fn part1(input: &str) -> error::Result<String> {
+    let snafu = NumeralSystem::snafu();
+    let mut total = 0i64;
+    for (i, line) in input.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let requirement = snafu
+            .parse(line.trim())
+            .ok_or_else(|| Error::parse_at(i + 1, format!("{line:?} isn't a SNAFU number")))?;
+        total = total
+            .checked_add(requirement)
+            .ok_or_else(|| Error::parse_at(i + 1, "The total fuel requirement overflowed"))?;
+    }
+    Ok(snafu.format(total))
 }
 
-fn part2(_input: &mut dyn BufRead) -> io::Result<()> {
-    todo!("Year 2022 Day 25 Part 2")
+fn part2(_input: &str) -> error::Result<u32> {
+    Err(Error::Unimplemented {
+        year: 2022,
+        day: 25,
+        part: Some(2),
+    })
 }
 
-pub(super) fn run() -> io::Result<()> {
-    {
-        println!("Year 2022 Day 25 Part 1");
-        println!(
-            "{:?}",
-            part1(&mut BufReader::new(File::open("2022_25.txt")?))?
-        );
-    }
-    {
-        println!("Year 2022 Day 25 Part 2");
-        println!(
-            "{:?}",
-            part2(&mut BufReader::new(File::open("2022_25.txt")?))?
-        );
-    }
-    Ok(())
-}
+aoc_util::register_day!(2022, 25, part1, part2, "Full of Hot Air");
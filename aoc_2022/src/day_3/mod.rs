@@ -1,15 +1,10 @@
-use std::{
-    collections::HashSet,
-    fs::File,
-    io::{self, BufRead, BufReader},
-};
+use std::{collections::HashSet, io};
 
-fn part1(input: &mut dyn BufRead) -> io::Result<u32> {
+fn part1(input: &str) -> io::Result<u32> {
     let mut compartment_one = HashSet::new();
     let mut compartment_two = HashSet::new();
     let mut total_error = 0;
     for line in input.lines() {
-        let line = line?;
         let num_items = line.len();
         for (i, item) in line.bytes().enumerate() {
             let compartment = if i < num_items / 2 {
@@ -34,7 +29,7 @@ fn part1(input: &mut dyn BufRead) -> io::Result<u32> {
     Ok(total_error)
 }
 
-fn part2(input: &mut dyn BufRead) -> io::Result<u32> {
+fn part2(input: &str) -> io::Result<u32> {
     let mut compartments = [
         (HashSet::new(), HashSet::new()),
         (HashSet::new(), HashSet::new()),
@@ -43,7 +38,6 @@ fn part2(input: &mut dyn BufRead) -> io::Result<u32> {
     let mut elf_index = 0;
     let mut total_badge = 0;
     for line in input.lines() {
-        let line = line?;
         let num_items = line.len();
         for (i, item) in line.bytes().enumerate() {
             let compartment = if i < num_items / 2 {
@@ -85,52 +79,14 @@ fn part2(input: &mut dyn BufRead) -> io::Result<u32> {
     Ok(total_badge)
 }
 
-pub(super) fn run() -> io::Result<()> {
-    {
-        println!("Year 2022 Day 3 Part 1");
-        println!(
-            "{}",
-            part1(&mut BufReader::new(File::open("2022_03.txt")?))?
-        );
-    }
-    {
-        println!("Year 2022 Day 3 Part 2");
-        println!(
-            "{}",
-            part2(&mut BufReader::new(File::open("2022_03.txt")?))?
-        );
-    }
-    Ok(())
-}
-
-#[cfg(test)]
-mod tests {
-    use std::io::Cursor;
-
-    use super::*;
-
-    const TEST_DATA: &str = concat!(
-        "vJrwpWtwJgWrhcsFMMfFFhFp\n",
-        "jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL\n",
-        "PmmdzqPrVvPwwTWBwg\n",
-        "wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn\n",
-        "ttgJtRGJQctTZtZT\n",
-        "CrZsJsPPZsGzwwsLwLmpwMDw\n",
-    );
-
-    #[test]
-    fn test_part1() -> io::Result<()> {
-        let expected = 157;
-        let actual = part1(&mut Cursor::new(TEST_DATA))?;
-        assert_eq!(expected, actual);
-        Ok(())
-    }
-
-    #[test]
-    fn test_part2() -> io::Result<()> {
-        let expected = 70;
-        let actual = part2(&mut Cursor::new(TEST_DATA))?;
-        assert_eq!(expected, actual);
-        Ok(())
-    }
+aoc_util::solution! {
+    year: 2022,
+    day: 3,
+    title: "Rucksack Reorganization",
+    part1: part1,
+    part2: part2,
+    examples: [
+        part1 => 157,
+        part2 => 70,
+    ],
 }
@@ -1,126 +1,73 @@
-use std::{
-    fs::File,
-    io::{self, BufRead, BufReader},
-};
+use std::ops::RangeInclusive;
 
-fn part1(input: &mut dyn BufRead) -> io::Result<u32> {
-    let mut total_containment = 0;
-    for line in input.lines() {
-        let line = line?;
-        let (left, right) = line
-            .split_once(',')
-            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, line.clone()))?;
-        let (left_low, left_high) = left
-            .split_once('-')
-            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, left.to_string()))?;
-        let (right_low, right_high) = right
+use aoc_util::error::{self, Error};
+
+/// Parses a line of the form `2-4,6-8` into the two ranges of sections that it describes.
+fn parse_pair(line: &str) -> error::Result<(RangeInclusive<u32>, RangeInclusive<u32>)> {
+    let parse_range = |range: &str| -> error::Result<RangeInclusive<u32>> {
+        let (low, high) = range
             .split_once('-')
-            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, right.to_string()))?;
-        let left_low = left_low
-            .parse::<u32>()
-            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
-        let left_high = left_high
-            .parse::<u32>()
-            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
-        let right_low = right_low
-            .parse::<u32>()
-            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
-        let right_high = right_high
-            .parse::<u32>()
-            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
-        let left = left_low..=left_high;
-        let right = right_low..=right_high;
-        if left.contains(&right_low) && left.contains(&right_high)
-            || right.contains(&left_low) && right.contains(&left_high)
-        {
-            total_containment += 1;
-        }
-    }
-    Ok(total_containment)
+            .ok_or_else(|| Error::parse(format!("Invalid range {range:?}")))?;
+        Ok(low.parse()?..=high.parse()?)
+    };
+    let (left, right) = line
+        .split_once(',')
+        .ok_or_else(|| Error::parse(format!("Invalid pair {line:?}")))?;
+    Ok((parse_range(left)?, parse_range(right)?))
 }
 
-fn part2(input: &mut dyn BufRead) -> io::Result<u32> {
-    let mut total_overlaps = 0;
-    for line in input.lines() {
-        let line = line?;
-        let (left, right) = line
-            .split_once(',')
-            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, line.clone()))?;
-        let (left_low, left_high) = left
-            .split_once('-')
-            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, left.to_string()))?;
-        let (right_low, right_high) = right
-            .split_once('-')
-            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, right.to_string()))?;
-        let left_low = left_low
-            .parse::<u32>()
-            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
-        let left_high = left_high
-            .parse::<u32>()
-            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
-        let right_low = right_low
-            .parse::<u32>()
-            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
-        let right_high = right_high
-            .parse::<u32>()
-            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
-        let left = left_low..=left_high;
-        let right = right_low..=right_high;
-        if (left.contains(&right_low) || left.contains(&right_high))
-            || (right.contains(&left_low) || right.contains(&left_high))
-        {
-            total_overlaps += 1;
+/// Parses every line of `input` into a pair of ranges and counts the pairs which satisfy
+/// `predicate`.
+fn count_pairs(
+    input: &str,
+    predicate: impl Fn(&RangeInclusive<u32>, &RangeInclusive<u32>) -> bool,
+) -> error::Result<u32> {
+    let mut count = 0;
+    for (line_num, line) in input.lines().enumerate() {
+        let (left, right) = parse_pair(line).map_err(|e| e.on_line(line_num + 1))?;
+        if predicate(&left, &right) {
+            count += 1;
         }
     }
-    Ok(total_overlaps)
+    Ok(count)
 }
 
-pub(super) fn run() -> io::Result<()> {
-    {
-        println!("Year 2022 Day 4 Part 1");
-        println!(
-            "{:?}",
-            part1(&mut BufReader::new(File::open("2022_04.txt")?))?
-        );
-    }
-    {
-        println!("Year 2022 Day 4 Part 2");
-        println!(
-            "{:?}",
-            part2(&mut BufReader::new(File::open("2022_04.txt")?))?
-        );
-    }
-    Ok(())
+fn part1(input: &str) -> error::Result<u32> {
+    count_pairs(input, |left, right| {
+        left.contains(right.start()) && left.contains(right.end())
+            || right.contains(left.start()) && right.contains(left.end())
+    })
+}
+
+fn part2(input: &str) -> error::Result<u32> {
+    count_pairs(input, |left, right| {
+        (left.contains(right.start()) || left.contains(right.end()))
+            || (right.contains(left.start()) || right.contains(left.end()))
+    })
+}
+
+aoc_util::solution! {
+    year: 2022,
+    day: 4,
+    title: "Camp Cleanup",
+    part1: part1,
+    part2: part2,
+    examples: [
+        part1 => 2,
+        part2 => 4,
+    ],
 }
 
 #[cfg(test)]
 mod tests {
-    use std::io::Cursor;
-
     use super::*;
 
-    const TEST_DATA: &str = concat!(
-        "2-4,6-8\n",
-        "2-3,4-5\n",
-        "5-7,7-9\n",
-        "2-8,3-7\n",
-        "6-6,4-6\n",
-        "2-6,4-8\n",
-    );
-
     #[test]
-    fn test_part1() -> io::Result<()> {
-        let expected = 2;
-        let actual = part1(&mut Cursor::new(TEST_DATA))?;
-        assert_eq!(expected, actual);
-        Ok(())
-    }
-
-    #[test]
-    fn test_part2() -> io::Result<()> {
-        let expected = 4;
-        let actual = part2(&mut Cursor::new(TEST_DATA))?;
-        assert_eq!(expected, actual);
-        Ok(())
+    fn test_parse_error() {
+        let e = part1("2-4,6-8\n2-3;4-5\n").unwrap_err();
+        assert_eq!(
+            "Parse error at line 2: Invalid pair \"2-3;4-5\"",
+            e.to_string()
+        );
     }
 }
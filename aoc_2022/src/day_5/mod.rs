@@ -1,8 +1,5 @@
-use std::{
-    fs::File,
-    io::{self, BufRead, BufReader},
-    str::FromStr,
-};
+use aoc_util::error::{self, Error};
+use std::str::{FromStr, Lines};
 
 type Stack<T> = Vec<T>;
 
@@ -30,24 +27,17 @@ impl Warehouse {
         }
     }
 
-    fn read(input: &mut dyn BufRead) -> io::Result<Self> {
+    fn read(lines: &mut Lines<'_>) -> error::Result<Self> {
         // A warehouse consists of 0 or more lines of "crates" followed by a line with the numbers
         // from 1 to 9 directly below the crate labels, truncated to the same length as the lines
         // of crates. All lines of crates are the same number of bytes and there is a space between
         // adjacent crates or in the place of a crate if the stack at that position is shorter than
         // the current height. A crate consists of a '[', an uppercase letter, and a ']'.
         let mut ret = Self::new();
-        let mut lines = input.lines();
         let mut next_line = || {
             lines
                 .next()
-                .ok_or_else(|| {
-                    io::Error::new(
-                        io::ErrorKind::UnexpectedEof,
-                        "Failed to reach bottom of warehouse",
-                    )
-                })
-                .map_or_else(Err, |x| x)
+                .ok_or_else(|| Error::parse("Failed to reach bottom of warehouse"))
         };
         loop {
             let line = next_line()?;
@@ -58,10 +48,7 @@ impl Warehouse {
             if bytes[1] == b'1' {
                 let next_line = next_line()?;
                 if !next_line.is_empty() {
-                    return Err(io::Error::new(
-                        io::ErrorKind::InvalidData,
-                        "Unexpected non-empty line after warehouse",
-                    ));
+                    return Err(Error::parse("Unexpected non-empty line after warehouse"));
                 }
                 break;
             }
@@ -123,11 +110,11 @@ struct Move {
 }
 
 impl FromStr for Move {
-    type Err = io::Error;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        fn mk_error(msg: String) -> io::Error {
-            io::Error::new(io::ErrorKind::InvalidData, msg)
+        fn mk_error(msg: String) -> Error {
+            Error::parse(msg)
         }
         let bytes = s.as_bytes();
         let log_num_crates = bytes.len() - 17;
@@ -157,71 +144,32 @@ impl FromStr for Move {
     }
 }
 
-fn part1(input: &mut dyn BufRead) -> io::Result<String> {
-    let mut warehouse = Warehouse::read(input)?;
-    for line in input.lines() {
-        warehouse.move_crates(line?.parse()?, false);
+fn part1(input: &str) -> error::Result<String> {
+    let mut lines = input.lines();
+    let mut warehouse = Warehouse::read(&mut lines)?;
+    for line in lines {
+        warehouse.move_crates(line.parse()?, false);
     }
     Ok(warehouse.top_crates())
 }
 
-fn part2(input: &mut dyn BufRead) -> io::Result<String> {
-    let mut warehouse = Warehouse::read(input)?;
-    for line in input.lines() {
-        warehouse.move_crates(line?.parse()?, true);
+fn part2(input: &str) -> error::Result<String> {
+    let mut lines = input.lines();
+    let mut warehouse = Warehouse::read(&mut lines)?;
+    for line in lines {
+        warehouse.move_crates(line.parse()?, true);
     }
     Ok(warehouse.top_crates())
 }
 
-pub(super) fn run() -> io::Result<()> {
-    {
-        println!("Year 2022 Day 5 Part 1");
-        println!(
-            "{}",
-            part1(&mut BufReader::new(File::open("2022_05.txt")?))?
-        );
-    }
-    {
-        println!("Year 2022 Day 5 Part 2");
-        println!(
-            "{}",
-            part2(&mut BufReader::new(File::open("2022_05.txt")?))?
-        );
-    }
-    Ok(())
-}
-
-#[cfg(test)]
-mod tests {
-    use std::io::Cursor;
-
-    use super::*;
-
-    const TEST_DATA: &str = concat!(
-        "    [D]    \n",
-        "[N] [C]    \n",
-        "[Z] [M] [P]\n",
-        " 1   2   3 \n",
-        "\n",
-        "move 1 from 2 to 1\n",
-        "move 3 from 1 to 3\n",
-        "move 2 from 2 to 1\n",
-        "move 1 from 1 to 2\n",
-    );
-
-    #[test]
-    fn test_part1() -> io::Result<()> {
-        let expected = "CMZ";
-        let actual = part1(&mut Cursor::new(TEST_DATA))?;
-        assert_eq!(expected, actual);
-        Ok(())
-    }
-
-    #[test]
-    fn test_part2() -> io::Result<()> {
-        let expected = "MCD";
-        let actual = part2(&mut Cursor::new(TEST_DATA))?;
-        assert_eq!(expected, actual);
-        Ok(())
-    }
+aoc_util::solution! {
+    year: 2022,
+    day: 5,
+    title: "Supply Stacks",
+    part1: part1,
+    part2: part2,
+    examples: [
+        part1 => "CMZ",
+        part2 => "MCD",
+    ],
 }
@@ -1,7 +1,4 @@
-use std::{
-    fs::File,
-    io::{self, BufRead, BufReader},
-};
+use std::io;
 
 type TreeHeight = u8;
 
@@ -166,67 +163,30 @@ impl Forest {
     }
 }
 
-fn part1(input: &mut dyn BufRead) -> io::Result<usize> {
-    let forest = input
-        .lines()
-        .fold(Ok(Forest::default()), |forest: io::Result<_>, line| {
-            let mut forest = forest?;
-            forest.add_row(line?.bytes().map(|b| b - b'0' + 1).collect());
-            Ok(forest)
-        })?;
+fn part1(input: &str) -> io::Result<usize> {
+    let forest = input.lines().fold(Forest::default(), |mut forest, line| {
+        forest.add_row(line.bytes().map(|b| b - b'0' + 1).collect());
+        forest
+    });
     Ok(forest.num_visible_trees())
 }
 
-fn part2(input: &mut dyn BufRead) -> io::Result<usize> {
-    let forest = input
-        .lines()
-        .fold(Ok(Forest::default()), |forest: io::Result<_>, line| {
-            let mut forest = forest?;
-            forest.add_row(line?.bytes().map(|b| b - b'0' + 1).collect());
-            Ok(forest)
-        })?;
+fn part2(input: &str) -> io::Result<usize> {
+    let forest = input.lines().fold(Forest::default(), |mut forest, line| {
+        forest.add_row(line.bytes().map(|b| b - b'0' + 1).collect());
+        forest
+    });
     Ok(forest.max_scenic_score())
 }
 
-pub(super) fn run() -> io::Result<()> {
-    {
-        println!("Year 2022 Day 8 Part 1");
-        println!(
-            "{}",
-            part1(&mut BufReader::new(File::open("2022_08.txt")?))?
-        );
-    }
-    {
-        println!("Year 2022 Day 8 Part 2");
-        println!(
-            "{}",
-            part2(&mut BufReader::new(File::open("2022_08.txt")?))?
-        );
-    }
-    Ok(())
-}
-
-#[cfg(test)]
-mod tests {
-    use std::io::Cursor;
-
-    use super::*;
-
-    const TEST_DATA: &str = "30373\n25512\n65332\n33549\n35390\n";
-
-    #[test]
-    fn test_part1() -> io::Result<()> {
-        let expected = 21;
-        let actual = part1(&mut Cursor::new(TEST_DATA))?;
-        assert_eq!(expected, actual);
-        Ok(())
-    }
-
-    #[test]
-    fn test_part2() -> io::Result<()> {
-        let expected = 8;
-        let actual = part2(&mut Cursor::new(TEST_DATA))?;
-        assert_eq!(expected, actual);
-        Ok(())
-    }
+aoc_util::solution! {
+    year: 2022,
+    day: 8,
+    title: "Treetop Tree House",
+    part1: part1,
+    part2: part2,
+    examples: [
+        part1 => 21,
+        part2 => 8,
+    ],
 }
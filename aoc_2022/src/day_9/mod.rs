@@ -1,17 +1,6 @@
-use std::{
-    cmp::Ordering,
-    collections::HashSet,
-    fs::File,
-    io::{self, BufRead, BufReader},
-};
+use std::{cmp::Ordering, collections::HashSet, io};
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
-enum Direction {
-    Up,
-    Right,
-    Down,
-    Left,
-}
+use aoc_util::geometry::Direction;
 
 #[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
 struct Position {
@@ -21,23 +10,10 @@ struct Position {
 
 impl Position {
     fn step_head(&self, direction: Direction) -> Self {
-        match direction {
-            Direction::Up => Position {
-                y: self.y + 1,
-                ..*self
-            },
-            Direction::Right => Position {
-                x: self.x + 1,
-                ..*self
-            },
-            Direction::Down => Position {
-                y: self.y - 1,
-                ..*self
-            },
-            Direction::Left => Position {
-                x: self.x - 1,
-                ..*self
-            },
+        let delta = direction.delta();
+        Position {
+            x: self.x + *delta.x() as i32,
+            y: self.y + *delta.y() as i32,
         }
     }
 
@@ -84,12 +60,11 @@ impl Position {
     }
 }
 
-fn part1(input: &mut dyn BufRead) -> io::Result<usize> {
+fn part1(input: &str) -> io::Result<usize> {
     let mut visited_cells = HashSet::from([Position::default()]);
     let mut current_head = Position::default();
     let mut current_tail = Position::default();
     for line in input.lines() {
-        let line = line?;
         let (direction, distance) = {
             let bytes = line.as_bytes();
             (
@@ -100,13 +75,8 @@ fn part1(input: &mut dyn BufRead) -> io::Result<usize> {
                     .fold(0, |acc, d| acc * 10 + (d - b'0') as usize),
             )
         };
-        let direction = match direction {
-            b'R' => Direction::Right,
-            b'U' => Direction::Up,
-            b'L' => Direction::Left,
-            b'D' => Direction::Down,
-            _ => unreachable!("Invalid direction {:?}", line.chars().next().unwrap()),
-        };
+        let direction = Direction::try_from(direction as char)
+            .unwrap_or_else(|e| unreachable!("Invalid direction: {e}"));
         for _ in 0..distance {
             let new_head = current_head.step_head(direction);
             let new_tail = current_tail.step_tail(new_head);
@@ -118,13 +88,12 @@ fn part1(input: &mut dyn BufRead) -> io::Result<usize> {
     Ok(visited_cells.len())
 }
 
-fn part2(input: &mut dyn BufRead) -> io::Result<usize> {
+fn part2(input: &str) -> io::Result<usize> {
     let mut visited_cells = HashSet::from([Position::default()]);
     let mut current_head = Position::default();
     let mut current_knots = [Position { x: 0, y: 0 }; 9];
     let mut new_knots = Vec::with_capacity(9);
     for line in input.lines() {
-        let line = line?;
         let (direction, distance) = {
             let bytes = line.as_bytes();
             (
@@ -135,13 +104,8 @@ fn part2(input: &mut dyn BufRead) -> io::Result<usize> {
                     .fold(0, |acc, d| acc * 10 + (d - b'0') as usize),
             )
         };
-        let direction = match direction {
-            b'R' => Direction::Right,
-            b'U' => Direction::Up,
-            b'L' => Direction::Left,
-            b'D' => Direction::Down,
-            _ => unreachable!("Invalid direction {:?}", line.chars().next().unwrap()),
-        };
+        let direction = Direction::try_from(direction as char)
+            .unwrap_or_else(|e| unreachable!("Invalid direction: {e}"));
         for _ in 0..distance {
             let new_head = current_head.step_head(direction);
             new_knots.push(current_knots[0].step_tail(new_head));
@@ -158,49 +122,15 @@ fn part2(input: &mut dyn BufRead) -> io::Result<usize> {
     Ok(visited_cells.len())
 }
 
-pub(super) fn run() -> io::Result<()> {
-    {
-        println!("Year 2022 Day 9 Part 1");
-        println!(
-            "{}",
-            part1(&mut BufReader::new(File::open("2022_09.txt")?))?
-        );
-    }
-    {
-        println!("Year 2022 Day 9 Part 2");
-        println!(
-            "{}",
-            part2(&mut BufReader::new(File::open("2022_09.txt")?))?
-        );
-    }
-    Ok(())
-}
-
-#[cfg(test)]
-mod tests {
-    use std::io::Cursor;
-
-    use super::*;
-
-    const TEST_DATA: &str = "R 4\nU 4\nL 3\nD 1\nR 4\nD 1\nL 5\nR 2\n";
-    const TEST_DATA_LARGE: &str = "R 5\nU 8\nL 8\nD 3\nR 17\nD 10\nL 25\nU 20\n";
-
-    #[test]
-    fn test_part1() -> io::Result<()> {
-        let expected = 13;
-        let actual = part1(&mut Cursor::new(TEST_DATA))?;
-        assert_eq!(expected, actual);
-        Ok(())
-    }
-
-    #[test]
-    fn test_part2() -> io::Result<()> {
-        let expected = 1;
-        let actual = part2(&mut Cursor::new(TEST_DATA))?;
-        assert_eq!(expected, actual);
-        let expected = 36;
-        let actual = part2(&mut Cursor::new(TEST_DATA_LARGE))?;
-        assert_eq!(expected, actual);
-        Ok(())
-    }
+aoc_util::solution! {
+    year: 2022,
+    day: 9,
+    title: "Rope Bridge",
+    part1: part1,
+    part2: part2,
+    examples: [
+        part1 => 13,
+        part2 => 1,
+        part2("large") => 36,
+    ],
 }
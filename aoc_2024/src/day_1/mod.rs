@@ -0,0 +1,40 @@
+use aoc_util::error::{self, Error};
+
+/// The puzzle input after it has been parsed.
+#[derive(Clone, Debug)]
+struct Model;
+
+fn parse(_input: &str) -> error::Result<Model> {
+    Ok(Model)
+}
+
+fn part1(_model: &Model) -> error::Result<u32> {
+    Err(Error::Unimplemented {
+        year: 2024,
+        day: 1,
+        part: Some(1),
+    })
+}
+
+fn part2(_model: &Model) -> error::Result<u32> {
+    Err(Error::Unimplemented {
+        year: 2024,
+        day: 1,
+        part: Some(2),
+    })
+}
+
+aoc_util::solution! {
+    year: 2024,
+    day: 1,
+    title: "Historian Hysteria",
+    parse: parse,
+    part1: part1,
+    part2: part2,
+    // TODO: Remove the `#[ignore]` once both parts are solved.
+    #[ignore]
+    examples: [
+        part1 => 11,
+        part2 => 31,
+    ],
+}
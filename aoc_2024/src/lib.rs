@@ -0,0 +1,8 @@
+use aoc_util::registry::Day;
+
+mod day_1;
+
+/// Every day of 2024 which has a registered solution, sorted by day.
+pub fn days() -> Vec<&'static Day> {
+    aoc_util::registry::days_in(2024)
+}
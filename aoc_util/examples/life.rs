@@ -0,0 +1,94 @@
+//! Steps through Conway's game of life on a small board, starting from a glider.
+//!
+//! The generations are kept in a [`History`] so that they can be stepped through backward as well
+//! as forward with [`explore`], which also shows which cells changed between two generations.
+
+use std::{
+    collections::HashSet,
+    fmt::{self, Display, Formatter},
+    io,
+};
+
+use aoc_util::automata::{self, History};
+
+const SIZE: i32 = 8;
+
+const GLIDER: &str = "\
+.#.
+..#
+###
+";
+
+/// The live cells of a board which wraps around at its edges.
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct Board {
+    live: HashSet<(i32, i32)>,
+}
+
+impl Board {
+    fn parse(s: &str) -> Self {
+        let live = s
+            .lines()
+            .zip(0..)
+            .flat_map(|(line, y)| {
+                line.chars()
+                    .zip(0..)
+                    .filter(|&(c, _)| c == '#')
+                    .map(move |(_, x)| (x, y))
+            })
+            .collect();
+        Self { live }
+    }
+
+    fn live_neighbors(&self, (x, y): (i32, i32)) -> usize {
+        (-1..=1)
+            .flat_map(|dy| (-1..=1).map(move |dx| (dx, dy)))
+            .filter(|&delta| delta != (0, 0))
+            .filter(|(dx, dy)| {
+                let neighbor = ((x + dx).rem_euclid(SIZE), (y + dy).rem_euclid(SIZE));
+                self.live.contains(&neighbor)
+            })
+            .count()
+    }
+
+    fn step(&self) -> Self {
+        let live = (0..SIZE)
+            .flat_map(|y| (0..SIZE).map(move |x| (x, y)))
+            .filter(|&cell| {
+                matches!(
+                    (self.live.contains(&cell), self.live_neighbors(cell)),
+                    (true, 2 | 3) | (false, 3)
+                )
+            })
+            .collect();
+        Self { live }
+    }
+}
+
+impl Display for Board {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for y in 0..SIZE {
+            for x in 0..SIZE {
+                let c = if self.live.contains(&(x, y)) {
+                    '#'
+                } else {
+                    '.'
+                };
+                write!(f, "{c}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+fn main() -> io::Result<()> {
+    let mut history = History::new(Board::parse(GLIDER), 16);
+    automata::explore(
+        &mut history,
+        Board::step,
+        |board| board.live.clone(),
+        &mut io::stdin().lock(),
+        &mut io::stdout(),
+    )
+}
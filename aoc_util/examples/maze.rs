@@ -0,0 +1,133 @@
+//! Finds the shortest path through a maze.
+//!
+//! The maze is parsed with [`NomParse`], stored as a [`Grid`] of tiles which is indexed by
+//! [`Point2D`]s, and searched with [`run_a_star_for_path`] using the manhattan distance to the
+//! exit as the heuristic.
+
+use std::{
+    fmt::{self, Display, Formatter},
+    hash::{Hash, Hasher},
+};
+
+use aoc_util::{
+    a_star::{self, AStarState},
+    geometry::{Direction, Point2D},
+    grid::Grid,
+    nom_extended::NomParse,
+};
+use nom::{branch, bytes::complete as bytes, combinator, IResult};
+
+const MAZE: &str = "\
+#########
+#S..#...#
+#.#.#.#.#
+#.#...#.#
+#.#####.#
+#...#..E#
+#########
+";
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Tile {
+    Wall,
+    Open,
+    Start,
+    End,
+}
+
+impl NomParse<&'_ str> for Tile {
+    fn nom_parse(s: &str) -> IResult<&str, Self> {
+        branch::alt((
+            combinator::value(Self::Wall, bytes::tag("#")),
+            combinator::value(Self::Open, bytes::tag(".")),
+            combinator::value(Self::Start, bytes::tag("S")),
+            combinator::value(Self::End, bytes::tag("E")),
+        ))(s)
+    }
+}
+
+#[derive(Debug)]
+struct Maze {
+    tiles: Grid<Tile>,
+}
+
+impl Maze {
+    fn tile(&self, point: Point2D<usize>) -> Tile {
+        self.tiles[point]
+    }
+
+    fn find(&self, target: Tile) -> Option<Point2D<usize>> {
+        self.tiles.position(|&tile| tile == target)
+    }
+}
+
+impl NomParse<&'_ str> for Maze {
+    fn nom_parse(s: &str) -> IResult<&str, Self> {
+        combinator::map(Grid::nom_parse, |tiles| Self { tiles })(s)
+    }
+}
+
+/// A position in the maze. Two positions are equal if they are at the same point, regardless of
+/// which maze they're in.
+#[derive(Clone, Copy, Debug)]
+struct Position<'maze> {
+    maze: &'maze Maze,
+    location: Point2D<usize>,
+}
+
+impl PartialEq for Position<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.location == other.location
+    }
+}
+
+impl Eq for Position<'_> {}
+
+impl Hash for Position<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.location.hash(state);
+    }
+}
+
+impl Display for Position<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.location.x(), self.location.y())
+    }
+}
+
+impl AStarState for Position<'_> {
+    type Distance = usize;
+
+    fn neighbors(&self) -> Vec<(Self::Distance, Self)> {
+        // The maze is surrounded by walls, so stepping off of an open tile never leaves the grid.
+        Direction::values()
+            .iter()
+            .map(|&direction| self.location + direction)
+            .filter(|&location| self.maze.tile(location) != Tile::Wall)
+            .map(|location| (1, Self { location, ..*self }))
+            .collect()
+    }
+}
+
+fn main() {
+    let (_, maze) = combinator::all_consuming(Maze::nom_parse)(MAZE).expect("The maze is valid");
+    let start = maze.find(Tile::Start).expect("The maze has a start");
+    let end = maze.find(Tile::End).expect("The maze has an end");
+    let initial_state = Position {
+        maze: &maze,
+        location: start,
+    };
+    let path = a_star::run_a_star_for_path::<_, usize, _, usize>(
+        initial_state,
+        |position: &Position<'_>| position.location.manhattan_distance(&end),
+    );
+    match path {
+        Ok(Some((distance, path))) => {
+            println!("The shortest path through the maze takes {distance} steps:");
+            let path = path.iter().map(ToString::to_string).collect::<Vec<_>>();
+            println!("{}", path.join(" -> "));
+        }
+        Ok(None) => println!("There is no path through the maze"),
+        Err(e) => println!("{e}"),
+    }
+}
@@ -1,11 +1,18 @@
 use std::{
-    collections::HashMap,
+    cmp::Reverse,
+    collections::{hash_map::Entry, BinaryHeap, HashMap},
     fmt::{Debug, Display},
     hash::Hash,
-    io::{self, Write},
     ops::Add,
 };
 
+use crate::{
+    error::LimitExceeded,
+    limits::{Budget, Limits},
+    progress::Progress,
+    trace,
+};
+
 /// Runs the A* search algorithm on `initial_state` using `heuristic` to estimate the remaining
 /// distance. If this function returns `None`, then there is no path from `initial_state` to a
 /// state for which `heuristic` returns 0.
@@ -17,7 +24,34 @@ use std::{
 /// `O` is the type of the value of the heuristic.
 ///
 /// In general, `D` and `O` should usually be the same numerical type.
-pub fn run_a_star_for_distance<S, D, H, O>(initial_state: S, mut heuristic: H) -> Option<D>
+///
+/// The search is held to [`Limits::default_limits()`].
+pub fn run_a_star_for_distance<S, D, H, O>(
+    initial_state: S,
+    heuristic: H,
+) -> Result<Option<D>, LimitExceeded>
+where
+    S: AStarState<Distance = D> + Clone + Debug + Display + Eq + Hash,
+    for<'a> &'a D: Add<O, Output = D> + Add<Output = D>,
+    D: Add<Output = D> + Clone + Debug + Default + Ord,
+    H: Heuristic<S, O>,
+    O: Default + PartialEq,
+{
+    run_a_star_for_distance_with_limits::<S, D, H, O>(
+        initial_state,
+        heuristic,
+        Limits::default_limits(),
+    )
+}
+
+/// Like [`run_a_star_for_distance()`] except that the search is held to `limits`, giving up once
+/// it has expanded more than `limits.max_states` states or the states that it keeps take up more
+/// than about `limits.max_memory` bytes.
+pub fn run_a_star_for_distance_with_limits<S, D, H, O>(
+    initial_state: S,
+    heuristic: H,
+    limits: Limits,
+) -> Result<Option<D>, LimitExceeded>
 where
     S: AStarState<Distance = D> + Clone + Debug + Display + Eq + Hash,
     for<'a> &'a D: Add<O, Output = D> + Add<Output = D>,
@@ -25,80 +59,507 @@ where
     H: Heuristic<S, O>,
     O: Default + PartialEq,
 {
-    writeln!(io::stderr().lock(), "This implementation of the A* algorithm is not correct. Output is likely to be *near* the true answer but no guarantees are given.").expect("Coudln't write to stderr");
+    let path = run_a_star_for_path_with_limits::<S, D, H, O>(initial_state, heuristic, limits)?;
+    Ok(path.map(|(distance, _)| distance))
+}
+
+/// Like [`run_a_star_for_distance()`] except that the states along the path that was found are
+/// returned along with its distance, starting with `initial_state` and ending with the goal.
+pub fn run_a_star_for_path<S, D, H, O>(
+    initial_state: S,
+    heuristic: H,
+) -> Result<Option<(D, Vec<S>)>, LimitExceeded>
+where
+    S: AStarState<Distance = D> + Clone + Debug + Display + Eq + Hash,
+    for<'a> &'a D: Add<O, Output = D> + Add<Output = D>,
+    D: Add<Output = D> + Clone + Debug + Default + Ord,
+    H: Heuristic<S, O>,
+    O: Default + PartialEq,
+{
+    run_a_star_for_path_with_limits::<S, D, H, O>(
+        initial_state,
+        heuristic,
+        Limits::default_limits(),
+    )
+}
+
+/// Like [`run_a_star_for_path()`] except that the search is held to `limits` as in
+/// [`run_a_star_for_distance_with_limits()`].
+pub fn run_a_star_for_path_with_limits<S, D, H, O>(
+    initial_state: S,
+    heuristic: H,
+    limits: Limits,
+) -> Result<Option<(D, Vec<S>)>, LimitExceeded>
+where
+    S: AStarState<Distance = D> + Clone + Debug + Display + Eq + Hash,
+    for<'a> &'a D: Add<O, Output = D> + Add<Output = D>,
+    D: Add<Output = D> + Clone + Debug + Default + Ord,
+    H: Heuristic<S, O>,
+    O: Default + PartialEq,
+{
+    run_a_star_for_path_by_key::<S, D, H, O, S>(initial_state, heuristic, S::clone, limits)
+}
+
+/// Like [`run_a_star_for_path_with_limits()`] except that two states are treated as the same
+/// state whenever `key` gives them equal keys, instead of only when they're equal. A key which
+/// leaves out the parts of a state that don't matter for the rest of the search, or which is the
+/// same for states that are symmetric to each other, keeps the search from expanding what is
+/// really the same state over and over. Of the states with the same key, only the one which was
+/// reached first by the shortest path is kept.
+pub fn run_a_star_for_path_by_key<S, D, H, O, K>(
+    initial_state: S,
+    mut heuristic: H,
+    mut key: impl FnMut(&S) -> K,
+    limits: Limits,
+) -> Result<Option<(D, Vec<S>)>, LimitExceeded>
+where
+    S: AStarState<Distance = D> + Clone + Debug + Display,
+    for<'a> &'a D: Add<O, Output = D> + Add<Output = D>,
+    D: Add<Output = D> + Clone + Debug + Default + Ord,
+    H: Heuristic<S, O>,
+    O: Default + PartialEq,
+    K: Clone + Eq + Hash,
+{
+    let _span = tracing::info_span!("a_star").entered();
     let target_heuristic = O::default();
-    let mut completed_states: HashMap<S, (Option<S>, D)> = HashMap::new();
+    let mut budget = limits.budget();
+    // The states which have been expanded and the states which are waiting to be, by their keys,
+    // along with the keys of their parents and their distances.
+    let mut completed_states: HashMap<K, (S, Option<K>, D)> = HashMap::new();
     let mut states = HashMap::new();
-    let mut least_state = None;
-    states.insert(initial_state, (None, D::default()));
-    let mut i = 0;
+    // The states waiting to be expanded are queued by their estimated total distance. Keys aren't
+    // ordered, so the queue refers to them by their index in `queued` along with the distance at
+    // which they were queued.
+    let mut queue = BinaryHeap::new();
+    let mut queued = vec![];
+    let initial_key = key(&initial_state);
+    queue.push(Reverse((
+        &D::default() + heuristic.value(&initial_state),
+        queued.len(),
+    )));
+    queued.push((initial_key.clone(), D::default()));
+    states.insert(initial_key, (initial_state, None, D::default()));
+    let mut progress = Progress::new("Expanding states", None);
     let result = loop {
+        let Some(Reverse((_, index))) = queue.pop() else {
+            break None;
+        };
+        // A state is queued again whenever a shorter route to it is found, so the earlier entries
+        // for it are stale by the time that they're popped.
+        let (best_key, queued_distance) = &queued[index];
+        if states
+            .get(best_key)
+            .is_none_or(|(_, _, distance)| distance != queued_distance)
         {
-            i += 1;
-            if i == 1000 {
-                dbg!(
-                    states.len(),
-                    // &states,
-                    completed_states.len(),
-                    // &completed_states
-                );
-                i = 0;
+            continue;
+        }
+        let (best_key, (best_state, parent, current_distance)) =
+            states.remove_entry(best_key).unwrap();
+        budget.expand()?;
+        progress.inc();
+        budget.check_memory::<(K, (S, Option<K>, D))>(states.len() + completed_states.len())?;
+        trace::event(
+            "expand",
+            format_args!("{best_state:?} at {current_distance:?}"),
+        );
+        completed_states.insert(
+            best_key.clone(),
+            (best_state.clone(), parent, current_distance.clone()),
+        );
+        if heuristic.value(&best_state) == target_heuristic {
+            // Follow the parents back to the initial state, which is the only one without a parent.
+            let mut path = vec![best_state];
+            let mut current = &best_key;
+            while let Some((_, Some(parent), _)) = completed_states.get(current) {
+                path.push(completed_states[parent].0.clone());
+                current = parent;
             }
+            path.reverse();
+            break Some((current_distance, path));
         }
-        let (best_state, (parent, current_distance)) = {
-            let mut min = None;
-            for (state, (_, actual_distance)) in states.iter() {
-                let h = &D::default() + heuristic.value(state);
-                match least_state {
-                    None => least_state = Some((h, state.clone())),
-                    Some((least_h, _)) if h < least_h => least_state = Some((h, state.clone())),
-                    _ => {}
-                }
-                let current_distance = actual_distance + heuristic.value(state);
-                match &min {
-                    None => min = Some((current_distance, state)),
-                    Some((min_distance, _)) => {
-                        if &current_distance < min_distance {
-                            min = Some((current_distance, state));
-                        }
+        for (distance, state) in best_state.neighbors() {
+            check_consistency::<S, D, H, O>(&mut heuristic, &best_state, &distance, &state);
+            let state_key = key(&state);
+            let distance = current_distance.clone() + distance;
+            let improved = !completed_states.contains_key(&state_key)
+                && states
+                    .get(&state_key)
+                    .is_none_or(|(_, _, known)| &distance < known);
+            budget.generate(!improved);
+            if improved {
+                queue.push(Reverse((&distance + heuristic.value(&state), queued.len())));
+                queued.push((state_key.clone(), distance.clone()));
+                states.insert(state_key, (state, Some(best_key.clone()), distance));
+            }
+        }
+        budget.frontier(states.len());
+    };
+    Ok(result)
+}
+
+/// Runs the iterative-deepening A* search algorithm on `initial_state`, using `heuristic` as in
+/// [`run_a_star_for_path()`]. Instead of keeping every state that it has reached, this searches
+/// depth-first over and over, each time allowing paths whose estimated distance is longer than in
+/// the last, so it only ever keeps the states along the current path. This makes it suitable for
+/// state spaces which are too large for A* to fit in memory, at the cost of expanding states again
+/// in each iteration. If this function returns `None`, then there is no path from `initial_state`
+/// to a state for which `heuristic` returns 0.
+///
+/// For the answer to be the shortest distance, `heuristic` must never overestimate the remaining
+/// distance.
+///
+/// The search is held to [`Limits::default_limits()`].
+pub fn run_ida_star_for_path<S, D, H, O>(
+    initial_state: S,
+    heuristic: H,
+) -> Result<Option<(D, Vec<S>)>, LimitExceeded>
+where
+    S: AStarState<Distance = D> + Clone + Debug + Eq,
+    for<'a> &'a D: Add<O, Output = D>,
+    D: Add<Output = D> + Clone + Debug + Default + Ord,
+    H: Heuristic<S, O>,
+    O: Default + PartialEq,
+{
+    run_ida_star_for_path_with_limits::<S, D, H, O>(
+        initial_state,
+        heuristic,
+        Limits::default_limits(),
+    )
+}
+
+/// Like [`run_ida_star_for_path()`] except that the search is held to `limits`, giving up once it
+/// has expanded more than `limits.max_states` states in all of its iterations together or a path
+/// has more than `limits.max_depth` moves.
+pub fn run_ida_star_for_path_with_limits<S, D, H, O>(
+    initial_state: S,
+    mut heuristic: H,
+    limits: Limits,
+) -> Result<Option<(D, Vec<S>)>, LimitExceeded>
+where
+    S: AStarState<Distance = D> + Clone + Debug + Eq,
+    for<'a> &'a D: Add<O, Output = D>,
+    D: Add<Output = D> + Clone + Debug + Default + Ord,
+    H: Heuristic<S, O>,
+    O: Default + PartialEq,
+{
+    let _span = tracing::info_span!("ida_star").entered();
+    let mut search = DeepeningSearch {
+        heuristic: &mut heuristic,
+        budget: limits.budget(),
+        progress: Progress::new("Expanding states", None),
+        path: vec![initial_state],
+    };
+    let mut bound = &D::default() + search.heuristic.value(&search.path[0]);
+    loop {
+        trace::event("deepen", format_args!("{bound:?}"));
+        match search.search(D::default(), &bound)? {
+            Deepening::Found(distance) => return Ok(Some((distance, search.path))),
+            Deepening::Exceeded(Some(next_bound)) => bound = next_bound,
+            Deepening::Exceeded(None) => return Ok(None),
+        }
+    }
+}
+
+/// The result of searching below one state in an iteration of [`run_ida_star_for_path()`].
+enum Deepening<D> {
+    /// A goal was found at this distance from the initial state.
+    Found(D),
+    /// No goal was found within the bound. Contains the least estimated distance which was over
+    /// the bound, if any path went over it instead of coming to a dead end.
+    Exceeded(Option<D>),
+}
+
+/// The state of an iterative-deepening search which is kept across its iterations.
+struct DeepeningSearch<'h, S, H> {
+    heuristic: &'h mut H,
+    budget: Budget,
+    progress: Progress,
+    /// The states along the path to the state being expanded, starting with the initial state.
+    path: Vec<S>,
+}
+
+impl<S, H> DeepeningSearch<'_, S, H> {
+    /// Searches below the last state of the path, which is `distance` from the initial state, for
+    /// a goal whose estimated distance is at most `bound`. If a goal is found, the path is left
+    /// ending at it.
+    fn search<D, O>(&mut self, distance: D, bound: &D) -> Result<Deepening<D>, LimitExceeded>
+    where
+        S: AStarState<Distance = D> + Clone + Debug + Eq,
+        for<'a> &'a D: Add<O, Output = D>,
+        D: Add<Output = D> + Clone + Debug + Default + Ord,
+        H: Heuristic<S, O>,
+        O: Default + PartialEq,
+    {
+        let state = self.path[self.path.len() - 1].clone();
+        let remaining = self.heuristic.value(&state);
+        let is_goal = remaining == O::default();
+        let estimate = &distance + remaining;
+        if &estimate > bound {
+            return Ok(Deepening::Exceeded(Some(estimate)));
+        }
+        if is_goal {
+            return Ok(Deepening::Found(distance));
+        }
+        self.budget.expand()?;
+        self.progress.inc();
+        self.budget.check_depth(self.path.len())?;
+        trace::event("expand", format_args!("{state:?} at {distance:?}"));
+        let mut next_bound: Option<D> = None;
+        for (step, neighbor) in state.neighbors() {
+            check_consistency::<S, D, H, O>(self.heuristic, &state, &step, &neighbor);
+            // Going back to a state on the path can never make it shorter.
+            if self.path.contains(&neighbor) {
+                continue;
+            }
+            self.path.push(neighbor);
+            match self.search(distance.clone() + step, bound)? {
+                Deepening::Found(distance) => return Ok(Deepening::Found(distance)),
+                Deepening::Exceeded(Some(estimate)) => {
+                    if next_bound
+                        .as_ref()
+                        .is_none_or(|next_bound| &estimate < next_bound)
+                    {
+                        next_bound = Some(estimate);
                     }
                 }
+                Deepening::Exceeded(None) => {}
             }
-            match min {
-                None => {
-                    assert!(states.is_empty());
-                    break None;
-                }
-                Some((_, state)) => {
-                    let state = state.clone();
-                    states.remove_entry(&state).unwrap()
+            self.path.pop();
+        }
+        Ok(Deepening::Exceeded(next_bound))
+    }
+}
+
+/// Searches for the shortest path from `initial_state` to `goal_state` from both ends at once,
+/// with Dijkstra's algorithm forward from `initial_state` over [`AStarState::neighbors()`] and
+/// backward from `goal_state` over [`ReversibleState::predecessors()`], until the two searches
+/// meet. Since each search only has to cover about half of the distance, this typically expands
+/// far fewer states than searching from one end. If this function returns `None`, then there is
+/// no path from `initial_state` to `goal_state`.
+///
+/// Along with the distance, the states along the path that was found are returned, starting with
+/// `initial_state` and ending with `goal_state`. Every distance between neighbors must be at least
+/// `D::default()`.
+///
+/// The search is held to [`Limits::default_limits()`].
+pub fn run_bidirectional_for_path<S, D>(
+    initial_state: S,
+    goal_state: S,
+) -> Result<Option<(D, Vec<S>)>, LimitExceeded>
+where
+    S: ReversibleState<Distance = D> + Clone + Debug + Eq + Hash,
+    D: Add<Output = D> + Clone + Debug + Default + Ord,
+{
+    run_bidirectional_for_path_with_limits(initial_state, goal_state, Limits::default_limits())
+}
+
+/// Like [`run_bidirectional_for_path()`] except that the search is held to `limits`, counting the
+/// states that are expanded and kept by both ends together.
+pub fn run_bidirectional_for_path_with_limits<S, D>(
+    initial_state: S,
+    goal_state: S,
+    limits: Limits,
+) -> Result<Option<(D, Vec<S>)>, LimitExceeded>
+where
+    S: ReversibleState<Distance = D> + Clone + Debug + Eq + Hash,
+    D: Add<Output = D> + Clone + Debug + Default + Ord,
+{
+    let _span = tracing::info_span!("bidirectional").entered();
+    let mut budget = limits.budget();
+    let mut progress = Progress::new("Expanding states", None);
+    let mut forward: Frontier<S, D> = Frontier::new(initial_state.clone());
+    let mut backward: Frontier<S, D> = Frontier::new(goal_state.clone());
+    // The shortest path found so far, as its distance and the state where the two searches met.
+    let mut best = (initial_state == goal_state).then(|| (D::default(), initial_state));
+    loop {
+        let (forward_next, backward_next) = match (forward.peek(), backward.peek()) {
+            // Once either end has run out of states, every path from that end has been found.
+            (None, _) | (_, None) => break,
+            (Some(forward_next), Some(backward_next)) => (forward_next, backward_next),
+        };
+        // Any path which hasn't been found yet has to go through a state which has yet to be
+        // expanded from each end, so it can't be shorter than this.
+        let unexplored = forward_next.clone() + backward_next.clone();
+        if best
+            .as_ref()
+            .is_some_and(|(distance, _)| &unexplored >= distance)
+        {
+            break;
+        }
+        let is_forward = forward_next <= backward_next;
+        let (frontier, other) = if is_forward {
+            (&mut forward, &backward)
+        } else {
+            (&mut backward, &forward)
+        };
+        let Some((distance, index)) = frontier.pop() else {
+            continue;
+        };
+        budget.expand()?;
+        progress.inc();
+        budget.check_memory::<(S, D, Option<usize>)>(frontier.len() + other.len())?;
+        let state = frontier.state(index).clone();
+        trace::event(
+            "expand",
+            format_args!(
+                "{state:?} at {distance:?} {}",
+                if is_forward { "forward" } else { "backward" }
+            ),
+        );
+        let steps = if is_forward {
+            state.neighbors()
+        } else {
+            state.predecessors()
+        };
+        for (step, next) in steps {
+            let next_distance = distance.clone() + step;
+            if let Some(remaining) = other.distance(&next) {
+                let total = next_distance.clone() + remaining.clone();
+                if best.as_ref().is_none_or(|(distance, _)| &total < distance) {
+                    best = Some((total, next.clone()));
                 }
             }
-        };
-        completed_states.insert(best_state.clone(), (parent, current_distance.clone()));
-        if heuristic.value(&best_state) == target_heuristic {
-            println!("Found goal at {best_state}");
-            let mut s = best_state;
-            while let Some((Some(parent), distance)) = completed_states.get(&s) {
-                println!("Total distance {distance:?}");
-                println!("From {parent}");
-                s = parent.clone();
+            let improved = frontier.relax(next, next_distance, index);
+            budget.generate(!improved);
+        }
+        budget.frontier(forward.queued() + backward.queued());
+    }
+    Ok(best.map(|(distance, meeting)| {
+        let mut path = forward.path_to(&meeting);
+        let mut rest = backward.path_to(&meeting);
+        rest.reverse();
+        path.extend(rest.into_iter().skip(1));
+        (distance, path)
+    }))
+}
+
+/// The states which a search from one state has reached so far, and the shortest distance and
+/// route to each of them. States are referred to by their index so that the queue of states to
+/// expand doesn't need them to be ordered.
+#[derive(Debug)]
+pub(crate) struct Frontier<S, D> {
+    states: Vec<S>,
+    indices: HashMap<S, usize>,
+    distances: Vec<D>,
+    parents: Vec<Option<usize>>,
+    expanded: Vec<bool>,
+    queue: BinaryHeap<Reverse<(D, usize)>>,
+}
+
+impl<S, D> Frontier<S, D>
+where
+    S: Clone + Eq + Hash,
+    D: Clone + Default + Ord,
+{
+    /// Starts a search from `initial_state`.
+    pub(crate) fn new(initial_state: S) -> Self {
+        Self {
+            states: vec![initial_state.clone()],
+            indices: HashMap::from([(initial_state, 0)]),
+            distances: vec![D::default()],
+            parents: vec![None],
+            expanded: vec![false],
+            queue: BinaryHeap::from([Reverse((D::default(), 0))]),
+        }
+    }
+
+    /// The number of states which have been reached.
+    pub(crate) fn len(&self) -> usize {
+        self.states.len()
+    }
+
+    /// The state with index `index`.
+    pub(crate) fn state(&self, index: usize) -> &S {
+        &self.states[index]
+    }
+
+    /// The shortest distance to `state` that has been found so far, if it has been reached.
+    pub(crate) fn distance(&self, state: &S) -> Option<&D> {
+        self.indices.get(state).map(|&index| &self.distances[index])
+    }
+
+    /// A lower bound on the distance of the next state that [`pop()`] will return, or `None` if
+    /// every state which has been reached has been expanded.
+    ///
+    /// [`pop()`]: Self::pop
+    pub(crate) fn peek(&self) -> Option<&D> {
+        self.queue.peek().map(|Reverse((distance, _))| distance)
+    }
+
+    /// Takes the closest state which hasn't been expanded yet to expand it, returning its distance
+    /// and index.
+    pub(crate) fn pop(&mut self) -> Option<(D, usize)> {
+        while let Some(Reverse((distance, index))) = self.queue.pop() {
+            // A state is queued again whenever a shorter route to it is found, so the later
+            // entries for it are stale by the time that they're popped.
+            if !self.expanded[index] {
+                self.expanded[index] = true;
+                return Some((distance, index));
             }
-            break Some(current_distance);
-        }
-        let neighbors = best_state.neighbors();
-        neighbors
-            .into_iter()
-            .filter(|(_, state)| !completed_states.contains_key(state))
-            .map(move |(distance, state)| (state, current_distance.clone() + distance))
-            .for_each(|(state, distance)| {
-                if !states.contains_key(&state) || distance < states[&state].1 {
-                    states.insert(state, (Some(best_state.clone()), distance));
+        }
+        None
+    }
+
+    /// Takes every state which hasn't been expanded yet and is as close as the closest one to
+    /// expand them all at once, returning their distance and indices.
+    #[cfg(feature = "parallel")]
+    pub(crate) fn pop_tied(&mut self) -> Option<(D, Vec<usize>)> {
+        let (distance, index) = self.pop()?;
+        let mut indices = vec![index];
+        while self.peek() == Some(&distance) {
+            let Reverse((_, index)) = self.queue.pop().unwrap();
+            if !self.expanded[index] {
+                self.expanded[index] = true;
+                indices.push(index);
+            }
+        }
+        Some((distance, indices))
+    }
+
+    /// The number of entries in the queue of states to expand, including those for states which
+    /// have since been reached by a shorter route.
+    pub(crate) fn queued(&self) -> usize {
+        self.queue.len()
+    }
+
+    /// Records that `state` can be reached in `distance` through the state with index `parent`,
+    /// unless a route to it which is at least as short is already known. Returns false if such a
+    /// route was already known.
+    pub(crate) fn relax(&mut self, state: S, distance: D, parent: usize) -> bool {
+        let index = match self.indices.entry(state) {
+            Entry::Occupied(entry) => {
+                let index = *entry.get();
+                if self.expanded[index] || self.distances[index] <= distance {
+                    return false;
                 }
-            });
-    };
-    dbg!(completed_states.len());
-    result
+                self.distances[index] = distance.clone();
+                self.parents[index] = Some(parent);
+                index
+            }
+            Entry::Vacant(entry) => {
+                self.states.push(entry.key().clone());
+                self.distances.push(distance.clone());
+                self.parents.push(Some(parent));
+                self.expanded.push(false);
+                *entry.insert(self.states.len() - 1)
+            }
+        };
+        self.queue.push(Reverse((distance, index)));
+        true
+    }
+
+    /// The states along the shortest route that has been found to `state`, starting with the
+    /// initial state, or an empty route if `state` hasn't been reached.
+    pub(crate) fn path_to(&self, state: &S) -> Vec<S> {
+        let mut path = vec![];
+        let mut current = self.indices.get(state).copied();
+        while let Some(index) = current {
+            path.push(self.states[index].clone());
+            current = self.parents[index];
+        }
+        path.reverse();
+        path
+    }
 }
 
 /// A state that can be used for the A* search algorithm.
@@ -111,10 +572,68 @@ pub trait AStarState: Sized {
     fn neighbors(&self) -> Vec<(Self::Distance, Self)>;
 }
 
+/// A state which knows the states that it can be reached from, so that it can be searched for
+/// backward from a known goal.
+pub trait ReversibleState: AStarState {
+    /// All states from which this state can be reached in one move along with their distances
+    /// from this state.
+    fn predecessors(&self) -> Vec<(Self::Distance, Self)>;
+}
+
 /// A simple function that gives a general idea of how far the given state is from the goal.
 pub trait Heuristic<S, O> {
     /// The actual heuristic function.
     fn value(&mut self, state: &S) -> O;
+
+    /// Whether the search should check that the heuristic never estimates that a state is further
+    /// from the goal than moving to one of its neighbors and estimating from there. A heuristic
+    /// which passes this check never overestimates the remaining distance, which A* needs in order
+    /// to find the shortest path. See [`Checked`].
+    fn checks_consistency(&self) -> bool {
+        false
+    }
+}
+
+/// A heuristic which the searches check for consistency at every move that they consider,
+/// panicking with the offending pair of states as soon as it estimates that a state is further
+/// from the goal than moving to a neighbor and estimating from there. This makes a bad heuristic
+/// fail loudly in tests instead of making the search quietly find a path which isn't the shortest.
+/// The check is only made in builds with debug assertions, so it costs nothing in release builds.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Checked<H>(pub H);
+
+impl<H, S, O> Heuristic<S, O> for Checked<H>
+where
+    H: Heuristic<S, O>,
+{
+    fn value(&mut self, state: &S) -> O {
+        self.0.value(state)
+    }
+
+    fn checks_consistency(&self) -> bool {
+        cfg!(debug_assertions)
+    }
+}
+
+/// Panics if `heuristic` should be checked and it estimates that `state` is further from the goal
+/// than moving `distance` to `neighbor` and estimating from there.
+fn check_consistency<S, D, H, O>(heuristic: &mut H, state: &S, distance: &D, neighbor: &S)
+where
+    S: Debug,
+    for<'a> &'a D: Add<O, Output = D>,
+    D: Debug + Default + Ord,
+    H: Heuristic<S, O>,
+{
+    if !heuristic.checks_consistency() {
+        return;
+    }
+    let here = &D::default() + heuristic.value(state);
+    let there = distance + heuristic.value(neighbor);
+    assert!(
+        here <= there,
+        "Inconsistent heuristic: it estimates {here:?} from {state:?} but only {there:?} from \
+        moving {distance:?} to {neighbor:?}",
+    );
 }
 
 // Any function that can be called multiple times can be used as a heuristic.
@@ -126,3 +645,164 @@ where
         self(data)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::fmt::{self, Formatter};
+
+    use crate::error::Limit;
+
+    /// A point on a number line, from which it's possible to step forward by 1 at a cost of 2 or
+    /// to jump forward by 3 at a cost of 5.
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+    struct Point(u32);
+
+    impl Display for Point {
+        fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+            write!(f, "{}", self.0)
+        }
+    }
+
+    impl AStarState for Point {
+        type Distance = u32;
+
+        fn neighbors(&self) -> Vec<(Self::Distance, Self)> {
+            vec![(2, Point(self.0 + 1)), (5, Point(self.0 + 3))]
+        }
+    }
+
+    impl ReversibleState for Point {
+        fn predecessors(&self) -> Vec<(Self::Distance, Self)> {
+            [(2, 1), (5, 3)]
+                .into_iter()
+                .filter_map(|(distance, step)| Some((distance, Point(self.0.checked_sub(step)?))))
+                .collect()
+        }
+    }
+
+    #[test]
+    fn test_path() {
+        let heuristic = |point: &Point| 7_u32.saturating_sub(point.0);
+        let (distance, path) = run_a_star_for_path::<_, u32, _, u32>(Point(0), heuristic)
+            .unwrap()
+            .unwrap();
+        assert_eq!(12, distance);
+        assert_eq!(Point(0), path[0]);
+        assert_eq!(Point(7), path[path.len() - 1]);
+        assert_eq!(4, path.len());
+    }
+
+    #[test]
+    fn test_bidirectional() {
+        let (distance, path) = run_bidirectional_for_path(Point(0), Point(7))
+            .unwrap()
+            .unwrap();
+        assert_eq!(12, distance);
+        assert_eq!(Point(0), path[0]);
+        assert_eq!(Point(7), path[path.len() - 1]);
+        assert_eq!(4, path.len());
+        assert!(path
+            .windows(2)
+            .all(|pair| [1, 3].contains(&(pair[1].0 - pair[0].0))));
+        assert_eq!(
+            Ok(Some((0, vec![Point(4)]))),
+            run_bidirectional_for_path(Point(4), Point(4))
+        );
+        // The search forward never runs out of states, but the search backward does.
+        assert_eq!(Ok(None), run_bidirectional_for_path(Point(5), Point(2)));
+    }
+
+    #[test]
+    fn test_ida_star() {
+        let heuristic = |point: &Point| 7_u32.saturating_sub(point.0);
+        let (distance, path) = run_ida_star_for_path::<_, u32, _, u32>(Point(0), heuristic)
+            .unwrap()
+            .unwrap();
+        assert_eq!(12, distance);
+        assert_eq!(Point(0), path[0]);
+        assert_eq!(Point(7), path[path.len() - 1]);
+        assert_eq!(4, path.len());
+        let limits = Limits::NONE.with_max_depth(2);
+        assert_eq!(
+            Err(LimitExceeded {
+                limit: Limit::Depth,
+                max: 2
+            }),
+            run_ida_star_for_path_with_limits::<_, u32, _, u32>(Point(0), heuristic, limits)
+        );
+    }
+
+    #[test]
+    fn test_checked() {
+        let heuristic = Checked(|point: &Point| 7_u32.saturating_sub(point.0));
+        let path = run_a_star_for_path::<_, u32, _, u32>(Point(0), heuristic).unwrap();
+        assert_eq!(Some(12), path.map(|(distance, _)| distance));
+    }
+
+    /// A heuristic which thinks that point 1 is much closer to the goal than it is.
+    fn inconsistent(point: &Point) -> u32 {
+        match point.0 {
+            1 => 1,
+            n => 7_u32.saturating_sub(n),
+        }
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "Inconsistent heuristic")]
+    fn test_checked_inconsistent() {
+        let _ = run_ida_star_for_path::<_, u32, _, u32>(Point(0), Checked(inconsistent));
+    }
+
+    #[cfg(not(debug_assertions))]
+    #[test]
+    fn test_checked_inconsistent_release() {
+        let heuristic = Checked(inconsistent);
+        assert!(!Heuristic::<Point, u32>::checks_consistency(&heuristic));
+        let path = run_ida_star_for_path::<_, u32, _, u32>(Point(0), heuristic).unwrap();
+        assert!(path.is_some());
+    }
+
+    #[test]
+    fn test_path_by_key() {
+        // Every point from 7 on is as good as the goal, so they can all share a key.
+        let heuristic = |point: &Point| 7_u32.saturating_sub(point.0);
+        let (distance, path) = run_a_star_for_path_by_key::<_, u32, _, u32, _>(
+            Point(0),
+            heuristic,
+            |point| point.0.min(7),
+            Limits::NONE,
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(12, distance);
+        assert_eq!(Point(0), path[0]);
+        assert_eq!(Point(7), path[path.len() - 1]);
+    }
+
+    #[test]
+    fn test_stats() {
+        use crate::limits;
+
+        let heuristic = |point: &Point| 7_u32.saturating_sub(point.0);
+        let (distance, _) = run_a_star_for_path_by_key::<_, u32, _, u32, _>(
+            Point(0),
+            heuristic,
+            |point| point.0,
+            Limits::NONE,
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(12, distance);
+        let stats = limits::last_stats().unwrap();
+        // 0, 1, 3, 2, 4, 6, 5 and then the goal, 7.
+        assert_eq!(8, stats.expanded);
+        // Every state but the goal has two neighbors, and 3, 4, 5, 6 and 7 are each reached again
+        // by a route which is no shorter.
+        assert_eq!(14, stats.generated);
+        assert_eq!(5, stats.duplicates);
+        assert_eq!(3, stats.max_frontier);
+    }
+}
@@ -0,0 +1,272 @@
+use std::ops::{Add, Mul, Sub};
+
+use crate::geometry::Point;
+
+use super::Aabb;
+
+/// An axis-aligned bounding box in `N`-dimensional space. Includes all points whose coordinate
+/// along each axis is between the coordinates of `min` and `max` along that axis, inclusive.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct BoundingBox<T, const N: usize> {
+    /// The corner of the box with the minimum coordinate along every axis.
+    pub min: Point<T, N>,
+    /// The corner of the box with the maximum coordinate along every axis.
+    pub max: Point<T, N>,
+}
+
+impl<T, const N: usize> BoundingBox<T, N> {
+    /// Creates the box whose corners are `min` and `max`.
+    pub const fn new(min: Point<T, N>, max: Point<T, N>) -> Self {
+        Self { min, max }
+    }
+}
+
+impl<T, const N: usize> BoundingBox<T, N>
+where
+    T: Copy + Ord,
+{
+    /// Creates the smallest box which contains every point in `points`, or `None` if there are no
+    /// points.
+    pub fn from_points<I>(points: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = Point<T, N>>,
+    {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        let mut bounds = Self::new(first, first);
+        for point in points {
+            bounds.expand(&point);
+        }
+        Some(bounds)
+    }
+
+    /// Checks whether the box contains no points, which only occurs when the maximum along some
+    /// axis is strictly less than the minimum along that axis.
+    pub fn is_empty(&self) -> bool {
+        (0..N).any(|axis| self.max[axis] < self.min[axis])
+    }
+
+    /// Checks whether `point` is in the box.
+    pub fn contains(&self, point: &Point<T, N>) -> bool {
+        (0..N).all(|axis| (self.min[axis]..=self.max[axis]).contains(&point[axis]))
+    }
+
+    /// Checks whether every point in `other` is also in `self`. An empty box is contained by every
+    /// box.
+    pub fn contains_box(&self, other: &Self) -> bool {
+        other.is_empty() || (self.contains(&other.min) && self.contains(&other.max))
+    }
+
+    /// Grows the box just enough to contain `point`, such as when finding the bounds of the active
+    /// cells of a cellular automaton.
+    pub fn expand(&mut self, point: &Point<T, N>) {
+        for axis in 0..N {
+            self.min[axis] = self.min[axis].min(point[axis]);
+            self.max[axis] = self.max[axis].max(point[axis]);
+        }
+    }
+
+    /// Checks whether there is some point which is in both `self` and `rhs`.
+    pub fn intersects(&self, rhs: &Self) -> bool {
+        self.intersection(rhs).is_some()
+    }
+
+    /// Gets the box which contains all and only those points which are in both `self` and `rhs`,
+    /// or `None` if there are no such points.
+    pub fn intersection(&self, rhs: &Self) -> Option<Self> {
+        let mut intersection = *self;
+        for axis in 0..N {
+            intersection.min[axis] = self.min[axis].max(rhs.min[axis]);
+            intersection.max[axis] = self.max[axis].min(rhs.max[axis]);
+        }
+        (!intersection.is_empty()).then_some(intersection)
+    }
+}
+
+impl<T, const N: usize> BoundingBox<T, N>
+where
+    T: Copy + Ord + Add<Output = T> + From<u8> + Mul<Output = T> + Sub<Output = T>,
+{
+    /// Gets the number of points with integer coordinates in the box.
+    pub fn volume(&self) -> T {
+        if self.is_empty() {
+            return T::from(0);
+        }
+        (0..N).fold(T::from(1), |volume, axis| {
+            volume * (self.max[axis] - self.min[axis] + T::from(1))
+        })
+    }
+}
+
+impl<T, const N: usize> BoundingBox<T, N>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T>,
+{
+    /// Grows the box by `n` in both directions along every axis, such as to include the cells
+    /// just outside of the active cells which a cellular automaton might activate next.
+    pub fn inflate(&self, n: T) -> Self {
+        let mut inflated = *self;
+        for axis in 0..N {
+            inflated.min[axis] = self.min[axis] - n;
+            inflated.max[axis] = self.max[axis] + n;
+        }
+        inflated
+    }
+
+    /// Shrinks the box by `n` in both directions along every axis, leaving it empty if it isn't
+    /// more than `2 * n` wide along every axis.
+    pub fn deflate(&self, n: T) -> Self {
+        let mut deflated = *self;
+        for axis in 0..N {
+            deflated.min[axis] = self.min[axis] + n;
+            deflated.max[axis] = self.max[axis] - n;
+        }
+        deflated
+    }
+}
+
+impl<T, const N: usize> BoundingBox<T, N>
+where
+    T: Copy + Ord + Add<Output = T> + From<u8>,
+{
+    /// Gets an iterator over every point with integer coordinates in the box, in order of their
+    /// coordinates with the last axis changing the fastest.
+    pub fn iter_points(&self) -> impl Iterator<Item = Point<T, N>> {
+        let bounds = *self;
+        let first = (!bounds.is_empty()).then_some(bounds.min);
+        std::iter::successors(first, move |point| {
+            let mut next = *point;
+            for axis in (0..N).rev() {
+                if next[axis] < bounds.max[axis] {
+                    next[axis] = next[axis] + T::from(1);
+                    return Some(next);
+                }
+                next[axis] = bounds.min[axis];
+            }
+            None
+        })
+    }
+}
+
+impl From<Aabb> for BoundingBox<i64, 3> {
+    fn from(aabb: Aabb) -> Self {
+        Self::new(
+            Point::at([aabb.min_x, aabb.min_y, aabb.min_z]),
+            Point::at([aabb.max_x, aabb.max_y, aabb.max_z]),
+        )
+    }
+}
+
+impl From<BoundingBox<i64, 3>> for Aabb {
+    fn from(bounding_box: BoundingBox<i64, 3>) -> Self {
+        let [min_x, min_y, min_z] = bounding_box.min.into_coords();
+        let [max_x, max_y, max_z] = bounding_box.max.into_coords();
+        Self {
+            min_x,
+            max_x,
+            min_y,
+            max_y,
+            min_z,
+            max_z,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cuboid(min: [i64; 3], max: [i64; 3]) -> BoundingBox<i64, 3> {
+        BoundingBox::new(Point::at(min), Point::at(max))
+    }
+
+    #[test]
+    fn test_volume() {
+        assert_eq!(27, cuboid([10, 10, 10], [12, 12, 12]).volume());
+        // A brick from 2023 day 22 which is only one cube wide along two axes.
+        assert_eq!(3, cuboid([1, 0, 1], [1, 2, 1]).volume());
+        let empty = cuboid([0, 0, 0], [5, -1, 5]);
+        assert!(empty.is_empty());
+        assert_eq!(0, empty.volume());
+        let tesseract = BoundingBox::new(Point::at([0_u32; 4]), Point::at([1; 4]));
+        assert_eq!(16, tesseract.volume());
+        let aabb = Aabb::from(cuboid([-1, 0, 1], [2, 3, 4]));
+        assert_eq!(aabb.size() as i64, BoundingBox::from(aabb).volume());
+    }
+
+    #[test]
+    fn test_contains() {
+        let room = cuboid([0, 0, 0], [4, 4, 4]);
+        assert!(room.contains(&Point::at([0, 4, 2])));
+        assert!(!room.contains(&Point::at([0, 5, 2])));
+        assert!(room.contains_box(&cuboid([1, 1, 1], [4, 4, 4])));
+        assert!(!room.contains_box(&cuboid([1, 1, 1], [5, 4, 4])));
+        assert!(room.contains_box(&cuboid([9, 9, 9], [0, 0, 0])));
+    }
+
+    #[test]
+    fn test_expand() {
+        let mut bounds = BoundingBox::new(Point::at([0, 0]), Point::at([0, 0]));
+        for point in [[3, -1], [-2, 2], [1, 1]] {
+            bounds.expand(&Point::at(point));
+        }
+        assert_eq!(
+            BoundingBox::new(Point::at([-2, -1]), Point::at([3, 2])),
+            bounds
+        );
+        assert_eq!(24, bounds.volume());
+    }
+
+    #[test]
+    fn test_from_points() {
+        let points = [[2, -3], [0, 1], [4, 0]].map(Point::at);
+        let bounds = BoundingBox::from_points(points).unwrap();
+        assert_eq!(
+            BoundingBox::new(Point::at([0, -3]), Point::at([4, 1])),
+            bounds
+        );
+        assert!(points.iter().all(|point| bounds.contains(point)));
+        assert_eq!(None, BoundingBox::<i32, 2>::from_points([]));
+    }
+
+    #[test]
+    fn test_inflate() {
+        let bounds = BoundingBox::new(Point::at([0, 0]), Point::at([2, 1]));
+        let inflated = bounds.inflate(1);
+        assert_eq!(
+            BoundingBox::new(Point::at([-1, -1]), Point::at([3, 2])),
+            inflated
+        );
+        assert_eq!(bounds, inflated.deflate(1));
+        assert!(bounds.deflate(1).is_empty());
+    }
+
+    #[test]
+    fn test_iter_points() {
+        let bounds = BoundingBox::new(Point::at([0, 5]), Point::at([1, 7]));
+        assert_eq!(
+            vec![[0, 5], [0, 6], [0, 7], [1, 5], [1, 6], [1, 7]],
+            bounds
+                .iter_points()
+                .map(Point::into_coords)
+                .collect::<Vec<_>>()
+        );
+        let tesseract = BoundingBox::new(Point::at([-1_i64; 4]), Point::at([1; 4]));
+        assert_eq!(tesseract.volume() as usize, tesseract.iter_points().count());
+        assert_eq!(1, cuboid([3, 3, 3], [3, 3, 3]).iter_points().count());
+        assert_eq!(0, cuboid([3, 3, 3], [3, 2, 3]).iter_points().count());
+    }
+
+    #[test]
+    fn test_intersection() {
+        let a = cuboid([10, 10, 10], [12, 12, 12]);
+        let b = cuboid([11, 11, 11], [13, 13, 13]);
+        assert_eq!(Some(cuboid([11, 11, 11], [12, 12, 12])), a.intersection(&b));
+        assert!(a.intersects(&b));
+        // Bricks which are stacked on top of each other touch without overlapping.
+        let above = cuboid([10, 10, 13], [12, 12, 13]);
+        assert_eq!(None, a.intersection(&above));
+        assert!(!a.intersects(&above));
+        assert!(b.intersects(&above));
+    }
+}
@@ -1,3 +1,7 @@
+/// Axis-aligned bounding boxes in any number of dimensions.
+pub mod bounding_box;
+pub use bounding_box::BoundingBox;
+
 /// An axis-aligned bounding box. Includes all points `(x, y, z)` such that
 /// `(self.min_x..=self.max_x).contains(&x) && (self.min_y..=self.max_y).contains(&y) &&
 /// (self.min_z..=self.max_z).contains(&z)` holds.
@@ -55,6 +59,29 @@ impl Aabb {
                 || (self.min_z..=self.max_z).contains(&rhs.max_z))
     }
 
+    /// Gets the box which contains all and only those points which are in both `self` and `rhs`,
+    /// or `None` if there are no such points.
+    pub fn intersection(&self, rhs: &Self) -> Option<Self> {
+        let intersection = Self {
+            min_x: self.min_x.max(rhs.min_x),
+            max_x: self.max_x.min(rhs.max_x),
+            min_y: self.min_y.max(rhs.min_y),
+            max_y: self.max_y.min(rhs.max_y),
+            min_z: self.min_z.max(rhs.min_z),
+            max_z: self.max_z.min(rhs.max_z),
+        };
+        (!intersection.is_empty()).then_some(intersection)
+    }
+
+    /// Creates an [`AabbSet`] which contains all and only those points which are in `self` or in
+    /// `rhs`. The boxes in the set are disjoint, so the size of the set counts every point once.
+    pub fn union(&self, rhs: &Self) -> AabbSet {
+        let mut union = AabbSet::default();
+        union.insert(*self);
+        union.insert(*rhs);
+        union
+    }
+
     /// Creates an [`AabbSet`] which contains all and only those points which are in `self` but not
     /// in `rhs`. No guarantees are made about how those points are collected into boxes.
     pub fn except(&self, rhs: &Self) -> AabbSet {
@@ -166,6 +193,14 @@ impl AabbSetInner {
 }
 
 impl AabbSetInner {
+    fn pieces(&self) -> &[Aabb] {
+        match self {
+            Self::Empty => &[],
+            Self::Singleton(singleton) => std::slice::from_ref(singleton),
+            Self::Multi { pieces } => pieces,
+        }
+    }
+
     fn insert(&mut self, aabb: Aabb) {
         match self {
             Self::Empty => *self = Self::Singleton(aabb),
@@ -261,6 +296,19 @@ impl AabbSet {
 }
 
 impl AabbSet {
+    /// Gets an iterator over the disjoint boxes which make up this set.
+    pub fn iter(&self) -> impl Iterator<Item = &Aabb> {
+        self.inner.pieces().iter()
+    }
+
+    /// Creates a set which contains all and only those points which are in both this set and
+    /// `aabb`.
+    pub fn intersection(&self, aabb: &Aabb) -> Self {
+        self.iter()
+            .filter_map(|piece| piece.intersection(aabb))
+            .collect()
+    }
+
     /// Adds the points contained in `aabb` to this set.
     pub fn insert(&mut self, aabb: Aabb) {
         self.inner.insert(aabb)
@@ -371,6 +419,78 @@ mod tests {
         assert_eq!(139_590 + 17_325 + 15_510 + 38_493, set.size());
     }
 
+    #[test]
+    fn test_aabb_intersection() {
+        let box1 = Aabb {
+            min_x: 10,
+            max_x: 12,
+            min_y: 10,
+            max_y: 12,
+            min_z: 10,
+            max_z: 12,
+        };
+        let box2 = Aabb {
+            min_x: 11,
+            max_x: 13,
+            min_y: 11,
+            max_y: 13,
+            min_z: 11,
+            max_z: 13,
+        };
+        assert_eq!(
+            Some(Aabb {
+                min_x: 11,
+                max_x: 12,
+                min_y: 11,
+                max_y: 12,
+                min_z: 11,
+                max_z: 12,
+            }),
+            box1.intersection(&box2)
+        );
+        let box3 = Aabb {
+            min_x: 13,
+            max_x: 13,
+            ..box2
+        };
+        assert_eq!(None, box1.intersection(&box3));
+        assert_eq!(27 + 27 - 8, box1.union(&box2).size());
+        assert_eq!(27 + 9, box1.union(&box3).size());
+        assert_eq!(27 - 8, box1.except(&box2).size());
+        let union = box1.union(&box2);
+        assert_eq!(
+            8,
+            union
+                .intersection(&box2.intersection(&box1).unwrap())
+                .size()
+        );
+        assert_eq!(9, union.intersection(&box3).size());
+        let pieces = union.iter().copied().collect::<Vec<_>>();
+        for (i, a) in pieces.iter().enumerate() {
+            for b in &pieces[i + 1..] {
+                assert_eq!(None, a.intersection(b));
+            }
+        }
+    }
+
+    #[test]
+    fn test_reboot() {
+        // The first example reboot steps from 2021 day 22, which leave 39 cubes on.
+        let cube = |min: i64, max: i64| Aabb {
+            min_x: min,
+            max_x: max,
+            min_y: min,
+            max_y: max,
+            min_z: min,
+            max_z: max,
+        };
+        let mut reactor = cube(10, 12).union(&cube(11, 13));
+        reactor.remove(cube(9, 11));
+        reactor.insert(cube(10, 10));
+        assert_eq!(39, reactor.size());
+        assert_eq!(reactor.size(), reactor.iter().map(Aabb::size).sum::<u64>());
+    }
+
     #[test]
     fn test_aabb_except_inner() {
         let box1 = Aabb {
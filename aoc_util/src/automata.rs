@@ -0,0 +1,375 @@
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    fmt::{self, Debug, Display, Formatter},
+    hash::Hash,
+    io::{self, BufRead, Write},
+    ops::RangeInclusive,
+};
+
+#[cfg(feature = "grid")]
+use crate::{geometry::Point2D, grid::Grid};
+
+/// The generations of a cellular automaton, of which the most recent few are kept so that its
+/// evolution can be stepped through backward as well as forward.
+///
+/// The generations are stored in a ring buffer, so once the buffer is full each new generation
+/// forgets the oldest one.
+pub struct History<S> {
+    generations: VecDeque<S>,
+    /// The generation number of `generations[0]`.
+    first: usize,
+    /// The index into `generations` of the generation which is being looked at.
+    cursor: usize,
+    capacity: usize,
+}
+
+impl<S> History<S> {
+    /// Starts a history at generation 0 with the state `initial`, which keeps at most `capacity`
+    /// generations.
+    ///
+    /// # Panics
+    /// Panics if `capacity` is 0.
+    pub fn new(initial: S, capacity: usize) -> Self {
+        assert!(capacity > 0, "A history must keep at least one generation");
+        let mut generations = VecDeque::with_capacity(capacity);
+        generations.push_back(initial);
+        Self {
+            generations,
+            first: 0,
+            cursor: 0,
+            capacity,
+        }
+    }
+
+    /// The generation which is being looked at.
+    pub fn current(&self) -> &S {
+        &self.generations[self.cursor]
+    }
+
+    /// The number of the generation which is being looked at.
+    pub fn generation(&self) -> usize {
+        self.first + self.cursor
+    }
+
+    /// The numbers of the generations which are still kept.
+    pub fn retained(&self) -> RangeInclusive<usize> {
+        self.first..=(self.first + self.generations.len() - 1)
+    }
+
+    /// The generation numbered `generation`, if it's still kept.
+    pub fn get(&self, generation: usize) -> Option<&S> {
+        self.generations.get(generation.checked_sub(self.first)?)
+    }
+
+    /// Goes back one generation. Returns `false` without moving if the previous generation has
+    /// been forgotten or this is generation 0.
+    pub fn step_back(&mut self) -> bool {
+        if self.cursor == 0 {
+            false
+        } else {
+            self.cursor -= 1;
+            true
+        }
+    }
+
+    /// Goes forward one generation. If the next generation has already been computed it's revisited
+    /// as it was, otherwise it's computed from the latest one by `step`.
+    pub fn step_forward(&mut self, step: impl FnOnce(&S) -> S) -> &S {
+        if self.cursor + 1 == self.generations.len() {
+            let next = step(&self.generations[self.cursor]);
+            if self.generations.len() == self.capacity {
+                self.generations.pop_front();
+                self.first += 1;
+            } else {
+                self.cursor += 1;
+            }
+            self.generations.push_back(next);
+        } else {
+            self.cursor += 1;
+        }
+        self.current()
+    }
+}
+
+impl<S> Debug for History<S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("History")
+            .field("retained", &self.retained())
+            .field("generation", &self.generation())
+            .field("capacity", &self.capacity)
+            .finish()
+    }
+}
+
+/// The cells which changed between two generations.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Diff<C> {
+    /// The cells which are live in the later generation but not the earlier one, in order.
+    pub born: Vec<C>,
+    /// The cells which are live in the earlier generation but not the later one, in order.
+    pub died: Vec<C>,
+}
+
+impl<C> Diff<C>
+where
+    C: Clone + Eq + Hash + Ord,
+{
+    /// Compares the live cells `before` of one generation with the live cells `after` of another.
+    pub fn between(before: &HashSet<C>, after: &HashSet<C>) -> Self {
+        let mut born = after.difference(before).cloned().collect::<Vec<_>>();
+        born.sort_unstable();
+        let mut died = before.difference(after).cloned().collect::<Vec<_>>();
+        died.sort_unstable();
+        Self { born, died }
+    }
+
+    /// Whether the two generations have the same live cells.
+    pub fn is_empty(&self) -> bool {
+        self.born.is_empty() && self.died.is_empty()
+    }
+}
+
+impl<C> Display for Diff<C>
+where
+    C: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{} born, {} died", self.born.len(), self.died.len())?;
+        for cell in &self.born {
+            writeln!(f, "+ {cell:?}")?;
+        }
+        for cell in &self.died {
+            writeln!(f, "- {cell:?}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Finds the live cells of the next generation of an automaton whose live cells are `live`, such
+/// as the active cubes of 2020 day 17, which can grow without bound. The cells around each cell
+/// are given by `neighborhood`, and a cell is live in the next generation if and only if `rule`
+/// returns true when given whether the cell is live now and how many live cells are around it.
+///
+/// Only the live cells and the cells around them are looked at, so `rule` must return false for a
+/// dead cell with no live cells around it. The live cells are returned in no particular order.
+pub fn step_sparse<C, I>(
+    live: impl IntoIterator<Item = C>,
+    mut neighborhood: impl FnMut(&C) -> I,
+    mut rule: impl FnMut(bool, usize) -> bool,
+) -> impl Iterator<Item = C>
+where
+    C: Clone + Eq + Hash,
+    I: IntoIterator<Item = C>,
+{
+    // Whether each cell is live and how many live cells are around it.
+    let mut cells = HashMap::<C, (bool, usize)>::new();
+    for cell in live {
+        for neighbor in neighborhood(&cell) {
+            cells.entry(neighbor).or_default().1 += 1;
+        }
+        cells.entry(cell).or_default().0 = true;
+    }
+    cells
+        .into_iter()
+        .filter(move |&(_, (is_live, live_neighbors))| rule(is_live, live_neighbors))
+        .map(|(cell, _)| cell)
+}
+
+/// Finds the next generation of an automaton whose cells are kept in a [`Grid`], such as the seats
+/// of 2020 day 11. The positions of the cells around each cell are given by `neighborhood`, and
+/// the cell at each position in the next generation is what `rule` returns when given the cell
+/// there now and the cells around it. Positions outside of the grid are skipped.
+#[cfg(feature = "grid")]
+pub fn step_dense<T, I>(
+    grid: &Grid<T>,
+    mut neighborhood: impl FnMut(Point2D<usize>) -> I,
+    mut rule: impl FnMut(&T, &[&T]) -> T,
+) -> Grid<T>
+where
+    I: IntoIterator<Item = Point2D<usize>>,
+{
+    let mut neighbors = vec![];
+    Grid::from_fn(grid.width(), grid.height(), |point| {
+        neighbors.clear();
+        neighbors.extend(
+            neighborhood(point)
+                .into_iter()
+                .filter_map(|neighbor| grid.get(&neighbor)),
+        );
+        rule(&grid[point], &neighbors)
+    })
+}
+
+/// Lets the user step through the generations of `history` by entering commands on `input`, and
+/// shows each generation on `output`. The commands are:
+///
+/// - `n` or an empty line: go forward one generation, computing it with `step` if it's new.
+/// - `p`: go back one generation.
+/// - `d`: show which cells changed from the previous generation, as found by `live_cells`.
+/// - `g <generation>`: go to a generation that's still kept.
+/// - `q`: stop.
+pub fn explore<S, C>(
+    history: &mut History<S>,
+    mut step: impl FnMut(&S) -> S,
+    live_cells: impl Fn(&S) -> HashSet<C>,
+    input: &mut dyn BufRead,
+    output: &mut dyn Write,
+) -> io::Result<()>
+where
+    S: Display,
+    C: Clone + Debug + Eq + Hash + Ord,
+{
+    writeln!(
+        output,
+        "Generation {}:\n{}",
+        history.generation(),
+        history.current()
+    )?;
+    let mut buf = String::new();
+    loop {
+        write!(output, "[n]ext, [p]revious, [d]iff, [g]o to, [q]uit> ")?;
+        output.flush()?;
+        buf.clear();
+        if input.read_line(&mut buf)? == 0 {
+            return Ok(());
+        }
+        let command = buf.trim();
+        match command.split_once(' ').unwrap_or((command, "")) {
+            ("" | "n", _) => {
+                history.step_forward(&mut step);
+            }
+            ("p", _) => {
+                if !history.step_back() {
+                    writeln!(
+                        output,
+                        "Generation {} is the oldest kept",
+                        history.generation()
+                    )?;
+                    continue;
+                }
+            }
+            ("d", _) => {
+                let generation = history.generation();
+                match generation.checked_sub(1).and_then(|prev| history.get(prev)) {
+                    Some(prev) => {
+                        let diff = Diff::between(&live_cells(prev), &live_cells(history.current()));
+                        write!(
+                            output,
+                            "From generation {} to {generation}: {diff}",
+                            generation - 1
+                        )?;
+                    }
+                    None => writeln!(output, "No earlier generation is kept")?,
+                }
+                continue;
+            }
+            ("g", target) => match target.trim().parse::<usize>() {
+                Ok(target) if history.retained().contains(&target) => {
+                    while history.generation() > target {
+                        history.step_back();
+                    }
+                    while history.generation() < target {
+                        history.step_forward(&mut step);
+                    }
+                }
+                _ => {
+                    let retained = history.retained();
+                    writeln!(
+                        output,
+                        "Only generations {} to {} are kept",
+                        retained.start(),
+                        retained.end()
+                    )?;
+                    continue;
+                }
+            },
+            ("q", _) => return Ok(()),
+            _ => {
+                writeln!(output, "Unknown command {command:?}")?;
+                continue;
+            }
+        }
+        writeln!(
+            output,
+            "Generation {}:\n{}",
+            history.generation(),
+            history.current()
+        )?;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_history() {
+        let mut history = History::new(0, 3);
+        assert!(!history.step_back());
+        for _ in 0..4 {
+            history.step_forward(|&n| n + 1);
+        }
+        assert_eq!(4, *history.current());
+        assert_eq!(2..=4, history.retained());
+        assert!(history.step_back());
+        assert!(history.step_back());
+        assert!(!history.step_back());
+        assert_eq!(2, history.generation());
+        // Revisiting a kept generation mustn't recompute it.
+        assert_eq!(3, *history.step_forward(|_| unreachable!()));
+        assert_eq!(None, history.get(1));
+        assert_eq!(Some(&4), history.get(4));
+    }
+
+    #[test]
+    fn test_diff() {
+        let before = HashSet::from([(0, 0), (0, 1), (1, 1)]);
+        let after = HashSet::from([(0, 1), (1, 1), (2, 0), (1, 0)]);
+        let diff = Diff::between(&before, &after);
+        assert_eq!(vec![(1, 0), (2, 0)], diff.born);
+        assert_eq!(vec![(0, 0)], diff.died);
+        assert_eq!(
+            "2 born, 1 died\n+ (1, 0)\n+ (2, 0)\n- (0, 0)\n",
+            diff.to_string()
+        );
+        assert!(Diff::between(&after, &after).is_empty());
+    }
+
+    #[test]
+    fn test_step_sparse() {
+        // A blinker in the Game of Life flips between a row and a column every generation.
+        let neighborhood = |&(x, y): &(i32, i32)| {
+            (-1..=1)
+                .flat_map(move |dy| (-1..=1).map(move |dx| (x + dx, y + dy)))
+                .filter(move |&neighbor| neighbor != (x, y))
+        };
+        let rule =
+            |is_live, live_neighbors| matches!((is_live, live_neighbors), (_, 3) | (true, 2));
+        let row = HashSet::from([(-1, 0), (0, 0), (1, 0)]);
+        let column = step_sparse(row.iter().copied(), neighborhood, rule).collect::<HashSet<_>>();
+        assert_eq!(HashSet::from([(0, -1), (0, 0), (0, 1)]), column);
+        let row_again = step_sparse(column, neighborhood, rule).collect::<HashSet<_>>();
+        assert_eq!(row, row_again);
+    }
+
+    #[cfg(feature = "grid")]
+    #[test]
+    fn test_step_dense() {
+        // A blinker flips the same way in a grid, which cuts off the cells outside of it.
+        let grid = Grid::parse(".....\n.....\n.###.\n.....\n.....\n", |c| c == '#').unwrap();
+        let step = |grid: &Grid<bool>| {
+            step_dense(
+                grid,
+                |point| grid.neighbors8(point).map(|(neighbor, _)| neighbor),
+                |&is_live, neighbors| {
+                    let live_neighbors = neighbors.iter().filter(|&&&neighbor| neighbor).count();
+                    matches!((is_live, live_neighbors), (_, 3) | (true, 2))
+                },
+            )
+        };
+        let column = step(&grid);
+        let expected = Grid::parse(".....\n..#..\n..#..\n..#..\n.....\n", |c| c == '#').unwrap();
+        assert_eq!(expected, column);
+        assert_eq!(grid, step(&column));
+    }
+}
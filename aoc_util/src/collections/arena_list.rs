@@ -0,0 +1,364 @@
+/// A slot in the arena of an [`ArenaList`], which is either an element of the list or free to hold
+/// a new one.
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct Node<T> {
+    value: Option<T>,
+    prev: Option<usize>,
+    next: Option<usize>,
+}
+
+/// A doubly-linked list whose elements are kept in an arena and addressed by handles, such as the
+/// numbers of the encrypted file of 2022 day 20, which have to be moved around in their original
+/// order. Inserting, removing, and moving elements next to an element whose handle is known takes
+/// constant time.
+///
+/// Each element is given a handle when it's inserted which refers to it until it's removed, even
+/// as other elements are inserted, removed, and moved around it. Handles are reused after their
+/// elements are removed.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ArenaList<T> {
+    nodes: Vec<Node<T>>,
+    free: Vec<usize>,
+    head: Option<usize>,
+    tail: Option<usize>,
+    len: usize,
+}
+
+impl<T> Default for ArenaList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> ArenaList<T> {
+    /// Creates an empty list.
+    pub const fn new() -> Self {
+        Self {
+            nodes: vec![],
+            free: vec![],
+            head: None,
+            tail: None,
+            len: 0,
+        }
+    }
+
+    /// Creates an empty list with room for `capacity` elements before it needs to reallocate.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            nodes: Vec::with_capacity(capacity),
+            ..Self::new()
+        }
+    }
+
+    /// The number of elements in the list.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if and only if the list has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The handle of the first element, or `None` if the list is empty.
+    pub fn front(&self) -> Option<usize> {
+        self.head
+    }
+
+    /// The handle of the last element, or `None` if the list is empty.
+    pub fn back(&self) -> Option<usize> {
+        self.tail
+    }
+
+    /// The element with the given handle, or `None` if it's been removed.
+    pub fn get(&self, handle: usize) -> Option<&T> {
+        self.nodes.get(handle)?.value.as_ref()
+    }
+
+    /// The element with the given handle mutably, or `None` if it's been removed.
+    pub fn get_mut(&mut self, handle: usize) -> Option<&mut T> {
+        self.nodes.get_mut(handle)?.value.as_mut()
+    }
+
+    /// The handle of the element after the one with the given handle, or `None` if it's the last
+    /// element or it's been removed.
+    pub fn next(&self, handle: usize) -> Option<usize> {
+        self.get(handle)?;
+        self.nodes[handle].next
+    }
+
+    /// The handle of the element before the one with the given handle, or `None` if it's the first
+    /// element or it's been removed.
+    pub fn prev(&self, handle: usize) -> Option<usize> {
+        self.get(handle)?;
+        self.nodes[handle].prev
+    }
+
+    /// Like [`next()`] except that the first element comes after the last one, for lists which
+    /// stand for a circle.
+    ///
+    /// [`next()`]: #method.next
+    pub fn next_wrapping(&self, handle: usize) -> Option<usize> {
+        self.get(handle)?;
+        self.nodes[handle].next.or(self.head)
+    }
+
+    /// Like [`prev()`] except that the last element comes before the first one, for lists which
+    /// stand for a circle.
+    ///
+    /// [`prev()`]: #method.prev
+    pub fn prev_wrapping(&self, handle: usize) -> Option<usize> {
+        self.get(handle)?;
+        self.nodes[handle].prev.or(self.tail)
+    }
+
+    /// Puts `value` in a free slot, returning its handle without linking it into the list.
+    fn allocate(&mut self, value: T) -> usize {
+        let node = Node {
+            value: Some(value),
+            prev: None,
+            next: None,
+        };
+        self.len += 1;
+        match self.free.pop() {
+            Some(handle) => {
+                self.nodes[handle] = node;
+                handle
+            }
+            None => {
+                self.nodes.push(node);
+                self.nodes.len() - 1
+            }
+        }
+    }
+
+    /// Links the unlinked element `handle` into the list between `prev` and `next`, either of
+    /// which is `None` at the ends of the list.
+    fn link(&mut self, handle: usize, prev: Option<usize>, next: Option<usize>) {
+        self.nodes[handle].prev = prev;
+        self.nodes[handle].next = next;
+        match prev {
+            Some(prev) => self.nodes[prev].next = Some(handle),
+            None => self.head = Some(handle),
+        }
+        match next {
+            Some(next) => self.nodes[next].prev = Some(handle),
+            None => self.tail = Some(handle),
+        }
+    }
+
+    /// Unlinks the element `handle` from the elements around it, leaving it in its slot.
+    fn unlink(&mut self, handle: usize) {
+        let Node { prev, next, .. } = self.nodes[handle];
+        match prev {
+            Some(prev) => self.nodes[prev].next = next,
+            None => self.head = next,
+        }
+        match next {
+            Some(next) => self.nodes[next].prev = prev,
+            None => self.tail = prev,
+        }
+    }
+
+    /// Panics unless `handle` refers to an element of the list.
+    fn check(&self, handle: usize) {
+        assert!(
+            self.get(handle).is_some(),
+            "Element {handle} has been removed"
+        );
+    }
+
+    /// Inserts `value` at the start of the list. Returns the handle of the new element.
+    pub fn push_front(&mut self, value: T) -> usize {
+        let handle = self.allocate(value);
+        self.link(handle, None, self.head);
+        handle
+    }
+
+    /// Inserts `value` at the end of the list. Returns the handle of the new element.
+    pub fn push_back(&mut self, value: T) -> usize {
+        let handle = self.allocate(value);
+        self.link(handle, self.tail, None);
+        handle
+    }
+
+    /// Inserts `value` right after the element `target`. Returns the handle of the new element.
+    ///
+    /// # Panics
+    /// Panics if `target` has been removed.
+    pub fn insert_after(&mut self, target: usize, value: T) -> usize {
+        self.check(target);
+        let handle = self.allocate(value);
+        self.link(handle, Some(target), self.nodes[target].next);
+        handle
+    }
+
+    /// Inserts `value` right before the element `target`. Returns the handle of the new element.
+    ///
+    /// # Panics
+    /// Panics if `target` has been removed.
+    pub fn insert_before(&mut self, target: usize, value: T) -> usize {
+        self.check(target);
+        let handle = self.allocate(value);
+        self.link(handle, self.nodes[target].prev, Some(target));
+        handle
+    }
+
+    /// Removes the element `handle` from the list and returns it, or returns `None` if it's
+    /// already been removed.
+    pub fn remove(&mut self, handle: usize) -> Option<T> {
+        self.get(handle)?;
+        self.unlink(handle);
+        self.free.push(handle);
+        self.len -= 1;
+        self.nodes[handle].value.take()
+    }
+
+    /// Moves the element `handle` so that it comes right after the element `target`. Every handle
+    /// stays on the same element.
+    ///
+    /// # Panics
+    /// Panics if either element has been removed or if they're the same element.
+    pub fn move_after(&mut self, handle: usize, target: usize) {
+        self.check(handle);
+        self.check(target);
+        assert_ne!(handle, target, "Can't move an element after itself");
+        self.unlink(handle);
+        self.link(handle, Some(target), self.nodes[target].next);
+    }
+
+    /// Moves the element `handle` so that it comes right before the element `target`. Every
+    /// handle stays on the same element.
+    ///
+    /// # Panics
+    /// Panics if either element has been removed or if they're the same element.
+    pub fn move_before(&mut self, handle: usize, target: usize) {
+        self.check(handle);
+        self.check(target);
+        assert_ne!(handle, target, "Can't move an element before itself");
+        self.unlink(handle);
+        self.link(handle, self.nodes[target].prev, Some(target));
+    }
+
+    /// Gets an iterator over the elements of the list from the first to the last along with their
+    /// handles.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, &T)> + '_ {
+        let mut next = self.head;
+        std::iter::from_fn(move || {
+            let handle = next?;
+            next = self.nodes[handle].next;
+            let value = self.nodes[handle]
+                .value
+                .as_ref()
+                .expect("Linked elements haven't been removed");
+            Some((handle, value))
+        })
+    }
+}
+
+impl<T> Extend<T> for ArenaList<T> {
+    /// Inserts each value at the end of the list.
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        for value in iter {
+            self.push_back(value);
+        }
+    }
+}
+
+impl<T> FromIterator<T> for ArenaList<T> {
+    /// Creates a list of the values in order, whose handles count up from 0.
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut list = Self::new();
+        list.extend(iter);
+        list
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn items<T: Copy>(list: &ArenaList<T>) -> Vec<T> {
+        list.iter().map(|(_, &value)| value).collect()
+    }
+
+    #[test]
+    fn test_insert_and_remove() {
+        let mut list = ArenaList::new();
+        assert_eq!(None, list.remove(0));
+        let two = list.push_back(2);
+        list.push_front(1);
+        let four = list.push_back(4);
+        list.insert_before(four, 3);
+        list.insert_after(four, 5);
+        assert_eq!(vec![1, 2, 3, 4, 5], items(&list));
+        assert_eq!(Some(2), list.remove(two));
+        assert_eq!(None, list.get(two));
+        assert_eq!(None, list.next(two));
+        assert_eq!(vec![1, 3, 4, 5], items(&list));
+        let six = list.push_back(6);
+        assert_eq!(two, six, "Removed handles are reused");
+        *list.get_mut(four).unwrap() = 40;
+        assert_eq!(vec![1, 3, 40, 5, 6], items(&list));
+        while let Some(front) = list.front() {
+            list.remove(front);
+        }
+        assert!(list.is_empty());
+        assert_eq!(None, list.back());
+    }
+
+    #[test]
+    fn test_move() {
+        let mut list = (0..5).collect::<ArenaList<_>>();
+        list.move_after(0, 4);
+        assert_eq!(vec![1, 2, 3, 4, 0], items(&list));
+        assert_eq!(Some(0), list.back());
+        list.move_before(3, 1);
+        assert_eq!(vec![3, 1, 2, 4, 0], items(&list));
+        assert_eq!(Some(3), list.front());
+        assert_eq!(None, list.prev(3));
+        assert_eq!(Some(0), list.prev_wrapping(3));
+        assert_eq!(Some(3), list.next_wrapping(0));
+        assert_eq!(5, list.len());
+    }
+
+    #[test]
+    fn test_mixing() {
+        // The encrypted file of 2022 day 20, where each number is moved forward or backward around
+        // the circle as many places as its value in the order that they were given, after which
+        // the numbers 1000, 2000, and 3000 places after the 0 are 4, -3, and 2.
+        let numbers = [1, 2, -3, 3, -2, 0, 4];
+        let mut file = numbers.into_iter().collect::<ArenaList<i64>>();
+        let len = file.len() as i64;
+        for (handle, number) in numbers.into_iter().enumerate() {
+            let steps = number.rem_euclid(len - 1);
+            if steps == 0 {
+                continue;
+            }
+            let mut target = file.prev_wrapping(handle).unwrap();
+            for _ in 0..steps {
+                target = file.next_wrapping(target).unwrap();
+                if target == handle {
+                    target = file.next_wrapping(target).unwrap();
+                }
+            }
+            file.move_after(handle, target);
+        }
+        let zero = numbers.iter().position(|&n| n == 0).unwrap();
+        let mut handle = zero;
+        let mut coordinates = vec![];
+        for i in 1..=3000 {
+            handle = file.next_wrapping(handle).unwrap();
+            if i % 1000 == 0 {
+                coordinates.push(*file.get(handle).unwrap());
+            }
+        }
+        assert_eq!(vec![4, -3, 2], coordinates);
+    }
+}
@@ -0,0 +1,293 @@
+use std::{
+    cmp::Ordering,
+    fmt::{self, Debug, Formatter},
+    hash::{Hash, Hasher},
+    ops::{Deref, DerefMut},
+    slice,
+};
+
+/// A vector which holds at most `N` elements inline instead of on the heap, such as the amphipods
+/// in a room of 2021 day 23, so that the states of a search can be copied and compared without
+/// allocating. It dereferences to a slice of its elements, so everything that can be done with a
+/// slice can be done with it.
+///
+/// The slots past the last element hold [`T::default()`], which is never seen through the slice,
+/// so two vectors with the same elements are equal and hash the same no matter what they held
+/// before.
+///
+/// [`T::default()`]: Default::default
+#[derive(Clone, Copy)]
+pub struct ArrayVec<T, const N: usize> {
+    items: [T; N],
+    len: usize,
+}
+
+impl<T, const N: usize> ArrayVec<T, N> {
+    /// The number of elements that the vector can hold.
+    pub const CAPACITY: usize = N;
+
+    /// Returns true if and only if the vector can't hold any more elements.
+    pub fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    /// Adds `value` to the end of the vector, or returns it if the vector is full.
+    pub fn try_push(&mut self, value: T) -> Result<(), T> {
+        if self.is_full() {
+            return Err(value);
+        }
+        self.items[self.len] = value;
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Adds `value` to the end of the vector.
+    ///
+    /// # Panics
+    /// Panics if the vector is full.
+    pub fn push(&mut self, value: T) {
+        if self.try_push(value).is_err() {
+            panic!("Can't push onto a full ArrayVec of capacity {N}");
+        }
+    }
+
+    /// Inserts `value` at `index`, moving the elements after it one place later.
+    ///
+    /// # Panics
+    /// Panics if the vector is full or `index` is greater than its length.
+    pub fn insert(&mut self, index: usize, value: T) {
+        assert!(
+            index <= self.len,
+            "Insertion index {index} is out of bounds"
+        );
+        self.push(value);
+        self.items[index..self.len].rotate_right(1);
+    }
+}
+
+impl<T, const N: usize> ArrayVec<T, N>
+where
+    T: Default,
+{
+    /// Creates an empty vector.
+    pub fn new() -> Self {
+        Self {
+            items: std::array::from_fn(|_| T::default()),
+            len: 0,
+        }
+    }
+
+    /// Removes the last element of the vector and returns it, or returns `None` if the vector is
+    /// empty.
+    pub fn pop(&mut self) -> Option<T> {
+        self.len = self.len.checked_sub(1)?;
+        Some(std::mem::take(&mut self.items[self.len]))
+    }
+
+    /// Removes the element at `index` and returns it, moving the elements after it one place
+    /// earlier.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    pub fn remove(&mut self, index: usize) -> T {
+        assert!(index < self.len, "Removal index {index} is out of bounds");
+        self.items[index..self.len].rotate_left(1);
+        self.pop().expect("The vector has an element to remove")
+    }
+
+    /// Removes every element after the first `len`.
+    pub fn truncate(&mut self, len: usize) {
+        while self.len > len {
+            self.pop();
+        }
+    }
+
+    /// Removes every element.
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+}
+
+impl<T, const N: usize> Default for ArrayVec<T, N>
+where
+    T: Default,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> Deref for ArrayVec<T, N> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        &self.items[..self.len]
+    }
+}
+
+impl<T, const N: usize> DerefMut for ArrayVec<T, N> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        &mut self.items[..self.len]
+    }
+}
+
+impl<T, const N: usize> Debug for ArrayVec<T, N>
+where
+    T: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T, const N: usize> PartialEq for ArrayVec<T, N>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+impl<T, const N: usize> Eq for ArrayVec<T, N> where T: Eq {}
+
+impl<T, const N: usize> PartialOrd for ArrayVec<T, N>
+where
+    T: PartialOrd,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        (**self).partial_cmp(&**other)
+    }
+}
+
+impl<T, const N: usize> Ord for ArrayVec<T, N>
+where
+    T: Ord,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        (**self).cmp(&**other)
+    }
+}
+
+impl<T, const N: usize> Hash for ArrayVec<T, N>
+where
+    T: Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (**self).hash(state);
+    }
+}
+
+impl<T, const N: usize> Extend<T> for ArrayVec<T, N> {
+    /// Pushes each value onto the end of the vector.
+    ///
+    /// # Panics
+    /// Panics if there are more values than the vector has room for.
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+impl<T, const N: usize> FromIterator<T> for ArrayVec<T, N>
+where
+    T: Default,
+{
+    /// Creates a vector of the values in order.
+    ///
+    /// # Panics
+    /// Panics if there are more than `N` values.
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut vec = Self::new();
+        vec.extend(iter);
+        vec
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a ArrayVec<T, N> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a mut ArrayVec<T, N> {
+    type Item = &'a mut T;
+    type IntoIter = slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_push_and_pop() {
+        let mut vec = ArrayVec::<u8, 3>::new();
+        assert!(vec.is_empty());
+        vec.push(1);
+        vec.push(2);
+        assert_eq!(Ok(()), vec.try_push(3));
+        assert!(vec.is_full());
+        assert_eq!(Err(4), vec.try_push(4));
+        assert_eq!(&[1, 2, 3], &*vec);
+        assert_eq!(Some(3), vec.pop());
+        vec.insert(0, 0);
+        assert_eq!(vec![0, 1, 2], vec.to_vec());
+        assert_eq!(1, vec.remove(1));
+        assert_eq!("[0, 2]", format!("{vec:?}"));
+        vec.clear();
+        assert_eq!(None, vec.pop());
+    }
+
+    #[test]
+    #[should_panic = "full ArrayVec of capacity 2"]
+    fn test_push_full() {
+        let _ = [1, 2, 3].into_iter().collect::<ArrayVec<i32, 2>>();
+    }
+
+    #[test]
+    fn test_equality() {
+        // The slots past the end mustn't matter, even when they held something else before.
+        let mut a = [1, 2, 3].into_iter().collect::<ArrayVec<i32, 4>>();
+        a.pop();
+        let b = [1, 2].into_iter().collect::<ArrayVec<i32, 4>>();
+        assert_eq!(a, b);
+        assert!(a < [1, 3].into_iter().collect());
+        assert!(a > [1].into_iter().collect());
+        let states = HashSet::from([a, b]);
+        assert_eq!(1, states.len());
+    }
+
+    #[test]
+    fn test_rooms() {
+        // A room of amphipods in 2021 day 23 is a stack of up to four of them, and moving one
+        // into another room copies the whole state without allocating.
+        type Room = ArrayVec<char, 4>;
+        let rooms: [Room; 2] = [
+            ['A', 'B'].into_iter().collect(),
+            ['B'].into_iter().collect(),
+        ];
+        let mut next = rooms;
+        let amphipod = next[0].pop().unwrap();
+        next[1].push(amphipod);
+        assert_eq!(vec!['A'], next[0].to_vec());
+        assert_eq!(vec!['B', 'B'], next[1].to_vec());
+        assert_eq!(vec!['A', 'B'], rooms[0].to_vec());
+        assert!(next[1].iter().all(|&amphipod| amphipod == 'B'));
+    }
+}
@@ -0,0 +1,205 @@
+macro_rules! bit_set {
+    ($($name:ident($bits:ty);)+) => ($(
+        #[doc = concat!(
+            "A set of integers below `", stringify!($name), "::CAPACITY` which is kept as the bits ",
+            "of a `", stringify!($bits), "`, such as the keys that have been collected in 2019 day ",
+            "18 or the nodes that a path has visited. It's `Copy` and hashes quickly, so it can be ",
+            "part of the states of a search or the keys of a memo.",
+        )]
+        #[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+        pub struct $name($bits);
+
+        impl $name {
+            /// The number of integers that the set can hold, all of which are less than it.
+            pub const CAPACITY: usize = <$bits>::BITS as usize;
+
+            /// The set which contains no integers.
+            pub const EMPTY: Self = Self(0);
+
+            /// Creates the set which contains the integers whose bits are set in `bits`.
+            pub const fn from_bits(bits: $bits) -> Self {
+                Self(bits)
+            }
+
+            /// The bits of the integers in the set.
+            pub const fn bits(&self) -> $bits {
+                self.0
+            }
+
+            /// The bit of `value`.
+            ///
+            /// # Panics
+            #[doc = concat!("Panics if `value` isn't less than [`", stringify!($name), "::CAPACITY`].")]
+            const fn bit(value: usize) -> $bits {
+                assert!(value < Self::CAPACITY, "Value is too big for the set");
+                1 << value
+            }
+
+            /// Returns true if and only if the set contains no integers.
+            pub const fn is_empty(&self) -> bool {
+                self.0 == 0
+            }
+
+            /// The number of integers in the set.
+            pub const fn len(&self) -> usize {
+                self.0.count_ones() as usize
+            }
+
+            /// Returns true if and only if `value` is in the set.
+            pub const fn contains(&self, value: usize) -> bool {
+                value < Self::CAPACITY && self.0 & Self::bit(value) != 0
+            }
+
+            /// Adds `value` to the set. Returns true if it wasn't already in the set.
+            ///
+            /// # Panics
+            #[doc = concat!("Panics if `value` isn't less than [`", stringify!($name), "::CAPACITY`].")]
+            pub fn insert(&mut self, value: usize) -> bool {
+                let added = !self.contains(value);
+                self.0 |= Self::bit(value);
+                added
+            }
+
+            /// Removes `value` from the set. Returns true if it was in the set.
+            pub fn remove(&mut self, value: usize) -> bool {
+                let removed = self.contains(value);
+                if removed {
+                    self.0 &= !Self::bit(value);
+                }
+                removed
+            }
+
+            /// The set with `value` added to it, for building sets in constant expressions and
+            /// for states which are copied rather than changed.
+            ///
+            /// # Panics
+            #[doc = concat!("Panics if `value` isn't less than [`", stringify!($name), "::CAPACITY`].")]
+            pub const fn with(self, value: usize) -> Self {
+                Self(self.0 | Self::bit(value))
+            }
+
+            /// The set of integers which are in `self`, `other`, or both.
+            pub const fn union(self, other: Self) -> Self {
+                Self(self.0 | other.0)
+            }
+
+            /// The set of integers which are in both `self` and `other`.
+            pub const fn intersection(self, other: Self) -> Self {
+                Self(self.0 & other.0)
+            }
+
+            /// The set of integers which are in `self` but not in `other`.
+            pub const fn difference(self, other: Self) -> Self {
+                Self(self.0 & !other.0)
+            }
+
+            /// Returns true if and only if every integer in `self` is also in `other`.
+            pub const fn is_subset(&self, other: &Self) -> bool {
+                self.0 & !other.0 == 0
+            }
+
+            /// Gets an iterator over the integers in the set in increasing order.
+            pub fn iter(&self) -> impl Iterator<Item = usize> {
+                let mut bits = self.0;
+                std::iter::from_fn(move || {
+                    if bits == 0 {
+                        return None;
+                    }
+                    let value = bits.trailing_zeros() as usize;
+                    bits &= bits - 1;
+                    Some(value)
+                })
+            }
+        }
+
+        impl Extend<usize> for $name {
+            fn extend<I>(&mut self, iter: I)
+            where
+                I: IntoIterator<Item = usize>,
+            {
+                for value in iter {
+                    self.insert(value);
+                }
+            }
+        }
+
+        impl FromIterator<usize> for $name {
+            fn from_iter<I>(iter: I) -> Self
+            where
+                I: IntoIterator<Item = usize>,
+            {
+                let mut set = Self::EMPTY;
+                set.extend(iter);
+                set
+            }
+        }
+    )+)
+}
+
+bit_set! {
+    BitSet64(u64);
+    BitSet128(u128);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert_and_remove() {
+        let mut set = BitSet64::EMPTY;
+        assert!(set.is_empty());
+        assert!(set.insert(3));
+        assert!(set.insert(63));
+        assert!(!set.insert(3));
+        assert!(set.contains(3));
+        assert!(!set.contains(4));
+        assert!(!set.contains(64));
+        assert_eq!(2, set.len());
+        assert_eq!(vec![3, 63], set.iter().collect::<Vec<_>>());
+        assert!(set.remove(63));
+        assert!(!set.remove(63));
+        assert!(!set.remove(100));
+        assert_eq!(BitSet64::from_bits(0b1000), set);
+        let big = BitSet128::EMPTY.with(127).with(0);
+        assert_eq!(vec![0, 127], big.iter().collect::<Vec<_>>());
+        assert_eq!(128, BitSet128::CAPACITY);
+    }
+
+    #[test]
+    #[should_panic = "too big"]
+    fn test_insert_too_big() {
+        let mut set = BitSet64::EMPTY;
+        set.insert(64);
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = [1, 2, 5].into_iter().collect::<BitSet64>();
+        let b = [2, 5, 9].into_iter().collect::<BitSet64>();
+        assert_eq!(vec![1, 2, 5, 9], a.union(b).iter().collect::<Vec<_>>());
+        assert_eq!(vec![2, 5], a.intersection(b).iter().collect::<Vec<_>>());
+        assert_eq!(vec![1], a.difference(b).iter().collect::<Vec<_>>());
+        assert!(a.intersection(b).is_subset(&a));
+        assert!(!a.is_subset(&b));
+    }
+
+    #[test]
+    fn test_keys() {
+        // The doors of a vault in 2019 day 18 can only be opened once their keys are collected.
+        let key = |c: char| c as usize - 'a' as usize;
+        let mut collected = BitSet64::EMPTY;
+        let door_b = BitSet64::EMPTY.with(key('b'));
+        collected.extend("ac".chars().map(key));
+        assert!(!door_b.is_subset(&collected));
+        collected.insert(key('b'));
+        assert!(door_b.is_subset(&collected));
+        assert_eq!(
+            "abc",
+            collected
+                .iter()
+                .map(|bit| char::from(b'a' + bit as u8))
+                .collect::<String>()
+        );
+    }
+}
@@ -0,0 +1,157 @@
+use std::collections::VecDeque;
+
+/// A monotone priority queue for small integer priorities, which pops the value with the least
+/// priority first. Every value is kept in a bucket with the others of the same priority, so
+/// inserting and popping take constant time instead of the logarithmic time of a binary heap.
+///
+/// The queue is monotone in that no value may be inserted with a lower priority than the last one
+/// that was popped, which is always the case for Dijkstra's algorithm when no move has a negative
+/// cost. The buckets between the least and greatest priority in the queue are all kept, so the
+/// priorities in the queue at once should be close together, as they are when every move costs
+/// at most a few points.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BucketQueue<T> {
+    /// The values with priority `first + i` are in `buckets[i]`.
+    buckets: VecDeque<Vec<T>>,
+    first: usize,
+    len: usize,
+}
+
+impl<T> Default for BucketQueue<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> BucketQueue<T> {
+    /// Creates an empty queue.
+    pub const fn new() -> Self {
+        Self {
+            buckets: VecDeque::new(),
+            first: 0,
+            len: 0,
+        }
+    }
+
+    /// Returns true if and only if there are no values in the queue.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of values in the queue.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Inserts `value` into the queue with priority `priority`.
+    ///
+    /// # Panics
+    /// Panics if `priority` is less than the priority of the last value that was popped.
+    pub fn insert(&mut self, value: T, priority: usize) {
+        assert!(
+            priority >= self.first,
+            "Can't insert a value with priority {priority} after popping one with priority {}",
+            self.first,
+        );
+        let idx = priority - self.first;
+        if idx >= self.buckets.len() {
+            self.buckets.resize_with(idx + 1, Vec::new);
+        }
+        self.buckets[idx].push(value);
+        self.len += 1;
+    }
+
+    /// Removes a value with the least priority from the queue and returns it along with its
+    /// priority. Values with the same priority are popped in an arbitrary order.
+    pub fn pop(&mut self) -> Option<(usize, T)> {
+        loop {
+            let bucket = self.buckets.front_mut()?;
+            if let Some(value) = bucket.pop() {
+                self.len -= 1;
+                return Some((self.first, value));
+            }
+            // The empty bucket can't be needed again since the queue is monotone.
+            self.buckets.pop_front();
+            self.first += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pop() {
+        let mut queue = BucketQueue::new();
+        queue.insert('c', 3);
+        queue.insert('a', 1);
+        queue.insert('d', 7);
+        queue.insert('b', 1);
+        assert_eq!(4, queue.len());
+        let (priority, first) = queue.pop().unwrap();
+        let (_, second) = queue.pop().unwrap();
+        assert_eq!(1, priority);
+        let mut tied = [first, second];
+        tied.sort_unstable();
+        assert_eq!(['a', 'b'], tied);
+        queue.insert('e', 2);
+        assert_eq!(Some((2, 'e')), queue.pop());
+        assert_eq!(Some((3, 'c')), queue.pop());
+        assert_eq!(Some((7, 'd')), queue.pop());
+        assert_eq!(None, queue.pop());
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    #[should_panic = "after popping one with priority 3"]
+    fn test_not_monotone() {
+        let mut queue = BucketQueue::new();
+        queue.insert((), 3);
+        queue.pop();
+        queue.insert((), 2);
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        // The example cave from 2021 day 15, whose lowest total risk is 40.
+        let cave = [
+            "1163751742",
+            "1381373672",
+            "2136511328",
+            "3694931569",
+            "7463417111",
+            "1319128137",
+            "1359912421",
+            "3125421639",
+            "1293138521",
+            "2311944581",
+        ]
+        .map(|line| {
+            line.bytes()
+                .map(|b| (b - b'0') as usize)
+                .collect::<Vec<_>>()
+        });
+        let mut best = vec![vec![usize::MAX; 10]; 10];
+        let mut queue = BucketQueue::new();
+        queue.insert((0, 0), 0);
+        while let Some((risk, (x, y))) = queue.pop() {
+            if risk >= best[y][x] {
+                continue;
+            }
+            best[y][x] = risk;
+            let neighbors = [
+                (x.wrapping_sub(1), y),
+                (x + 1, y),
+                (x, y.wrapping_sub(1)),
+                (x, y + 1),
+            ];
+            for (x, y) in neighbors {
+                if let Some(&step) = cave.get(y).and_then(|row| row.get(x)) {
+                    queue.insert((x, y), risk + step);
+                }
+            }
+        }
+        assert_eq!(40, best[9][9]);
+    }
+}
@@ -0,0 +1,365 @@
+/// A slot in the arena of a [`CircularList`], which is either an element of the list or free to
+/// hold a new one.
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct Node<T> {
+    value: Option<T>,
+    prev: usize,
+    next: usize,
+}
+
+/// A list whose last element is followed by its first, such as the circle of marbles in 2018 day
+/// 9 or of cups in 2020 day 23, with a cursor that can be moved around it. Inserting and removing
+/// elements at the cursor takes constant time.
+///
+/// Each element is given a handle when it's inserted which refers to it until it's removed, even
+/// as other elements are inserted and removed around it, so that elements can be found again in
+/// constant time. Handles are reused after their elements are removed.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CircularList<T> {
+    nodes: Vec<Node<T>>,
+    free: Vec<usize>,
+    cursor: Option<usize>,
+    len: usize,
+}
+
+impl<T> Default for CircularList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> CircularList<T> {
+    /// Creates an empty list.
+    pub const fn new() -> Self {
+        Self {
+            nodes: vec![],
+            free: vec![],
+            cursor: None,
+            len: 0,
+        }
+    }
+
+    /// Creates an empty list with room for `capacity` elements before it needs to reallocate.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            nodes: Vec::with_capacity(capacity),
+            ..Self::new()
+        }
+    }
+
+    /// The number of elements in the list.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if and only if the list has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The handle of the element at the cursor, or `None` if the list is empty.
+    pub fn cursor(&self) -> Option<usize> {
+        self.cursor
+    }
+
+    /// The element at the cursor, or `None` if the list is empty.
+    pub fn current(&self) -> Option<&T> {
+        self.get(self.cursor?)
+    }
+
+    /// The element at the cursor mutably, or `None` if the list is empty.
+    pub fn current_mut(&mut self) -> Option<&mut T> {
+        self.get_mut(self.cursor?)
+    }
+
+    /// The element with the given handle, or `None` if it's been removed.
+    pub fn get(&self, handle: usize) -> Option<&T> {
+        self.nodes.get(handle)?.value.as_ref()
+    }
+
+    /// The element with the given handle mutably, or `None` if it's been removed.
+    pub fn get_mut(&mut self, handle: usize) -> Option<&mut T> {
+        self.nodes.get_mut(handle)?.value.as_mut()
+    }
+
+    /// The handle of the element after the one with the given handle, or `None` if it's been
+    /// removed.
+    pub fn next(&self, handle: usize) -> Option<usize> {
+        self.get(handle)?;
+        Some(self.nodes[handle].next)
+    }
+
+    /// The handle of the element before the one with the given handle, or `None` if it's been
+    /// removed.
+    pub fn prev(&self, handle: usize) -> Option<usize> {
+        self.get(handle)?;
+        Some(self.nodes[handle].prev)
+    }
+
+    /// Moves the cursor to the next element.
+    pub fn move_next(&mut self) {
+        self.cursor = self.cursor.map(|cursor| self.nodes[cursor].next);
+    }
+
+    /// Moves the cursor to the previous element.
+    pub fn move_prev(&mut self) {
+        self.cursor = self.cursor.map(|cursor| self.nodes[cursor].prev);
+    }
+
+    /// Moves the cursor `steps` elements forward, or backward if `steps` is negative.
+    pub fn rotate(&mut self, steps: isize) {
+        for _ in 0..steps.unsigned_abs() {
+            if steps > 0 {
+                self.move_next();
+            } else {
+                self.move_prev();
+            }
+        }
+    }
+
+    /// Moves the cursor to the element with the given handle.
+    ///
+    /// # Panics
+    /// Panics if the element has been removed.
+    pub fn move_to(&mut self, handle: usize) {
+        assert!(
+            self.get(handle).is_some(),
+            "Can't move to removed element {handle}"
+        );
+        self.cursor = Some(handle);
+    }
+
+    /// Puts `value` in a free slot, returning its handle without linking it into the list.
+    fn allocate(&mut self, value: T) -> usize {
+        let node = Node {
+            value: Some(value),
+            prev: 0,
+            next: 0,
+        };
+        self.len += 1;
+        match self.free.pop() {
+            Some(handle) => {
+                self.nodes[handle] = node;
+                handle
+            }
+            None => {
+                self.nodes.push(node);
+                self.nodes.len() - 1
+            }
+        }
+    }
+
+    /// Links the unlinked element `handle` into the list between `prev` and the element after it.
+    fn link_after(&mut self, handle: usize, prev: usize) {
+        let next = self.nodes[prev].next;
+        self.nodes[handle].prev = prev;
+        self.nodes[handle].next = next;
+        self.nodes[prev].next = handle;
+        self.nodes[next].prev = handle;
+    }
+
+    /// Unlinks the element `handle` from the elements around it, leaving it in its slot.
+    fn unlink(&mut self, handle: usize) {
+        let Node { prev, next, .. } = self.nodes[handle];
+        self.nodes[prev].next = next;
+        self.nodes[next].prev = prev;
+    }
+
+    /// Inserts `value` after the cursor without moving the cursor, or as the only element with the
+    /// cursor on it if the list is empty. Returns the handle of the new element.
+    pub fn insert_after(&mut self, value: T) -> usize {
+        let handle = self.allocate(value);
+        match self.cursor {
+            Some(cursor) => self.link_after(handle, cursor),
+            None => {
+                self.nodes[handle].prev = handle;
+                self.nodes[handle].next = handle;
+                self.cursor = Some(handle);
+            }
+        }
+        handle
+    }
+
+    /// Inserts `value` before the cursor without moving the cursor, or as the only element with
+    /// the cursor on it if the list is empty. Returns the handle of the new element.
+    pub fn insert_before(&mut self, value: T) -> usize {
+        match self.cursor {
+            Some(cursor) => {
+                let handle = self.allocate(value);
+                self.link_after(handle, self.nodes[cursor].prev);
+                handle
+            }
+            None => self.insert_after(value),
+        }
+    }
+
+    /// Removes the element at the cursor and moves the cursor to the element after it. Returns
+    /// `None` if the list is empty.
+    pub fn remove(&mut self) -> Option<T> {
+        let cursor = self.cursor?;
+        let next = self.nodes[cursor].next;
+        self.unlink(cursor);
+        self.cursor = (next != cursor).then_some(next);
+        self.free.push(cursor);
+        self.len -= 1;
+        self.nodes[cursor].value.take()
+    }
+
+    /// Moves the element `handle` so that it comes right after the element `target`, such as when
+    /// the crab of 2020 day 23 puts the cups it picked up back down. The cursor and every handle
+    /// stay on the same elements.
+    ///
+    /// # Panics
+    /// Panics if either element has been removed or if they're the same element.
+    pub fn move_after(&mut self, handle: usize, target: usize) {
+        assert!(
+            self.get(handle).is_some() && self.get(target).is_some(),
+            "Can't move removed elements"
+        );
+        assert_ne!(handle, target, "Can't move an element after itself");
+        self.unlink(handle);
+        self.link_after(handle, target);
+    }
+
+    /// Gets an iterator over the elements of the list, starting at the cursor and going forward
+    /// until just before it comes back around to the cursor.
+    pub fn iter(&self) -> impl Iterator<Item = &T> + '_ {
+        let mut next = self.cursor;
+        (0..self.len).map(move |_| {
+            let handle = next.expect("The list isn't empty");
+            next = Some(self.nodes[handle].next);
+            self.nodes[handle]
+                .value
+                .as_ref()
+                .expect("Linked elements haven't been removed")
+        })
+    }
+}
+
+impl<T> Extend<T> for CircularList<T> {
+    /// Inserts each value before the cursor, so that they end up at the end of the list in the
+    /// same order, and [`Self::iter()`] yields them last.
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        for value in iter {
+            self.insert_before(value);
+        }
+    }
+}
+
+impl<T> FromIterator<T> for CircularList<T> {
+    /// Creates a list of the values in order with the cursor on the first of them.
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut list = Self::new();
+        list.extend(iter);
+        list
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn items<T: Copy>(list: &CircularList<T>) -> Vec<T> {
+        list.iter().copied().collect()
+    }
+
+    #[test]
+    fn test_insert_and_remove() {
+        let mut list = CircularList::new();
+        assert_eq!(None, list.remove());
+        let first = list.insert_after(1);
+        assert_eq!(Some(first), list.cursor());
+        list.insert_after(3);
+        list.insert_after(2);
+        list.insert_before(4);
+        assert_eq!(vec![1, 2, 3, 4], items(&list));
+        list.rotate(-2);
+        assert_eq!(Some(&3), list.current());
+        assert_eq!(Some(3), list.remove());
+        assert_eq!(vec![4, 1, 2], items(&list));
+        assert_eq!(Some(first), list.next(list.cursor().unwrap()));
+        *list.get_mut(first).unwrap() = 10;
+        list.rotate(4);
+        assert_eq!(vec![10, 2, 4], items(&list));
+        list.move_to(first);
+        assert_eq!(Some(&10), list.current());
+        assert_eq!(Some(10), list.remove());
+        assert_eq!(None, list.get(first));
+        assert_eq!(2, list.len());
+        list.remove();
+        list.remove();
+        assert!(list.is_empty());
+        assert_eq!(None, list.cursor());
+        assert_eq!(Vec::<i32>::new(), items(&list));
+    }
+
+    #[test]
+    fn test_marbles() {
+        // The marble game of 2018 day 9, where the high score with 9 players and 25 marbles is 32
+        // and with 10 players and 1618 marbles is 8317.
+        let high_score = |players: usize, last_marble: u64| {
+            let mut scores = vec![0; players];
+            let mut circle = CircularList::with_capacity(last_marble as usize + 1);
+            circle.insert_after(0);
+            for marble in 1..=last_marble {
+                if marble % 23 == 0 {
+                    circle.rotate(-7);
+                    let removed = circle.remove().unwrap();
+                    scores[marble as usize % players] += marble + removed;
+                } else {
+                    circle.move_next();
+                    let placed = circle.insert_after(marble);
+                    circle.move_to(placed);
+                }
+            }
+            scores.into_iter().max().unwrap()
+        };
+        assert_eq!(32, high_score(9, 25));
+        assert_eq!(8317, high_score(10, 1618));
+        assert_eq!(146373, high_score(13, 7999));
+    }
+
+    #[test]
+    fn test_crab_cups() {
+        // The cups of 2020 day 23, where the labels after cup 1 after 10 moves are 92658374 and
+        // after 100 moves are 67384529.
+        let play = |moves: usize| {
+            let labels = [3, 8, 9, 1, 2, 5, 4, 6, 7];
+            let mut cups = labels.into_iter().collect::<CircularList<usize>>();
+            // The cups are given handles in the order that they're inserted.
+            let cup = |label: usize| labels.iter().position(|&l| l == label).unwrap();
+            for _ in 0..moves {
+                let current = cups.cursor().unwrap();
+                let first = cups.next(current).unwrap();
+                let second = cups.next(first).unwrap();
+                let third = cups.next(second).unwrap();
+                let picked_up = [first, second, third].map(|handle| *cups.get(handle).unwrap());
+                let mut destination = *cups.current().unwrap();
+                loop {
+                    destination = if destination == 1 { 9 } else { destination - 1 };
+                    if !picked_up.contains(&destination) {
+                        break;
+                    }
+                }
+                let destination = cup(destination);
+                cups.move_after(third, destination);
+                cups.move_after(second, destination);
+                cups.move_after(first, destination);
+                cups.move_next();
+            }
+            cups.move_to(cup(1));
+            cups.iter()
+                .skip(1)
+                .map(ToString::to_string)
+                .collect::<String>()
+        };
+        assert_eq!("92658374", play(10));
+        assert_eq!("67384529", play(100));
+    }
+}
@@ -0,0 +1,258 @@
+use std::{collections::HashMap, hash::Hash};
+
+/// A partition of the elements `0..len()` into disjoint sets, which can be merged and looked up
+/// in nearly constant time.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct DisjointSet {
+    /// The element that each element was merged into, which is itself for the representative of
+    /// each set.
+    parents: Vec<usize>,
+    /// An upper bound on the height of the tree below each representative.
+    ranks: Vec<u8>,
+    set_count: usize,
+}
+
+impl DisjointSet {
+    /// Creates a partition of `0..len` where every element is in a set of its own.
+    pub fn new(len: usize) -> Self {
+        Self {
+            parents: (0..len).collect(),
+            ranks: vec![0; len],
+            set_count: len,
+        }
+    }
+
+    /// Returns the number of elements.
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    /// Returns true if and only if there are no elements.
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+
+    /// Returns the number of sets that the elements are partitioned into.
+    pub fn set_count(&self) -> usize {
+        self.set_count
+    }
+
+    /// Adds a new element in a set of its own and returns it.
+    pub fn push(&mut self) -> usize {
+        let element = self.len();
+        self.parents.push(element);
+        self.ranks.push(0);
+        self.set_count += 1;
+        element
+    }
+
+    /// Returns the representative of the set that `element` is in, which is the same for every
+    /// element of the set until it's merged with another.
+    ///
+    /// # Panics
+    /// Panics if `element` isn't less than [`self.len()`].
+    ///
+    /// [`self.len()`]: #method.len
+    pub fn find(&mut self, element: usize) -> usize {
+        let mut root = element;
+        while self.parents[root] != root {
+            root = self.parents[root];
+        }
+        // Point everything along the way straight at the root so that finding them again is
+        // quicker.
+        let mut element = element;
+        while element != root {
+            element = std::mem::replace(&mut self.parents[element], root);
+        }
+        root
+    }
+
+    /// Merges the sets that `a` and `b` are in. Returns false if they were already in the same
+    /// set.
+    ///
+    /// # Panics
+    /// Panics if either element isn't less than [`self.len()`].
+    ///
+    /// [`self.len()`]: #method.len
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        // Hang the shorter tree below the taller one so that the trees stay shallow.
+        let (child, parent) = if self.ranks[a] < self.ranks[b] {
+            (a, b)
+        } else {
+            (b, a)
+        };
+        self.parents[child] = parent;
+        if self.ranks[child] == self.ranks[parent] {
+            self.ranks[parent] += 1;
+        }
+        self.set_count -= 1;
+        true
+    }
+
+    /// Returns true if and only if `a` and `b` are in the same set.
+    ///
+    /// # Panics
+    /// Panics if either element isn't less than [`self.len()`].
+    ///
+    /// [`self.len()`]: #method.len
+    pub fn same_set(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+}
+
+/// A [`DisjointSet`] whose elements are arbitrary values instead of indices.
+#[derive(Clone, Debug)]
+pub struct KeyedDisjointSet<T> {
+    elements: Vec<T>,
+    indices: HashMap<T, usize>,
+    sets: DisjointSet,
+}
+
+impl<T> Default for KeyedDisjointSet<T> {
+    fn default() -> Self {
+        Self {
+            elements: Vec::new(),
+            indices: HashMap::new(),
+            sets: DisjointSet::default(),
+        }
+    }
+}
+
+impl<T> KeyedDisjointSet<T>
+where
+    T: Clone + Eq + Hash,
+{
+    /// Creates a partition without any elements.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of elements.
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    /// Returns true if and only if there are no elements.
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
+    /// Returns the number of sets that the elements are partitioned into.
+    pub fn set_count(&self) -> usize {
+        self.sets.set_count()
+    }
+
+    /// Adds `element` in a set of its own if it isn't an element yet, and returns its index in
+    /// the underlying [`DisjointSet`].
+    pub fn insert(&mut self, element: T) -> usize {
+        if let Some(&index) = self.indices.get(&element) {
+            return index;
+        }
+        let index = self.sets.push();
+        self.elements.push(element.clone());
+        self.indices.insert(element, index);
+        index
+    }
+
+    /// Returns the representative of the set that `element` is in, or `None` if it isn't an
+    /// element.
+    pub fn find(&mut self, element: &T) -> Option<&T> {
+        let index = *self.indices.get(element)?;
+        let root = self.sets.find(index);
+        Some(&self.elements[root])
+    }
+
+    /// Merges the sets that `a` and `b` are in, first adding either of them that isn't an element
+    /// yet. Returns false if they were already in the same set.
+    pub fn union(&mut self, a: T, b: T) -> bool {
+        let (a, b) = (self.insert(a), self.insert(b));
+        self.sets.union(a, b)
+    }
+
+    /// Returns true if and only if `a` and `b` are both elements and are in the same set.
+    pub fn same_set(&mut self, a: &T, b: &T) -> bool {
+        match (self.indices.get(a), self.indices.get(b)) {
+            (Some(&a), Some(&b)) => self.sets.same_set(a, b),
+            _ => false,
+        }
+    }
+
+    /// Returns the sets that the elements are partitioned into, in no particular order.
+    pub fn sets(&mut self) -> Vec<Vec<T>> {
+        let mut sets = HashMap::<usize, Vec<T>>::new();
+        for (index, element) in self.elements.iter().enumerate() {
+            let root = self.sets.find(index);
+            sets.entry(root).or_default().push(element.clone());
+        }
+        sets.into_values().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_union() {
+        let mut sets = DisjointSet::new(6);
+        assert_eq!(6, sets.set_count());
+        assert!(sets.union(0, 1));
+        assert!(sets.union(2, 3));
+        assert!(sets.union(1, 3));
+        assert!(!sets.union(0, 2));
+        assert!(sets.same_set(0, 3));
+        assert!(!sets.same_set(0, 4));
+        assert_eq!(3, sets.set_count());
+        let element = sets.push();
+        assert!(sets.union(element, 4));
+        assert_eq!(3, sets.set_count());
+        assert_eq!(7, sets.len());
+    }
+
+    #[test]
+    fn test_keyed() {
+        // The constellations from the first example of 2018 day 25, which joins points that are at
+        // most 3 apart.
+        let points = [
+            [0_i32, 0, 0, 0],
+            [3, 0, 0, 0],
+            [0, 3, 0, 0],
+            [0, 0, 3, 0],
+            [0, 0, 0, 3],
+            [0, 0, 0, 6],
+            [9, 0, 0, 0],
+            [12, 0, 0, 0],
+        ];
+        let mut constellations = KeyedDisjointSet::new();
+        for a in points {
+            constellations.insert(a);
+            for b in points {
+                let distance = a
+                    .into_iter()
+                    .zip(b)
+                    .map(|(a, b)| a.abs_diff(b))
+                    .sum::<u32>();
+                if distance <= 3 {
+                    constellations.union(a, b);
+                }
+            }
+        }
+        assert_eq!(8, constellations.len());
+        assert_eq!(2, constellations.set_count());
+        assert!(constellations.same_set(&[0, 0, 0, 6], &[3, 0, 0, 0]));
+        assert!(!constellations.same_set(&[0, 0, 0, 0], &[9, 0, 0, 0]));
+        assert!(!constellations.same_set(&[0, 0, 0, 0], &[1, 1, 1, 1]));
+        assert_eq!(None, constellations.find(&[1, 1, 1, 1]));
+        let mut sizes = constellations
+            .sets()
+            .iter()
+            .map(Vec::len)
+            .collect::<Vec<_>>();
+        sizes.sort_unstable();
+        assert_eq!(vec![2, 6], sizes);
+    }
+}
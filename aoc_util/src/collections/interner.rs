@@ -0,0 +1,82 @@
+use std::collections::HashMap;
+
+/// Maps labels, such as the names of the valves of 2022 day 16 or the workflows of 2023 day 19, to
+/// dense ids counting up from 0 in the order that they were first seen, and back again. Once the
+/// labels are interned, a graph can keep its nodes in a [`Vec`] indexed by id instead of in a
+/// [`HashMap`] keyed by [`String`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Interner {
+    ids: HashMap<String, u32>,
+    labels: Vec<String>,
+}
+
+impl Interner {
+    /// Creates an interner which hasn't seen any labels.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The number of distinct labels which have been interned, which is also the id that the next
+    /// new label will get.
+    pub fn len(&self) -> usize {
+        self.labels.len()
+    }
+
+    /// Returns true if and only if no labels have been interned.
+    pub fn is_empty(&self) -> bool {
+        self.labels.is_empty()
+    }
+
+    /// Gets the id of `label`, giving it the next id if it hasn't been seen before.
+    ///
+    /// # Panics
+    /// Panics if there are already [`u32::MAX`] labels.
+    pub fn intern(&mut self, label: &str) -> u32 {
+        if let Some(&id) = self.ids.get(label) {
+            return id;
+        }
+        let id = u32::try_from(self.labels.len()).expect("Too many labels to intern");
+        self.ids.insert(label.to_owned(), id);
+        self.labels.push(label.to_owned());
+        id
+    }
+
+    /// Gets the id of `label`, or `None` if it hasn't been interned.
+    pub fn get(&self, label: &str) -> Option<u32> {
+        self.ids.get(label).copied()
+    }
+
+    /// Gets the label with id `id`, or `None` if no label has that id.
+    pub fn resolve(&self, id: u32) -> Option<&str> {
+        self.labels.get(id as usize).map(String::as_str)
+    }
+
+    /// Gets an iterator over the ids and labels in order of id.
+    pub fn iter(&self) -> impl Iterator<Item = (u32, &str)> + '_ {
+        (0..).zip(self.labels.iter().map(String::as_str))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_intern() {
+        let mut interner = Interner::new();
+        assert!(interner.is_empty());
+        assert_eq!(0, interner.intern("AA"));
+        assert_eq!(1, interner.intern("zg"));
+        assert_eq!(0, interner.intern("AA"));
+        assert_eq!(2, interner.intern("in"));
+        assert_eq!(3, interner.len());
+        assert_eq!(Some(1), interner.get("zg"));
+        assert_eq!(None, interner.get("px"));
+        assert_eq!(Some("in"), interner.resolve(2));
+        assert_eq!(None, interner.resolve(3));
+        assert_eq!(
+            vec![(0, "AA"), (1, "zg"), (2, "in")],
+            interner.iter().collect::<Vec<_>>()
+        );
+    }
+}
@@ -0,0 +1,136 @@
+use std::{collections::HashMap, hash::Hash};
+
+/// The values of a function which have been computed so far, so that a recursive solver only
+/// computes the value for each key once.
+///
+/// ```
+/// # use aoc_util::collections::Memo;
+/// fn fibonacci(memo: &mut Memo<u64, u64>, n: u64) -> u64 {
+///     memo.get_or_compute(n, |memo| {
+///         if n < 2 {
+///             n
+///         } else {
+///             fibonacci(memo, n - 1) + fibonacci(memo, n - 2)
+///         }
+///     })
+/// }
+///
+/// assert_eq!(12_586_269_025, fibonacci(&mut Memo::new(), 50));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Memo<K, V> {
+    values: HashMap<K, V>,
+}
+
+impl<K, V> Default for Memo<K, V> {
+    fn default() -> Self {
+        Self {
+            values: HashMap::new(),
+        }
+    }
+}
+
+impl<K, V> Memo<K, V> {
+    /// Creates a memo which hasn't computed any values yet.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of values which have been computed.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns true if and only if no values have been computed.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Forgets every value which has been computed, such as when moving on to an input whose
+    /// values are different.
+    pub fn clear(&mut self) {
+        self.values.clear();
+    }
+}
+
+impl<K, V> Memo<K, V>
+where
+    K: Eq + Hash,
+{
+    /// Returns the value for `key` if it has been computed.
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.values.get(key)
+    }
+
+    /// Records that the value for `key` is `value`, returning the value that was recorded before
+    /// if there was one.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        self.values.insert(key, value)
+    }
+}
+
+impl<K, V> Memo<K, V>
+where
+    K: Eq + Hash,
+    V: Clone,
+{
+    /// Returns the value for `key`, computing it with `compute` if it hasn't been computed yet.
+    /// `compute` is given the memo so that it can look up the values that the value for `key`
+    /// depends on, which makes recursion as simple as calling the recursive function with the
+    /// memo that `compute` was given.
+    ///
+    /// The value for `key` isn't recorded until `compute` returns, so if computing it depends on
+    /// itself, it will be computed over and over until the stack overflows.
+    pub fn get_or_compute(&mut self, key: K, compute: impl FnOnce(&mut Self) -> V) -> V {
+        if let Some(value) = self.values.get(&key) {
+            return value.clone();
+        }
+        let value = compute(self);
+        self.values.insert(key, value.clone());
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The number of ways to make `amount` out of any number of each of `coins`.
+    fn ways(memo: &mut Memo<(usize, u32), u64>, coins: &[u32], amount: u32) -> u64 {
+        memo.get_or_compute((coins.len(), amount), |memo| match coins {
+            _ if amount == 0 => 1,
+            [] => 0,
+            [coin, rest @ ..] => {
+                let without = ways(memo, rest, amount);
+                if *coin <= amount {
+                    without + ways(memo, coins, amount - coin)
+                } else {
+                    without
+                }
+            }
+        })
+    }
+
+    #[test]
+    fn test_get_or_compute() {
+        let mut memo = Memo::new();
+        assert_eq!(292, ways(&mut memo, &[50, 25, 10, 5, 1], 100));
+        let computed = memo.len();
+        assert!(computed > 0);
+        assert_eq!(Some(&292), memo.get(&(5, 100)));
+        // Everything that's needed has already been computed.
+        assert_eq!(292, ways(&mut memo, &[50, 25, 10, 5, 1], 100));
+        assert_eq!(computed, memo.len());
+        memo.clear();
+        assert!(memo.is_empty());
+    }
+
+    #[test]
+    fn test_insert() {
+        let mut memo = Memo::new();
+        assert_eq!(None, memo.insert("a", 1));
+        assert_eq!(1, memo.get_or_compute("a", |_| unreachable!()));
+        assert_eq!(Some(1), memo.insert("a", 2));
+        assert_eq!(2, memo.get_or_compute("a", |_| unreachable!()));
+    }
+}
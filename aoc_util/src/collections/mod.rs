@@ -1,3 +1,61 @@
 /// A priority queue has a constant-time lookup for the element with the greatest priority.
 pub mod priority_queue;
 pub use priority_queue::PriorityQueue;
+
+/// A priority map is a priority queue which holds each key at most once and can look up and
+/// change the priority of any key.
+pub mod priority_map;
+pub use priority_map::PriorityMap;
+
+/// A disjoint set, also known as union-find, keeps track of which elements have been merged into
+/// the same set.
+pub mod disjoint_set;
+pub use disjoint_set::{DisjointSet, KeyedDisjointSet};
+
+/// A memo keeps the values of a function that have been computed, for recursive solvers which
+/// would otherwise compute the same values over and over.
+pub mod memo;
+pub use memo::Memo;
+
+/// A bucket queue is a priority queue for small integer priorities which only ever increase, as
+/// in Dijkstra's algorithm when every move costs a few points.
+pub mod bucket_queue;
+pub use bucket_queue::BucketQueue;
+
+/// A range set keeps a set of integers as the ranges that cover them, for sets far too big to keep
+/// every integer of.
+pub mod range_set;
+pub use range_set::RangeSet;
+
+/// A circular list has a cursor which can be moved around it, with constant-time insertion and
+/// removal at the cursor.
+pub mod circular_list;
+pub use circular_list::CircularList;
+
+/// An arena list is a doubly-linked list whose elements are addressed by handles, with
+/// constant-time insertion, removal, and moves next to any element.
+pub mod arena_list;
+pub use arena_list::ArenaList;
+
+/// Bit sets keep small sets of integers as the bits of a single integer, for sets which are part
+/// of the states of a search.
+pub mod bit_set;
+pub use bit_set::{BitSet128, BitSet64};
+
+/// An interner gives labels dense ids, so that nodes named by strings can be kept in a [`Vec`].
+pub mod interner;
+pub use interner::Interner;
+
+/// A trie keeps a set of words by their prefixes, for finding which of them a text starts with.
+pub mod trie;
+pub use trie::Trie;
+
+/// An array vector holds a bounded number of elements inline, for search states which shouldn't
+/// allocate.
+pub mod array_vec;
+pub use array_vec::ArrayVec;
+
+/// A persistent list is an immutable linked list whose tail is shared by every list pushed onto
+/// it, for keeping the path to each branch of a depth-first search.
+pub mod persistent_list;
+pub use persistent_list::PersistentList;
@@ -0,0 +1,204 @@
+use std::{
+    fmt::{self, Debug, Formatter},
+    rc::Rc,
+};
+
+#[derive(Debug)]
+struct Node<T> {
+    value: T,
+    next: Option<Rc<Node<T>>>,
+}
+
+/// An immutable singly-linked list which shares its tail with every list that it was made from,
+/// such as the path that a depth-first search took to reach a state. Pushing onto a list makes a
+/// new list in constant time without changing or copying the old one, so each branch of a search
+/// can extend the path that led to it without the branches getting in each other's way.
+///
+/// The list is iterated from the value that was pushed last to the value that was pushed first.
+pub struct PersistentList<T> {
+    head: Option<Rc<Node<T>>>,
+    len: usize,
+}
+
+impl<T> PersistentList<T> {
+    /// Creates an empty list.
+    pub const fn new() -> Self {
+        Self { head: None, len: 0 }
+    }
+
+    /// The number of values in the list.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if and only if the list has no values.
+    pub fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+
+    /// Makes the list of `value` followed by the values of this list. This list is unchanged.
+    pub fn push(&self, value: T) -> Self {
+        Self {
+            head: Some(Rc::new(Node {
+                value,
+                next: self.head.clone(),
+            })),
+            len: self.len + 1,
+        }
+    }
+
+    /// The value that was pushed last, or `None` if the list is empty.
+    pub fn first(&self) -> Option<&T> {
+        self.head.as_ref().map(|node| &node.value)
+    }
+
+    /// The list of every value but the one that was pushed last, or `None` if the list is empty.
+    pub fn rest(&self) -> Option<Self> {
+        self.head.as_ref().map(|node| Self {
+            head: node.next.clone(),
+            len: self.len - 1,
+        })
+    }
+
+    /// Gets an iterator over the values of the list from the one that was pushed last to the one
+    /// that was pushed first.
+    pub fn iter(&self) -> impl Iterator<Item = &T> + '_ {
+        let mut next = self.head.as_deref();
+        std::iter::from_fn(move || {
+            let node = next?;
+            next = node.next.as_deref();
+            Some(&node.value)
+        })
+    }
+}
+
+impl<T> PersistentList<T>
+where
+    T: PartialEq,
+{
+    /// Returns true if and only if `value` is in the list. This takes time proportional to the
+    /// length of the list, so it suits short paths.
+    pub fn contains(&self, value: &T) -> bool {
+        self.iter().any(|other| other == value)
+    }
+}
+
+impl<T> Clone for PersistentList<T> {
+    /// Makes another handle to the same list in constant time.
+    fn clone(&self) -> Self {
+        Self {
+            head: self.head.clone(),
+            len: self.len,
+        }
+    }
+}
+
+impl<T> Default for PersistentList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Drop for PersistentList<T> {
+    /// Drops the nodes which aren't shared with any other list one at a time, since dropping them
+    /// recursively would overflow the stack for long lists.
+    fn drop(&mut self) {
+        let mut next = self.head.take();
+        while let Some(node) = next {
+            match Rc::try_unwrap(node) {
+                Ok(mut node) => next = node.next.take(),
+                Err(_) => break,
+            }
+        }
+    }
+}
+
+impl<T> Debug for PersistentList<T>
+where
+    T: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T> PartialEq for PersistentList<T>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T> Eq for PersistentList<T> where T: Eq {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sharing() {
+        let empty = PersistentList::new();
+        let ab = empty.push('a').push('b');
+        let abc = ab.push('c');
+        let abd = ab.push('d');
+        assert_eq!(vec!['c', 'b', 'a'], abc.iter().copied().collect::<Vec<_>>());
+        assert_eq!(vec!['d', 'b', 'a'], abd.iter().copied().collect::<Vec<_>>());
+        assert_eq!(2, ab.len());
+        assert_eq!(3, abd.len());
+        assert!(empty.is_empty());
+        assert_eq!(Some(&'d'), abd.first());
+        assert_eq!(Some(ab.clone()), abd.rest());
+        assert_eq!(abc.rest(), abd.rest());
+        assert_ne!(abc, abd);
+        assert!(abc.contains(&'a'));
+        assert!(!abc.contains(&'d'));
+        assert_eq!("['b', 'a']", format!("{ab:?}"));
+        assert_eq!(None, empty.rest());
+    }
+
+    #[test]
+    fn test_long_list() {
+        let mut list = PersistentList::new();
+        for i in 0..1_000_000 {
+            list = list.push(i);
+        }
+        assert_eq!(1_000_000, list.len());
+        let shared = list.rest().unwrap();
+        drop(list);
+        assert_eq!(Some(&999_998), shared.first());
+    }
+
+    #[test]
+    fn test_longest_hike() {
+        // A depth-first search for the longest path which doesn't visit any junction twice, as in
+        // 2023 day 23, where each branch keeps the path that led to it.
+        let edges = |node| match node {
+            'S' => vec![('a', 2), ('b', 3)],
+            'a' => vec![('b', 4), ('E', 1)],
+            'b' => vec![('a', 4), ('E', 3)],
+            _ => vec![],
+        };
+        fn longest(
+            path: &PersistentList<char>,
+            distance: u32,
+            edges: &impl Fn(char) -> Vec<(char, u32)>,
+        ) -> Option<(u32, PersistentList<char>)> {
+            let &node = path.first()?;
+            if node == 'E' {
+                return Some((distance, path.clone()));
+            }
+            edges(node)
+                .into_iter()
+                .filter(|(next, _)| !path.contains(next))
+                .filter_map(|(next, step)| longest(&path.push(next), distance + step, edges))
+                .max_by_key(|&(distance, _)| distance)
+        }
+        let (distance, path) = longest(&PersistentList::new().push('S'), 0, &edges).unwrap();
+        assert_eq!(9, distance);
+        let mut path = path.iter().copied().collect::<Vec<_>>();
+        path.reverse();
+        assert_eq!(vec!['S', 'a', 'b', 'E'], path);
+    }
+}
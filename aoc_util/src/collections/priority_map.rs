@@ -0,0 +1,298 @@
+use std::{collections::HashMap, hash::Hash};
+
+/// A priority queue in which each key is in the queue at most once, such as the states on the
+/// frontier of a search. The priority of any key can be looked up, changed, or removed in
+/// logarithmic time, since the heap is paired with a map from each key to its place in the heap.
+///
+/// Like [`PriorityQueue`], the key with the greatest priority is popped first, so the priorities
+/// should be wrapped in [`Reverse`] to pop the least distance first.
+///
+/// [`PriorityQueue`]: super::PriorityQueue
+/// [`Reverse`]: std::cmp::Reverse
+#[derive(Clone, Debug)]
+pub struct PriorityMap<K, P> {
+    heap: Vec<(P, K)>,
+    /// The index in `heap` of each key.
+    indices: HashMap<K, usize>,
+}
+
+impl<K, P> Default for PriorityMap<K, P> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, P> PriorityMap<K, P> {
+    /// Creates an empty map.
+    pub fn new() -> Self {
+        Self {
+            heap: vec![],
+            indices: HashMap::new(),
+        }
+    }
+
+    /// Returns true if and only if there are no keys in the map.
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    /// Returns the number of keys in the map.
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    /// Returns the key with the greatest priority and its priority without removing it.
+    pub fn peek(&self) -> Option<(&K, &P)> {
+        self.heap.first().map(|(priority, key)| (key, priority))
+    }
+
+    /// Gets an iterator over the keys in the map and their priorities in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &P)> + '_ {
+        self.heap.iter().map(|(priority, key)| (key, priority))
+    }
+}
+
+impl<K, P> PriorityMap<K, P>
+where
+    K: Clone + Eq + Hash,
+    P: Ord,
+{
+    /// Returns true if and only if `key` is in the map.
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.indices.contains_key(key)
+    }
+
+    /// Returns the priority of `key`, or `None` if it isn't in the map.
+    pub fn priority(&self, key: &K) -> Option<&P> {
+        self.indices.get(key).map(|&idx| &self.heap[idx].0)
+    }
+
+    /// Removes the key with the greatest priority from the map and returns it along with its
+    /// priority.
+    pub fn pop(&mut self) -> Option<(K, P)> {
+        self.remove_at(0)
+    }
+
+    /// Gives `key` the priority `priority`, inserting it if it isn't in the map already. Returns
+    /// its old priority if it was in the map.
+    pub fn insert(&mut self, key: K, priority: P) -> Option<P> {
+        match self.indices.get(&key) {
+            Some(&idx) => {
+                let old_priority = std::mem::replace(&mut self.heap[idx].0, priority);
+                let idx = self.sift_up(idx);
+                self.sift_down(idx);
+                Some(old_priority)
+            }
+            None => {
+                self.indices.insert(key.clone(), self.heap.len());
+                self.heap.push((priority, key));
+                self.sift_up(self.heap.len() - 1);
+                None
+            }
+        }
+    }
+
+    /// Inserts `key` with priority `priority` if it isn't in the map, or raises its priority to
+    /// `priority` if that's higher than its current priority. Returns true if and only if the map
+    /// changed.
+    ///
+    /// This is the "decrease key" step of Dijkstra's algorithm when the priorities are wrapped in
+    /// [`Reverse`].
+    ///
+    /// [`Reverse`]: std::cmp::Reverse
+    pub fn insert_or_raise(&mut self, key: K, priority: P) -> bool {
+        match self.indices.get(&key) {
+            Some(&idx) if self.heap[idx].0 < priority => {
+                self.heap[idx].0 = priority;
+                self.sift_up(idx);
+                true
+            }
+            Some(_) => false,
+            None => {
+                self.insert(key, priority);
+                true
+            }
+        }
+    }
+
+    /// Removes `key` from the map and returns its priority, or returns `None` if it wasn't in the
+    /// map.
+    pub fn remove(&mut self, key: &K) -> Option<P> {
+        let idx = *self.indices.get(key)?;
+        self.remove_at(idx).map(|(_, priority)| priority)
+    }
+
+    fn remove_at(&mut self, idx: usize) -> Option<(K, P)> {
+        if idx >= self.heap.len() {
+            return None;
+        }
+        let (priority, key) = self.heap.swap_remove(idx);
+        self.indices.remove(&key);
+        if idx < self.heap.len() {
+            self.indices.insert(self.heap[idx].1.clone(), idx);
+            // The entry that took the removed one's place came from the bottom of the heap, but
+            // not necessarily from below `idx`, so it may belong either higher or lower.
+            let idx = self.sift_up(idx);
+            self.sift_down(idx);
+        }
+        Some((key, priority))
+    }
+
+    /// Swaps the entries at `a` and `b` and keeps track of their new indices.
+    fn swap(&mut self, a: usize, b: usize) {
+        self.heap.swap(a, b);
+        for idx in [a, b] {
+            *self
+                .indices
+                .get_mut(&self.heap[idx].1)
+                .expect("Every key in the heap has an index") = idx;
+        }
+    }
+
+    /// Moves the entry at `idx` up past every ancestor with a lower priority and returns its new
+    /// index.
+    fn sift_up(&mut self, mut idx: usize) -> usize {
+        while idx > 0 {
+            let parent_idx = (idx - 1) / 2;
+            if self.heap[parent_idx].0 < self.heap[idx].0 {
+                self.swap(parent_idx, idx);
+                idx = parent_idx;
+            } else {
+                break;
+            }
+        }
+        idx
+    }
+
+    /// Moves the entry at `idx` down past every descendant with a greater priority.
+    fn sift_down(&mut self, mut idx: usize) {
+        loop {
+            let left_idx = 2 * idx + 1;
+            let right_idx = left_idx + 1;
+            let child_idx = match (self.heap.get(left_idx), self.heap.get(right_idx)) {
+                (Some((left_priority, _)), Some((right_priority, _)))
+                    if left_priority < right_priority =>
+                {
+                    right_idx
+                }
+                (Some(_), _) => left_idx,
+                (None, _) => break,
+            };
+            if self.heap[idx].0 < self.heap[child_idx].0 {
+                self.swap(idx, child_idx);
+                idx = child_idx;
+            } else {
+                break;
+            }
+        }
+    }
+}
+
+impl<K, P> Extend<(K, P)> for PriorityMap<K, P>
+where
+    K: Clone + Eq + Hash,
+    P: Ord,
+{
+    /// Gives each key the priority that it's paired with, so that a key which appears more than
+    /// once ends up with the last of its priorities.
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = (K, P)>,
+    {
+        for (key, priority) in iter {
+            self.insert(key, priority);
+        }
+    }
+}
+
+impl<K, P> FromIterator<(K, P)> for PriorityMap<K, P>
+where
+    K: Clone + Eq + Hash,
+    P: Ord,
+{
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = (K, P)>,
+    {
+        let mut map = Self::new();
+        map.extend(iter);
+        map
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::cmp::Reverse;
+
+    #[test]
+    fn test_insert_and_pop() {
+        let mut map = [('a', 3), ('b', 1), ('c', 4), ('d', 1), ('e', 5)]
+            .into_iter()
+            .collect::<PriorityMap<_, _>>();
+        assert_eq!(5, map.len());
+        assert_eq!(Some((&'e', &5)), map.peek());
+        assert_eq!(Some(1), map.insert('b', 6));
+        assert_eq!(Some(5), map.insert('e', 0));
+        assert_eq!(None, map.insert('f', 2));
+        assert!(!map.insert_or_raise('a', 2));
+        assert!(map.insert_or_raise('d', 3));
+        assert_eq!(Some(&3), map.priority(&'d'));
+        assert_eq!(Some(4), map.remove(&'c'));
+        assert_eq!(None, map.remove(&'c'));
+        assert!(!map.contains_key(&'c'));
+        let mut order = std::iter::from_fn(|| map.pop()).collect::<Vec<_>>();
+        // 'a' and 'd' are tied, so they may be popped in either order.
+        order[1..3].sort_unstable();
+        assert_eq!(
+            vec![('b', 6), ('a', 3), ('d', 3), ('f', 2), ('e', 0)],
+            order
+        );
+        assert!(map.is_empty());
+    }
+
+    #[test]
+    fn test_indices() {
+        // Every key must stay findable however the heap is shuffled.
+        let mut map = PriorityMap::new();
+        for i in 0..100u32 {
+            map.insert(i, (i * 37) % 101);
+        }
+        for i in (0..100).step_by(3) {
+            map.remove(&i);
+        }
+        for i in (1..100).step_by(3) {
+            map.insert(i, (i * 53) % 103);
+        }
+        for (&key, &priority) in map.iter() {
+            assert_eq!(Some(&priority), map.priority(&key));
+        }
+        let priorities = std::iter::from_fn(|| map.pop())
+            .map(|(_, priority)| priority)
+            .collect::<Vec<_>>();
+        assert_eq!(66, priorities.len());
+        assert!(priorities.windows(2).all(|pair| pair[0] >= pair[1]));
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        // A diamond where the direct edge to 'c' is longer than the way around through 'b'.
+        let edges = |node| match node {
+            'a' => vec![('b', 1), ('c', 5)],
+            'b' => vec![('c', 1), ('d', 7)],
+            'c' => vec![('d', 1)],
+            _ => vec![],
+        };
+        let mut frontier = PriorityMap::new();
+        frontier.insert('a', Reverse(0));
+        let mut distances = vec![];
+        while let Some((node, Reverse(distance))) = frontier.pop() {
+            distances.push((node, distance));
+            for (next, weight) in edges(node) {
+                frontier.insert_or_raise(next, Reverse(distance + weight));
+            }
+        }
+        assert_eq!(vec![('a', 0), ('b', 1), ('c', 2), ('d', 3)], distances);
+    }
+}
@@ -29,6 +29,12 @@ impl<P, T> PriorityQueue<P, T> {
     pub fn peek(&self) -> Option<&T> {
         self.values.first().map(|(_, value)| value)
     }
+
+    /// Removes every value from the queue and returns an iterator over them in no particular
+    /// order. The queue is empty afterward even if the iterator isn't used up.
+    pub fn drain(&mut self) -> impl Iterator<Item = T> + '_ {
+        self.values.drain(..).map(|(_, value)| value)
+    }
 }
 
 impl<P, T> PriorityQueue<P, T>
@@ -42,17 +48,8 @@ where
 
     /// Inserts `value` into the queue with priority `priority`.
     pub fn insert(&mut self, value: T, priority: P) {
-        let mut idx = self.len();
         self.values.push((priority, value));
-        while idx > 0 {
-            let parent_idx = (idx - 1) / 2;
-            if self.values[parent_idx].0 < self.values[idx].0 {
-                self.values.swap(parent_idx, idx);
-                idx = parent_idx;
-            } else {
-                break;
-            }
-        }
+        self.sift_up(self.len() - 1);
     }
 
     /// Like [`insert()`] except that the priority is `priority_fn(&value)` instead of being passed
@@ -67,44 +64,67 @@ where
         self.insert(value, priority)
     }
 
-    fn remove(&mut self, mut idx: usize) -> Option<(P, T)> {
-        if self.is_empty() {
+    /// Consumes the queue and returns its values in the order that [`pop()`] would have returned
+    /// them, from the greatest priority to the least. Values with equal priorities are in no
+    /// particular order.
+    ///
+    /// [`pop()`]: #method.pop
+    pub fn into_sorted_vec(mut self) -> Vec<T> {
+        self.values
+            .sort_unstable_by(|(left, _), (right, _)| right.cmp(left));
+        self.values.into_iter().map(|(_, value)| value).collect()
+    }
+
+    fn remove(&mut self, idx: usize) -> Option<(P, T)> {
+        if idx >= self.len() {
             return None;
         }
+        let ret = self.values.swap_remove(idx);
+        if idx < self.len() {
+            // The value that took the removed one's place came from the bottom of the heap, but
+            // not necessarily from below `idx`, so it may belong either higher or lower.
+            let idx = self.sift_up(idx);
+            self.sift_down(idx);
+        }
+        Some(ret)
+    }
+
+    /// Moves the value at `idx` up past every ancestor with a lower priority and returns its new
+    /// index.
+    fn sift_up(&mut self, mut idx: usize) -> usize {
         while idx > 0 {
             let parent_idx = (idx - 1) / 2;
-            self.values.swap(idx, parent_idx);
-            idx = parent_idx;
+            if self.values[parent_idx].0 < self.values[idx].0 {
+                self.values.swap(parent_idx, idx);
+                idx = parent_idx;
+            } else {
+                break;
+            }
         }
-        let ret = Some(self.values.swap_remove(0));
+        idx
+    }
+
+    /// Moves the value at `idx` down past every descendant with a greater priority.
+    fn sift_down(&mut self, mut idx: usize) {
         loop {
             let left_idx = 2 * idx + 1;
             let right_idx = left_idx + 1;
-            let left = self.values.get(left_idx);
-            let right = self.values.get(right_idx);
-            match (left, right) {
+            let child_idx = match (self.values.get(left_idx), self.values.get(right_idx)) {
                 (Some((left_priority, _)), Some((right_priority, _)))
-                    if left_priority > right_priority =>
+                    if left_priority < right_priority =>
                 {
-                    self.values.swap(idx, left_idx);
-                    idx = left_idx;
-                    continue;
+                    right_idx
                 }
-                (Some(_), None) => {
-                    self.values.swap(idx, left_idx);
-                    idx = left_idx;
-                    continue;
-                }
-                (Some(_), Some(_)) => {
-                    self.values.swap(idx, right_idx);
-                    idx = right_idx;
-                    continue;
-                }
-                (None, Some(_)) => unreachable!(),
-                (None, None) => break,
+                (Some(_), _) => left_idx,
+                (None, _) => break,
+            };
+            if self.values[idx].0 < self.values[child_idx].0 {
+                self.values.swap(idx, child_idx);
+                idx = child_idx;
+            } else {
+                break;
             }
         }
-        ret
     }
 }
 
@@ -140,15 +160,13 @@ where
     where
         E: FnMut(&T, &T) -> bool,
     {
-        for idx in 0..self.len() {
-            if eq(&self.values[0].1, &value) {
-                let ret = self.remove(idx);
-                self.insert(value, priority);
-                return ret;
-            }
-        }
+        let ret = self
+            .values
+            .iter()
+            .position(|(_, other)| eq(other, &value))
+            .and_then(|idx| self.remove(idx));
         self.insert(value, priority);
-        None
+        ret
     }
 
     /// Like [`replace()`] except that the priority is `priority_fn(&value)` instead of being
@@ -164,6 +182,53 @@ where
         let priority = priority_fn(&value);
         self.replace_by(value, priority, eq)
     }
+
+    /// Returns the priority of an arbitrary value in the queue equal to `value`, or `None` if
+    /// there's no such value. Since a value's priority can be changed in place with
+    /// [`change_priority()`], this can tell whether a priority that was computed for a value
+    /// before is out of date.
+    ///
+    /// [`change_priority()`]: #method.change_priority
+    pub fn priority(&self, value: &T) -> Option<&P> {
+        self.values
+            .iter()
+            .find(|(_, other)| other == value)
+            .map(|(priority, _)| priority)
+    }
+
+    /// Gives an arbitrary value in the queue equal to `value` the priority `priority` and returns
+    /// its old priority, or does nothing and returns `None` if there's no such value.
+    pub fn change_priority(&mut self, value: &T, priority: P) -> Option<P> {
+        let idx = self.values.iter().position(|(_, other)| other == value)?;
+        let old_priority = std::mem::replace(&mut self.values[idx].0, priority);
+        let idx = self.sift_up(idx);
+        self.sift_down(idx);
+        Some(old_priority)
+    }
+
+    /// Inserts `value` with priority `priority` if no value in the queue is equal to it, or raises
+    /// the priority of an arbitrary value equal to it to `priority` if that's higher than its
+    /// current priority. Returns true if and only if the queue changed.
+    ///
+    /// This is the "decrease key" step of Dijkstra's algorithm when the priorities are wrapped in
+    /// [`Reverse`], so that each state is in the queue at most once instead of once per route to
+    /// it that was found.
+    ///
+    /// [`Reverse`]: std::cmp::Reverse
+    pub fn insert_or_raise(&mut self, value: T, priority: P) -> bool {
+        match self.values.iter().position(|(_, other)| other == &value) {
+            Some(idx) if self.values[idx].0 < priority => {
+                self.values[idx].0 = priority;
+                self.sift_up(idx);
+                true
+            }
+            Some(_) => false,
+            None => {
+                self.insert(value, priority);
+                true
+            }
+        }
+    }
 }
 
 impl<T, P> Default for PriorityQueue<T, P> {
@@ -172,6 +237,37 @@ impl<T, P> Default for PriorityQueue<T, P> {
     }
 }
 
+impl<P, T> Extend<(T, P)> for PriorityQueue<P, T>
+where
+    P: Ord,
+{
+    /// Inserts each value with the priority that it's paired with.
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = (T, P)>,
+    {
+        let iter = iter.into_iter();
+        self.values.reserve(iter.size_hint().0);
+        for (value, priority) in iter {
+            self.insert(value, priority);
+        }
+    }
+}
+
+impl<P, T> FromIterator<(T, P)> for PriorityQueue<P, T>
+where
+    P: Ord,
+{
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = (T, P)>,
+    {
+        let mut queue = Self::new();
+        queue.extend(iter);
+        queue
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -199,4 +295,82 @@ mod tests {
         }
         assert_eq!(queue.pop(), None);
     }
+
+    #[test]
+    fn test_replace() {
+        let mut queue = PriorityQueue::new();
+        for i in 0..10 {
+            queue.insert(i, i);
+        }
+        assert_eq!(Some((3, 3)), queue.replace(3, 20));
+        assert_eq!(None, queue.replace(10, 5));
+        assert_eq!(11, queue.len());
+        assert_eq!(Some(3), queue.pop());
+        assert_eq!(Some(9), queue.pop());
+    }
+
+    #[test]
+    fn test_change_priority() {
+        let mut queue = PriorityQueue::new();
+        for i in 0..10 {
+            queue.insert(i, i);
+        }
+        assert_eq!(Some(&4), queue.priority(&4));
+        assert_eq!(Some(4), queue.change_priority(&4, 20));
+        assert_eq!(Some(9), queue.change_priority(&9, -1));
+        assert_eq!(None, queue.change_priority(&10, 0));
+        assert_eq!(None, queue.priority(&10));
+        let order = std::iter::from_fn(|| queue.pop()).collect::<Vec<_>>();
+        assert_eq!(vec![4, 8, 7, 6, 5, 3, 2, 1, 0, 9], order);
+    }
+
+    #[test]
+    fn test_insert_or_raise() {
+        use std::cmp::Reverse;
+
+        // Dijkstra's algorithm over a diamond where the direct edge to 'c' is longer than the way
+        // around through 'b'.
+        let edges = |node| match node {
+            'a' => vec![('b', 1), ('c', 5)],
+            'b' => vec![('c', 1)],
+            'c' => vec![('d', 1)],
+            _ => vec![],
+        };
+        let mut queue = PriorityQueue::new();
+        queue.insert('a', Reverse(0));
+        let mut distances = vec![];
+        while let Some(&node) = queue.peek() {
+            let Reverse(distance) = *queue.priority(&node).unwrap();
+            queue.pop();
+            distances.push((node, distance));
+            for (next, weight) in edges(node) {
+                queue.insert_or_raise(next, Reverse(distance + weight));
+            }
+        }
+        assert_eq!(vec![('a', 0), ('b', 1), ('c', 2), ('d', 3)], distances);
+        assert!(queue.insert_or_raise('e', Reverse(3)));
+        assert!(!queue.insert_or_raise('e', Reverse(4)));
+        assert!(queue.insert_or_raise('e', Reverse(2)));
+        assert_eq!(Some(&Reverse(2)), queue.priority(&'e'));
+    }
+
+    #[test]
+    fn test_from_iter() {
+        let mut queue = [('a', 3), ('b', 1), ('c', 4)]
+            .into_iter()
+            .collect::<PriorityQueue<_, _>>();
+        assert_eq!(3, queue.len());
+        assert_eq!(Some(&'c'), queue.peek());
+        queue.extend([('d', 2), ('e', 5)]);
+        assert_eq!(Some(&'e'), queue.peek());
+        assert_eq!(
+            vec!['e', 'c', 'a', 'd', 'b'],
+            queue.clone().into_sorted_vec()
+        );
+        let mut drained = queue.drain().collect::<Vec<_>>();
+        drained.sort_unstable();
+        assert_eq!(vec!['a', 'b', 'c', 'd', 'e'], drained);
+        assert!(queue.is_empty());
+        assert_eq!(None, queue.peek());
+    }
 }
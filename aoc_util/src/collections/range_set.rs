@@ -0,0 +1,243 @@
+use std::ops::{Range, Sub};
+
+/// A set of integers which is kept as the ranges that cover them, so that huge spans of integers,
+/// such as the positions that the sensors of 2022 day 15 rule out, take as little space as the
+/// number of separate spans.
+///
+/// The ranges are kept sorted, and ranges which overlap or touch are merged as they're inserted,
+/// so no two ranges in the set overlap or touch.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct RangeSet<T> {
+    ranges: Vec<Range<T>>,
+}
+
+impl<T> Default for RangeSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> RangeSet<T> {
+    /// Creates an empty set.
+    pub const fn new() -> Self {
+        Self { ranges: vec![] }
+    }
+
+    /// Returns true if and only if the set contains no integers.
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// The disjoint ranges which make up the set, in increasing order.
+    pub fn ranges(&self) -> &[Range<T>] {
+        &self.ranges
+    }
+}
+
+impl<T> RangeSet<T>
+where
+    T: Copy + Ord,
+{
+    /// Adds every integer in `range` to the set, merging it with the ranges that it overlaps or
+    /// touches.
+    pub fn insert(&mut self, range: Range<T>) {
+        if range.is_empty() {
+            return;
+        }
+        let first = self.ranges.partition_point(|other| other.end < range.start);
+        let last = self
+            .ranges
+            .partition_point(|other| other.start <= range.end);
+        let merged = if first < last {
+            self.ranges[first].start.min(range.start)..self.ranges[last - 1].end.max(range.end)
+        } else {
+            range
+        };
+        self.ranges.splice(first..last, [merged]);
+    }
+
+    /// Removes every integer in `range` from the set, splitting any range that `range` is in the
+    /// middle of.
+    pub fn remove(&mut self, range: Range<T>) {
+        if range.is_empty() {
+            return;
+        }
+        let first = self
+            .ranges
+            .partition_point(|other| other.end <= range.start);
+        let last = self.ranges.partition_point(|other| other.start < range.end);
+        if first == last {
+            return;
+        }
+        let before = self.ranges[first].start..range.start;
+        let after = range.end..self.ranges[last - 1].end;
+        self.ranges.splice(
+            first..last,
+            [before, after]
+                .into_iter()
+                .filter(|piece| !piece.is_empty()),
+        );
+    }
+
+    /// Returns true if and only if `value` is in the set.
+    pub fn contains(&self, value: &T) -> bool {
+        let idx = self.ranges.partition_point(|range| range.end <= *value);
+        self.ranges
+            .get(idx)
+            .is_some_and(|range| range.start <= *value)
+    }
+
+    /// The ranges of the integers in `within` which aren't in the set, in increasing order, such
+    /// as the only position that the distress beacon of 2022 day 15 can be in.
+    pub fn gaps(&self, within: Range<T>) -> Vec<Range<T>> {
+        let mut gaps = vec![];
+        let mut next = within.start;
+        let first = self
+            .ranges
+            .partition_point(|range| range.end <= within.start);
+        for range in &self.ranges[first..] {
+            if range.start >= within.end {
+                break;
+            }
+            if next < range.start {
+                gaps.push(next..range.start);
+            }
+            next = next.max(range.end);
+        }
+        if next < within.end {
+            gaps.push(next..within.end);
+        }
+        gaps
+    }
+}
+
+impl<T> RangeSet<T>
+where
+    T: Copy + Sub<Output = T> + std::iter::Sum,
+{
+    /// Gets the number of integers in the set.
+    pub fn size(&self) -> T {
+        self.ranges
+            .iter()
+            .map(|range| range.end - range.start)
+            .sum()
+    }
+}
+
+impl<T> Extend<Range<T>> for RangeSet<T>
+where
+    T: Copy + Ord,
+{
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = Range<T>>,
+    {
+        for range in iter {
+            self.insert(range);
+        }
+    }
+}
+
+impl<T> FromIterator<Range<T>> for RangeSet<T>
+where
+    T: Copy + Ord,
+{
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = Range<T>>,
+    {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert() {
+        let mut set = RangeSet::new();
+        set.insert(5..8);
+        set.insert(0..2);
+        set.insert(10..12);
+        assert_eq!([0..2, 5..8, 10..12], set.ranges());
+        // Ranges which only touch are merged too.
+        set.insert(2..3);
+        set.insert(7..10);
+        assert_eq!([0..3, 5..12], set.ranges());
+        set.insert(4..4);
+        assert_eq!([0..3, 5..12], set.ranges());
+        set.insert(30..31);
+        set.insert(-5..20);
+        assert_eq!([-5..20, 30..31], set.ranges());
+        assert_eq!(26, set.size());
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut set = [0..10, 20..30].into_iter().collect::<RangeSet<_>>();
+        set.remove(3..5);
+        assert_eq!([0..3, 5..10, 20..30], set.ranges());
+        set.remove(8..22);
+        assert_eq!([0..3, 5..8, 22..30], set.ranges());
+        set.remove(30..40);
+        assert_eq!([0..3, 5..8, 22..30], set.ranges());
+        assert!(set.contains(&0));
+        assert!(!set.contains(&3));
+        assert!(set.contains(&29));
+        assert!(!set.contains(&30));
+        assert_eq!(14, set.size());
+        set.remove(-1..31);
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn test_gaps() {
+        let set = [2..4, 6..8].into_iter().collect::<RangeSet<_>>();
+        assert_eq!(vec![0..2, 4..6, 8..10], set.gaps(0..10));
+        assert_eq!(vec![4..6, 8..9], set.gaps(3..9));
+        assert_eq!(Vec::<Range<i32>>::new(), set.gaps(6..8));
+        assert_eq!(vec![0..5], RangeSet::new().gaps(0..5));
+    }
+
+    #[test]
+    fn test_beacon_exclusion() {
+        // The sensors and their closest beacons from the example of 2022 day 15.
+        let sensors: [(i64, i64, i64, i64); 14] = [
+            (2, 18, -2, 15),
+            (9, 16, 10, 16),
+            (13, 2, 15, 3),
+            (12, 14, 10, 16),
+            (10, 20, 10, 16),
+            (14, 17, 10, 16),
+            (8, 7, 2, 10),
+            (2, 0, 2, 10),
+            (0, 11, 2, 10),
+            (20, 14, 25, 17),
+            (17, 20, 21, 22),
+            (16, 7, 15, 3),
+            (14, 3, 15, 3),
+            (20, 1, 15, 3),
+        ];
+        let covered = |y: i64| {
+            sensors
+                .iter()
+                .filter_map(|&(sx, sy, bx, by)| {
+                    let reach = sx.abs_diff(bx) + sy.abs_diff(by);
+                    let half_width = reach.checked_sub(sy.abs_diff(y))? as i64;
+                    Some(sx - half_width..sx + half_width + 1)
+                })
+                .collect::<RangeSet<_>>()
+        };
+        let mut row = covered(10);
+        // The beacon at (2, 10) is the only one in the row.
+        row.remove(2..3);
+        assert_eq!(26, row.size());
+        let beacons = (0..=20)
+            .flat_map(|y| covered(y).gaps(0..21).into_iter().map(move |gap| (gap, y)))
+            .collect::<Vec<_>>();
+        assert_eq!(vec![(14..15, 11)], beacons);
+    }
+}
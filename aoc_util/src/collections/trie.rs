@@ -0,0 +1,208 @@
+/// A node of a [`Trie`], which is reached by following the bytes of a prefix from the root.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+struct Node {
+    /// The byte that leads to each child and the child's index in the trie's arena.
+    children: Vec<(u8, usize)>,
+    /// Whether the prefix that leads here is a word of the trie.
+    is_word: bool,
+    /// The number of words which start with the prefix that leads here.
+    words: usize,
+}
+
+impl Node {
+    fn child(&self, byte: u8) -> Option<usize> {
+        self.children
+            .iter()
+            .find(|&&(child_byte, _)| child_byte == byte)
+            .map(|&(_, child)| child)
+    }
+}
+
+/// A set of words which can quickly find which of its words the start of a text matches, such as
+/// the towel patterns of 2024 day 19 or the molecules which can be replaced in 2015 day 19. Words
+/// are sequences of bytes, so `&str`s, `&[u8]`s, and `Vec<u8>`s can all be used.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Trie {
+    /// The nodes of the trie, of which the first is the root.
+    nodes: Vec<Node>,
+}
+
+impl Default for Trie {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Trie {
+    /// Creates a trie without any words.
+    pub fn new() -> Self {
+        Self {
+            nodes: vec![Node::default()],
+        }
+    }
+
+    /// The number of words in the trie.
+    pub fn len(&self) -> usize {
+        self.nodes[0].words
+    }
+
+    /// Returns true if and only if there are no words in the trie.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Follows `prefix` from the root and returns the index of the node that it leads to, or
+    /// `None` if no word starts with it.
+    fn find(&self, prefix: &[u8]) -> Option<usize> {
+        prefix
+            .iter()
+            .try_fold(0, |node, &byte| self.nodes[node].child(byte))
+    }
+
+    /// Adds `word` to the trie. Returns true if it wasn't already in the trie.
+    pub fn insert(&mut self, word: impl AsRef<[u8]>) -> bool {
+        let word = word.as_ref();
+        if self.contains(word) {
+            return false;
+        }
+        let mut node = 0;
+        self.nodes[node].words += 1;
+        for &byte in word {
+            node = match self.nodes[node].child(byte) {
+                Some(child) => child,
+                None => {
+                    let child = self.nodes.len();
+                    self.nodes.push(Node::default());
+                    self.nodes[node].children.push((byte, child));
+                    child
+                }
+            };
+            self.nodes[node].words += 1;
+        }
+        self.nodes[node].is_word = true;
+        true
+    }
+
+    /// Returns true if and only if `word` is in the trie.
+    pub fn contains(&self, word: impl AsRef<[u8]>) -> bool {
+        self.find(word.as_ref())
+            .is_some_and(|node| self.nodes[node].is_word)
+    }
+
+    /// The number of words in the trie which start with `prefix`, including `prefix` itself if
+    /// it's a word.
+    pub fn count_matches(&self, prefix: impl AsRef<[u8]>) -> usize {
+        self.find(prefix.as_ref())
+            .map_or(0, |node| self.nodes[node].words)
+    }
+
+    /// Gets an iterator over the lengths of the words in the trie which `text` starts with, from
+    /// shortest to longest.
+    pub fn prefix_lengths<'a, T>(&'a self, text: &'a T) -> impl Iterator<Item = usize> + 'a
+    where
+        T: AsRef<[u8]> + ?Sized,
+    {
+        let text = text.as_ref();
+        let mut node = Some(0);
+        // The root is the empty prefix, which is only a word if the empty word was inserted.
+        (0..=text.len()).filter_map(move |len| {
+            let current = node?;
+            node = text
+                .get(len)
+                .and_then(|&byte| self.nodes[current].child(byte));
+            self.nodes[current].is_word.then_some(len)
+        })
+    }
+
+    /// The length of the longest word in the trie which `text` starts with, or `None` if `text`
+    /// doesn't start with any of them.
+    pub fn longest_prefix(&self, text: impl AsRef<[u8]>) -> Option<usize> {
+        self.prefix_lengths(text.as_ref()).last()
+    }
+}
+
+impl<W> Extend<W> for Trie
+where
+    W: AsRef<[u8]>,
+{
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = W>,
+    {
+        for word in iter {
+            self.insert(word);
+        }
+    }
+}
+
+impl<W> FromIterator<W> for Trie
+where
+    W: AsRef<[u8]>,
+{
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = W>,
+    {
+        let mut trie = Self::new();
+        trie.extend(iter);
+        trie
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert() {
+        let mut trie = Trie::new();
+        assert!(trie.is_empty());
+        assert!(trie.insert("car"));
+        assert!(trie.insert("cart"));
+        assert!(trie.insert(b"cat"));
+        assert!(!trie.insert(vec![b'c', b'a', b'r']));
+        assert_eq!(3, trie.len());
+        assert!(trie.contains("cart"));
+        assert!(!trie.contains("ca"));
+        assert!(!trie.contains("carts"));
+        assert_eq!(3, trie.count_matches("ca"));
+        assert_eq!(2, trie.count_matches("car"));
+        assert_eq!(0, trie.count_matches("dog"));
+        assert_eq!(3, trie.count_matches(""));
+    }
+
+    #[test]
+    fn test_prefixes() {
+        let trie = ["H", "He", "Hel", "O"].into_iter().collect::<Trie>();
+        assert_eq!(vec![1, 2], trie.prefix_lengths(b"HeOH").collect::<Vec<_>>());
+        assert_eq!(Some(3), trie.longest_prefix("Hello"));
+        assert_eq!(Some(1), trie.longest_prefix("O"));
+        assert_eq!(None, trie.longest_prefix("e"));
+        assert_eq!(None, trie.longest_prefix(""));
+    }
+
+    #[test]
+    fn test_towels() {
+        // The towels of 2024 day 19, where 6 of the designs can be made in 16 ways in total.
+        let patterns = "r, wr, b, g, bwu, rb, gb, br".split(", ").collect::<Trie>();
+        let ways = |design: &str| {
+            // The number of ways to make the design's suffix which starts at each index.
+            let mut ways = vec![0u64; design.len() + 1];
+            ways[design.len()] = 1;
+            for start in (0..design.len()).rev() {
+                ways[start] = patterns
+                    .prefix_lengths(&design[start..])
+                    .map(|len| ways[start + len])
+                    .sum();
+            }
+            ways[0]
+        };
+        let designs = [
+            "brwrr", "bggr", "gbbr", "rrbgbr", "ubwu", "bwurrg", "brgr", "bbrwb",
+        ];
+        let ways = designs.map(ways);
+        assert_eq!(vec![2, 1, 4, 6, 0, 1, 2, 0], ways.to_vec());
+        assert_eq!(6, ways.iter().filter(|&&ways| ways > 0).count());
+        assert_eq!(16, ways.iter().sum::<u64>());
+    }
+}
@@ -0,0 +1,130 @@
+/// Where the states that come from stepping a state over and over start to repeat.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Cycle {
+    /// The number of steps before the first state which is part of the cycle.
+    pub start: usize,
+    /// The number of steps that it takes to get from any state in the cycle back to itself.
+    pub length: usize,
+}
+
+impl Cycle {
+    /// The fewest steps that reach the same state as `steps` steps do.
+    pub fn reduce(&self, steps: usize) -> usize {
+        if steps < self.start {
+            steps
+        } else {
+            self.start + (steps - self.start) % self.length
+        }
+    }
+}
+
+/// Finds the cycle that the states `initial`, `step(initial)`, `step(step(initial))`, ... fall
+/// into, which there must be for the search to end, such as when there are only finitely many
+/// states.
+///
+/// This is Brent's algorithm, which only needs to compare states and only keeps two of them at
+/// once, so it suits states that are too big to keep every one of or that can't be hashed.
+pub fn find_cycle<S>(initial: S, mut step: impl FnMut(&S) -> S) -> Cycle
+where
+    S: Clone + PartialEq,
+{
+    // Find the length of the cycle by moving the tortoise up to the hare at every power of 2
+    // until the hare comes back around to it.
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+    }
+    // With the hare a whole cycle ahead of the tortoise, they first meet at the start of the cycle.
+    let mut tortoise = initial.clone();
+    let mut hare = initial;
+    for _ in 0..length {
+        hare = step(&hare);
+    }
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+    Cycle { start, length }
+}
+
+/// The state that `steps` steps from `initial` reach, taking a shortcut around the cycle that the
+/// states fall into as found by [`find_cycle()`], so that `steps` can be far too many to take one
+/// at a time.
+pub fn state_after<S>(initial: S, mut step: impl FnMut(&S) -> S, steps: usize) -> S
+where
+    S: Clone + PartialEq,
+{
+    let cycle = find_cycle(initial.clone(), &mut step);
+    let mut state = initial;
+    for _ in 0..cycle.reduce(steps) {
+        state = step(&state);
+    }
+    state
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Goes 0, 1, 2, 3, 4, 2, 3, 4, ...
+    fn step(&state: &u32) -> u32 {
+        if state == 4 {
+            2
+        } else {
+            state + 1
+        }
+    }
+
+    #[test]
+    fn test_find_cycle() {
+        assert_eq!(
+            Cycle {
+                start: 2,
+                length: 3
+            },
+            find_cycle(0, step)
+        );
+        assert_eq!(
+            Cycle {
+                start: 0,
+                length: 3
+            },
+            find_cycle(3, step)
+        );
+        assert_eq!(
+            Cycle {
+                start: 0,
+                length: 1
+            },
+            find_cycle(7, |&state| state)
+        );
+    }
+
+    #[test]
+    fn test_state_after() {
+        assert_eq!(1, state_after(0, step, 1));
+        assert_eq!(4, state_after(0, step, 10));
+        assert_eq!(2, state_after(0, step, 1_000_000_001));
+        let mut steps = 0;
+        state_after(
+            0,
+            |state| {
+                steps += 1;
+                step(state)
+            },
+            usize::MAX,
+        );
+        assert!(steps < 100);
+    }
+}
@@ -0,0 +1,333 @@
+use std::{fmt::Debug, hash::Hash, ops::Add};
+
+use crate::{
+    a_star::{AStarState, Frontier},
+    error::LimitExceeded,
+    limits::Limits,
+    progress::Progress,
+    trace,
+};
+
+/// Runs Dijkstra's algorithm from `initial_state` until it reaches a state for which `is_goal`
+/// returns `true`, and returns the distance to that state. If this function returns `None`, then
+/// no goal can be reached from `initial_state`.
+///
+/// Unlike A*, no heuristic is needed, so this suits puzzles where there's no good way to estimate
+/// how far a state is from the goal. Every distance between neighbors must be at least
+/// `D::default()`.
+///
+/// The search is held to [`Limits::default_limits()`].
+pub fn dijkstra<S, D, G>(initial_state: S, is_goal: G) -> Result<Option<D>, LimitExceeded>
+where
+    S: AStarState<Distance = D> + Clone + Debug + Eq + Hash,
+    D: Add<Output = D> + Clone + Debug + Default + Ord,
+    G: FnMut(&S) -> bool,
+{
+    let path = dijkstra_with_limits(initial_state, is_goal, Limits::default_limits())?;
+    Ok(path.map(|(distance, _)| distance))
+}
+
+/// Like [`dijkstra()`] except that the states along the path that was found are returned along
+/// with its distance, starting with `initial_state` and ending with the goal.
+pub fn dijkstra_for_path<S, D, G>(
+    initial_state: S,
+    is_goal: G,
+) -> Result<Option<(D, Vec<S>)>, LimitExceeded>
+where
+    S: AStarState<Distance = D> + Clone + Debug + Eq + Hash,
+    D: Add<Output = D> + Clone + Debug + Default + Ord,
+    G: FnMut(&S) -> bool,
+{
+    dijkstra_with_limits(initial_state, is_goal, Limits::default_limits())
+}
+
+/// Like [`dijkstra_for_path()`] except that the search is held to `limits`, giving up once it has
+/// expanded more than `limits.max_states` states or the states that it keeps take up more than
+/// about `limits.max_memory` bytes.
+pub fn dijkstra_with_limits<S, D, G>(
+    initial_state: S,
+    is_goal: G,
+    limits: Limits,
+) -> Result<Option<(D, Vec<S>)>, LimitExceeded>
+where
+    S: AStarState<Distance = D> + Clone + Debug + Eq + Hash,
+    D: Add<Output = D> + Clone + Debug + Default + Ord,
+    G: FnMut(&S) -> bool,
+{
+    dijkstra_with_neighbors(initial_state, S::neighbors, is_goal, limits)
+}
+
+/// Like [`dijkstra_with_limits()`] except that the neighbors of each state are given by
+/// `neighbors` instead of [`AStarState::neighbors()`], and are found lazily as the search gets to
+/// them instead of all at once. When states have so many neighbors that collecting them into a
+/// [`Vec`] up front would be too slow, `neighbors` can generate them one at a time, such as with
+/// [`std::iter::from_fn()`] or a coroutine, and the search gives up partway through them once it
+/// has reached too many states to stay within `limits.max_memory`.
+pub fn dijkstra_with_neighbors<S, D, I>(
+    initial_state: S,
+    mut neighbors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+    limits: Limits,
+) -> Result<Option<(D, Vec<S>)>, LimitExceeded>
+where
+    S: Clone + Debug + Eq + Hash,
+    D: Add<Output = D> + Clone + Debug + Default + Ord,
+    I: IntoIterator<Item = (D, S)>,
+{
+    let _span = tracing::info_span!("dijkstra").entered();
+    let mut budget = limits.budget();
+    let mut progress = Progress::new("Expanding states", None);
+    let mut frontier = Frontier::new(initial_state);
+    while let Some((distance, index)) = frontier.pop() {
+        budget.expand()?;
+        progress.inc();
+        budget.check_memory::<(S, D, Option<usize>)>(frontier.len())?;
+        let state = frontier.state(index).clone();
+        trace::event("expand", format_args!("{state:?} at {distance:?}"));
+        if is_goal(&state) {
+            return Ok(Some((distance, frontier.path_to(&state))));
+        }
+        for (step, neighbor) in neighbors(&state) {
+            let improved = frontier.relax(neighbor, distance.clone() + step, index);
+            budget.generate(!improved);
+            budget.check_memory::<(S, D, Option<usize>)>(frontier.len())?;
+        }
+        budget.frontier(frontier.queued());
+    }
+    Ok(None)
+}
+
+/// Like [`dijkstra_for_path()`] except that all of the states which are tied for the shortest
+/// distance are expanded at once, finding their neighbors in parallel on [rayon]'s thread pool.
+/// This is only faster when finding the neighbors of a state takes long enough to outweigh
+/// handing the states out to threads, or when large parts of the search are tied, as on a big
+/// grid where every step costs about the same.
+///
+/// When more than one goal is tied for the shortest distance, the one which was reached first is
+/// returned, just as in [`dijkstra_for_path()`].
+///
+/// [rayon]: https://docs.rs/rayon
+#[cfg(feature = "parallel")]
+pub fn par_dijkstra<S, D, G>(
+    initial_state: S,
+    is_goal: G,
+) -> Result<Option<(D, Vec<S>)>, LimitExceeded>
+where
+    S: AStarState<Distance = D> + Clone + Debug + Eq + Hash + Send + Sync,
+    D: Add<Output = D> + Clone + Debug + Default + Ord + Send,
+    G: Fn(&S) -> bool + Sync,
+{
+    par_dijkstra_with_limits(initial_state, is_goal, Limits::default_limits())
+}
+
+/// Like [`par_dijkstra()`] except that the search is held to `limits` as in
+/// [`dijkstra_with_limits()`].
+#[cfg(feature = "parallel")]
+pub fn par_dijkstra_with_limits<S, D, G>(
+    initial_state: S,
+    is_goal: G,
+    limits: Limits,
+) -> Result<Option<(D, Vec<S>)>, LimitExceeded>
+where
+    S: AStarState<Distance = D> + Clone + Debug + Eq + Hash + Send + Sync,
+    D: Add<Output = D> + Clone + Debug + Default + Ord + Send,
+    G: Fn(&S) -> bool + Sync,
+{
+    use rayon::prelude::*;
+
+    let _span = tracing::info_span!("par_dijkstra").entered();
+    let mut budget = limits.budget();
+    let mut progress = Progress::new("Expanding states", None);
+    let mut frontier = Frontier::new(initial_state);
+    while let Some((distance, indices)) = frontier.pop_tied() {
+        for _ in &indices {
+            budget.expand()?;
+        }
+        progress.set(progress.done() + indices.len() as u64);
+        budget.check_memory::<(S, D, Option<usize>)>(frontier.len())?;
+        let states = indices
+            .iter()
+            .map(|&index| frontier.state(index))
+            .collect::<Vec<_>>();
+        for state in &states {
+            trace::event("expand", format_args!("{state:?} at {distance:?}"));
+        }
+        let expanded = states
+            .par_iter()
+            .map(|&state| {
+                if is_goal(state) {
+                    None
+                } else {
+                    Some(state.neighbors())
+                }
+            })
+            .collect::<Vec<_>>();
+        if let Some(goal) = expanded.iter().position(Option::is_none) {
+            let goal = states[goal].clone();
+            return Ok(Some((distance, frontier.path_to(&goal))));
+        }
+        for (index, neighbors) in indices.into_iter().zip(expanded) {
+            for (step, neighbor) in neighbors.into_iter().flatten() {
+                let improved = frontier.relax(neighbor, distance.clone() + step, index);
+                budget.generate(!improved);
+            }
+        }
+        budget.frontier(frontier.queued());
+    }
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::error::Limit;
+
+    /// The example cave from 2021 day 15, where the risk of entering each position is its digit.
+    const CAVE: &str = "\
+        1163751742\n\
+        1381373672\n\
+        2136511328\n\
+        3694931569\n\
+        7463417111\n\
+        1319128137\n\
+        1359912421\n\
+        3125421639\n\
+        1293138521\n\
+        2311944581\n";
+
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+    struct Position<'grid> {
+        grid: &'grid [Vec<u32>],
+        x: usize,
+        y: usize,
+    }
+
+    impl<'grid> Position<'grid> {
+        /// The top left corner of `grid`.
+        fn start(grid: &'grid [Vec<u32>]) -> Self {
+            Self { grid, x: 0, y: 0 }
+        }
+    }
+
+    impl AStarState for Position<'_> {
+        type Distance = u32;
+
+        fn neighbors(&self) -> Vec<(Self::Distance, Self)> {
+            let (x, y) = (self.x, self.y);
+            [
+                (x.wrapping_sub(1), y),
+                (x + 1, y),
+                (x, y.wrapping_sub(1)),
+                (x, y + 1),
+            ]
+            .into_iter()
+            .filter_map(|(x, y)| {
+                let risk = *self.grid.get(y)?.get(x)?;
+                Some((risk, Self { x, y, ..*self }))
+            })
+            .collect()
+        }
+    }
+
+    fn parse(grid: &str) -> Vec<Vec<u32>> {
+        grid.lines()
+            .map(|line| line.chars().map(|c| c.to_digit(10).unwrap()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let grid = parse(CAVE);
+        let start = Position::start(&grid);
+        let is_exit = |position: &Position<'_>| (position.x, position.y) == (9, 9);
+        assert_eq!(Ok(Some(40)), dijkstra(start, is_exit));
+        let (distance, path) = dijkstra_for_path(start, is_exit).unwrap().unwrap();
+        assert_eq!(40, distance);
+        assert_eq!(start, path[0]);
+        assert_eq!((9, 9), (path[path.len() - 1].x, path[path.len() - 1].y));
+        let risk = path[1..]
+            .iter()
+            .map(|position| grid[position.y][position.x])
+            .sum::<u32>();
+        assert_eq!(40, risk);
+    }
+
+    #[test]
+    fn test_goals() {
+        let grid = parse("19\n91\n");
+        let start = Position::start(&grid);
+        assert_eq!(Ok(None), dijkstra(start, |position| position.x == 2));
+        let is_corner = |position: &Position<'_>| (position.x, position.y) == (1, 1);
+        assert_eq!(Ok(Some(10)), dijkstra(start, is_corner));
+    }
+
+    #[test]
+    fn test_stats() {
+        use crate::limits;
+
+        let grid = parse("19\n91\n");
+        let start = Position::start(&grid);
+        assert_eq!(Ok(None), dijkstra(start, |_| false));
+        let stats = limits::last_stats().unwrap();
+        assert_eq!(4, stats.expanded);
+        // Each of the four corners has two neighbors, but only the first route that's found to
+        // each of the other three corners is kept.
+        assert_eq!(8, stats.generated);
+        assert_eq!(5, stats.duplicates);
+        assert_eq!(2, stats.max_frontier);
+    }
+
+    #[test]
+    fn test_lazy_neighbors() {
+        // From each number, either add 1 for 1 or double it for 3, without collecting the moves.
+        let neighbors = |&n: &u32| [(1, n + 1), (3, 2 * n)].into_iter();
+        let (distance, path) = dijkstra_with_neighbors(1, neighbors, |&n| n == 10, Limits::NONE)
+            .unwrap()
+            .unwrap();
+        assert_eq!(7, distance);
+        assert_eq!(vec![1, 2, 3, 4, 5, 10], path);
+        // Every number leads to infinitely many others, so only a limit on memory can stop the
+        // search partway through the neighbors of the very first number.
+        let neighbors = |&n: &u64| (1..).map(move |step| (step, n + step));
+        let limits = Limits::NONE.with_max_memory(1 << 10);
+        let result = dijkstra_with_neighbors(0, neighbors, |_| false, limits);
+        assert_eq!(
+            Some(Limit::Memory),
+            result.err().map(|exceeded| exceeded.limit)
+        );
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_par_dijkstra() {
+        let grid = parse(CAVE);
+        let start = Position::start(&grid);
+        let is_exit = |position: &Position<'_>| (position.x, position.y) == (9, 9);
+        let (distance, path) = par_dijkstra(start, is_exit).unwrap().unwrap();
+        assert_eq!(40, distance);
+        assert_eq!(start, path[0]);
+        assert_eq!(
+            Ok(Some(distance)),
+            dijkstra(start, is_exit),
+            "The parallel search should agree with the sequential one"
+        );
+        assert_eq!(
+            Ok(None),
+            par_dijkstra(start, |position: &Position<'_>| position.x == 10)
+        );
+    }
+
+    #[test]
+    fn test_limits() {
+        let grid = parse(CAVE);
+        let start = Position::start(&grid);
+        let limits = Limits::NONE.with_max_states(10);
+        let result = dijkstra_with_limits(start, |position| position.x == 9, limits);
+        let exceeded = LimitExceeded {
+            limit: Limit::States,
+            max: 10,
+        };
+        assert_eq!(Err(exceeded), result);
+    }
+}
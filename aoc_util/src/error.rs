@@ -0,0 +1,272 @@
+use std::{
+    any::Any,
+    error,
+    fmt::{self, Display, Formatter},
+    io,
+    num::ParseIntError,
+};
+
+/// The place in the puzzle input that a parse error was found at.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Location {
+    /// The 1-based number of the line.
+    pub line: usize,
+    /// The 1-based number of the column, if it's known.
+    pub column: Option<usize>,
+}
+
+impl Display for Location {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.column {
+            Some(column) => write!(f, "line {}, column {column}", self.line),
+            None => write!(f, "line {}", self.line),
+        }
+    }
+}
+
+/// The resources which a search can be limited to using.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Limit {
+    /// The number of states that are expanded.
+    States,
+    /// The depth of recursion.
+    Depth,
+    /// The estimated number of bytes that the states take up.
+    Memory,
+}
+
+/// A search gave up because it used more of a resource than it was allowed to.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct LimitExceeded {
+    /// The resource that ran out.
+    pub limit: Limit,
+    /// How much of the resource was allowed.
+    pub max: usize,
+}
+
+impl Display for LimitExceeded {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.limit {
+            Limit::States => write!(f, "Gave up after expanding {} states", self.max),
+            Limit::Depth => write!(f, "Gave up after recursing {} levels deep", self.max),
+            Limit::Memory => write!(f, "Gave up after using about {} bytes", self.max),
+        }
+    }
+}
+
+impl error::Error for LimitExceeded {}
+
+/// The ways that solving a puzzle can fail.
+#[derive(Debug)]
+pub enum Error {
+    /// The puzzle input doesn't have the expected format.
+    Parse {
+        /// Where the problem was found, if it's known.
+        location: Option<Location>,
+        /// What the problem is.
+        message: String,
+    },
+    /// The puzzle input is well-formed but has no answer, such as a maze without an exit.
+    UnsolvableInput(String),
+    /// The solution hasn't been written yet.
+    Unimplemented {
+        /// The year that the puzzle was released in.
+        year: u32,
+        /// The day of December that the puzzle was released on.
+        day: u32,
+        /// The part of the puzzle which hasn't been solved, or `None` if neither has.
+        part: Option<u32>,
+    },
+    /// A search used more of a resource than it was allowed to.
+    LimitExceeded(LimitExceeded),
+    /// The solver panicked instead of returning an answer or an error.
+    Panicked {
+        /// The part of the puzzle which was being solved, or `None` if the input was being parsed
+        /// or the day was solving both parts at once.
+        part: Option<u32>,
+        /// The message that the solver panicked with.
+        message: String,
+    },
+    /// Reading the puzzle input or writing the answer failed.
+    Io(io::Error),
+}
+
+impl Error {
+    /// A parse error at an unknown place in the input.
+    pub fn parse(message: impl Into<String>) -> Self {
+        Self::Parse {
+            location: None,
+            message: message.into(),
+        }
+    }
+
+    /// A parse error on the 1-based line `line` of the input.
+    pub fn parse_at(line: usize, message: impl Into<String>) -> Self {
+        Self::Parse {
+            location: Some(Location { line, column: None }),
+            message: message.into(),
+        }
+    }
+
+    /// Adds the 1-based line `line` to the location of a parse error which doesn't have one yet.
+    /// Other errors are returned unchanged.
+    pub fn on_line(self, line: usize) -> Self {
+        match self {
+            Self::Parse {
+                location: None,
+                message,
+            } => Self::parse_at(line, message),
+            e => e,
+        }
+    }
+
+    /// The error for a solver which panicked with the payload `payload` while solving part `part`,
+    /// as caught by [`std::panic::catch_unwind()`].
+    pub fn panicked(part: Option<u32>, payload: &(dyn Any + Send)) -> Self {
+        let message = match payload.downcast_ref::<&str>() {
+            Some(message) => message.to_string(),
+            None => match payload.downcast_ref::<String>() {
+                Some(message) => message.clone(),
+                None => "<non-string payload>".to_string(),
+            },
+        };
+        Self::Panicked { part, message }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Parse {
+                location: Some(location),
+                message,
+            } => write!(f, "Parse error at {location}: {message}"),
+            Self::Parse {
+                location: None,
+                message,
+            } => write!(f, "Parse error: {message}"),
+            Self::UnsolvableInput(message) => write!(f, "Unsolvable input: {message}"),
+            Self::Unimplemented {
+                year,
+                day,
+                part: Some(part),
+            } => write!(
+                f,
+                "Year {year} day {day} part {part} hasn't been solved yet"
+            ),
+            Self::Unimplemented {
+                year,
+                day,
+                part: None,
+            } => write!(f, "Year {year} day {day} hasn't been solved yet"),
+            Self::LimitExceeded(e) => e.fmt(f),
+            Self::Panicked {
+                part: Some(part),
+                message,
+            } => write!(f, "Part {part} panicked: {message}"),
+            Self::Panicked {
+                part: None,
+                message,
+            } => write!(f, "Panicked: {message}"),
+            Self::Io(e) => e.fmt(f),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::LimitExceeded(e) => Some(e),
+            Self::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+impl From<LimitExceeded> for Error {
+    fn from(e: LimitExceeded) -> Self {
+        Self::LimitExceeded(e)
+    }
+}
+
+impl From<ParseIntError> for Error {
+    fn from(e: ParseIntError) -> Self {
+        Self::parse(e.to_string())
+    }
+}
+
+impl From<LimitExceeded> for io::Error {
+    fn from(e: LimitExceeded) -> Self {
+        Error::from(e).into()
+    }
+}
+
+/// Keeps the original error so that callers can recover it with [`io::Error::get_ref()`].
+impl From<Error> for io::Error {
+    fn from(e: Error) -> Self {
+        let kind = match e {
+            Error::Parse { .. } => io::ErrorKind::InvalidData,
+            Error::UnsolvableInput(_) => io::ErrorKind::InvalidInput,
+            Error::Unimplemented { .. } => io::ErrorKind::Unsupported,
+            Error::LimitExceeded(LimitExceeded {
+                limit: Limit::Memory,
+                ..
+            }) => io::ErrorKind::OutOfMemory,
+            Error::LimitExceeded(_) | Error::Panicked { .. } => io::ErrorKind::Other,
+            Error::Io(e) => return e,
+        };
+        io::Error::new(kind, e)
+    }
+}
+
+/// The result of solving a puzzle.
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let e = Error::parse("Missing comma").on_line(3);
+        assert_eq!("Parse error at line 3: Missing comma", e.to_string());
+        let e = Error::from("x".parse::<u32>().unwrap_err());
+        assert_eq!("Parse error: invalid digit found in string", e.to_string());
+        let e = Error::Unimplemented {
+            year: 2022,
+            day: 22,
+            part: Some(2),
+        };
+        assert_eq!(
+            "Year 2022 day 22 part 2 hasn't been solved yet",
+            e.to_string()
+        );
+        let e = Error::from(LimitExceeded {
+            limit: Limit::States,
+            max: 1000,
+        });
+        assert_eq!("Gave up after expanding 1000 states", e.to_string());
+        let payload = std::panic::catch_unwind(|| panic!("Bad {}", "input")).unwrap_err();
+        let e = Error::panicked(Some(1), payload.as_ref());
+        assert_eq!("Part 1 panicked: Bad input", e.to_string());
+        let e = Error::panicked(None, &"Unreachable");
+        assert_eq!("Panicked: Unreachable", e.to_string());
+    }
+
+    #[test]
+    fn test_into_io_error() {
+        let e = io::Error::from(Error::UnsolvableInput("No exit".to_string()));
+        assert_eq!(io::ErrorKind::InvalidInput, e.kind());
+        let inner = e.get_ref().and_then(|e| e.downcast_ref::<Error>());
+        assert!(matches!(inner, Some(Error::UnsolvableInput(_))));
+        let e = io::Error::from(Error::Io(io::ErrorKind::NotFound.into()));
+        assert_eq!(io::ErrorKind::NotFound, e.kind());
+        assert!(e.get_ref().is_none());
+    }
+}
@@ -1,8 +1,12 @@
 use super::Point2D;
-use std::ops::{Add, AddAssign, Neg, Sub, SubAssign};
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+    ops::{Add, AddAssign, Neg, Sub, SubAssign},
+};
 
 /// A direction in 2-dimensional space.
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub enum Direction {
     #[allow(missing_docs)]
     Down,
@@ -15,9 +19,80 @@ pub enum Direction {
 }
 
 impl Direction {
+    /// All directions.
+    pub const ALL: [Self; 4] = [Self::Down, Self::Left, Self::Right, Self::Up];
+
     /// All directions.
     pub const fn values() -> &'static [Self] {
-        &[Self::Down, Self::Left, Self::Right, Self::Up]
+        &Self::ALL
+    }
+
+    /// The direction a quarter turn counterclockwise from this one.
+    pub const fn turn_left(self) -> Self {
+        match self {
+            Self::Down => Self::Right,
+            Self::Left => Self::Down,
+            Self::Right => Self::Up,
+            Self::Up => Self::Left,
+        }
+    }
+
+    /// The direction a quarter turn clockwise from this one.
+    pub const fn turn_right(self) -> Self {
+        match self {
+            Self::Down => Self::Left,
+            Self::Left => Self::Up,
+            Self::Right => Self::Down,
+            Self::Up => Self::Right,
+        }
+    }
+
+    /// The opposite direction.
+    pub const fn reverse(self) -> Self {
+        match self {
+            Self::Down => Self::Up,
+            Self::Left => Self::Right,
+            Self::Right => Self::Left,
+            Self::Up => Self::Down,
+        }
+    }
+
+    /// The step which moves one unit in this direction, with the y-axis pointing up.
+    pub const fn delta(self) -> Point2D<i64> {
+        match self {
+            Self::Down => Point2D::at(0, -1),
+            Self::Left => Point2D::at(-1, 0),
+            Self::Right => Point2D::at(1, 0),
+            Self::Up => Point2D::at(0, 1),
+        }
+    }
+}
+
+/// The error from parsing a character which doesn't name a direction.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ParseDirectionError(pub char);
+
+impl Display for ParseDirectionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} is not a direction", self.0)
+    }
+}
+
+impl Error for ParseDirectionError {}
+
+impl TryFrom<char> for Direction {
+    type Error = ParseDirectionError;
+
+    /// Parses a direction from its initial, as in `U`, `D`, `L`, and `R`, or from an arrow, as in
+    /// `^`, `v`, `<`, and `>`.
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'D' | 'v' => Ok(Self::Down),
+            'L' | '<' => Ok(Self::Left),
+            'R' | '>' => Ok(Self::Right),
+            'U' | '^' => Ok(Self::Up),
+            _ => Err(ParseDirectionError(c)),
+        }
     }
 }
 
@@ -57,11 +132,138 @@ impl Neg for Direction {
     type Output = Direction;
 
     fn neg(self) -> Self::Output {
+        self.reverse()
+    }
+}
+
+/// One of the eight directions to the cells around a cell of a grid, including the diagonals.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Direction8 {
+    #[allow(missing_docs)]
+    Up,
+    #[allow(missing_docs)]
+    UpRight,
+    #[allow(missing_docs)]
+    Right,
+    #[allow(missing_docs)]
+    DownRight,
+    #[allow(missing_docs)]
+    Down,
+    #[allow(missing_docs)]
+    DownLeft,
+    #[allow(missing_docs)]
+    Left,
+    #[allow(missing_docs)]
+    UpLeft,
+}
+
+impl Direction8 {
+    /// All eight directions, clockwise from [`Direction8::Up`].
+    pub const ALL: [Self; 8] = [
+        Self::Up,
+        Self::UpRight,
+        Self::Right,
+        Self::DownRight,
+        Self::Down,
+        Self::DownLeft,
+        Self::Left,
+        Self::UpLeft,
+    ];
+
+    /// The direction `eighths` eighths of a turn clockwise from this one.
+    const fn rotate(self, eighths: usize) -> Self {
+        Self::ALL[(self as usize + eighths) % 8]
+    }
+
+    /// The direction an eighth of a turn counterclockwise from this one.
+    pub const fn turn_left(self) -> Self {
+        self.rotate(7)
+    }
+
+    /// The direction an eighth of a turn clockwise from this one.
+    pub const fn turn_right(self) -> Self {
+        self.rotate(1)
+    }
+
+    /// The opposite direction.
+    pub const fn reverse(self) -> Self {
+        self.rotate(4)
+    }
+
+    /// Returns true if and only if this direction is diagonal.
+    pub const fn is_diagonal(self) -> bool {
+        self as usize % 2 == 1
+    }
+
+    /// The step which moves one unit in this direction along each axis that it moves along, with
+    /// the y-axis pointing up.
+    pub const fn delta(self) -> Point2D<i64> {
         match self {
-            Self::Down => Self::Up,
-            Self::Left => Self::Right,
-            Self::Right => Self::Left,
-            Self::Up => Self::Down,
+            Self::Up => Point2D::at(0, 1),
+            Self::UpRight => Point2D::at(1, 1),
+            Self::Right => Point2D::at(1, 0),
+            Self::DownRight => Point2D::at(1, -1),
+            Self::Down => Point2D::at(0, -1),
+            Self::DownLeft => Point2D::at(-1, -1),
+            Self::Left => Point2D::at(-1, 0),
+            Self::UpLeft => Point2D::at(-1, 1),
+        }
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Down => Self::Down,
+            Direction::Left => Self::Left,
+            Direction::Right => Self::Right,
+            Direction::Up => Self::Up,
+        }
+    }
+}
+
+impl Neg for Direction8 {
+    type Output = Direction8;
+
+    fn neg(self) -> Self::Output {
+        self.reverse()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_turns() {
+        for direction in Direction::ALL {
+            assert_eq!(direction, direction.turn_left().turn_right());
+            assert_eq!(direction.reverse(), direction.turn_left().turn_left());
+            assert_eq!(-direction.delta(), direction.reverse().delta());
+            assert_eq!(
+                direction.delta().rotate_left(),
+                direction.turn_left().delta()
+            );
+            let diagonal = Direction8::from(direction).turn_right();
+            assert!(diagonal.is_diagonal());
+            assert_eq!(
+                direction.delta() + direction.turn_right().delta(),
+                diagonal.delta()
+            );
         }
+        assert_eq!(Direction8::UpLeft, Direction8::Up.turn_left());
+        assert_eq!(Direction8::DownRight, -Direction8::UpLeft);
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Ok(Direction::Up), Direction::try_from('U'));
+        assert_eq!(Ok(Direction::Up), Direction::try_from('^'));
+        assert_eq!(Ok(Direction::Left), Direction::try_from('<'));
+        assert_eq!(Ok(Direction::Down), Direction::try_from('v'));
+        assert_eq!(
+            "'x' is not a direction",
+            Direction::try_from('x').unwrap_err().to_string()
+        );
     }
 }
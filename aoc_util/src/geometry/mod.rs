@@ -1,7 +1,23 @@
 /// Locations in n-dimensional space.
 pub mod point;
-pub use point::Point2D;
+pub use point::{Point, Point2D, Point3D};
 
 /// Directions in 2-dimensional space.
 pub mod direction;
-pub use direction::Direction;
+pub use direction::{Direction, Direction8};
+
+/// Line segments between points.
+pub mod segment;
+pub use segment::Segment;
+
+/// Closed shapes made of line segments.
+pub mod polygon;
+pub use polygon::Polygon;
+
+/// Rays cast across grids, for finding what can be seen from a cell.
+pub mod ray;
+pub use ray::cast_ray;
+
+/// Rotations and reflections of points and grids.
+pub mod transform;
+pub use transform::Orientation;
@@ -1,4 +1,7 @@
-use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use std::{
+    cmp::Ordering,
+    ops::{Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign},
+};
 
 /// A 2-dimensional point.
 #[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
@@ -24,7 +27,7 @@ impl<T> Point2D<T> {
     }
 }
 
-macro_rules! impl_manhattan_distance_const {
+macro_rules! impl_distances_const {
     ($($t:ty)+) => ($(
         impl Point2D<$t> {
             /// Calculates the sum of the distance between the x-coordinates and the distance
@@ -32,16 +35,32 @@ macro_rules! impl_manhattan_distance_const {
             pub const fn manhattan_distance(&self, rhs: &Self) -> $t {
                 (self.x.abs_diff(rhs.x) + self.y.abs_diff(rhs.y)) as $t
             }
+
+            /// Calculates the greater of the distance between the x-coordinates and the distance
+            /// between the y-coordinates, which is the number of moves that a king needs.
+            pub const fn chebyshev_distance(&self, rhs: &Self) -> $t {
+                let dx = self.x.abs_diff(rhs.x);
+                let dy = self.y.abs_diff(rhs.y);
+                (if dx < dy { dy } else { dx }) as $t
+            }
+
+            /// Calculates the square of the straight-line distance between the points, which can
+            /// be compared without taking square roots.
+            pub const fn euclidean_distance_sq(&self, rhs: &Self) -> $t {
+                let dx = self.x.abs_diff(rhs.x);
+                let dy = self.y.abs_diff(rhs.y);
+                (dx * dx + dy * dy) as $t
+            }
         }
     )+)
 }
 
-impl_manhattan_distance_const!(
+impl_distances_const!(
     u8 u16 u32 u64 u128 usize
     i8 i16 i32 i64 i128 isize
 );
 
-macro_rules! impl_manhattan_distance {
+macro_rules! impl_distances {
     ($($t:ty)+) => ($(
         impl Point2D<$t> {
             /// Calculates the sum of the distance between the x-coordinates and the distance
@@ -49,11 +68,134 @@ macro_rules! impl_manhattan_distance {
             pub fn manhattan_distance(&self, rhs: &Self) -> $t {
                 (self.x - rhs.x).abs() + (self.y - rhs.y).abs()
             }
+
+            /// Calculates the greater of the distance between the x-coordinates and the distance
+            /// between the y-coordinates, which is the number of moves that a king needs.
+            pub fn chebyshev_distance(&self, rhs: &Self) -> $t {
+                (self.x - rhs.x).abs().max((self.y - rhs.y).abs())
+            }
+
+            /// Calculates the square of the straight-line distance between the points, which can
+            /// be compared without taking square roots.
+            pub fn euclidean_distance_sq(&self, rhs: &Self) -> $t {
+                (self.x - rhs.x).powi(2) + (self.y - rhs.y).powi(2)
+            }
+        }
+    )+)
+}
+
+impl_distances!(f32 f64);
+
+impl<T> Point2D<T> {
+    /// Converts each coordinate to the type `U`.
+    pub fn cast<U>(self) -> Point2D<U>
+    where
+        T: Into<U>,
+    {
+        Point2D::at(self.x.into(), self.y.into())
+    }
+
+    /// Converts each coordinate to the type `U`, such as from the signed coordinates that moves
+    /// are calculated in to the unsigned ones that a grid is indexed by. Fails if either
+    /// coordinate doesn't fit in a `U`.
+    pub fn try_cast<U>(self) -> Result<Point2D<U>, T::Error>
+    where
+        T: TryInto<U>,
+    {
+        Ok(Point2D::at(self.x.try_into()?, self.y.try_into()?))
+    }
+}
+
+impl<T> Point2D<T>
+where
+    T: Copy + Add<Output = T> + Mul<Output = T>,
+{
+    /// Calculates the dot product of the two points as vectors from the origin.
+    pub fn dot(&self, rhs: &Self) -> T {
+        self.x * rhs.x + self.y * rhs.y
+    }
+}
+
+macro_rules! impl_signum {
+    ($($t:ty)+) => ($(
+        impl Point2D<$t> {
+            /// The point whose coordinates are the signs of this point's coordinates, which is the
+            /// step to take to move toward this point along each axis.
+            pub fn signum(&self) -> Self {
+                Self::at(self.x.signum(), self.y.signum())
+            }
+        }
+    )+)
+}
+
+impl_signum!(i8 i16 i32 i64 i128 isize f32 f64);
+
+macro_rules! impl_angles {
+    ($($t:ty)+) => ($(
+        impl Point2D<$t> {
+            /// Divides both coordinates by their greatest common divisor, which gives the smallest
+            /// step in the same direction as this point that lands on integer coordinates, such
+            /// as to tell which asteroids of 2019 day 10 are hidden behind each other. The origin
+            /// is left as it is.
+            pub fn reduced(&self) -> Self {
+                let (mut a, mut b) = (self.x.unsigned_abs(), self.y.unsigned_abs());
+                while b != 0 {
+                    (a, b) = (b, a % b);
+                }
+                if a == 0 {
+                    *self
+                } else {
+                    Self::at(self.x / a as $t, self.y / a as $t)
+                }
+            }
+
+            /// Compares the angles of the points as directions from the origin, going clockwise
+            /// from straight up, with the y-axis pointing up as it does for [`Direction::Up`].
+            /// Points in exactly the same direction are equal, however far they are from the
+            /// origin. The angles are compared exactly, without converting them to floating
+            /// point, so that directions which are very close together are never mixed up.
+            ///
+            /// For grids whose y-axis points down, such as the asteroid map of 2019 day 10,
+            /// compare the points [reflected](Self::reflect_y) across the x-axis instead.
+            ///
+            /// The origin isn't a direction, so where it's ordered is unspecified.
+            ///
+            /// [`Direction::Up`]: super::Direction::Up
+            pub fn clockwise_cmp(&self, other: &Self) -> Ordering {
+                // The first half-turn from straight up to just before straight down is ordered
+                // before the second, and within each half, the cross product tells whether one
+                // direction is clockwise of the other.
+                let half = |point: &Self| !(point.x > 0 || (point.x == 0 && point.y > 0));
+                half(self)
+                    .cmp(&half(other))
+                    .then_with(|| (self.x * other.y).cmp(&(self.y * other.x)))
+            }
+        }
+    )+)
+}
+
+impl_angles!(i8 i16 i32 i64 i128 isize);
+
+macro_rules! impl_checked_add_signed {
+    ($($t:ty: $signed:ty)+) => ($(
+        impl Point2D<$t> {
+            /// Moves the point by `offset`, or returns `None` if that would move it below 0 or
+            /// past the greatest value along either axis, such as when stepping off the edge of a
+            /// grid.
+            pub const fn checked_add_signed(&self, offset: Point2D<$signed>) -> Option<Self> {
+                match (
+                    self.x.checked_add_signed(offset.x),
+                    self.y.checked_add_signed(offset.y),
+                ) {
+                    (Some(x), Some(y)) => Some(Self::at(x, y)),
+                    _ => None,
+                }
+            }
         }
     )+)
 }
 
-impl_manhattan_distance!(f32 f64);
+impl_checked_add_signed!(u8: i8 u16: i16 u32: i32 u64: i64 u128: i128 usize: isize);
 
 impl<T, U, V> Add<Point2D<U>> for Point2D<T>
 where
@@ -521,3 +663,1098 @@ where
         self.y -= &mut other.y;
     }
 }
+
+/// A 3-dimensional point.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Point3D<T> {
+    x: T,
+    y: T,
+    z: T,
+}
+
+impl<T> Point3D<T> {
+    /// Creates a new point with the given coordinates.
+    pub const fn at(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+
+    /// The x-coordinate of the point.
+    pub const fn x(&self) -> &T {
+        &self.x
+    }
+
+    /// The y-coordinate of the point.
+    pub const fn y(&self) -> &T {
+        &self.y
+    }
+
+    /// The z-coordinate of the point.
+    pub const fn z(&self) -> &T {
+        &self.z
+    }
+}
+
+impl<T> Point3D<T>
+where
+    T: Copy + Add<Output = T> + Mul<Output = T> + Sub<Output = T>,
+{
+    /// Calculates the dot product of the two points as vectors from the origin.
+    pub fn dot(&self, rhs: &Self) -> T {
+        self.x * rhs.x + self.y * rhs.y + self.z * rhs.z
+    }
+
+    /// Calculates the cross product of the two points as vectors from the origin, which is
+    /// perpendicular to both of them.
+    pub fn cross(&self, rhs: &Self) -> Self {
+        Self::at(
+            self.y * rhs.z - self.z * rhs.y,
+            self.z * rhs.x - self.x * rhs.z,
+            self.x * rhs.y - self.y * rhs.x,
+        )
+    }
+}
+
+impl<T> Point3D<T>
+where
+    T: Copy + PartialOrd,
+{
+    /// The point whose coordinates are each the lesser of the two points' coordinates, such as
+    /// the least corner of a box which contains both points.
+    pub fn min(&self, rhs: &Self) -> Self {
+        let min = |a: T, b: T| if b < a { b } else { a };
+        Self::at(min(self.x, rhs.x), min(self.y, rhs.y), min(self.z, rhs.z))
+    }
+
+    /// The point whose coordinates are each the greater of the two points' coordinates, such as
+    /// the greatest corner of a box which contains both points.
+    pub fn max(&self, rhs: &Self) -> Self {
+        let max = |a: T, b: T| if b > a { b } else { a };
+        Self::at(max(self.x, rhs.x), max(self.y, rhs.y), max(self.z, rhs.z))
+    }
+}
+
+macro_rules! impl_distances_const_3d {
+    ($($t:ty)+) => ($(
+        impl Point3D<$t> {
+            /// Calculates the sum of the distances between the x-coordinates, the y-coordinates,
+            /// and the z-coordinates.
+            pub const fn manhattan_distance(&self, rhs: &Self) -> $t {
+                (self.x.abs_diff(rhs.x) + self.y.abs_diff(rhs.y) + self.z.abs_diff(rhs.z)) as $t
+            }
+
+            /// Calculates the greatest of the distances between the x-coordinates, the
+            /// y-coordinates, and the z-coordinates.
+            pub const fn chebyshev_distance(&self, rhs: &Self) -> $t {
+                let dx = self.x.abs_diff(rhs.x);
+                let dy = self.y.abs_diff(rhs.y);
+                let dz = self.z.abs_diff(rhs.z);
+                let dxy = if dx < dy { dy } else { dx };
+                (if dxy < dz { dz } else { dxy }) as $t
+            }
+
+            /// Calculates the square of the straight-line distance between the points, which can
+            /// be compared without taking square roots.
+            pub const fn euclidean_distance_sq(&self, rhs: &Self) -> $t {
+                let dx = self.x.abs_diff(rhs.x);
+                let dy = self.y.abs_diff(rhs.y);
+                let dz = self.z.abs_diff(rhs.z);
+                (dx * dx + dy * dy + dz * dz) as $t
+            }
+        }
+    )+)
+}
+
+impl_distances_const_3d!(
+    u8 u16 u32 u64 u128 usize
+    i8 i16 i32 i64 i128 isize
+);
+
+macro_rules! impl_distances_3d {
+    ($($t:ty)+) => ($(
+        impl Point3D<$t> {
+            /// Calculates the sum of the distances between the x-coordinates, the y-coordinates,
+            /// and the z-coordinates.
+            pub fn manhattan_distance(&self, rhs: &Self) -> $t {
+                (self.x - rhs.x).abs() + (self.y - rhs.y).abs() + (self.z - rhs.z).abs()
+            }
+
+            /// Calculates the greatest of the distances between the x-coordinates, the
+            /// y-coordinates, and the z-coordinates.
+            pub fn chebyshev_distance(&self, rhs: &Self) -> $t {
+                (self.x - rhs.x)
+                    .abs()
+                    .max((self.y - rhs.y).abs())
+                    .max((self.z - rhs.z).abs())
+            }
+
+            /// Calculates the square of the straight-line distance between the points, which can
+            /// be compared without taking square roots.
+            pub fn euclidean_distance_sq(&self, rhs: &Self) -> $t {
+                (self.x - rhs.x).powi(2) + (self.y - rhs.y).powi(2) + (self.z - rhs.z).powi(2)
+            }
+        }
+    )+)
+}
+
+impl_distances_3d!(f32 f64);
+
+impl<T, U, V> Add<Point3D<U>> for Point3D<T>
+where
+    T: Add<U, Output = V>,
+{
+    type Output = Point3D<V>;
+
+    fn add(self, other: Point3D<U>) -> Self::Output {
+        Point3D::at(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl<'a, T, U, V> Add<&'a Point3D<U>> for Point3D<T>
+where
+    T: Add<&'a U, Output = V>,
+{
+    type Output = Point3D<V>;
+
+    fn add(self, other: &'a Point3D<U>) -> Self::Output {
+        Point3D::at(self.x + &other.x, self.y + &other.y, self.z + &other.z)
+    }
+}
+
+impl<'a, T, U, V> Add<&'a mut Point3D<U>> for Point3D<T>
+where
+    T: Add<&'a mut U, Output = V>,
+{
+    type Output = Point3D<V>;
+
+    fn add(self, other: &'a mut Point3D<U>) -> Self::Output {
+        Point3D::at(
+            self.x + &mut other.x,
+            self.y + &mut other.y,
+            self.z + &mut other.z,
+        )
+    }
+}
+
+impl<'a, T, U, V> Add<Point3D<U>> for &'a Point3D<T>
+where
+    &'a T: Add<U, Output = V>,
+{
+    type Output = Point3D<V>;
+
+    fn add(self, other: Point3D<U>) -> Self::Output {
+        Point3D::at(&self.x + other.x, &self.y + other.y, &self.z + other.z)
+    }
+}
+
+impl<'a, 'b, T, U, V> Add<&'b Point3D<U>> for &'a Point3D<T>
+where
+    &'a T: Add<&'b U, Output = V>,
+{
+    type Output = Point3D<V>;
+
+    fn add(self, other: &'b Point3D<U>) -> Self::Output {
+        Point3D::at(&self.x + &other.x, &self.y + &other.y, &self.z + &other.z)
+    }
+}
+
+impl<'a, 'b, T, U, V> Add<&'b mut Point3D<U>> for &'a Point3D<T>
+where
+    &'a T: Add<&'b mut U, Output = V>,
+{
+    type Output = Point3D<V>;
+
+    fn add(self, other: &'b mut Point3D<U>) -> Self::Output {
+        Point3D::at(
+            &self.x + &mut other.x,
+            &self.y + &mut other.y,
+            &self.z + &mut other.z,
+        )
+    }
+}
+
+impl<'a, T, U, V> Add<Point3D<U>> for &'a mut Point3D<T>
+where
+    &'a mut T: Add<U, Output = V>,
+{
+    type Output = Point3D<V>;
+
+    fn add(self, other: Point3D<U>) -> Self::Output {
+        Point3D::at(
+            &mut self.x + other.x,
+            &mut self.y + other.y,
+            &mut self.z + other.z,
+        )
+    }
+}
+
+impl<'a, 'b, T, U, V> Add<&'b Point3D<U>> for &'a mut Point3D<T>
+where
+    &'a mut T: Add<&'b U, Output = V>,
+{
+    type Output = Point3D<V>;
+
+    fn add(self, other: &'b Point3D<U>) -> Self::Output {
+        Point3D::at(
+            &mut self.x + &other.x,
+            &mut self.y + &other.y,
+            &mut self.z + &other.z,
+        )
+    }
+}
+
+impl<'a, 'b, T, U, V> Add<&'b mut Point3D<U>> for &'a mut Point3D<T>
+where
+    &'a mut T: Add<&'b mut U, Output = V>,
+{
+    type Output = Point3D<V>;
+
+    fn add(self, other: &'b mut Point3D<U>) -> Self::Output {
+        Point3D::at(
+            &mut self.x + &mut other.x,
+            &mut self.y + &mut other.y,
+            &mut self.z + &mut other.z,
+        )
+    }
+}
+
+impl<T, U> AddAssign<Point3D<U>> for Point3D<T>
+where
+    T: AddAssign<U>,
+{
+    fn add_assign(&mut self, other: Point3D<U>) {
+        self.x += other.x;
+        self.y += other.y;
+        self.z += other.z;
+    }
+}
+
+impl<'a, T, U> AddAssign<&'a Point3D<U>> for Point3D<T>
+where
+    T: AddAssign<&'a U>,
+{
+    fn add_assign(&mut self, other: &'a Point3D<U>) {
+        self.x += &other.x;
+        self.y += &other.y;
+        self.z += &other.z;
+    }
+}
+
+impl<'a, T, U> AddAssign<&'a mut Point3D<U>> for Point3D<T>
+where
+    T: AddAssign<&'a mut U>,
+{
+    fn add_assign(&mut self, other: &'a mut Point3D<U>) {
+        self.x += &mut other.x;
+        self.y += &mut other.y;
+        self.z += &mut other.z;
+    }
+}
+
+impl<T, U> AddAssign<Point3D<U>> for &mut Point3D<T>
+where
+    T: AddAssign<U>,
+{
+    fn add_assign(&mut self, other: Point3D<U>) {
+        self.x += other.x;
+        self.y += other.y;
+        self.z += other.z;
+    }
+}
+
+impl<'b, T, U> AddAssign<&'b Point3D<U>> for &mut Point3D<T>
+where
+    T: AddAssign<&'b U>,
+{
+    fn add_assign(&mut self, other: &'b Point3D<U>) {
+        self.x += &other.x;
+        self.y += &other.y;
+        self.z += &other.z;
+    }
+}
+
+impl<'b, T, U> AddAssign<&'b mut Point3D<U>> for &mut Point3D<T>
+where
+    T: AddAssign<&'b mut U>,
+{
+    fn add_assign(&mut self, other: &'b mut Point3D<U>) {
+        self.x += &mut other.x;
+        self.y += &mut other.y;
+        self.z += &mut other.z;
+    }
+}
+
+impl<T, U, V> Div<U> for Point3D<T>
+where
+    T: Div<U, Output = V>,
+    U: Clone,
+{
+    type Output = Point3D<V>;
+
+    fn div(self, other: U) -> Self::Output {
+        Point3D::at(
+            self.x / other.clone(),
+            self.y / other.clone(),
+            self.z / other,
+        )
+    }
+}
+
+impl<'a, T, U, V> Div<U> for &'a Point3D<T>
+where
+    &'a T: Div<U, Output = V>,
+    U: Clone,
+{
+    type Output = Point3D<V>;
+
+    fn div(self, other: U) -> Self::Output {
+        Point3D::at(
+            &self.x / other.clone(),
+            &self.y / other.clone(),
+            &self.z / other,
+        )
+    }
+}
+
+impl<'a, T, U, V> Div<U> for &'a mut Point3D<T>
+where
+    &'a mut T: Div<U, Output = V>,
+    U: Clone,
+{
+    type Output = Point3D<V>;
+
+    fn div(self, other: U) -> Self::Output {
+        Point3D::at(
+            &mut self.x / other.clone(),
+            &mut self.y / other.clone(),
+            &mut self.z / other,
+        )
+    }
+}
+
+impl<T, U> DivAssign<U> for Point3D<T>
+where
+    T: DivAssign<U>,
+    U: Clone,
+{
+    fn div_assign(&mut self, other: U) {
+        self.x /= other.clone();
+        self.y /= other.clone();
+        self.z /= other;
+    }
+}
+
+impl<T, U> DivAssign<U> for &mut Point3D<T>
+where
+    T: DivAssign<U>,
+    U: Clone,
+{
+    fn div_assign(&mut self, other: U) {
+        self.x /= other.clone();
+        self.y /= other.clone();
+        self.z /= other;
+    }
+}
+
+impl<T, U, V> Mul<U> for Point3D<T>
+where
+    T: Mul<U, Output = V>,
+    U: Clone,
+{
+    type Output = Point3D<V>;
+
+    fn mul(self, other: U) -> Self::Output {
+        Point3D::at(
+            self.x * other.clone(),
+            self.y * other.clone(),
+            self.z * other,
+        )
+    }
+}
+
+impl<'a, T, U, V> Mul<U> for &'a Point3D<T>
+where
+    &'a T: Mul<U, Output = V>,
+    U: Clone,
+{
+    type Output = Point3D<V>;
+
+    fn mul(self, other: U) -> Self::Output {
+        Point3D::at(
+            &self.x * other.clone(),
+            &self.y * other.clone(),
+            &self.z * other,
+        )
+    }
+}
+
+impl<'a, T, U, V> Mul<U> for &'a mut Point3D<T>
+where
+    &'a mut T: Mul<U, Output = V>,
+    U: Clone,
+{
+    type Output = Point3D<V>;
+
+    fn mul(self, other: U) -> Self::Output {
+        Point3D::at(
+            &mut self.x * other.clone(),
+            &mut self.y * other.clone(),
+            &mut self.z * other,
+        )
+    }
+}
+
+impl<T, U> MulAssign<U> for Point3D<T>
+where
+    T: MulAssign<U>,
+    U: Clone,
+{
+    fn mul_assign(&mut self, other: U) {
+        self.x *= other.clone();
+        self.y *= other.clone();
+        self.z *= other;
+    }
+}
+
+impl<T, U> MulAssign<U> for &mut Point3D<T>
+where
+    T: MulAssign<U>,
+    U: Clone,
+{
+    fn mul_assign(&mut self, other: U) {
+        self.x *= other.clone();
+        self.y *= other.clone();
+        self.z *= other;
+    }
+}
+
+impl<T, U> Neg for Point3D<T>
+where
+    T: Neg<Output = U>,
+{
+    type Output = Point3D<U>;
+
+    fn neg(self) -> Self::Output {
+        Point3D::at(-self.x, -self.y, -self.z)
+    }
+}
+
+impl<'a, T, U> Neg for &'a Point3D<T>
+where
+    &'a T: Neg<Output = U>,
+{
+    type Output = Point3D<U>;
+
+    fn neg(self) -> Self::Output {
+        Point3D::at(-&self.x, -&self.y, -&self.z)
+    }
+}
+
+impl<'a, T, U> Neg for &'a mut Point3D<T>
+where
+    &'a mut T: Neg<Output = U>,
+{
+    type Output = Point3D<U>;
+
+    fn neg(self) -> Self::Output {
+        Point3D::at(-&mut self.x, -&mut self.y, -&mut self.z)
+    }
+}
+
+impl<T, U, V> Sub<Point3D<U>> for Point3D<T>
+where
+    T: Sub<U, Output = V>,
+{
+    type Output = Point3D<V>;
+
+    fn sub(self, other: Point3D<U>) -> Self::Output {
+        Point3D::at(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl<'a, T, U, V> Sub<&'a Point3D<U>> for Point3D<T>
+where
+    T: Sub<&'a U, Output = V>,
+{
+    type Output = Point3D<V>;
+
+    fn sub(self, other: &'a Point3D<U>) -> Self::Output {
+        Point3D::at(self.x - &other.x, self.y - &other.y, self.z - &other.z)
+    }
+}
+
+impl<'a, T, U, V> Sub<&'a mut Point3D<U>> for Point3D<T>
+where
+    T: Sub<&'a mut U, Output = V>,
+{
+    type Output = Point3D<V>;
+
+    fn sub(self, other: &'a mut Point3D<U>) -> Self::Output {
+        Point3D::at(
+            self.x - &mut other.x,
+            self.y - &mut other.y,
+            self.z - &mut other.z,
+        )
+    }
+}
+
+impl<'a, T, U, V> Sub<Point3D<U>> for &'a Point3D<T>
+where
+    &'a T: Sub<U, Output = V>,
+{
+    type Output = Point3D<V>;
+
+    fn sub(self, other: Point3D<U>) -> Self::Output {
+        Point3D::at(&self.x - other.x, &self.y - other.y, &self.z - other.z)
+    }
+}
+
+impl<'a, 'b, T, U, V> Sub<&'b Point3D<U>> for &'a Point3D<T>
+where
+    &'a T: Sub<&'b U, Output = V>,
+{
+    type Output = Point3D<V>;
+
+    fn sub(self, other: &'b Point3D<U>) -> Self::Output {
+        Point3D::at(&self.x - &other.x, &self.y - &other.y, &self.z - &other.z)
+    }
+}
+
+impl<'a, 'b, T, U, V> Sub<&'b mut Point3D<U>> for &'a Point3D<T>
+where
+    &'a T: Sub<&'b mut U, Output = V>,
+{
+    type Output = Point3D<V>;
+
+    fn sub(self, other: &'b mut Point3D<U>) -> Self::Output {
+        Point3D::at(
+            &self.x - &mut other.x,
+            &self.y - &mut other.y,
+            &self.z - &mut other.z,
+        )
+    }
+}
+
+impl<'a, T, U, V> Sub<Point3D<U>> for &'a mut Point3D<T>
+where
+    &'a mut T: Sub<U, Output = V>,
+{
+    type Output = Point3D<V>;
+
+    fn sub(self, other: Point3D<U>) -> Self::Output {
+        Point3D::at(
+            &mut self.x - other.x,
+            &mut self.y - other.y,
+            &mut self.z - other.z,
+        )
+    }
+}
+
+impl<'a, 'b, T, U, V> Sub<&'b Point3D<U>> for &'a mut Point3D<T>
+where
+    &'a mut T: Sub<&'b U, Output = V>,
+{
+    type Output = Point3D<V>;
+
+    fn sub(self, other: &'b Point3D<U>) -> Self::Output {
+        Point3D::at(
+            &mut self.x - &other.x,
+            &mut self.y - &other.y,
+            &mut self.z - &other.z,
+        )
+    }
+}
+
+impl<'a, 'b, T, U, V> Sub<&'b mut Point3D<U>> for &'a mut Point3D<T>
+where
+    &'a mut T: Sub<&'b mut U, Output = V>,
+{
+    type Output = Point3D<V>;
+
+    fn sub(self, other: &'b mut Point3D<U>) -> Self::Output {
+        Point3D::at(
+            &mut self.x - &mut other.x,
+            &mut self.y - &mut other.y,
+            &mut self.z - &mut other.z,
+        )
+    }
+}
+
+impl<T, U> SubAssign<Point3D<U>> for Point3D<T>
+where
+    T: SubAssign<U>,
+{
+    fn sub_assign(&mut self, other: Point3D<U>) {
+        self.x -= other.x;
+        self.y -= other.y;
+        self.z -= other.z;
+    }
+}
+
+impl<'a, T, U> SubAssign<&'a Point3D<U>> for Point3D<T>
+where
+    T: SubAssign<&'a U>,
+{
+    fn sub_assign(&mut self, other: &'a Point3D<U>) {
+        self.x -= &other.x;
+        self.y -= &other.y;
+        self.z -= &other.z;
+    }
+}
+
+impl<'a, T, U> SubAssign<&'a mut Point3D<U>> for Point3D<T>
+where
+    T: SubAssign<&'a mut U>,
+{
+    fn sub_assign(&mut self, other: &'a mut Point3D<U>) {
+        self.x -= &mut other.x;
+        self.y -= &mut other.y;
+        self.z -= &mut other.z;
+    }
+}
+
+impl<T, U> SubAssign<Point3D<U>> for &mut Point3D<T>
+where
+    T: SubAssign<U>,
+{
+    fn sub_assign(&mut self, other: Point3D<U>) {
+        self.x -= other.x;
+        self.y -= other.y;
+        self.z -= other.z;
+    }
+}
+
+impl<'b, T, U> SubAssign<&'b Point3D<U>> for &mut Point3D<T>
+where
+    T: SubAssign<&'b U>,
+{
+    fn sub_assign(&mut self, other: &'b Point3D<U>) {
+        self.x -= &other.x;
+        self.y -= &other.y;
+        self.z -= &other.z;
+    }
+}
+
+impl<'b, T, U> SubAssign<&'b mut Point3D<U>> for &mut Point3D<T>
+where
+    T: SubAssign<&'b mut U>,
+{
+    fn sub_assign(&mut self, other: &'b mut Point3D<U>) {
+        self.x -= &mut other.x;
+        self.y -= &mut other.y;
+        self.z -= &mut other.z;
+    }
+}
+
+/// A point in `N`-dimensional space, for puzzles with more dimensions than [`Point2D`] and
+/// [`Point3D`] have, such as the 4-dimensional points of 2018 day 25.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Point<T, const N: usize> {
+    coords: [T; N],
+}
+
+impl<T, const N: usize> Point<T, N> {
+    /// Creates a new point with the given coordinates.
+    pub const fn at(coords: [T; N]) -> Self {
+        Self { coords }
+    }
+
+    /// The coordinates of the point.
+    pub const fn coords(&self) -> &[T; N] {
+        &self.coords
+    }
+
+    /// Converts the point into its coordinates.
+    pub fn into_coords(self) -> [T; N] {
+        self.coords
+    }
+
+    /// Combines the coordinates of the two points pairwise with `f`.
+    fn zip_with<U, V>(self, other: Point<U, N>, mut f: impl FnMut(T, U) -> V) -> Point<V, N> {
+        let mut other = other.coords.into_iter();
+        Point::at(self.coords.map(|coord| f(coord, other.next().unwrap())))
+    }
+}
+
+impl<T, const N: usize> Point<T, N>
+where
+    T: Copy + Add<Output = T> + Default + Ord + Sub<Output = T>,
+{
+    /// Calculates the sum of the distances between each pair of coordinates.
+    pub fn manhattan_distance(&self, rhs: &Self) -> T {
+        self.distances(rhs)
+            .fold(T::default(), |total, distance| total + distance)
+    }
+
+    /// Calculates the greatest of the distances between each pair of coordinates.
+    pub fn chebyshev_distance(&self, rhs: &Self) -> T {
+        self.distances(rhs).max().unwrap_or_default()
+    }
+
+    /// Calculates the square of the straight-line distance between the points, which can be
+    /// compared without taking square roots.
+    pub fn euclidean_distance_sq(&self, rhs: &Self) -> T
+    where
+        T: Mul<Output = T>,
+    {
+        self.distances(rhs)
+            .fold(T::default(), |total, distance| total + distance * distance)
+    }
+
+    /// The distance between each pair of coordinates.
+    fn distances<'a>(&'a self, rhs: &'a Self) -> impl Iterator<Item = T> + 'a {
+        self.coords
+            .iter()
+            .zip(&rhs.coords)
+            .map(|(&a, &b)| a.max(b) - a.min(b))
+    }
+}
+
+impl<T, const N: usize> Point<T, N>
+where
+    T: Copy + Add<Output = T> + From<u8> + PartialEq + Sub<Output = T>,
+{
+    /// The `2 * N` points which are one step away from this one along a single axis.
+    pub fn orthogonal_neighbors(&self) -> Vec<Self> {
+        let one = T::from(1);
+        (0..N)
+            .flat_map(|axis| {
+                let mut before = *self;
+                before.coords[axis] = before.coords[axis] - one;
+                let mut after = *self;
+                after.coords[axis] = after.coords[axis] + one;
+                [before, after]
+            })
+            .collect()
+    }
+
+    /// The `3.pow(N) - 1` points which are at most one step away from this one along every axis,
+    /// including those which are diagonal to it.
+    pub fn neighbors(&self) -> Vec<Self> {
+        let one = T::from(1);
+        let mut neighbors = vec![*self];
+        for axis in 0..N {
+            neighbors = neighbors
+                .into_iter()
+                .flat_map(|point| {
+                    let mut before = point;
+                    before.coords[axis] = before.coords[axis] - one;
+                    let mut after = point;
+                    after.coords[axis] = after.coords[axis] + one;
+                    [before, point, after]
+                })
+                .collect();
+        }
+        neighbors.retain(|point| point != self);
+        neighbors
+    }
+}
+
+impl<T, const N: usize> Default for Point<T, N>
+where
+    T: Default,
+{
+    fn default() -> Self {
+        Self::at(std::array::from_fn(|_| T::default()))
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for Point<T, N> {
+    fn from(coords: [T; N]) -> Self {
+        Self::at(coords)
+    }
+}
+
+impl<T, const N: usize> Index<usize> for Point<T, N> {
+    type Output = T;
+
+    fn index(&self, axis: usize) -> &Self::Output {
+        &self.coords[axis]
+    }
+}
+
+impl<T, const N: usize> IndexMut<usize> for Point<T, N> {
+    fn index_mut(&mut self, axis: usize) -> &mut Self::Output {
+        &mut self.coords[axis]
+    }
+}
+
+impl<T, U, V, const N: usize> Add<Point<U, N>> for Point<T, N>
+where
+    T: Add<U, Output = V>,
+{
+    type Output = Point<V, N>;
+
+    fn add(self, other: Point<U, N>) -> Self::Output {
+        self.zip_with(other, |a, b| a + b)
+    }
+}
+
+impl<T, U, const N: usize> AddAssign<Point<U, N>> for Point<T, N>
+where
+    T: AddAssign<U>,
+{
+    fn add_assign(&mut self, other: Point<U, N>) {
+        for (coord, other) in self.coords.iter_mut().zip(other.coords) {
+            *coord += other;
+        }
+    }
+}
+
+impl<T, U, V, const N: usize> Mul<U> for Point<T, N>
+where
+    T: Mul<U, Output = V>,
+    U: Clone,
+{
+    type Output = Point<V, N>;
+
+    fn mul(self, other: U) -> Self::Output {
+        Point::at(self.coords.map(|coord| coord * other.clone()))
+    }
+}
+
+impl<T, U, const N: usize> Neg for Point<T, N>
+where
+    T: Neg<Output = U>,
+{
+    type Output = Point<U, N>;
+
+    fn neg(self) -> Self::Output {
+        Point::at(self.coords.map(|coord| -coord))
+    }
+}
+
+impl<T, U, V, const N: usize> Sub<Point<U, N>> for Point<T, N>
+where
+    T: Sub<U, Output = V>,
+{
+    type Output = Point<V, N>;
+
+    fn sub(self, other: Point<U, N>) -> Self::Output {
+        self.zip_with(other, |a, b| a - b)
+    }
+}
+
+impl<T, U, const N: usize> SubAssign<Point<U, N>> for Point<T, N>
+where
+    T: SubAssign<U>,
+{
+    fn sub_assign(&mut self, other: Point<U, N>) {
+        for (coord, other) in self.coords.iter_mut().zip(other.coords) {
+            *coord -= other;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_point_3d() {
+        let a = Point3D::at(1_i64, 2, 3);
+        let b = Point3D::at(-4_i64, 5, 0);
+        assert_eq!(Point3D::at(-3, 7, 3), a + b);
+        assert_eq!(Point3D::at(5, -3, 3), a - b);
+        assert_eq!(Point3D::at(2, 4, 6), a * 2);
+        assert_eq!(Point3D::at(-1, -2, -3), -a);
+        assert_eq!(6, a.dot(&b));
+        let cross = a.cross(&b);
+        assert_eq!(Point3D::at(-15, -12, 13), cross);
+        assert_eq!((0, 0), (cross.dot(&a), cross.dot(&b)));
+        assert_eq!(Point3D::at(-4, 2, 0), a.min(&b));
+        assert_eq!(Point3D::at(1, 5, 3), a.max(&b));
+        assert_eq!(11, a.manhattan_distance(&b));
+        let mut c = a;
+        c += b;
+        c /= 2;
+        assert_eq!((&-1, &3, &1), (c.x(), c.y(), c.z()));
+    }
+
+    #[test]
+    fn test_point_nd() {
+        let a = Point::at([0_i64, 0, 0, 3]);
+        let b = Point::at([0_i64, 0, 0, 6]);
+        assert_eq!(3, a.manhattan_distance(&b));
+        assert_eq!(Point::at([0, 0, 0, 9]), a + b);
+        assert_eq!(Point::at([0, 0, 0, -3]), a - b);
+        assert_eq!(Point::at([0, 0, 0, -6]), -b);
+        assert_eq!(Point::at([0, 0, 0, 6]), a * 2);
+        let mut c = a;
+        c += b;
+        c -= Point::from([1, 1, 1, 1]);
+        c[0] = 5;
+        assert_eq!([5, -1, -1, 8], c.into_coords());
+        assert_eq!(Point::at([0; 4]), Point::<i64, 4>::default());
+        // The neighbors that the cubes of 2020 day 17 count in four dimensions.
+        let neighbors = a.neighbors();
+        assert_eq!(80, neighbors.len());
+        assert!(neighbors.contains(&Point::at([1, -1, 1, 2])));
+        assert!(!neighbors.contains(&a));
+        let orthogonal = a.orthogonal_neighbors();
+        assert_eq!(8, orthogonal.len());
+        assert!(orthogonal
+            .iter()
+            .all(|point| point.manhattan_distance(&a) == 1));
+    }
+
+    #[test]
+    fn test_point_2d() {
+        let a = Point2D::at(3_i32, -4);
+        let b = Point2D::at(-2_i32, 0);
+        assert_eq!(-6, a.dot(&b));
+        assert_eq!(Point2D::at(1, -1), a.signum());
+        assert_eq!(Point2D::at(-1, 0), b.signum());
+        assert_eq!(Point2D::at(3_i64, -4), a.cast());
+        assert!(a.try_cast::<usize>().is_err());
+        assert_eq!(Ok(Point2D::at(3_u8, 4)), Point2D::at(3, 4).try_cast());
+        let corner = Point2D::at(0_usize, 5);
+        assert_eq!(
+            Some(Point2D::at(1, 4)),
+            corner.checked_add_signed(Point2D::at(1, -1))
+        );
+        assert_eq!(None, corner.checked_add_signed(Point2D::at(-1, 0)));
+    }
+
+    #[test]
+    fn test_distances() {
+        let a = Point2D::at(1_i32, -2);
+        let b = Point2D::at(-3_i32, 1);
+        assert_eq!(
+            (7, 4, 25),
+            (
+                a.manhattan_distance(&b),
+                a.chebyshev_distance(&b),
+                a.euclidean_distance_sq(&b)
+            )
+        );
+        let a = Point2D::at(1.5_f64, 0.0);
+        let b = Point2D::at(0.0_f64, -2.0);
+        assert_eq!(
+            (3.5, 2.0, 6.25),
+            (
+                a.manhattan_distance(&b),
+                a.chebyshev_distance(&b),
+                a.euclidean_distance_sq(&b)
+            )
+        );
+        let a = Point3D::at(1_u8, 5, 2);
+        let b = Point3D::at(4_u8, 3, 2);
+        assert_eq!(
+            (5, 3, 13),
+            (
+                a.manhattan_distance(&b),
+                a.chebyshev_distance(&b),
+                a.euclidean_distance_sq(&b)
+            )
+        );
+        let a = Point::at([0_i64, 3, -1, 2]);
+        let b = Point::at([1_i64, -1, -1, 0]);
+        assert_eq!(
+            (7, 4, 21),
+            (
+                a.manhattan_distance(&b),
+                a.chebyshev_distance(&b),
+                a.euclidean_distance_sq(&b)
+            )
+        );
+    }
+
+    #[test]
+    fn test_reduced() {
+        assert_eq!(Point2D::at(2, -3), Point2D::at(8_i32, -12).reduced());
+        assert_eq!(Point2D::at(0, 1), Point2D::at(0_i32, 7).reduced());
+        assert_eq!(Point2D::at(-1, 0), Point2D::at(-5_i32, 0).reduced());
+        assert_eq!(Point2D::at(0, 0), Point2D::at(0_i32, 0).reduced());
+        assert_eq!(Point2D::at(i8::MIN, 1), Point2D::at(i8::MIN, 1).reduced());
+    }
+
+    #[test]
+    fn test_clockwise_cmp() {
+        let mut directions = [
+            (-1_i32, 1),
+            (0, -1),
+            (1, 0),
+            (-1, 0),
+            (1, -1),
+            (0, 1),
+            (-1, -1),
+            (1, 1),
+            (2, 1),
+        ]
+        .map(|(x, y)| Point2D::at(x, y));
+        directions.sort_by(|a, b| a.clockwise_cmp(b));
+        assert_eq!(
+            [
+                (0, 1),
+                (1, 1),
+                (2, 1),
+                (1, 0),
+                (1, -1),
+                (0, -1),
+                (-1, -1),
+                (-1, 0),
+                (-1, 1)
+            ]
+            .map(|(x, y)| Point2D::at(x, y)),
+            directions
+        );
+        assert_eq!(
+            Ordering::Equal,
+            Point2D::at(2_i32, 6).clockwise_cmp(&Point2D::at(1, 3))
+        );
+        // These are too close together to tell apart as `f32` angles.
+        let a = Point2D::at(100_000_000_i64, 99_999_999);
+        let b = Point2D::at(99_999_999_i64, 99_999_998);
+        assert_eq!(Ordering::Less, a.clockwise_cmp(&b));
+        assert_eq!(Ordering::Greater, b.clockwise_cmp(&a));
+    }
+
+    #[test]
+    fn test_laser() {
+        // The asteroids of an example from 2019 day 10, where the y-axis points down, and the
+        // first 9 which the laser at (8, 3) vaporizes as it rotates clockwise from straight up.
+        let map = [
+            ".#....#####...#..",
+            "##...##.#####..##",
+            "##...#...#.#####.",
+            "..#.....X...###..",
+            "..#.#.....#....##",
+        ];
+        let station = Point2D::at(8_i32, 3);
+        let mut asteroids = map
+            .iter()
+            .zip(0..)
+            .flat_map(|(row, y)| {
+                row.bytes()
+                    .zip(0..)
+                    .filter(|&(cell, _)| cell == b'#')
+                    .map(move |(_, x)| Point2D::at(x, y))
+            })
+            .collect::<Vec<_>>();
+        asteroids.sort_by(|a, b| {
+            let a = (a - station).reflect_y();
+            let b = (b - station).reflect_y();
+            a.clockwise_cmp(&b).then_with(|| {
+                a.manhattan_distance(&Point2D::at(0, 0))
+                    .cmp(&b.manhattan_distance(&Point2D::at(0, 0)))
+            })
+        });
+        // Only the nearest asteroid in each direction is vaporized during the first rotation.
+        asteroids.dedup_by_key(|asteroid| (*asteroid - station).reduced());
+        assert_eq!(
+            [
+                (8, 1),
+                (9, 0),
+                (9, 1),
+                (10, 0),
+                (9, 2),
+                (11, 1),
+                (12, 1),
+                (11, 2),
+                (15, 1)
+            ]
+            .map(|(x, y)| Point2D::at(x, y)),
+            asteroids[..9]
+        );
+    }
+}
@@ -0,0 +1,177 @@
+use super::{Point2D, Segment};
+
+/// A closed shape whose edges run between its vertices in order and from its last vertex back to
+/// its first, such as the loop of pipes in 2023 day 10.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Polygon<T> {
+    vertices: Vec<Point2D<T>>,
+}
+
+impl<T> Polygon<T> {
+    /// Creates the polygon with the given vertices.
+    pub const fn new(vertices: Vec<Point2D<T>>) -> Self {
+        Self { vertices }
+    }
+
+    /// The vertices of the polygon.
+    pub fn vertices(&self) -> &[Point2D<T>] {
+        &self.vertices
+    }
+}
+
+impl<T> Polygon<T>
+where
+    T: Copy,
+{
+    /// The edges of the polygon, starting with the one from its first vertex to its second and
+    /// ending with the one from its last vertex back to its first.
+    pub fn edges(&self) -> impl Iterator<Item = Segment<T>> + '_ {
+        self.vertices
+            .iter()
+            .zip(self.vertices.iter().cycle().skip(1))
+            .map(|(&start, &end)| Segment::new(start, end))
+    }
+}
+
+impl<T> From<Vec<Point2D<T>>> for Polygon<T> {
+    fn from(vertices: Vec<Point2D<T>>) -> Self {
+        Self::new(vertices)
+    }
+}
+
+macro_rules! impl_polygon {
+    ($($t:ty)+) => ($(
+        impl Polygon<$t> {
+            /// Returns true if and only if `point` is on one of the edges of the polygon.
+            pub fn on_boundary(&self, point: &Point2D<$t>) -> bool {
+                self.edges().any(|edge| edge.contains(point))
+            }
+
+            /// Returns true if and only if `point` is strictly inside the polygon, so that the
+            /// points on its edges aren't contained by it. The inside of a polygon whose edges
+            /// cross themselves is found by the even-odd rule, so that a point is inside if a ray
+            /// from it crosses the edges an odd number of times.
+            pub fn contains(&self, point: &Point2D<$t>) -> bool {
+                if self.on_boundary(point) {
+                    return false;
+                }
+                let (x, y) = (*point.x(), *point.y());
+                let mut inside = false;
+                for edge in self.edges() {
+                    let (start, end) = (edge.start(), edge.end());
+                    // Only count edges which cross the ray in the direction of increasing x,
+                    // including their lower ends but not their upper ends so that a ray through a
+                    // vertex is only counted once for the two edges that meet there.
+                    if (*start.y() > y) == (*end.y() > y) {
+                        continue;
+                    }
+                    // The ray crosses the edge to the right of `point` when
+                    // `x < start.x + (y - start.y) * dx / dy`, which is multiplied through by
+                    // `dy` to keep it in integers.
+                    let dx = end.x() - start.x();
+                    let dy = end.y() - start.y();
+                    let lhs = (x - start.x()) * dy;
+                    let rhs = (y - start.y()) * dx;
+                    if (dy > 0 && lhs < rhs) || (dy < 0 && lhs > rhs) {
+                        inside = !inside;
+                    }
+                }
+                inside
+            }
+
+            /// Every point with integer coordinates along the edges of the polygon, each exactly
+            /// once, starting with its first vertex and going around through the others in order.
+            ///
+            /// # Panics
+            /// Panics if any edge isn't horizontal, vertical, or diagonal, as
+            /// [`Segment::points()`] does.
+            pub fn perimeter(&self) -> impl Iterator<Item = Point2D<$t>> + '_ {
+                // Each edge ends where the next one starts, so leave out the last point of each.
+                self.edges().flat_map(|edge| {
+                    let length = edge.length();
+                    edge.points().take(length as usize)
+                })
+            }
+        }
+    )+)
+}
+
+impl_polygon!(i8 i16 i32 i64 i128 isize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::collections::HashSet;
+
+    fn polygon(vertices: &[(i32, i32)]) -> Polygon<i32> {
+        vertices
+            .iter()
+            .map(|&(x, y)| Point2D::at(x, y))
+            .collect::<Vec<_>>()
+            .into()
+    }
+
+    #[test]
+    fn test_enclosed_tiles() {
+        // The loop of pipes from an example of 2023 day 10, which encloses 4 tiles:
+        // ...........
+        // .S-------7.
+        // .|F-----7|.
+        // .||.....||.
+        // .||.....||.
+        // .|L-7.F-J|.
+        // .|..|.|..|.
+        // .L--J.L--J.
+        // ...........
+        let pipes = polygon(&[
+            (1, 1),
+            (9, 1),
+            (9, 7),
+            (6, 7),
+            (6, 5),
+            (8, 5),
+            (8, 2),
+            (2, 2),
+            (2, 5),
+            (4, 5),
+            (4, 7),
+            (1, 7),
+        ]);
+        let perimeter = pipes.perimeter().collect::<Vec<_>>();
+        assert_eq!(46, perimeter.len());
+        assert_eq!(Point2D::at(1, 1), perimeter[0]);
+        assert_eq!(46, perimeter.iter().collect::<HashSet<_>>().len());
+        let enclosed = (0..9)
+            .flat_map(|y| (0..11).map(move |x| Point2D::at(x, y)))
+            .filter(|tile| pipes.contains(tile))
+            .collect::<HashSet<_>>();
+        assert_eq!(
+            HashSet::from([
+                Point2D::at(2, 6),
+                Point2D::at(3, 6),
+                Point2D::at(7, 6),
+                Point2D::at(8, 6)
+            ]),
+            enclosed
+        );
+    }
+
+    #[test]
+    fn test_contains() {
+        let triangle = polygon(&[(0, 0), (6, 0), (0, 6)]);
+        assert!(triangle.contains(&Point2D::at(1, 1)));
+        assert!(triangle.contains(&Point2D::at(2, 3)));
+        assert!(!triangle.contains(&Point2D::at(3, 3)));
+        assert!(triangle.on_boundary(&Point2D::at(3, 3)));
+        assert!(!triangle.contains(&Point2D::at(4, 3)));
+        assert!(!triangle.contains(&Point2D::at(-1, 0)));
+        assert!(!triangle.contains(&Point2D::at(7, 0)));
+        // A ray from (1, 2) to the right passes through the vertex at (3, 2).
+        let diamond = polygon(&[(1, 0), (3, 2), (1, 4), (-1, 2)]);
+        assert!(diamond.contains(&Point2D::at(1, 2)));
+        assert!(!diamond.contains(&Point2D::at(-2, 2)));
+        assert!(!diamond.contains(&Point2D::at(4, 2)));
+        assert_eq!(8, diamond.perimeter().count());
+    }
+}
@@ -0,0 +1,130 @@
+use super::Point2D;
+
+/// Walks a ray across `grid` from the cell `from` in the direction `direction`, yielding each cell
+/// that it passes through along with its position, where the cell at `(x, y)` is `grid[y][x]`.
+/// The ray stops at the first cell which `blocks` returns true for, after yielding it, such as
+/// the first asteroid that can be seen from a station in 2019 day 10 or the first tree which is
+/// at least as tall as the one that the ray starts from in 2022 day 8. It also stops at the edge
+/// of the grid. `from` itself isn't yielded.
+///
+/// `direction` is [reduced](Point2D::reduced) to its lowest terms, so that the ray passes through
+/// every cell exactly on the line, rather than skipping over some of them.
+///
+/// # Panics
+/// Panics if `direction` is `(0, 0)`, since the ray wouldn't go anywhere.
+pub fn cast_ray<'a, T>(
+    grid: &'a [Vec<T>],
+    from: Point2D<usize>,
+    direction: Point2D<isize>,
+    mut blocks: impl FnMut(&T) -> bool + 'a,
+) -> impl Iterator<Item = (Point2D<usize>, &'a T)> + 'a {
+    let step = direction.reduced();
+    assert_ne!(
+        Point2D::at(0, 0),
+        step,
+        "Can't cast a ray without a direction"
+    );
+    let mut current = Some(from);
+    std::iter::from_fn(move || {
+        let next = current?.checked_add_signed(step)?;
+        let cell = grid.get(*next.y())?.get(*next.x())?;
+        current = (!blocks(cell)).then_some(next);
+        Some((next, cell))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(grid: &str) -> Vec<Vec<char>> {
+        grid.lines().map(|line| line.chars().collect()).collect()
+    }
+
+    #[test]
+    fn test_trees() {
+        // The example forest from 2022 day 8.
+        let forest = parse("30373\n25512\n65332\n33549\n35390\n");
+        let directions = [(1, 0), (-1, 0), (0, 1), (0, -1)].map(|(x, y)| Point2D::at(x, y));
+        let mut visible = 0;
+        let mut best_score = 0;
+        for (y, row) in forest.iter().enumerate() {
+            for (x, &height) in row.iter().enumerate() {
+                let from = Point2D::at(x, y);
+                let mut rays = directions.map(|direction| {
+                    cast_ray(&forest, from, direction, move |&tree| tree >= height)
+                });
+                if rays
+                    .iter_mut()
+                    .any(|ray| ray.all(|(_, &tree)| tree < height))
+                {
+                    visible += 1;
+                }
+                let score = directions
+                    .map(|direction| {
+                        cast_ray(&forest, from, direction, |&tree| tree >= height).count()
+                    })
+                    .into_iter()
+                    .product();
+                best_score = best_score.max(score);
+            }
+        }
+        assert_eq!(21, visible);
+        assert_eq!(8, best_score);
+    }
+
+    #[test]
+    fn test_asteroids() {
+        // The first example map from 2019 day 10, where the best station can see 8 asteroids.
+        let map = parse(".#..#\n.....\n#####\n....#\n...##\n");
+        let asteroids = map
+            .iter()
+            .enumerate()
+            .flat_map(|(y, row)| {
+                row.iter()
+                    .enumerate()
+                    .filter(|&(_, &cell)| cell == '#')
+                    .map(move |(x, _)| Point2D::at(x, y))
+            })
+            .collect::<Vec<_>>();
+        let detected = |station: Point2D<usize>| {
+            asteroids
+                .iter()
+                .filter(|&&asteroid| asteroid != station)
+                .filter(|&&asteroid| {
+                    let direction = asteroid.try_cast::<isize>().unwrap()
+                        - station.try_cast::<isize>().unwrap();
+                    let first_seen = cast_ray(&map, station, direction, |&cell| cell == '#')
+                        .last()
+                        .map(|(position, _)| position);
+                    first_seen == Some(asteroid)
+                })
+                .count()
+        };
+        assert_eq!(8, detected(Point2D::at(3, 4)));
+        assert_eq!(7, detected(Point2D::at(1, 0)));
+        assert_eq!(
+            Some(8),
+            asteroids.iter().map(|&station| detected(station)).max()
+        );
+    }
+
+    #[test]
+    fn test_reduced_direction() {
+        let grid = vec![vec![0; 7]; 7];
+        let cells = cast_ray(&grid, Point2D::at(0, 6), Point2D::at(4, -4), |_| false)
+            .map(|(position, _)| position)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            (1..7).map(|i| Point2D::at(i, 6 - i)).collect::<Vec<_>>(),
+            cells
+        );
+    }
+
+    #[test]
+    #[should_panic = "without a direction"]
+    fn test_no_direction() {
+        let grid = vec![vec![0]];
+        cast_ray(&grid, Point2D::at(0, 0), Point2D::at(0, 0), |_| false).count();
+    }
+}
@@ -0,0 +1,498 @@
+use std::ops::{Mul, Sub};
+
+#[cfg(feature = "math")]
+use crate::math::Ratio;
+
+use super::Point2D;
+
+/// A line segment between two points, including both of its ends.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Segment<T> {
+    start: Point2D<T>,
+    end: Point2D<T>,
+}
+
+impl<T> Segment<T> {
+    /// Creates the segment from `start` to `end`.
+    pub const fn new(start: Point2D<T>, end: Point2D<T>) -> Self {
+        Self { start, end }
+    }
+
+    /// The point that the segment starts at.
+    pub const fn start(&self) -> &Point2D<T> {
+        &self.start
+    }
+
+    /// The point that the segment ends at.
+    pub const fn end(&self) -> &Point2D<T> {
+        &self.end
+    }
+}
+
+impl<T> Segment<T>
+where
+    T: PartialEq,
+{
+    /// Returns true if and only if both ends of the segment have the same y-coordinate.
+    pub fn is_horizontal(&self) -> bool {
+        self.start.y() == self.end.y()
+    }
+
+    /// Returns true if and only if both ends of the segment have the same x-coordinate.
+    pub fn is_vertical(&self) -> bool {
+        self.start.x() == self.end.x()
+    }
+
+    /// Returns true if and only if the segment is horizontal or vertical.
+    pub fn is_axis_aligned(&self) -> bool {
+        self.is_horizontal() || self.is_vertical()
+    }
+}
+
+/// The z-coordinate of the cross product of `a` and `b`, which is positive if `b` is
+/// counterclockwise of `a`, negative if it's clockwise, and 0 if they're parallel.
+fn cross<T>(a: &Point2D<T>, b: &Point2D<T>) -> T
+where
+    T: Copy + Mul<Output = T> + Sub<Output = T>,
+{
+    *a.x() * *b.y() - *a.y() * *b.x()
+}
+
+macro_rules! impl_segment {
+    ($($t:ty)+) => ($(
+        impl Segment<$t> {
+            /// Returns true if and only if the segment runs at 45 degrees to the axes.
+            pub fn is_diagonal(&self) -> bool {
+                let delta = self.end - self.start;
+                delta.x() != &0 && delta.x().abs() == delta.y().abs()
+            }
+
+            /// The number of steps that it takes to get from one end of the segment to the other,
+            /// moving to each of the 8 surrounding points in one step, so that a segment which is
+            /// horizontal, vertical, or diagonal has one more point than its length.
+            pub fn length(&self) -> $t {
+                self.start.chebyshev_distance(&self.end)
+            }
+
+            /// The points with integer coordinates along the segment from its start to its end,
+            /// such as the cells of a grid that a line of vents covers.
+            ///
+            /// # Panics
+            /// Panics if the segment isn't horizontal, vertical, or diagonal, since the points
+            /// along it would be too far apart to be next to each other.
+            pub fn points(&self) -> impl Iterator<Item = Point2D<$t>> {
+                assert!(
+                    self.is_axis_aligned() || self.is_diagonal(),
+                    "Can't list the points along {self:?}, which is neither axis-aligned nor diagonal",
+                );
+                let start = self.start;
+                let step = (self.end - self.start).signum();
+                (0..=self.length()).map(move |i| start + step * i)
+            }
+
+            /// The points with integer coordinates which are exactly on the segment, from its
+            /// start to its end. Unlike [`Segment::points()`], the segment may go in any
+            /// direction, in which case the points are spread out along it.
+            pub fn lattice_points(&self) -> impl Iterator<Item = Point2D<$t>> {
+                let start = self.start;
+                let delta = self.end - self.start;
+                let step = delta.reduced();
+                let steps = match (delta.x(), step.x()) {
+                    (_, 0) if *step.y() == 0 => 0,
+                    (_, 0) => delta.y() / step.y(),
+                    (dx, sx) => dx / sx,
+                };
+                (0..=steps).map(move |i| start + step * i)
+            }
+
+            /// The points with integer coordinates which are closest to the segment, one for each
+            /// step along whichever axis the segment is longer along, from its start to its end,
+            /// as found by Bresenham's line algorithm. They're the same as the
+            /// [points](Segment::points) of a horizontal, vertical, or diagonal segment, and are
+            /// the cells of a grid that a line drawn along any other segment would cover.
+            pub fn bresenham_points(&self) -> impl Iterator<Item = Point2D<$t>> {
+                let end = self.end;
+                let dx = (end.x() - self.start.x()).abs();
+                let dy = -(end.y() - self.start.y()).abs();
+                let step = (end - self.start).signum();
+                // How far the points so far have strayed from the line, scaled so that it's
+                // always an integer.
+                let mut error = dx + dy;
+                let mut next = Some(self.start);
+                std::iter::from_fn(move || {
+                    let current = next?;
+                    next = (current != end).then(|| {
+                        let (mut x, mut y) = (*current.x(), *current.y());
+                        let doubled = 2 * error;
+                        if doubled >= dy {
+                            error += dy;
+                            x += step.x();
+                        }
+                        if doubled <= dx {
+                            error += dx;
+                            y += step.y();
+                        }
+                        Point2D::at(x, y)
+                    });
+                    Some(current)
+                })
+            }
+
+            /// Returns true if and only if `point` is on the segment.
+            pub fn contains(&self, point: &Point2D<$t>) -> bool {
+                cross(&(self.end - self.start), &(point - self.start)) == 0
+                    && self.start.x().min(self.end.x()) <= point.x()
+                    && point.x() <= self.start.x().max(self.end.x())
+                    && self.start.y().min(self.end.y()) <= point.y()
+                    && point.y() <= self.start.y().max(self.end.y())
+            }
+
+            /// Returns true if and only if the segments have at least one point in common.
+            pub fn intersects(&self, other: &Self) -> bool {
+                let direction = self.end - self.start;
+                let other_direction = other.end - other.start;
+                let sides = [
+                    cross(&direction, &(other.start - self.start)).signum(),
+                    cross(&direction, &(other.end - self.start)).signum(),
+                    cross(&other_direction, &(self.start - other.start)).signum(),
+                    cross(&other_direction, &(self.end - other.start)).signum(),
+                ];
+                if sides[0] * sides[1] < 0 && sides[2] * sides[3] < 0 {
+                    return true;
+                }
+                (sides[0] == 0 && self.contains(&other.start))
+                    || (sides[1] == 0 && self.contains(&other.end))
+                    || (sides[2] == 0 && other.contains(&self.start))
+                    || (sides[3] == 0 && other.contains(&self.end))
+            }
+
+            /// How far along each segment the lines through them cross, as `(t, u, denominator)`
+            /// where the lines cross `t / denominator` of the way from the start to the end of this
+            /// segment and `u / denominator` of the way along `other`, with a positive
+            /// denominator. Returns `None` if the segments are parallel.
+            fn crossing_fractions(&self, other: &Self) -> Option<($t, $t, $t)> {
+                let direction = self.end - self.start;
+                let other_direction = other.end - other.start;
+                let denominator = cross(&direction, &other_direction);
+                if denominator == 0 {
+                    return None;
+                }
+                let offset = other.start - self.start;
+                let t = cross(&offset, &other_direction);
+                let u = cross(&offset, &direction);
+                Some(if denominator < 0 {
+                    (-t, -u, -denominator)
+                } else {
+                    (t, u, denominator)
+                })
+            }
+
+            /// The only point that the segments have in common, such as where two wires cross.
+            /// Returns `None` if the segments don't meet, if they overlap along more than one
+            /// point, or if the point where they cross doesn't have integer coordinates.
+            pub fn crossing(&self, other: &Self) -> Option<Point2D<$t>> {
+                let Some((t, u, denominator)) = self.crossing_fractions(other) else {
+                    return self
+                        .overlap(other)
+                        .filter(|overlap| overlap.start == overlap.end)
+                        .map(|overlap| overlap.start);
+                };
+                if !(0..=denominator).contains(&t) || !(0..=denominator).contains(&u) {
+                    return None;
+                }
+                let scaled = (self.end - self.start) * t;
+                if scaled.x() % denominator != 0 || scaled.y() % denominator != 0 {
+                    return None;
+                }
+                Some(self.start + scaled / denominator)
+            }
+
+            /// The point `fraction` of the way from the start of the segment to its end.
+            #[cfg(feature = "math")]
+            fn point_at(&self, fraction: Ratio<$t>) -> Point2D<Ratio<$t>> {
+                let direction = self.end - self.start;
+                Point2D::at(
+                    Ratio::from_integer(*self.start.x())
+                        + Ratio::from_integer(*direction.x()) * fraction,
+                    Ratio::from_integer(*self.start.y())
+                        + Ratio::from_integer(*direction.y()) * fraction,
+                )
+            }
+
+            /// The exact point where the segments cross, even if it doesn't have integer
+            /// coordinates. Returns `None` if the segments don't meet or if they're parallel,
+            /// even if they overlap.
+            #[cfg(feature = "math")]
+            pub fn intersection(&self, other: &Self) -> Option<Point2D<Ratio<$t>>> {
+                let (t, u, denominator) = self.crossing_fractions(other)?;
+                ((0..=denominator).contains(&t) && (0..=denominator).contains(&u))
+                    .then(|| self.point_at(Ratio::new(t, denominator)))
+            }
+
+            /// The exact point where the lines through the segments cross, which may be past the
+            /// ends of either segment, such as where the paths of two hailstones of 2023 day 24
+            /// cross. Returns `None` if the segments are parallel.
+            #[cfg(feature = "math")]
+            pub fn line_intersection(&self, other: &Self) -> Option<Point2D<Ratio<$t>>> {
+                let (t, _, denominator) = self.crossing_fractions(other)?;
+                Some(self.point_at(Ratio::new(t, denominator)))
+            }
+
+            /// The part of the segment that lies along `other`, if the segments are on the same
+            /// line and have at least one point in common. The overlap runs in the direction of
+            /// increasing x-coordinate, or of increasing y-coordinate if the segments are
+            /// vertical.
+            pub fn overlap(&self, other: &Self) -> Option<Self> {
+                let on_line = |segment: &Self, point: Point2D<$t>| {
+                    cross(&(segment.end - segment.start), &(point - segment.start)) == 0
+                };
+                if !(on_line(self, other.start)
+                    && on_line(self, other.end)
+                    && on_line(other, self.start)
+                    && on_line(other, self.end))
+                {
+                    return None;
+                }
+                // Points along a line are in the same order as their coordinates are.
+                let key = |point: &Point2D<$t>| (*point.x(), *point.y());
+                let ordered = |segment: &Self| {
+                    if key(&segment.start) <= key(&segment.end) {
+                        (segment.start, segment.end)
+                    } else {
+                        (segment.end, segment.start)
+                    }
+                };
+                let (start, end) = ordered(self);
+                let (other_start, other_end) = ordered(other);
+                let start = std::cmp::max_by_key(start, other_start, key);
+                let end = std::cmp::min_by_key(end, other_end, key);
+                (key(&start) <= key(&end)).then(|| Self::new(start, end))
+            }
+
+            /// The [length](Self::length) of the [overlap](Self::overlap) of the segments, or 0 if
+            /// they don't overlap. Segments which have only one point in common also overlap by
+            /// 0.
+            pub fn overlap_length(&self, other: &Self) -> $t {
+                self.overlap(other).map_or(0, |overlap| overlap.length())
+            }
+        }
+    )+)
+}
+
+impl_segment!(i8 i16 i32 i64 i128 isize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::collections::HashMap;
+
+    fn segment(x1: i32, y1: i32, x2: i32, y2: i32) -> Segment<i32> {
+        Segment::new(Point2D::at(x1, y1), Point2D::at(x2, y2))
+    }
+
+    #[test]
+    fn test_vents() {
+        // The example vents from 2021 day 5.
+        let vents = [
+            segment(0, 9, 5, 9),
+            segment(8, 0, 0, 8),
+            segment(9, 4, 3, 4),
+            segment(2, 2, 2, 1),
+            segment(7, 0, 7, 4),
+            segment(6, 4, 2, 0),
+            segment(0, 9, 2, 9),
+            segment(3, 4, 1, 4),
+            segment(0, 0, 8, 8),
+            segment(5, 5, 8, 2),
+        ];
+        let dangerous = |vents: &mut dyn Iterator<Item = &Segment<i32>>| {
+            let mut counts = HashMap::new();
+            for point in vents.flat_map(Segment::<i32>::points) {
+                *counts.entry(point).or_insert(0) += 1;
+            }
+            counts.values().filter(|&&count| count >= 2).count()
+        };
+        assert_eq!(
+            5,
+            dangerous(&mut vents.iter().filter(|vent| vent.is_axis_aligned()))
+        );
+        assert!(vents
+            .iter()
+            .all(|vent| vent.is_axis_aligned() || vent.is_diagonal()));
+        assert_eq!(12, dangerous(&mut vents.iter()));
+    }
+
+    #[test]
+    fn test_lattice_points() {
+        assert_eq!(
+            vec![Point2D::at(0, 0), Point2D::at(3, 2), Point2D::at(6, 4)],
+            segment(0, 0, 6, 4).lattice_points().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![Point2D::at(1, 5), Point2D::at(1, 3), Point2D::at(1, 1)],
+            segment(1, 5, 1, 1)
+                .lattice_points()
+                .step_by(2)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![Point2D::at(0, 0), Point2D::at(5, -2)],
+            segment(0, 0, 5, -2).lattice_points().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![Point2D::at(2, 2)],
+            segment(2, 2, 2, 2).lattice_points().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_bresenham_points() {
+        assert_eq!(
+            [(0, 0), (1, 0), (2, 1), (3, 1), (4, 2), (5, 2)].map(|(x, y)| Point2D::at(x, y)),
+            *segment(0, 0, 5, 2).bresenham_points().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            [(0, 0), (-1, -1), (-1, -2), (-2, -3)].map(|(x, y)| Point2D::at(x, y)),
+            *segment(0, 0, -2, -3).bresenham_points().collect::<Vec<_>>()
+        );
+        for line in [
+            segment(0, 9, 5, 9),
+            segment(8, 0, 0, 8),
+            segment(2, 2, 2, 1),
+            segment(6, 4, 2, 0),
+            segment(3, 3, 3, 3),
+        ] {
+            assert!(line.bresenham_points().eq(line.points()));
+            assert!(line.bresenham_points().eq(line.lattice_points()));
+        }
+    }
+
+    #[test]
+    #[should_panic = "neither axis-aligned nor diagonal"]
+    fn test_points_steep() {
+        segment(0, 0, 1, 2).points().count();
+    }
+
+    #[test]
+    fn test_wires() {
+        // The first example wires from 2019 day 3, which cross closest to the origin at (3, 3).
+        let wire = |points: &[(i32, i32)]| {
+            points
+                .windows(2)
+                .map(|pair| segment(pair[0].0, pair[0].1, pair[1].0, pair[1].1))
+                .collect::<Vec<_>>()
+        };
+        let first = wire(&[(0, 0), (8, 0), (8, 5), (3, 5), (3, 2)]);
+        let second = wire(&[(0, 0), (0, 7), (6, 7), (6, 3), (2, 3)]);
+        let origin = Point2D::at(0, 0);
+        let closest = first
+            .iter()
+            .flat_map(|a| second.iter().filter_map(|b| a.crossing(b)))
+            .filter(|&crossing| crossing != origin)
+            .map(|crossing| crossing.manhattan_distance(&origin))
+            .min();
+        assert_eq!(Some(6), closest);
+    }
+
+    #[test]
+    fn test_crossing() {
+        let a = segment(0, 0, 4, 4);
+        assert_eq!(Some(Point2D::at(2, 2)), a.crossing(&segment(0, 4, 4, 0)));
+        assert!(a.intersects(&segment(0, 4, 4, 0)));
+        // These cross at (1.5, 1.5), which isn't a point with integer coordinates.
+        assert_eq!(None, a.crossing(&segment(0, 3, 3, 0)));
+        assert!(a.intersects(&segment(0, 3, 3, 0)));
+        assert_eq!(None, a.crossing(&segment(0, 1, 1, 5)));
+        assert!(!a.intersects(&segment(0, 1, 1, 5)));
+        // Touching at an end counts.
+        assert_eq!(Some(Point2D::at(4, 4)), a.crossing(&segment(4, 4, 9, 0)));
+        assert_eq!(Some(Point2D::at(4, 4)), a.crossing(&segment(4, 4, 6, 6)));
+        assert_eq!(None, a.crossing(&segment(3, 3, 6, 6)));
+        assert!(a.intersects(&segment(3, 3, 6, 6)));
+        assert!(!a.intersects(&segment(5, 5, 6, 6)));
+        assert!(!a.intersects(&segment(1, 0, 5, 4)));
+    }
+
+    #[cfg(feature = "math")]
+    #[test]
+    fn test_intersection() {
+        let a = segment(0, 0, 4, 4);
+        let point =
+            |x: (i32, i32), y: (i32, i32)| Point2D::at(Ratio::new(x.0, x.1), Ratio::new(y.0, y.1));
+        assert_eq!(
+            Some(point((3, 2), (3, 2))),
+            a.intersection(&segment(0, 3, 3, 0))
+        );
+        assert_eq!(
+            Some(point((2, 1), (2, 1))),
+            a.intersection(&segment(0, 4, 4, 0))
+        );
+        assert_eq!(None, a.intersection(&segment(0, 1, 1, 5)));
+        assert_eq!(
+            Some(point((-1, 3), (-1, 3))),
+            a.line_intersection(&segment(0, 1, 1, 5))
+        );
+        assert_eq!(None, a.intersection(&segment(3, 3, 6, 6)));
+        assert_eq!(None, a.line_intersection(&segment(1, 0, 5, 4)));
+    }
+
+    #[cfg(feature = "math")]
+    #[test]
+    fn test_hailstones() {
+        // The hailstones of 2023 day 24, ignoring the z axis, of which the paths of 2 pairs cross
+        // inside the test area in the future.
+        let hailstones: [((i64, i64), (i64, i64)); 5] = [
+            ((19, 13), (-2, 1)),
+            ((18, 19), (-1, -1)),
+            ((20, 25), (-2, -2)),
+            ((12, 31), (-1, -2)),
+            ((20, 19), (1, -5)),
+        ];
+        let paths = hailstones
+            .map(|((x, y), (dx, dy))| Segment::new(Point2D::at(x, y), Point2D::at(x + dx, y + dy)));
+        let in_future = |path: &Segment<i64>, point: &Point2D<Ratio<i64>>| {
+            let start = Ratio::from_integer(*path.start().x());
+            let velocity = Ratio::from_integer(path.end().x() - path.start().x());
+            (*point.x() - start) * velocity >= Ratio::ZERO
+        };
+        let test_area = Ratio::from_integer(7)..=Ratio::from_integer(27);
+        let mut crossings = 0;
+        for (i, a) in paths.iter().enumerate() {
+            for b in &paths[i + 1..] {
+                if let Some(point) = a.line_intersection(b) {
+                    if test_area.contains(point.x())
+                        && test_area.contains(point.y())
+                        && in_future(a, &point)
+                        && in_future(b, &point)
+                    {
+                        crossings += 1;
+                    }
+                }
+            }
+        }
+        assert_eq!(2, crossings);
+        assert_eq!(
+            Some(Point2D::at(Ratio::new(43, 3), Ratio::new(46, 3))),
+            paths[0].line_intersection(&paths[1])
+        );
+    }
+
+    #[test]
+    fn test_overlap() {
+        let a = segment(0, 0, 0, 5);
+        assert_eq!(Some(segment(0, 2, 0, 5)), a.overlap(&segment(0, 9, 0, 2)));
+        assert_eq!(3, a.overlap_length(&segment(0, 9, 0, 2)));
+        assert_eq!(Some(segment(0, 5, 0, 5)), a.overlap(&segment(0, 5, 0, 7)));
+        assert_eq!(0, a.overlap_length(&segment(0, 5, 0, 7)));
+        assert_eq!(None, a.overlap(&segment(0, 6, 0, 7)));
+        assert_eq!(None, a.overlap(&segment(1, 0, 1, 5)));
+        assert_eq!(None, a.overlap(&segment(0, 0, 5, 0)));
+        let diagonal = segment(5, 0, 0, 5);
+        assert_eq!(
+            Some(segment(0, 5, 3, 2)),
+            diagonal.overlap(&segment(3, 2, -1, 6))
+        );
+        assert_eq!(3, diagonal.overlap_length(&segment(3, 2, -1, 6)));
+    }
+}
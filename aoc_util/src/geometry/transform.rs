@@ -0,0 +1,289 @@
+use std::ops::Neg;
+
+use super::{Point2D, Point3D};
+
+impl<T> Point2D<T>
+where
+    T: Clone + Neg<Output = T>,
+{
+    /// Rotates the point a quarter turn counterclockwise around the origin, with the y-axis
+    /// pointing up as it does for [`Direction::Up`].
+    ///
+    /// [`Direction::Up`]: super::Direction::Up
+    pub fn rotate_left(&self) -> Self {
+        Self::at(-self.y().clone(), self.x().clone())
+    }
+
+    /// Rotates the point a quarter turn clockwise around the origin, with the y-axis pointing up
+    /// as it does for [`Direction::Up`].
+    ///
+    /// [`Direction::Up`]: super::Direction::Up
+    pub fn rotate_right(&self) -> Self {
+        Self::at(self.y().clone(), -self.x().clone())
+    }
+
+    /// Reflects the point across the y-axis, negating its x-coordinate.
+    pub fn reflect_x(&self) -> Self {
+        Self::at(-self.x().clone(), self.y().clone())
+    }
+
+    /// Reflects the point across the x-axis, negating its y-coordinate.
+    pub fn reflect_y(&self) -> Self {
+        Self::at(self.x().clone(), -self.y().clone())
+    }
+}
+
+/// Rotates a grid whose first row is the top a quarter turn clockwise, so that its first column
+/// becomes its first row. Every row must be as long as the first.
+pub fn rotate_grid<T>(grid: &[Vec<T>]) -> Vec<Vec<T>>
+where
+    T: Clone,
+{
+    let width = grid.first().map_or(0, Vec::len);
+    (0..width)
+        .map(|x| grid.iter().rev().map(|row| row[x].clone()).collect())
+        .collect()
+}
+
+/// Rotates a grid whose first row is the top a quarter turn counterclockwise, so that its last
+/// column becomes its first row. Every row must be as long as the first.
+pub fn rotate_grid_left<T>(grid: &[Vec<T>]) -> Vec<Vec<T>>
+where
+    T: Clone,
+{
+    let width = grid.first().map_or(0, Vec::len);
+    (0..width)
+        .rev()
+        .map(|x| grid.iter().map(|row| row[x].clone()).collect())
+        .collect()
+}
+
+/// Transposes a grid, so that its columns become its rows, such as to look for a line of
+/// reflection between two columns of the patterns of 2023 day 13 the same way as between two
+/// rows. Every row must be as long as the first.
+pub fn transpose_grid<T>(grid: &[Vec<T>]) -> Vec<Vec<T>>
+where
+    T: Clone,
+{
+    let width = grid.first().map_or(0, Vec::len);
+    (0..width)
+        .map(|x| grid.iter().map(|row| row[x].clone()).collect())
+        .collect()
+}
+
+/// Flips a grid from top to bottom, reversing the order of its rows.
+pub fn flip_grid<T>(grid: &[Vec<T>]) -> Vec<Vec<T>>
+where
+    T: Clone,
+{
+    grid.iter().rev().cloned().collect()
+}
+
+/// Reflects a grid from left to right, reversing each of its rows.
+pub fn reflect_grid<T>(grid: &[Vec<T>]) -> Vec<Vec<T>>
+where
+    T: Clone,
+{
+    grid.iter()
+        .map(|row| row.iter().rev().cloned().collect())
+        .collect()
+}
+
+/// The 8 ways that a grid can be rotated and reflected, such as to find the one in which the
+/// tiles of 2020 day 20 line up. The first is the grid as it is, the next three are it rotated
+/// clockwise one, two, and three times, and the last four are the same for the grid reflected.
+pub fn grid_orientations<T>(grid: &[Vec<T>]) -> Vec<Vec<Vec<T>>>
+where
+    T: Clone,
+{
+    let mut orientations = Vec::with_capacity(8);
+    for start in [grid.to_vec(), reflect_grid(grid)] {
+        let mut current = start;
+        for _ in 0..4 {
+            let next = rotate_grid(&current);
+            orientations.push(current);
+            current = next;
+        }
+    }
+    orientations
+}
+
+/// One of the 24 ways that 3-dimensional space can be rotated so that each axis points along an
+/// axis, which are the ways that the scanners of 2021 day 19 can be facing.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Orientation {
+    /// The axis of the original point which each axis of the rotated point comes from.
+    axes: [usize; 3],
+    /// Whether each axis of the rotated point is negated.
+    negated: [bool; 3],
+}
+
+impl Orientation {
+    /// The orientation which leaves every point where it is.
+    pub const IDENTITY: Self = Self {
+        axes: [0, 1, 2],
+        negated: [false; 3],
+    };
+
+    /// All 24 orientations, starting with [`Orientation::IDENTITY`].
+    pub fn all() -> Vec<Self> {
+        const PERMUTATIONS: [[usize; 3]; 6] = [
+            [0, 1, 2],
+            [1, 2, 0],
+            [2, 0, 1],
+            [0, 2, 1],
+            [1, 0, 2],
+            [2, 1, 0],
+        ];
+        PERMUTATIONS
+            .into_iter()
+            .enumerate()
+            .flat_map(|(i, axes)| {
+                // The last three permutations swap two axes, which reflects space, so an odd
+                // number of axes have to be negated to make them rotations.
+                let odd = i >= 3;
+                (0..8_u8)
+                    .map(move |signs| [signs & 1 != 0, signs & 2 != 0, signs & 4 != 0])
+                    .filter(move |negated| (negated.iter().filter(|&&n| n).count() % 2 == 1) == odd)
+                    .map(move |negated| Self { axes, negated })
+            })
+            .collect()
+    }
+
+    /// Rotates `point` by this orientation.
+    pub fn apply<T>(&self, point: &Point3D<T>) -> Point3D<T>
+    where
+        T: Clone + Neg<Output = T>,
+    {
+        let coords = [point.x(), point.y(), point.z()];
+        let [x, y, z] = [0, 1, 2].map(|axis| {
+            let coord = coords[self.axes[axis]].clone();
+            if self.negated[axis] {
+                -coord
+            } else {
+                coord
+            }
+        });
+        Point3D::at(x, y, z)
+    }
+
+    /// The orientation which undoes this one.
+    pub fn inverse(&self) -> Self {
+        let mut inverse = Self::IDENTITY;
+        for axis in 0..3 {
+            inverse.axes[self.axes[axis]] = axis;
+            inverse.negated[self.axes[axis]] = self.negated[axis];
+        }
+        inverse
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_rotate_point() {
+        let point = Point2D::at(2, 1);
+        assert_eq!(Point2D::at(-1, 2), point.rotate_left());
+        assert_eq!(Point2D::at(1, -2), point.rotate_right());
+        assert_eq!(point, point.rotate_left().rotate_right());
+        assert_eq!(-point, point.rotate_left().rotate_left());
+        assert_eq!(Point2D::at(-2, 1), point.reflect_x());
+        assert_eq!(Point2D::at(2, -1), point.reflect_y());
+    }
+
+    #[test]
+    fn test_grid_orientations() {
+        let grid = vec![vec!['a', 'b', 'c'], vec!['d', 'e', 'f']];
+        assert_eq!(
+            vec![vec!['d', 'a'], vec!['e', 'b'], vec!['f', 'c']],
+            rotate_grid(&grid)
+        );
+        assert_eq!(
+            vec![vec!['c', 'b', 'a'], vec!['f', 'e', 'd']],
+            reflect_grid(&grid)
+        );
+        assert_eq!(
+            vec![vec!['c', 'f'], vec!['b', 'e'], vec!['a', 'd']],
+            rotate_grid_left(&grid)
+        );
+        assert_eq!(
+            vec![vec!['a', 'd'], vec!['b', 'e'], vec!['c', 'f']],
+            transpose_grid(&grid)
+        );
+        assert_eq!(
+            vec![vec!['d', 'e', 'f'], vec!['a', 'b', 'c']],
+            flip_grid(&grid)
+        );
+        let orientations = grid_orientations(&grid);
+        assert_eq!(grid, orientations[0]);
+        assert_eq!(rotate_grid(&orientations[3]), grid);
+        assert_eq!(rotate_grid_left(&grid), orientations[3]);
+        assert_eq!(8, orientations.iter().collect::<HashSet<_>>().len());
+        // Every way of transforming the grid is one of the 8 orientations.
+        for transformed in [
+            transpose_grid(&grid),
+            flip_grid(&grid),
+            reflect_grid(&flip_grid(&grid)),
+            transpose_grid(&rotate_grid(&grid)),
+        ] {
+            assert!(orientations.contains(&transformed));
+        }
+        // A square grid whose cells are all different has 8 distinct orientations as well.
+        let square = vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]];
+        let orientations = grid_orientations(&square);
+        assert_eq!(8, orientations.iter().collect::<HashSet<_>>().len());
+        assert!(orientations.contains(&transpose_grid(&square)));
+        assert!(orientations.contains(&transpose_grid(&rotate_grid(&square))));
+    }
+
+    #[test]
+    fn test_mirrors() {
+        // The number of rows above the line that a pattern of 2023 day 13 is reflected across.
+        fn mirror_row(pattern: &[Vec<char>]) -> Option<usize> {
+            (1..pattern.len()).find(|&row| {
+                pattern[..row]
+                    .iter()
+                    .rev()
+                    .zip(&pattern[row..])
+                    .all(|(above, below)| above == below)
+            })
+        }
+        let parse = |pattern: &str| {
+            pattern
+                .lines()
+                .map(|line| line.chars().collect::<Vec<_>>())
+                .collect::<Vec<_>>()
+        };
+        let first =
+            parse("#.##..##.\n..#.##.#.\n##......#\n##......#\n..#.##.#.\n..##..##.\n#.#.##.#.\n");
+        let second =
+            parse("#...##..#\n#....#..#\n..##..###\n#####.##.\n#####.##.\n..##..###\n#....#..#\n");
+        assert_eq!(None, mirror_row(&first));
+        assert_eq!(Some(5), mirror_row(&transpose_grid(&first)));
+        assert_eq!(Some(4), mirror_row(&second));
+        // Flipping the pattern over puts the line 4 rows from the bottom instead.
+        assert_eq!(Some(3), mirror_row(&flip_grid(&second)));
+    }
+
+    #[test]
+    fn test_orientations() {
+        let orientations = Orientation::all();
+        assert_eq!(Orientation::IDENTITY, orientations[0]);
+        let point = Point3D::at(1, 2, 3);
+        let rotated = orientations
+            .iter()
+            .map(|orientation| orientation.apply(&point))
+            .collect::<HashSet<_>>();
+        assert_eq!(24, rotated.len());
+        // Rotations keep the handedness of the axes, so they never reflect a point.
+        assert!(!rotated.contains(&Point3D::at(-1, 2, 3)));
+        for orientation in orientations {
+            let inverse = orientation.inverse();
+            assert_eq!(point, inverse.apply(&orientation.apply(&point)));
+        }
+    }
+}
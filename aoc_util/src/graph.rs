@@ -0,0 +1,921 @@
+use std::{
+    cmp::Reverse,
+    collections::{BTreeSet, BinaryHeap, HashMap, HashSet, VecDeque},
+    error,
+    fmt::{self, Debug, Display, Formatter},
+    hash::Hash,
+    ops::{Add, Sub},
+};
+
+use crate::{
+    collections::BitSet64,
+    error::LimitExceeded,
+    limits::{Budget, Limits},
+};
+
+/// The identifier of a node in a [`Graph`]. Identifiers are never reused, so removing nodes
+/// doesn't change which node any other identifier refers to.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct NodeId(usize);
+
+/// An edge from one node of a [`Graph`] to another.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Connection<W> {
+    /// The node that the edge leads to.
+    pub to: NodeId,
+    /// The weight of the edge, such as the distance between the nodes.
+    pub weight: W,
+}
+
+/// A directed graph whose nodes hold values of type `N` and whose edges have weights of type `W`.
+/// There is at most one edge from any node to any other.
+#[derive(Clone, Debug)]
+pub struct Graph<N, W> {
+    nodes: HashMap<NodeId, N>,
+    outgoing: HashMap<NodeId, HashMap<NodeId, W>>,
+    incoming: HashMap<NodeId, HashSet<NodeId>>,
+    next_id: usize,
+}
+
+impl<N, W> Default for Graph<N, W> {
+    fn default() -> Self {
+        Self {
+            nodes: HashMap::new(),
+            outgoing: HashMap::new(),
+            incoming: HashMap::new(),
+            next_id: 0,
+        }
+    }
+}
+
+impl<N, W> Graph<N, W> {
+    /// Creates a graph without any nodes.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The number of nodes in the graph.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Whether the graph has no nodes.
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Adds a node which holds `value` and isn't connected to any other node yet.
+    pub fn add_node(&mut self, value: N) -> NodeId {
+        let id = NodeId(self.next_id);
+        self.next_id += 1;
+        self.nodes.insert(id, value);
+        self.outgoing.insert(id, HashMap::new());
+        self.incoming.insert(id, HashSet::new());
+        id
+    }
+
+    /// The value of the node `id`, if it's in the graph.
+    pub fn node(&self, id: NodeId) -> Option<&N> {
+        self.nodes.get(&id)
+    }
+
+    /// The nodes of the graph along with their values, in no particular order.
+    pub fn nodes(&self) -> impl Iterator<Item = (NodeId, &N)> + '_ {
+        self.nodes.iter().map(|(&id, value)| (id, value))
+    }
+
+    /// Adds an edge from `from` to `to` with weight `weight`, replacing any edge that was already
+    /// between them in that direction.
+    ///
+    /// # Panics
+    /// Panics if either node isn't in the graph.
+    pub fn add_edge(&mut self, from: NodeId, to: NodeId, weight: W) {
+        assert!(self.nodes.contains_key(&to), "{to:?} isn't in the graph");
+        self.outgoing
+            .get_mut(&from)
+            .unwrap_or_else(|| panic!("{from:?} isn't in the graph"))
+            .insert(to, weight);
+        self.incoming.get_mut(&to).unwrap().insert(from);
+    }
+
+    /// Adds edges with weight `weight` in both directions between `a` and `b`.
+    ///
+    /// # Panics
+    /// Panics if either node isn't in the graph.
+    pub fn connect(&mut self, a: NodeId, b: NodeId, weight: W)
+    where
+        W: Clone,
+    {
+        self.add_edge(a, b, weight.clone());
+        self.add_edge(b, a, weight);
+    }
+
+    /// The weight of the edge from `from` to `to`, if there is one.
+    pub fn weight(&self, from: NodeId, to: NodeId) -> Option<&W> {
+        self.outgoing.get(&from)?.get(&to)
+    }
+
+    /// The edges from the node `id`, in no particular order.
+    pub fn neighbors(&self, id: NodeId) -> impl Iterator<Item = Connection<W>> + '_
+    where
+        W: Clone,
+    {
+        self.outgoing
+            .get(&id)
+            .into_iter()
+            .flatten()
+            .map(|(&to, weight)| Connection {
+                to,
+                weight: weight.clone(),
+            })
+    }
+
+    /// Removes the node `id` along with every edge to or from it, returning its value.
+    pub fn remove_node(&mut self, id: NodeId) -> Option<N> {
+        let value = self.nodes.remove(&id)?;
+        for to in self.outgoing.remove(&id).unwrap().into_keys() {
+            if let Some(sources) = self.incoming.get_mut(&to) {
+                sources.remove(&id);
+            }
+        }
+        for from in self.incoming.remove(&id).unwrap() {
+            if let Some(targets) = self.outgoing.get_mut(&from) {
+                targets.remove(&id);
+            }
+        }
+        Some(value)
+    }
+
+    /// Removes every node which is connected to exactly two other nodes and can be passed through,
+    /// such as a tile in the middle of a corridor, unless `keep` returns `true` for it. Each path
+    /// through a removed node is replaced by an edge whose weight is the sum of the weights along
+    /// the path, so that a search only has to visit the nodes where something interesting happens.
+    /// A node isn't removed if one of the edges that would replace it is already in the graph.
+    pub fn contract(&mut self, mut keep: impl FnMut(NodeId, &N) -> bool)
+    where
+        W: Add<Output = W> + Clone,
+    {
+        let mut ids = self.nodes.keys().copied().collect::<Vec<_>>();
+        ids.sort_unstable();
+        for id in ids {
+            if keep(id, &self.nodes[&id]) {
+                continue;
+            }
+            let sources = &self.incoming[&id];
+            let targets = &self.outgoing[&id];
+            let adjacent = sources.iter().chain(targets.keys()).collect::<HashSet<_>>();
+            if adjacent.len() != 2 || adjacent.contains(&id) {
+                continue;
+            }
+            let bypasses = sources
+                .iter()
+                .flat_map(|&from| {
+                    targets
+                        .iter()
+                        .filter(move |&(&to, _)| to != from)
+                        .map(move |(&to, weight)| (from, to, weight))
+                })
+                .map(|(from, to, weight)| {
+                    (from, to, self.outgoing[&from][&id].clone() + weight.clone())
+                })
+                .collect::<Vec<_>>();
+            // A node that can't be passed through, such as a dead end, has to stay.
+            if bypasses.is_empty()
+                || bypasses
+                    .iter()
+                    .any(|&(from, to, _)| self.weight(from, to).is_some())
+            {
+                continue;
+            }
+            self.remove_node(id);
+            for (from, to, weight) in bypasses {
+                self.add_edge(from, to, weight);
+            }
+        }
+    }
+}
+
+impl<N, W> Graph<N, W>
+where
+    W: Add<Output = W> + Copy + Default + Ord,
+{
+    /// The length of the longest path from `start` to `end` which doesn't visit any node twice, or
+    /// `None` if there's no path between them. Finding it is exponential in the number of nodes,
+    /// so the graph should be [contracted] first.
+    ///
+    /// The nodes that a path has visited are kept in a [`BitSet64`], and the longest way to finish
+    /// from each node with each set of visited nodes is only worked out once.
+    ///
+    /// # Panics
+    /// Panics if the graph has more than 64 nodes or either node isn't in the graph.
+    ///
+    /// [contracted]: Self::contract
+    pub fn longest_path(&self, start: NodeId, end: NodeId) -> Option<W> {
+        assert!(
+            self.len() <= BitSet64::CAPACITY,
+            "{} nodes don't fit in a bitmask",
+            self.len()
+        );
+        let mut ids = self.nodes.keys().copied().collect::<Vec<_>>();
+        ids.sort_unstable();
+        let bits = ids
+            .iter()
+            .enumerate()
+            .map(|(bit, &id)| (id, bit))
+            .collect::<HashMap<_, _>>();
+        let edges = ids
+            .iter()
+            .map(|id| {
+                self.outgoing[id]
+                    .iter()
+                    .map(|(to, &weight)| (bits[to], weight))
+                    .collect()
+            })
+            .collect();
+        let mut search = LongestPath {
+            edges,
+            end: bits[&end],
+            longest: HashMap::new(),
+        };
+        let start = bits[&start];
+        search.longest_from(start, BitSet64::EMPTY.with(start))
+    }
+}
+
+/// The state of [`Graph::longest_path()`], with the nodes numbered by their bits.
+struct LongestPath<W> {
+    edges: Vec<Vec<(usize, W)>>,
+    end: usize,
+    /// The longest way to the end from each node with each set of nodes already visited.
+    longest: HashMap<(usize, BitSet64), Option<W>>,
+}
+
+impl<W> LongestPath<W>
+where
+    W: Add<Output = W> + Copy + Default + Ord,
+{
+    fn longest_from(&mut self, node: usize, visited: BitSet64) -> Option<W> {
+        if node == self.end {
+            return Some(W::default());
+        }
+        if let Some(&longest) = self.longest.get(&(node, visited)) {
+            return longest;
+        }
+        let mut longest = None;
+        for i in 0..self.edges[node].len() {
+            let (to, weight) = self.edges[node][i];
+            if visited.contains(to) {
+                continue;
+            }
+            if let Some(rest) = self.longest_from(to, visited.with(to)) {
+                longest = longest.max(Some(weight + rest));
+            }
+        }
+        self.longest.insert((node, visited), longest);
+        longest
+    }
+}
+
+/// A cut of a [`Graph`] with as little capacity as possible between a source and a sink, found
+/// by [`Graph::min_cut()`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MinCut<W> {
+    /// The most that can flow from the source to the sink, which is the total capacity of the
+    /// edges across the cut.
+    pub flow: W,
+    /// The nodes on the same side of the cut as the source.
+    pub source_side: HashSet<NodeId>,
+}
+
+impl<W> MinCut<W> {
+    /// The edges of `graph` which cross the cut, from the source's side to the sink's.
+    pub fn edges<N>(&self, graph: &Graph<N, W>) -> Vec<(NodeId, NodeId)> {
+        let mut edges = self
+            .source_side
+            .iter()
+            .flat_map(|&from| {
+                graph.outgoing[&from]
+                    .keys()
+                    .filter(|to| !self.source_side.contains(to))
+                    .map(move |&to| (from, to))
+            })
+            .collect::<Vec<_>>();
+        edges.sort_unstable();
+        edges
+    }
+}
+
+impl<N, W> Graph<N, W>
+where
+    W: Add<Output = W> + Sub<Output = W> + Copy + Default + Ord,
+{
+    /// Finds the most that can flow from `source` to `sink` when the weight of each edge is how
+    /// much can flow along it, along with a cut between them which only that much can cross. An
+    /// edge in each direction, as added by [`connect()`], can carry that much either way.
+    ///
+    /// This is the Edmonds-Karp algorithm, which keeps sending flow along the shortest path that
+    /// still has room for more until there's none left.
+    ///
+    /// # Panics
+    /// Panics if `source` is `sink`.
+    ///
+    /// [`connect()`]: Self::connect
+    pub fn min_cut(&self, source: NodeId, sink: NodeId) -> MinCut<W> {
+        assert_ne!(source, sink, "The source and the sink have to be different");
+        // How much more can flow along each edge, including the edges back against each edge
+        // that flow has been sent along.
+        let mut residual = HashMap::<(NodeId, NodeId), W>::new();
+        for (&from, targets) in &self.outgoing {
+            for (&to, &capacity) in targets {
+                *residual.entry((from, to)).or_default() = capacity;
+                residual.entry((to, from)).or_default();
+            }
+        }
+        let mut adjacent = HashMap::<NodeId, Vec<NodeId>>::new();
+        for &(from, to) in residual.keys() {
+            adjacent.entry(from).or_default().push(to);
+        }
+        let mut flow = W::default();
+        loop {
+            let mut parents = HashMap::from([(source, source)]);
+            let mut queue = VecDeque::from([source]);
+            while let Some(node) = queue.pop_front() {
+                if node == sink {
+                    break;
+                }
+                for &next in adjacent.get(&node).into_iter().flatten() {
+                    if residual[&(node, next)] > W::default() && !parents.contains_key(&next) {
+                        parents.insert(next, node);
+                        queue.push_back(next);
+                    }
+                }
+            }
+            if !parents.contains_key(&sink) {
+                // The nodes that can still be reached are cut off from the sink.
+                let source_side = parents.into_keys().collect();
+                return MinCut { flow, source_side };
+            }
+            let mut path = vec![];
+            let mut node = sink;
+            while node != source {
+                path.push((parents[&node], node));
+                node = parents[&node];
+            }
+            let added = path
+                .iter()
+                .map(|edge| residual[edge])
+                .min()
+                .expect("The source isn't the sink");
+            for (from, to) in path {
+                *residual.get_mut(&(from, to)).unwrap() = residual[&(from, to)] - added;
+                *residual.get_mut(&(to, from)).unwrap() = residual[&(to, from)] + added;
+            }
+            flow = flow + added;
+        }
+    }
+}
+
+/// A graph couldn't be sorted topologically because it has a cycle.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Cycle<T> {
+    /// The nodes around the cycle in the order of its edges. The last node has an edge back to
+    /// the first.
+    pub nodes: Vec<T>,
+}
+
+impl<T: Display> Display for Cycle<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "Cycle: ")?;
+        for node in &self.nodes {
+            write!(f, "{node} -> ")?;
+        }
+        match self.nodes.first() {
+            Some(first) => write!(f, "{first}"),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<T: Debug + Display> error::Error for Cycle<T> {}
+
+/// Sorts the nodes of the graph whose edges are given by `graph` so that every node comes before
+/// the nodes that it has edges to, such as steps before the steps which depend on them. Nodes
+/// which only appear as the target of an edge are included. Whenever more than one node could
+/// come next, the least one does, so the order is the same from run to run.
+///
+/// If the graph has a cycle, then no such order exists and one of the cycles is returned instead.
+pub fn toposort<T>(graph: &HashMap<T, Vec<T>>) -> Result<Vec<T>, Cycle<T>>
+where
+    T: Clone + Eq + Hash + Ord,
+{
+    let mut in_degrees = HashMap::<&T, usize>::new();
+    for (node, targets) in graph {
+        in_degrees.entry(node).or_default();
+        for target in targets {
+            *in_degrees.entry(target).or_default() += 1;
+        }
+    }
+    let mut ready = in_degrees
+        .iter()
+        .filter(|&(_, &in_degree)| in_degree == 0)
+        .map(|(&node, _)| Reverse(node))
+        .collect::<BinaryHeap<_>>();
+    let mut sorted = Vec::with_capacity(in_degrees.len());
+    while let Some(Reverse(node)) = ready.pop() {
+        sorted.push(node.clone());
+        for target in graph.get(node).into_iter().flatten() {
+            let in_degree = in_degrees
+                .get_mut(target)
+                .expect("Every target has an in-degree");
+            *in_degree -= 1;
+            if *in_degree == 0 {
+                ready.push(Reverse(target));
+            }
+        }
+    }
+    if sorted.len() == in_degrees.len() {
+        return Ok(sorted);
+    }
+    // Every node which couldn't be sorted has an edge from another such node, so following those
+    // edges backward from any of them has to come around to a node that was already visited.
+    let mut sources = HashMap::<&T, &T>::new();
+    for (node, targets) in graph {
+        if in_degrees[node] > 0 {
+            for target in targets {
+                sources.entry(target).or_insert(node);
+            }
+        }
+    }
+    let mut node = *in_degrees
+        .iter()
+        .filter(|&(_, &in_degree)| in_degree > 0)
+        .map(|(node, _)| node)
+        .min()
+        .expect("Some node wasn't sorted");
+    let mut visited = HashSet::new();
+    let mut path = vec![];
+    while visited.insert(node) {
+        path.push(node);
+        node = sources[node];
+    }
+    let start = path.iter().position(|&visited| visited == node).unwrap();
+    let mut nodes = path[start..]
+        .iter()
+        .map(|&node| node.clone())
+        .collect::<Vec<_>>();
+    // The path was followed backward, against the edges.
+    nodes.reverse();
+    Err(Cycle { nodes })
+}
+
+/// The strongly connected components of a graph, found by [`strongly_connected_components()`].
+/// Every node of a component can be reached from every other node of it.
+#[derive(Clone, Debug)]
+pub struct Components<T> {
+    /// The nodes of each component. The components are in topological order, so every edge
+    /// between two components goes from an earlier component to a later one.
+    pub components: Vec<Vec<T>>,
+    /// The index of the component that each node is in.
+    pub component_of: HashMap<T, usize>,
+    /// The graph with each component as a single node, as lists of the components that each
+    /// component has edges to. Since the components are in topological order, it has no cycles.
+    pub condensation: Vec<Vec<usize>>,
+}
+
+/// Finds the strongly connected components of the graph whose edges are given by `graph`, as for
+/// [`toposort()`], with Tarjan's algorithm. Nodes which only appear as the target of an edge are
+/// included.
+pub fn strongly_connected_components<T>(graph: &HashMap<T, Vec<T>>) -> Components<T>
+where
+    T: Clone + Eq + Hash + Ord,
+{
+    let mut nodes = graph
+        .iter()
+        .flat_map(|(node, targets)| std::iter::once(node).chain(targets))
+        .collect::<Vec<_>>();
+    nodes.sort_unstable();
+    nodes.dedup();
+    let mut tarjan = Tarjan {
+        graph,
+        indices: HashMap::new(),
+        low_links: HashMap::new(),
+        stack: vec![],
+        on_stack: HashSet::new(),
+        components: vec![],
+    };
+    for node in nodes {
+        if !tarjan.indices.contains_key(node) {
+            tarjan.visit(node);
+        }
+    }
+    // Tarjan's algorithm finds each component after every component that it has edges to.
+    let mut components = tarjan.components;
+    components.reverse();
+    let component_of = components
+        .iter()
+        .enumerate()
+        .flat_map(|(i, component)| component.iter().map(move |node| (node.clone(), i)))
+        .collect::<HashMap<_, _>>();
+    let mut condensation = vec![vec![]; components.len()];
+    for (node, targets) in graph {
+        let from = component_of[node];
+        for target in targets {
+            let to = component_of[target];
+            if from != to {
+                condensation[from].push(to);
+            }
+        }
+    }
+    for targets in &mut condensation {
+        targets.sort_unstable();
+        targets.dedup();
+    }
+    Components {
+        components,
+        component_of,
+        condensation,
+    }
+}
+
+/// The state of [`strongly_connected_components()`].
+struct Tarjan<'g, T> {
+    graph: &'g HashMap<T, Vec<T>>,
+    /// The order that each node was first visited in.
+    indices: HashMap<&'g T, usize>,
+    /// The earliest visited node on the stack that can be reached from each node.
+    low_links: HashMap<&'g T, usize>,
+    /// The nodes which have been visited but whose components haven't been found yet.
+    stack: Vec<&'g T>,
+    on_stack: HashSet<&'g T>,
+    components: Vec<Vec<T>>,
+}
+
+impl<'g, T> Tarjan<'g, T>
+where
+    T: Clone + Eq + Hash + Ord,
+{
+    fn visit(&mut self, node: &'g T) {
+        let index = self.indices.len();
+        self.indices.insert(node, index);
+        self.low_links.insert(node, index);
+        self.stack.push(node);
+        self.on_stack.insert(node);
+        for target in self.graph.get(node).into_iter().flatten() {
+            if !self.indices.contains_key(target) {
+                self.visit(target);
+                let low_link = self.low_links[node].min(self.low_links[target]);
+                self.low_links.insert(node, low_link);
+            } else if self.on_stack.contains(target) {
+                let low_link = self.low_links[node].min(self.indices[target]);
+                self.low_links.insert(node, low_link);
+            }
+        }
+        if self.low_links[node] == index {
+            let mut component = vec![];
+            loop {
+                let member = self.stack.pop().expect("The node is on the stack");
+                self.on_stack.remove(member);
+                component.push(member.clone());
+                if member == node {
+                    break;
+                }
+            }
+            component.sort_unstable();
+            self.components.push(component);
+        }
+    }
+}
+
+/// Finds every maximal clique of the graph whose edges are given by `graph`, where a clique is a
+/// set of nodes which all have edges to each other and a maximal clique is one which no other
+/// node could be added to. Every edge is taken to go both ways. Each clique is sorted, and so is
+/// the list of them.
+///
+/// The search is held to [`Limits::default_limits()`].
+pub fn maximal_cliques<T>(graph: &HashMap<T, Vec<T>>) -> Result<Vec<Vec<T>>, LimitExceeded>
+where
+    T: Clone + Eq + Hash + Ord,
+{
+    maximal_cliques_with_limits(graph, Limits::default_limits())
+}
+
+/// Like [`maximal_cliques()`] except that the search is held to `limits`, giving up once it has
+/// tried to extend more than `limits.max_states` cliques or has recursed more than
+/// `limits.max_depth` levels deep, which is one level per node of the clique being built.
+///
+/// This is the Bron-Kerbosch algorithm with pivoting, which never tries to extend a clique with a
+/// neighbor of the pivot since any clique containing it would be found through the pivot instead.
+pub fn maximal_cliques_with_limits<T>(
+    graph: &HashMap<T, Vec<T>>,
+    limits: Limits,
+) -> Result<Vec<Vec<T>>, LimitExceeded>
+where
+    T: Clone + Eq + Hash + Ord,
+{
+    let mut nodes = graph
+        .iter()
+        .flat_map(|(node, targets)| std::iter::once(node).chain(targets))
+        .collect::<Vec<_>>();
+    nodes.sort_unstable();
+    nodes.dedup();
+    let indices = nodes
+        .iter()
+        .enumerate()
+        .map(|(i, &node)| (node, i))
+        .collect::<HashMap<_, _>>();
+    let mut neighbors = vec![BTreeSet::new(); nodes.len()];
+    for (node, targets) in graph {
+        for target in targets {
+            let (a, b) = (indices[node], indices[target]);
+            if a != b {
+                neighbors[a].insert(b);
+                neighbors[b].insert(a);
+            }
+        }
+    }
+    let mut search = BronKerbosch {
+        neighbors,
+        budget: limits.budget(),
+        clique: vec![],
+        cliques: vec![],
+    };
+    search.extend((0..nodes.len()).collect(), BTreeSet::new())?;
+    let mut cliques = search
+        .cliques
+        .into_iter()
+        .map(|mut clique| {
+            // The nodes are numbered in sorted order.
+            clique.sort_unstable();
+            clique.into_iter().map(|i| nodes[i].clone()).collect()
+        })
+        .collect::<Vec<Vec<_>>>();
+    cliques.sort_unstable();
+    Ok(cliques)
+}
+
+/// One of the largest cliques of the graph whose edges are given by `graph`, as found by
+/// [`maximal_cliques()`]. If more than one clique is the largest, the least of them is returned.
+pub fn maximum_clique<T>(graph: &HashMap<T, Vec<T>>) -> Result<Vec<T>, LimitExceeded>
+where
+    T: Clone + Eq + Hash + Ord,
+{
+    let cliques = maximal_cliques(graph)?;
+    let largest = cliques.iter().map(Vec::len).max().unwrap_or_default();
+    Ok(cliques
+        .into_iter()
+        .find(|clique| clique.len() == largest)
+        .unwrap_or_default())
+}
+
+/// The state of [`maximal_cliques_with_limits()`], with the nodes numbered in sorted order.
+struct BronKerbosch {
+    neighbors: Vec<BTreeSet<usize>>,
+    budget: Budget,
+    /// The clique which is being extended.
+    clique: Vec<usize>,
+    cliques: Vec<Vec<usize>>,
+}
+
+impl BronKerbosch {
+    /// Finds every maximal clique which extends the current clique with some of the `candidates`
+    /// and none of the `excluded` nodes, which have already had all of their cliques found.
+    fn extend(
+        &mut self,
+        mut candidates: BTreeSet<usize>,
+        mut excluded: BTreeSet<usize>,
+    ) -> Result<(), LimitExceeded> {
+        self.budget.expand()?;
+        self.budget.check_depth(self.clique.len())?;
+        if candidates.is_empty() {
+            if excluded.is_empty() {
+                self.cliques.push(self.clique.clone());
+            }
+            return Ok(());
+        }
+        let pivot = candidates
+            .iter()
+            .chain(&excluded)
+            .copied()
+            .max_by_key(|&node| candidates.intersection(&self.neighbors[node]).count())
+            .expect("There is at least one candidate");
+        let tried = candidates
+            .difference(&self.neighbors[pivot])
+            .copied()
+            .collect::<Vec<_>>();
+        for node in tried {
+            let neighbors = &self.neighbors[node];
+            let next_candidates = candidates.intersection(neighbors).copied().collect();
+            let next_excluded = excluded.intersection(neighbors).copied().collect();
+            self.clique.push(node);
+            self.extend(next_candidates, next_excluded)?;
+            self.clique.pop();
+            candidates.remove(&node);
+            excluded.insert(node);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::error::Limit;
+
+    #[test]
+    fn test_contract() {
+        // A -1- x -2- y -3- B -4- C, where x and y are in the middle of a corridor.
+        let mut graph = Graph::new();
+        let [a, x, y, b, c] = ['A', 'x', 'y', 'B', 'C'].map(|name| graph.add_node(name));
+        graph.connect(a, x, 1);
+        graph.connect(x, y, 2);
+        graph.connect(y, b, 3);
+        graph.connect(b, c, 4);
+        graph.contract(|_, name| name.is_ascii_uppercase());
+        assert_eq!(3, graph.len());
+        assert_eq!(None, graph.node(x));
+        assert_eq!(Some(&6), graph.weight(a, b));
+        assert_eq!(Some(&6), graph.weight(b, a));
+        let mut neighbors = graph.neighbors(b).collect::<Vec<_>>();
+        neighbors.sort_by_key(|connection| connection.to);
+        assert_eq!(
+            vec![
+                Connection { to: a, weight: 6 },
+                Connection { to: c, weight: 4 }
+            ],
+            neighbors
+        );
+        // B is connected to two other nodes, but it's kept.
+        assert_eq!(Some(&4), graph.weight(c, b));
+    }
+
+    #[test]
+    fn test_contract_one_way() {
+        // a -> b -> c -> d, and e -> f -> g where f would be replaced by an edge that's already
+        // there.
+        let mut graph = Graph::new();
+        let [a, b, c, d, e, f, g] = ['a', 'b', 'c', 'd', 'e', 'f', 'g'].map(|n| graph.add_node(n));
+        graph.add_edge(a, b, 1);
+        graph.add_edge(b, c, 2);
+        graph.add_edge(c, d, 3);
+        graph.add_edge(e, f, 1);
+        graph.add_edge(f, g, 1);
+        graph.add_edge(e, g, 5);
+        graph.contract(|id, _| [a, d, e, g].contains(&id));
+        assert_eq!(5, graph.len());
+        assert_eq!(Some(&6), graph.weight(a, d));
+        assert_eq!(None, graph.weight(d, a));
+        assert_eq!(Some(&'f'), graph.node(f));
+        assert_eq!(Some(&5), graph.weight(e, g));
+    }
+
+    #[test]
+    fn test_longest_path() {
+        let mut graph = Graph::new();
+        let [a, b, c, d, e] = ['a', 'b', 'c', 'd', 'e'].map(|name| graph.add_node(name));
+        graph.connect(a, b, 10);
+        graph.connect(b, d, 1);
+        graph.connect(a, c, 5);
+        graph.connect(c, d, 5);
+        graph.connect(b, c, 1);
+        assert_eq!(Some(16), graph.longest_path(a, d));
+        assert_eq!(Some(0), graph.longest_path(a, a));
+        assert_eq!(None, graph.longest_path(a, e));
+    }
+
+    #[test]
+    fn test_min_cut() {
+        // The flow network from the chapter on maximum flow in Introduction to Algorithms.
+        let mut graph = Graph::new();
+        let [s, v1, v2, v3, v4, t] = [0, 1, 2, 3, 4, 5].map(|n| graph.add_node(n));
+        for (from, to, capacity) in [
+            (s, v1, 16),
+            (s, v2, 13),
+            (v1, v3, 12),
+            (v2, v1, 4),
+            (v2, v4, 14),
+            (v3, v2, 9),
+            (v3, t, 20),
+            (v4, v3, 7),
+            (v4, t, 4),
+        ] {
+            graph.add_edge(from, to, capacity);
+        }
+        let cut = graph.min_cut(s, t);
+        assert_eq!(23, cut.flow);
+        assert_eq!(HashSet::from([s, v1, v2, v4]), cut.source_side);
+        assert_eq!(vec![(v1, v3), (v4, v3), (v4, t)], cut.edges(&graph));
+    }
+
+    #[test]
+    fn test_min_cut_undirected() {
+        // Two groups of four nodes which are all connected to each other, with two wires between
+        // the groups.
+        let mut graph = Graph::new();
+        let nodes = (0..8).map(|n| graph.add_node(n)).collect::<Vec<_>>();
+        for group in nodes.chunks(4) {
+            for (i, &a) in group.iter().enumerate() {
+                for &b in &group[i + 1..] {
+                    graph.connect(a, b, 1);
+                }
+            }
+        }
+        graph.connect(nodes[0], nodes[4], 1);
+        graph.connect(nodes[3], nodes[6], 1);
+        let cut = graph.min_cut(nodes[1], nodes[5]);
+        assert_eq!(2, cut.flow);
+        assert_eq!(4, cut.source_side.len());
+        assert_eq!(
+            vec![(nodes[0], nodes[4]), (nodes[3], nodes[6])],
+            cut.edges(&graph)
+        );
+    }
+
+    #[test]
+    fn test_toposort() {
+        // The steps from the example of 2018 day 7.
+        let graph = HashMap::from([
+            ('C', vec!['A', 'F']),
+            ('A', vec!['B', 'D']),
+            ('B', vec!['E']),
+            ('D', vec!['E']),
+            ('F', vec!['E']),
+        ]);
+        assert_eq!(Ok("CABDFE".chars().collect()), toposort(&graph));
+        assert_eq!(Ok(vec![]), toposort(&HashMap::<u32, Vec<u32>>::new()));
+    }
+
+    #[test]
+    fn test_strongly_connected_components() {
+        let graph = HashMap::from([
+            (1, vec![2]),
+            (2, vec![3]),
+            (3, vec![1, 4]),
+            (4, vec![5]),
+            (5, vec![4, 7]),
+            (6, vec![]),
+        ]);
+        let components = strongly_connected_components(&graph);
+        assert_eq!(4, components.components.len());
+        let component_of = |node| components.component_of[&node];
+        assert_eq!(vec![1, 2, 3], components.components[component_of(1)]);
+        assert_eq!(vec![4, 5], components.components[component_of(5)]);
+        assert_eq!(vec![7], components.components[component_of(7)]);
+        assert_eq!(vec![6], components.components[component_of(6)]);
+        assert_eq!(
+            vec![component_of(4)],
+            components.condensation[component_of(2)]
+        );
+        assert_eq!(
+            vec![component_of(7)],
+            components.condensation[component_of(4)]
+        );
+        for (from, targets) in components.condensation.iter().enumerate() {
+            assert!(targets.iter().all(|&to| from < to));
+        }
+    }
+
+    #[test]
+    fn test_cliques() {
+        // a-b-c-d all connected, c-e-f a triangle, and g on its own at the end of an edge from f.
+        let graph = HashMap::from([
+            ('a', vec!['b', 'c', 'd']),
+            ('b', vec!['c', 'd']),
+            ('c', vec!['d', 'e', 'f']),
+            ('e', vec!['f']),
+            ('f', vec!['g']),
+        ]);
+        assert_eq!(
+            Ok(vec![
+                vec!['a', 'b', 'c', 'd'],
+                vec!['c', 'e', 'f'],
+                vec!['f', 'g']
+            ]),
+            maximal_cliques(&graph)
+        );
+        assert_eq!(Ok(vec!['a', 'b', 'c', 'd']), maximum_clique(&graph));
+        assert_eq!(Ok(vec![]), maximum_clique(&HashMap::<u32, Vec<u32>>::new()));
+        let limits = Limits::NONE.with_max_depth(2);
+        assert_eq!(
+            Err(LimitExceeded {
+                limit: Limit::Depth,
+                max: 2
+            }),
+            maximal_cliques_with_limits(&graph, limits)
+        );
+    }
+
+    #[test]
+    fn test_cycle() {
+        let graph = HashMap::from([(1, vec![2]), (2, vec![3]), (3, vec![4, 2]), (0, vec![1])]);
+        let cycle = toposort(&graph).unwrap_err();
+        assert_eq!(2, cycle.nodes.len());
+        assert!(cycle.nodes.contains(&2) && cycle.nodes.contains(&3));
+        assert_eq!(
+            "Cycle: 2 -> 3 -> 2",
+            Cycle { nodes: vec![2, 3] }.to_string()
+        );
+        let graph = HashMap::from([('a', vec!['a'])]);
+        assert_eq!(Err(Cycle { nodes: vec!['a'] }), toposort(&graph));
+    }
+}
@@ -0,0 +1,488 @@
+/// Grids which only store the cells that have been set, in any number of dimensions.
+pub mod sparse;
+pub use sparse::SparseGrid;
+/// Tables of sums of the cells of grids, for summing any rectangle in constant time.
+pub mod summed_area;
+pub use summed_area::SummedArea;
+/// Views of grids which repeat them forever in every direction.
+pub mod wrapping;
+pub use wrapping::Wrapping;
+
+use std::{
+    fmt::{self, Debug, Display, Formatter},
+    ops::{Index, IndexMut},
+};
+
+#[cfg(feature = "nom_extended")]
+use nom::{character::complete as character, combinator, multi, sequence, IResult};
+
+use crate::geometry::Point2D;
+#[cfg(feature = "nom_extended")]
+use crate::nom_extended::NomParse;
+
+/// The offsets to the four cells next to a cell, in the order right, down, left, up.
+pub const ORTHOGONAL: [Point2D<isize>; 4] = [
+    Point2D::at(1, 0),
+    Point2D::at(0, 1),
+    Point2D::at(-1, 0),
+    Point2D::at(0, -1),
+];
+
+/// The offsets to the eight cells around a cell, including those which are diagonal to it, in
+/// clockwise order starting with the cell to the right.
+pub const ALL_AROUND: [Point2D<isize>; 8] = [
+    Point2D::at(1, 0),
+    Point2D::at(1, 1),
+    Point2D::at(0, 1),
+    Point2D::at(-1, 1),
+    Point2D::at(-1, 0),
+    Point2D::at(-1, -1),
+    Point2D::at(0, -1),
+    Point2D::at(1, -1),
+];
+
+/// A rectangular grid of cells, such as the map of a maze or a forest, which is indexed by the
+/// [`Point2D`] of each cell. The cell at `(x, y)` is in column `x` of row `y`, so that `y`
+/// increases down the grid as the rows of a puzzle input are read.
+///
+/// The cells are kept in a single `Vec` in order of their rows rather than in a `Vec` per row, so
+/// every row is the same width.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid which is `width` cells wide and `height` cells tall, where the cell at each
+    /// point is `f(point)`.
+    pub fn from_fn(width: usize, height: usize, f: impl FnMut(Point2D<usize>) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| Point2D::at(x, y)))
+            .map(f)
+            .collect();
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Creates a grid from its rows, from the top row to the bottom row. Returns `None` if the rows
+    /// aren't all the same width.
+    pub fn from_rows<I>(rows: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = Vec<T>>,
+    {
+        let mut rows = rows.into_iter().peekable();
+        let width = rows.peek().map_or(0, Vec::len);
+        let mut height = 0;
+        let mut cells = vec![];
+        for row in rows {
+            if row.len() != width {
+                return None;
+            }
+            cells.extend(row);
+            height += 1;
+        }
+        Some(Self {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    /// Creates a grid from a map which has one line per row and one character per cell, such as
+    /// most puzzle inputs, where `f` converts each character into a cell. Returns `None` if the
+    /// lines aren't all the same length.
+    pub fn parse(input: &str, mut f: impl FnMut(char) -> T) -> Option<Self> {
+        Self::from_rows(input.lines().map(|line| line.chars().map(&mut f).collect()))
+    }
+
+    /// The number of cells in each row.
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    /// The number of rows.
+    pub const fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Checks whether `point` is the position of a cell in the grid.
+    pub fn contains(&self, point: &Point2D<usize>) -> bool {
+        *point.x() < self.width && *point.y() < self.height
+    }
+
+    fn offset(&self, point: &Point2D<usize>) -> Option<usize> {
+        self.contains(point)
+            .then(|| point.y() * self.width + point.x())
+    }
+
+    /// Gets the cell at `point`, or `None` if `point` is outside of the grid.
+    pub fn get(&self, point: &Point2D<usize>) -> Option<&T> {
+        self.offset(point).map(|offset| &self.cells[offset])
+    }
+
+    /// Gets the cell at `point` mutably, or `None` if `point` is outside of the grid.
+    pub fn get_mut(&mut self, point: &Point2D<usize>) -> Option<&mut T> {
+        self.offset(point).map(|offset| &mut self.cells[offset])
+    }
+
+    /// Gets row `y` of the grid, or `None` if there are only `y` rows or fewer.
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        (y < self.height).then(|| &self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    /// Gets an iterator over the rows of the grid from top to bottom.
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator + '_ {
+        (0..self.height).map(|y| &self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    /// Gets an iterator over the cells in column `x` of the grid from top to bottom, or `None` if
+    /// there are only `x` columns or fewer.
+    pub fn column(
+        &self,
+        x: usize,
+    ) -> Option<impl DoubleEndedIterator<Item = &T> + ExactSizeIterator + '_> {
+        (x < self.width).then(|| (0..self.height).map(move |y| &self.cells[y * self.width + x]))
+    }
+
+    /// Gets an iterator over the columns of the grid from left to right, each of which is an
+    /// iterator over its cells from top to bottom.
+    pub fn columns(
+        &self,
+    ) -> impl Iterator<Item = impl DoubleEndedIterator<Item = &T> + ExactSizeIterator + '_> + '_
+    {
+        (0..self.width).map(move |x| (0..self.height).map(move |y| &self.cells[y * self.width + x]))
+    }
+
+    /// Gets an iterator over the cells of the grid along with their positions, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point2D<usize>, &T)> + '_ {
+        let width = self.width;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(offset, cell)| (Point2D::at(offset % width, offset / width), cell))
+    }
+
+    /// Gets an iterator over the cells of the grid mutably along with their positions, row by row.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Point2D<usize>, &mut T)> + '_ {
+        let width = self.width;
+        self.cells
+            .iter_mut()
+            .enumerate()
+            .map(move |(offset, cell)| (Point2D::at(offset % width, offset / width), cell))
+    }
+
+    /// Gets an iterator over the cells which are `offsets` away from `point` along with their
+    /// positions, leaving out those which would be outside of the grid.
+    pub fn neighbors_with<'a, I>(
+        &'a self,
+        point: Point2D<usize>,
+        offsets: I,
+    ) -> impl Iterator<Item = (Point2D<usize>, &'a T)> + 'a
+    where
+        I: IntoIterator<Item = Point2D<isize>>,
+        I::IntoIter: 'a,
+    {
+        offsets.into_iter().filter_map(move |offset| {
+            let neighbor = point.checked_add_signed(offset)?;
+            Some((neighbor, self.get(&neighbor)?))
+        })
+    }
+
+    /// Gets an iterator over the up to four cells next to `point` along with their positions, in
+    /// the order of [`ORTHOGONAL`].
+    pub fn neighbors4(
+        &self,
+        point: Point2D<usize>,
+    ) -> impl Iterator<Item = (Point2D<usize>, &T)> + '_ {
+        self.neighbors_with(point, ORTHOGONAL)
+    }
+
+    /// Gets an iterator over the up to eight cells around `point`, including those which are
+    /// diagonal to it, along with their positions, in the order of [`ALL_AROUND`].
+    pub fn neighbors8(
+        &self,
+        point: Point2D<usize>,
+    ) -> impl Iterator<Item = (Point2D<usize>, &T)> + '_ {
+        self.neighbors_with(point, ALL_AROUND)
+    }
+
+    /// Draws the grid as text with one line per row, where `f` gives the character to draw for
+    /// each cell.
+    pub fn render(&self, mut f: impl FnMut(&T) -> char) -> String {
+        let mut rendered = String::with_capacity((self.width + 1) * self.height);
+        for row in self.rows() {
+            rendered.extend(row.iter().map(&mut f));
+            rendered.push('\n');
+        }
+        rendered
+    }
+
+    /// Wraps the grid so that it can be printed in the same way as [`Grid::render`] draws it,
+    /// without building a `String` first, such as when showing each step of a simulation while
+    /// debugging it.
+    pub fn display<F>(&self, f: F) -> Rendered<'_, T, F>
+    where
+        F: Fn(&T) -> char,
+    {
+        Rendered { grid: self, f }
+    }
+
+    /// Finds the position of the first cell, row by row, for which `predicate` returns true, such
+    /// as the start of a maze.
+    pub fn position(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Point2D<usize>> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(point, _)| point)
+    }
+}
+
+impl<T> Grid<T>
+where
+    T: Clone,
+{
+    /// Creates a grid which is `width` cells wide and `height` cells tall, where every cell is
+    /// `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+}
+
+/// A grid which is displayed with one line per row, where a function gives the character to draw
+/// for each cell. Created by [`Grid::display`].
+pub struct Rendered<'a, T, F> {
+    grid: &'a Grid<T>,
+    f: F,
+}
+
+impl<T, F> Debug for Rendered<'_, T, F>
+where
+    T: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Rendered")
+            .field("grid", self.grid)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T, F> Display for Rendered<'_, T, F>
+where
+    F: Fn(&T) -> char,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for row in self.grid.rows() {
+            for cell in row {
+                write!(f, "{}", (self.f)(cell))?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// Parses a grid which has one row per line, where each row is made up of cells which are parsed
+/// one after another without any separator, such as a map of tiles that are each one character.
+/// Fails if the rows aren't all the same width.
+#[cfg(feature = "nom_extended")]
+impl<'s, T> NomParse<&'s str> for Grid<T>
+where
+    T: NomParse<&'s str>,
+{
+    fn nom_parse(s: &'s str) -> IResult<&'s str, Self> {
+        combinator::map_opt(
+            multi::many1(sequence::terminated(
+                multi::many1(T::nom_parse),
+                character::line_ending,
+            )),
+            Self::from_rows,
+        )(s)
+    }
+}
+
+impl<T> Index<Point2D<usize>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point2D<usize>) -> &Self::Output {
+        match self.get(&point) {
+            Some(cell) => cell,
+            None => panic!(
+                "{point:?} is outside of a {}x{} grid",
+                self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<Point2D<usize>> for Grid<T> {
+    fn index_mut(&mut self, point: Point2D<usize>) -> &mut Self::Output {
+        let (width, height) = (self.width, self.height);
+        match self.get_mut(&point) {
+            Some(cell) => cell,
+            None => panic!("{point:?} is outside of a {width}x{height} grid"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(grid: &str) -> Grid<char> {
+        Grid::parse(grid, |c| c).unwrap()
+    }
+
+    #[test]
+    fn test_from_rows() {
+        let grid = parse("abc\ndef\n");
+        assert_eq!(3, grid.width());
+        assert_eq!(2, grid.height());
+        assert_eq!(Grid::from_fn(3, 2, |point| grid[point]), grid);
+        assert_eq!(None, Grid::from_rows([vec![1, 2], vec![3]]));
+        let empty = Grid::<u8>::from_rows([]).unwrap();
+        assert_eq!((0, 0), (empty.width(), empty.height()));
+        assert_eq!(0, empty.rows().count());
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = Grid::parse("#.\r\n.#\r\n", |c| c == '#').unwrap();
+        assert_eq!(Grid::from_fn(2, 2, |point| point.x() == point.y()), grid);
+        assert_eq!(None, Grid::parse("##\n#\n", |c| c));
+    }
+
+    #[cfg(feature = "nom_extended")]
+    #[test]
+    fn test_nom_parse() {
+        #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+        enum Tile {
+            Open,
+            Wall,
+        }
+
+        impl NomParse<&'_ str> for Tile {
+            fn nom_parse(s: &str) -> IResult<&str, Self> {
+                nom::branch::alt((
+                    combinator::value(Self::Open, character::char('.')),
+                    combinator::value(Self::Wall, character::char('#')),
+                ))(s)
+            }
+        }
+
+        let (rest, grid) = Grid::<Tile>::nom_parse("#.#\n...\n\nnext").unwrap();
+        assert_eq!("\nnext", rest);
+        assert_eq!(
+            Grid::parse("#.#\n...\n", |c| if c == '#' {
+                Tile::Wall
+            } else {
+                Tile::Open
+            }),
+            Some(grid)
+        );
+        assert!(Grid::<Tile>::nom_parse("#.#\n..\n").is_err());
+        assert!(Grid::<Tile>::nom_parse("#.#").is_err());
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = parse("abc\ndef\nghi\n");
+        let neighbors = |cells: &mut dyn Iterator<Item = (Point2D<usize>, &char)>| {
+            cells.map(|(_, &cell)| cell).collect::<String>()
+        };
+        assert_eq!("fhdb", neighbors(&mut grid.neighbors4(Point2D::at(1, 1))));
+        assert_eq!("ihebc", neighbors(&mut grid.neighbors8(Point2D::at(2, 1))));
+        assert_eq!("bd", neighbors(&mut grid.neighbors4(Point2D::at(0, 0))));
+        let knight = [Point2D::at(1, 2), Point2D::at(2, 1), Point2D::at(-1, 2)];
+        assert_eq!(
+            vec![(Point2D::at(1, 2), &'h'), (Point2D::at(2, 1), &'f')],
+            grid.neighbors_with(Point2D::at(0, 0), knight)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(0, grid.neighbors4(Point2D::at(5, 5)).count());
+    }
+
+    #[test]
+    fn test_render() {
+        let map = "#..#\n.##.\n";
+        let grid = Grid::parse(map, |c| c == '#').unwrap();
+        let draw = |&wall: &bool| if wall { '#' } else { '.' };
+        assert_eq!(map, grid.render(draw));
+        assert_eq!(map, grid.display(draw).to_string());
+        assert_eq!("", Grid::<bool>::default().render(draw));
+    }
+
+    #[test]
+    fn test_get() {
+        let mut grid = parse("abc\ndef\n");
+        assert_eq!(Some(&'a'), grid.get(&Point2D::at(0, 0)));
+        assert_eq!(Some(&'f'), grid.get(&Point2D::at(2, 1)));
+        assert_eq!(None, grid.get(&Point2D::at(3, 0)));
+        assert_eq!(None, grid.get(&Point2D::at(0, 2)));
+        *grid.get_mut(&Point2D::at(1, 1)).unwrap() = 'E';
+        grid[Point2D::at(1, 0)] = 'B';
+        assert_eq!(parse("aBc\ndEf\n"), grid);
+        assert!(grid.get_mut(&Point2D::at(5, 5)).is_none());
+    }
+
+    #[test]
+    #[should_panic = "outside of a 3x2 grid"]
+    fn test_index_outside() {
+        let grid = parse("abc\ndef\n");
+        let _ = grid[Point2D::at(3, 1)];
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = parse("abc\ndef\n");
+        assert_eq!(
+            vec![['a', 'b', 'c'], ['d', 'e', 'f']],
+            grid.rows().collect::<Vec<_>>()
+        );
+        assert_eq!(Some(&['d', 'e', 'f'][..]), grid.row(1));
+        assert_eq!(None, grid.row(2));
+        assert_eq!(
+            vec!["ad", "be", "cf"],
+            grid.columns()
+                .map(|column| column.collect::<String>())
+                .collect::<Vec<_>>()
+        );
+        assert_eq!("fc", grid.column(2).unwrap().rev().collect::<String>());
+        assert!(grid.column(3).is_none());
+    }
+
+    #[test]
+    fn test_visible_trees() {
+        // The example forest from 2022 day 8, where 21 trees can be seen from outside of it.
+        let forest = parse("30373\n25512\n65332\n33549\n35390\n");
+        let visible = forest
+            .iter()
+            .filter(|&(point, &height)| {
+                let (x, y) = (*point.x(), *point.y());
+                let row = forest.row(y).unwrap();
+                let shorter = |tree: &char| *tree < height;
+                row[..x].iter().all(shorter)
+                    || row[x + 1..].iter().all(shorter)
+                    || forest.column(x).unwrap().take(y).all(shorter)
+                    || forest.column(x).unwrap().skip(y + 1).all(shorter)
+            })
+            .count();
+        assert_eq!(21, visible);
+        let mut marked = forest.clone();
+        for (_, cell) in marked.iter_mut() {
+            *cell = '.';
+        }
+        assert_eq!(Grid::new(5, 5, '.'), marked);
+        assert_eq!(
+            Some(Point2D::at(1, 1)),
+            forest.position(|&tree| tree == '5')
+        );
+    }
+}
@@ -0,0 +1,238 @@
+use std::collections::{hash_map, HashMap};
+
+use crate::{aabb::BoundingBox, geometry::Point};
+
+/// A grid in `N`-dimensional space which only stores the cells that have been set, such as the
+/// active cubes of 2020 day 17, so that it can grow in any direction without bound. The smallest
+/// box which contains every cell that's been set is kept up to date as cells are inserted and
+/// removed.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SparseGrid<T, const N: usize> {
+    cells: HashMap<Point<i64, N>, T>,
+    bounds: Option<BoundingBox<i64, N>>,
+}
+
+impl<T, const N: usize> Default for SparseGrid<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> SparseGrid<T, N> {
+    /// Creates a grid with no cells set.
+    pub fn new() -> Self {
+        Self {
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+
+    /// The number of cells which are set.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Returns true if and only if no cells are set.
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// The smallest box which contains every cell that's set, or `None` if no cells are set.
+    pub const fn bounds(&self) -> Option<BoundingBox<i64, N>> {
+        self.bounds
+    }
+
+    /// Checks whether the cell at `point` is set.
+    pub fn contains(&self, point: &Point<i64, N>) -> bool {
+        self.cells.contains_key(point)
+    }
+
+    /// Gets the cell at `point`, or `None` if it isn't set.
+    pub fn get(&self, point: &Point<i64, N>) -> Option<&T> {
+        self.cells.get(point)
+    }
+
+    /// Gets the cell at `point` mutably, or `None` if it isn't set.
+    pub fn get_mut(&mut self, point: &Point<i64, N>) -> Option<&mut T> {
+        self.cells.get_mut(point)
+    }
+
+    /// Sets the cell at `point` to `value`, growing the bounds of the grid to contain it if
+    /// necessary. Returns the old value of the cell if it was already set.
+    pub fn insert(&mut self, point: Point<i64, N>, value: T) -> Option<T> {
+        match &mut self.bounds {
+            Some(bounds) => bounds.expand(&point),
+            None => self.bounds = Some(BoundingBox::new(point, point)),
+        }
+        self.cells.insert(point, value)
+    }
+
+    /// Unsets the cell at `point`, shrinking the bounds of the grid if it was on their edge.
+    /// Returns the value of the cell if it was set.
+    pub fn remove(&mut self, point: &Point<i64, N>) -> Option<T> {
+        let value = self.cells.remove(point)?;
+        let on_edge = self.bounds.is_some_and(|bounds| {
+            (0..N).any(|axis| point[axis] == bounds.min[axis] || point[axis] == bounds.max[axis])
+        });
+        if on_edge {
+            self.bounds = BoundingBox::from_points(self.cells.keys().copied());
+        }
+        Some(value)
+    }
+
+    /// Gets an iterator over the cells which are set along with their positions, in no particular
+    /// order.
+    pub fn iter(&self) -> hash_map::Iter<'_, Point<i64, N>, T> {
+        self.cells.iter()
+    }
+
+    /// Gets an iterator over the cells which are set among the `3.pow(N) - 1` cells around
+    /// `point`, including those which are diagonal to it, along with their positions.
+    pub fn neighbors(
+        &self,
+        point: &Point<i64, N>,
+    ) -> impl Iterator<Item = (Point<i64, N>, &T)> + '_ {
+        point
+            .neighbors()
+            .into_iter()
+            .filter_map(|neighbor| Some((neighbor, self.cells.get(&neighbor)?)))
+    }
+
+    /// Gets an iterator over every position in the bounds of the grid after growing them by
+    /// `margin` along every axis, along with the cell at that position if it's set. A margin of 1
+    /// includes every cell which a cellular automaton might set in its next generation.
+    pub fn iter_bounds(
+        &self,
+        margin: i64,
+    ) -> impl Iterator<Item = (Point<i64, N>, Option<&T>)> + '_ {
+        self.bounds
+            .into_iter()
+            .flat_map(move |bounds| bounds.inflate(margin).iter_points())
+            .map(|point| (point, self.cells.get(&point)))
+    }
+}
+
+impl<T, const N: usize> Extend<(Point<i64, N>, T)> for SparseGrid<T, N> {
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = (Point<i64, N>, T)>,
+    {
+        for (point, value) in iter {
+            self.insert(point, value);
+        }
+    }
+}
+
+impl<T, const N: usize> FromIterator<(Point<i64, N>, T)> for SparseGrid<T, N> {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = (Point<i64, N>, T)>,
+    {
+        let mut grid = Self::new();
+        grid.extend(iter);
+        grid
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn points<const N: usize>(grid: &SparseGrid<(), N>) -> Vec<[i64; N]> {
+        let mut points = grid
+            .iter()
+            .map(|(point, _)| point.into_coords())
+            .collect::<Vec<_>>();
+        points.sort_unstable();
+        points
+    }
+
+    #[test]
+    fn test_bounds() {
+        let mut grid = SparseGrid::new();
+        assert_eq!(None, grid.bounds());
+        grid.insert(Point::at([2, 3]), 'a');
+        assert_eq!(
+            Some(BoundingBox::new(Point::at([2, 3]), Point::at([2, 3]))),
+            grid.bounds()
+        );
+        grid.insert(Point::at([-1, 5]), 'b');
+        grid.insert(Point::at([0, 4]), 'c');
+        assert_eq!(
+            Some(BoundingBox::new(Point::at([-1, 3]), Point::at([2, 5]))),
+            grid.bounds()
+        );
+        assert_eq!(Some('a'), grid.insert(Point::at([2, 3]), 'd'));
+        assert_eq!(3, grid.len());
+        assert_eq!(Some('d'), grid.remove(&Point::at([2, 3])));
+        assert_eq!(None, grid.remove(&Point::at([2, 3])));
+        assert_eq!(
+            Some(BoundingBox::new(Point::at([-1, 4]), Point::at([0, 5]))),
+            grid.bounds()
+        );
+        grid.remove(&Point::at([-1, 5]));
+        grid.remove(&Point::at([0, 4]));
+        assert!(grid.is_empty());
+        assert_eq!(None, grid.bounds());
+        assert_eq!(0, grid.iter_bounds(1).count());
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = [[0, 0, 0], [1, 1, 1], [0, 2, 0], [5, 5, 5]]
+            .map(|coords| (Point::at(coords), coords[0]))
+            .into_iter()
+            .collect::<SparseGrid<_, 3>>();
+        let mut neighbors = grid
+            .neighbors(&Point::at([0, 1, 0]))
+            .map(|(point, &value)| (point.into_coords(), value))
+            .collect::<Vec<_>>();
+        neighbors.sort_unstable();
+        assert_eq!(
+            vec![([0, 0, 0], 0), ([0, 2, 0], 0), ([1, 1, 1], 1)],
+            neighbors
+        );
+        assert_eq!(0, grid.neighbors(&Point::at([5, 5, 5])).count());
+    }
+
+    #[test]
+    fn test_conway_cubes() {
+        // The first generation of the example of 2020 day 17 in three dimensions.
+        let cubes = [[1, 0, 0], [2, 1, 0], [0, 2, 0], [1, 2, 0], [2, 2, 0]]
+            .map(|coords| (Point::at(coords), ()))
+            .into_iter()
+            .collect::<SparseGrid<_, 3>>();
+        assert_eq!(5 * 5 * 3, cubes.iter_bounds(1).count());
+        let cubes = cubes
+            .iter_bounds(1)
+            .filter(|(point, cell)| {
+                let active = cubes.neighbors(point).count();
+                active == 3 || (active == 2 && cell.is_some())
+            })
+            .map(|(point, _)| (point, ()))
+            .collect::<SparseGrid<_, 3>>();
+        assert_eq!(
+            vec![
+                [0, 1, -1],
+                [0, 1, 0],
+                [0, 1, 1],
+                [1, 2, 0],
+                [1, 3, -1],
+                [1, 3, 0],
+                [1, 3, 1],
+                [2, 1, 0],
+                [2, 2, -1],
+                [2, 2, 0],
+                [2, 2, 1],
+            ],
+            points(&cubes)
+        );
+        assert_eq!(
+            Some(BoundingBox::new(
+                Point::at([0, 1, -1]),
+                Point::at([2, 3, 1])
+            )),
+            cubes.bounds()
+        );
+    }
+}
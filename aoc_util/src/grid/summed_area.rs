@@ -0,0 +1,139 @@
+use crate::geometry::Point2D;
+
+use super::Grid;
+
+/// A table of the sums of the cells of a [`Grid`] above and to the left of each point, so that the
+/// sum of the cells in any rectangle can be found in constant time, such as the total power of
+/// each square of fuel cells in 2018 day 11.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SummedArea {
+    width: usize,
+    height: usize,
+    /// The sum of the cells whose coordinates are less than `(x, y)` is at `y * (width + 1) + x`,
+    /// so the first row and column are all 0.
+    sums: Vec<i64>,
+}
+
+impl SummedArea {
+    /// Creates the table of sums of the cells of `grid`.
+    pub fn new(grid: &Grid<i64>) -> Self {
+        Self::from_fn(grid, |&value| value)
+    }
+
+    /// Creates the table of sums of `f` of each cell of `grid`.
+    pub fn from_fn<T>(grid: &Grid<T>, mut f: impl FnMut(&T) -> i64) -> Self {
+        let (width, height) = (grid.width(), grid.height());
+        let mut sums = vec![0; (width + 1) * (height + 1)];
+        for (y, row) in grid.rows().enumerate() {
+            let mut row_sum = 0;
+            for (x, cell) in row.iter().enumerate() {
+                row_sum += f(cell);
+                sums[(y + 1) * (width + 1) + x + 1] = sums[y * (width + 1) + x + 1] + row_sum;
+            }
+        }
+        Self {
+            width,
+            height,
+            sums,
+        }
+    }
+
+    /// The width of the grid whose sums are in the table.
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    /// The height of the grid whose sums are in the table.
+    pub const fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The sum of the cells whose coordinates are less than `x` and `y`.
+    fn before(&self, x: usize, y: usize) -> i64 {
+        self.sums[y * (self.width + 1) + x]
+    }
+
+    /// The sum of the cells in the rectangle whose corners are `min` and `max`, inclusive. The sum
+    /// is 0 if `max` is before `min` along either axis.
+    ///
+    /// # Panics
+    /// Panics if `max` is outside of the grid.
+    pub fn sum(&self, min: Point2D<usize>, max: Point2D<usize>) -> i64 {
+        assert!(
+            *max.x() < self.width && *max.y() < self.height,
+            "{max:?} is outside of a {}x{} grid",
+            self.width,
+            self.height,
+        );
+        let (min_x, min_y) = (*min.x(), *min.y());
+        let (end_x, end_y) = (*max.x() + 1, *max.y() + 1);
+        if min_x >= end_x || min_y >= end_y {
+            return 0;
+        }
+        self.before(end_x, end_y) - self.before(min_x, end_y) - self.before(end_x, min_y)
+            + self.before(min_x, min_y)
+    }
+
+    /// The sum of the cells in the `size` by `size` square whose top left corner is `corner`.
+    ///
+    /// # Panics
+    /// Panics if `size` is 0 or if the square doesn't fit in the grid.
+    pub fn square_sum(&self, corner: Point2D<usize>, size: usize) -> i64 {
+        assert!(size > 0, "A square must have at least one cell");
+        self.sum(corner, corner + Point2D::at(size - 1, size - 1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sum() {
+        let grid = Grid::from_rows([vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]).unwrap();
+        let sums = SummedArea::new(&grid);
+        assert_eq!(45, sums.sum(Point2D::at(0, 0), Point2D::at(2, 2)));
+        assert_eq!(5, sums.sum(Point2D::at(1, 1), Point2D::at(1, 1)));
+        assert_eq!(28, sums.square_sum(Point2D::at(1, 1), 2));
+        assert_eq!(15, sums.sum(Point2D::at(0, 1), Point2D::at(2, 1)));
+        assert_eq!(0, sums.sum(Point2D::at(2, 0), Point2D::at(1, 2)));
+        let odd = SummedArea::from_fn(&grid, |&value| value % 2);
+        assert_eq!(5, odd.sum(Point2D::at(0, 0), Point2D::at(2, 2)));
+    }
+
+    #[test]
+    #[should_panic = "outside of a 3x3 grid"]
+    fn test_sum_outside() {
+        let sums = SummedArea::new(&Grid::new(3, 3, 1));
+        sums.square_sum(Point2D::at(1, 1), 3);
+    }
+
+    #[test]
+    fn test_fuel_cells() {
+        // The fuel cells of 2018 day 11, where the 3x3 square with the most power for serial
+        // number 18 has its top left at (33, 45) and for 42 has it at (21, 61). The grid is
+        // numbered from 1, so each cell is one up and to the left of its coordinates.
+        let most_powerful = |serial: i64| {
+            let grid = Grid::from_fn(300, 300, |point| {
+                let (x, y) = (*point.x() as i64 + 1, *point.y() as i64 + 1);
+                let rack_id = x + 10;
+                (rack_id * y + serial) * rack_id / 100 % 10 - 5
+            });
+            let sums = SummedArea::new(&grid);
+            let best = (0..298)
+                .flat_map(|y| (0..298).map(move |x| Point2D::at(x, y)))
+                .max_by_key(|&corner| sums.square_sum(corner, 3))
+                .unwrap();
+            (
+                (*best.x() + 1, *best.y() + 1),
+                sums.square_sum(best, 3),
+                sums,
+            )
+        };
+        let (corner, power, sums) = most_powerful(18);
+        assert_eq!(((33, 45), 29), (corner, power));
+        assert_eq!(113, sums.square_sum(Point2D::at(89, 268), 16));
+        let (corner, power, _) = most_powerful(42);
+        assert_eq!(((21, 61), 30), (corner, power));
+    }
+}
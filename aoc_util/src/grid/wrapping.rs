@@ -0,0 +1,211 @@
+use std::fmt::{self, Debug, Formatter};
+
+use crate::geometry::Point2D;
+
+use super::{Grid, ORTHOGONAL};
+
+/// A view of a [`Grid`] which repeats it forever in every direction, such as the garden of 2023
+/// day 21, so that every point, including those with negative coordinates, is in some copy of the
+/// grid. Each copy is a tile, and the tile at `(0, 0)` is the grid itself. Created by
+/// [`Grid::wrapping`].
+///
+/// # Panics
+/// Every method which looks up a point panics if the grid is empty, since then there are no cells
+/// to repeat.
+#[derive(Debug)]
+pub struct Wrapping<'a, T> {
+    grid: &'a Grid<T>,
+}
+
+impl<T> Clone for Wrapping<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Wrapping<'_, T> {}
+
+impl<T> Grid<T> {
+    /// Views the grid as if it were repeated forever in every direction.
+    pub fn wrapping(&self) -> Wrapping<'_, T> {
+        Wrapping { grid: self }
+    }
+}
+
+impl<'a, T> Wrapping<'a, T> {
+    /// The grid which is repeated.
+    pub fn grid(&self) -> &'a Grid<T> {
+        self.grid
+    }
+
+    /// Finds which tile `point` is in and the position of its cell in the grid.
+    pub fn wrap(&self, point: Point2D<isize>) -> (Point2D<isize>, Point2D<usize>) {
+        assert!(
+            self.grid.width > 0 && self.grid.height > 0,
+            "Can't wrap around an empty grid"
+        );
+        let (width, height) = (self.grid.width as isize, self.grid.height as isize);
+        let (x, y) = (*point.x(), *point.y());
+        let tile = Point2D::at(x.div_euclid(width), y.div_euclid(height));
+        let cell = Point2D::at(x.rem_euclid(width) as usize, y.rem_euclid(height) as usize);
+        (tile, cell)
+    }
+
+    /// Gets the cell at `point`.
+    pub fn get(&self, point: Point2D<isize>) -> &'a T {
+        let (_, cell) = self.wrap(point);
+        &self.grid[cell]
+    }
+
+    /// Gets an iterator over the four cells next to `point` along with their positions, in the
+    /// order of [`ORTHOGONAL`]. Unlike [`Grid::neighbors4`], there are always four of them.
+    pub fn neighbors4(
+        &self,
+        point: Point2D<isize>,
+    ) -> impl Iterator<Item = (Point2D<isize>, &'a T)> + 'a {
+        let view = *self;
+        ORTHOGONAL.into_iter().map(move |offset| {
+            let neighbor = point + offset;
+            (neighbor, view.get(neighbor))
+        })
+    }
+
+    /// Changes each cell depending on which tile it's in, such as the risk levels of 2021 day 15
+    /// which go up by one for each tile right or down from the first one.
+    pub fn transformed<F, U>(self, transform: F) -> Transformed<'a, T, F>
+    where
+        F: Fn(&T, Point2D<isize>) -> U,
+    {
+        Transformed {
+            view: self,
+            transform,
+        }
+    }
+}
+
+/// A view of a [`Grid`] which repeats it forever in every direction and changes each cell
+/// depending on which tile it's in. Created by [`Wrapping::transformed`].
+pub struct Transformed<'a, T, F> {
+    view: Wrapping<'a, T>,
+    transform: F,
+}
+
+impl<T, F> Debug for Transformed<'_, T, F>
+where
+    T: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Transformed")
+            .field("view", &self.view)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T, F, U> Transformed<'_, T, F>
+where
+    F: Fn(&T, Point2D<isize>) -> U,
+{
+    /// Gets the cell at `point` after it's been changed for the tile that it's in.
+    pub fn get(&self, point: Point2D<isize>) -> U {
+        let (tile, cell) = self.view.wrap(point);
+        (self.transform)(&self.view.grid[cell], tile)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_wrap() {
+        let grid = Grid::parse("ab\ncd\ne.\n", |c| c).unwrap();
+        let view = grid.wrapping();
+        assert_eq!(
+            (Point2D::at(0, 0), Point2D::at(1, 2)),
+            view.wrap(Point2D::at(1, 2))
+        );
+        assert_eq!(
+            (Point2D::at(1, 1), Point2D::at(0, 0)),
+            view.wrap(Point2D::at(2, 3))
+        );
+        assert_eq!(
+            (Point2D::at(-1, -1), Point2D::at(1, 2)),
+            view.wrap(Point2D::at(-1, -1))
+        );
+        assert_eq!(
+            (Point2D::at(-2, 0), Point2D::at(1, 1)),
+            view.wrap(Point2D::at(-3, 1))
+        );
+        assert_eq!(&'d', view.get(Point2D::at(-3, 1)));
+        assert_eq!(
+            "bcbe",
+            view.neighbors4(Point2D::at(0, -3))
+                .map(|(_, &cell)| cell)
+                .collect::<String>()
+        );
+    }
+
+    #[test]
+    fn test_garden() {
+        // The garden from the example of 2023 day 21, where 16 plots can be reached in exactly 6
+        // steps and 50 plots can be reached in exactly 10 steps once the garden repeats forever.
+        let garden = Grid::parse(
+            "...........\n\
+             .....###.#.\n\
+             .###.##..#.\n\
+             ..#.#...#..\n\
+             ....#.#....\n\
+             .##..S####.\n\
+             .##..#...#.\n\
+             .......##..\n\
+             .##.#.####.\n\
+             .##..##.##.\n\
+             ...........\n",
+            |c| c,
+        )
+        .unwrap();
+        let start = garden
+            .position(|&c| c == 'S')
+            .unwrap()
+            .try_cast::<isize>()
+            .unwrap();
+        let view = garden.wrapping();
+        let mut reached = HashSet::from([start]);
+        let mut counts = vec![reached.len()];
+        for _ in 0..10 {
+            reached = reached
+                .into_iter()
+                .flat_map(|plot| view.neighbors4(plot))
+                .filter(|&(_, &c)| c != '#')
+                .map(|(plot, _)| plot)
+                .collect();
+            counts.push(reached.len());
+        }
+        assert_eq!(16, counts[6]);
+        assert_eq!(50, counts[10]);
+    }
+
+    #[test]
+    fn test_expanded_risks() {
+        // Each tile of the expanded cave of 2021 day 15 is one riskier than the tile to its left
+        // or above it, with risks above 9 wrapping around to 1.
+        let cave = Grid::parse("18\n92\n", |c| c.to_digit(10).unwrap()).unwrap();
+        let expanded = cave.wrapping().transformed(|&risk, tile: Point2D<isize>| {
+            (risk - 1 + (tile.x() + tile.y()) as u32) % 9 + 1
+        });
+        let rows = (0..4)
+            .map(|y| (0..4).map(|x| expanded.get(Point2D::at(x, y))).collect())
+            .collect::<Vec<Vec<_>>>();
+        assert_eq!(
+            vec![
+                vec![1, 8, 2, 9],
+                vec![9, 2, 1, 3],
+                vec![2, 9, 3, 1],
+                vec![1, 3, 2, 4],
+            ],
+            rows
+        );
+    }
+}
@@ -0,0 +1,145 @@
+use std::{
+    env, fs,
+    io::{self, BufReader},
+    path::{Path, PathBuf},
+};
+
+/// The environment variable which, if set, names the directory that the puzzle inputs are in.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// The configuration file which is looked for in the workspace root. Each line of the file has the
+/// form `key = value`. The only key is `input_dir`, the directory that the puzzle inputs are in,
+/// which is relative to the workspace root unless it's absolute.
+pub const CONFIG_FILE: &str = ".aoc_config";
+
+/// Finds the root of the cargo workspace that contains the current directory, if there is one.
+pub fn workspace_root() -> Option<PathBuf> {
+    find_workspace_root(&env::current_dir().ok()?)
+}
+
+/// Finds the root of the cargo workspace that contains `dir`, if there is one.
+fn find_workspace_root(dir: &Path) -> Option<PathBuf> {
+    dir.ancestors()
+        .find(|dir| {
+            fs::read_to_string(dir.join("Cargo.toml"))
+                .map(|manifest| manifest.lines().any(|line| line.trim() == "[workspace]"))
+                .unwrap_or(false)
+        })
+        .map(Path::to_path_buf)
+}
+
+/// The path of the file named `name` in the workspace root, or in the current directory if it isn't
+/// in a workspace, so that files such as the history of runs are the same no matter which directory
+/// of the workspace the solutions are run from.
+pub fn workspace_file(name: impl AsRef<Path>) -> io::Result<PathBuf> {
+    match workspace_root() {
+        Some(root) => Ok(root.join(name)),
+        None => Ok(env::current_dir()?.join(name)),
+    }
+}
+
+/// Reads the `input_dir` entry of the configuration file in `root`, if there is one.
+fn configured_input_dir(root: &Path) -> io::Result<Option<PathBuf>> {
+    let config = match fs::read_to_string(root.join(CONFIG_FILE)) {
+        Ok(config) => config,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e),
+    };
+    for line in config.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        match line.split_once('=') {
+            Some((key, value)) if key.trim() == "input_dir" => {
+                return Ok(Some(root.join(value.trim().trim_matches('"'))));
+            }
+            Some(_) => {}
+            None => {
+                let msg = format!("Invalid line in {CONFIG_FILE}: {line:?}");
+                return Err(io::Error::new(io::ErrorKind::InvalidData, msg));
+            }
+        }
+    }
+    Ok(None)
+}
+
+/// Finds the directory that the puzzle inputs are in. The directory is the first of:
+///
+/// 1. the value of [`INPUT_DIR_VAR`],
+/// 2. the `input_dir` entry of [`CONFIG_FILE`] in the workspace root,
+/// 3. the workspace root, and
+/// 4. the current directory,
+///
+/// so inputs are found no matter which directory of the workspace the solutions are run from.
+pub fn input_dir() -> io::Result<PathBuf> {
+    if let Some(dir) = env::var_os(INPUT_DIR_VAR).filter(|dir| !dir.is_empty()) {
+        return Ok(PathBuf::from(dir));
+    }
+    match workspace_root() {
+        Some(root) => Ok(configured_input_dir(&root)?.unwrap_or(root)),
+        None => env::current_dir(),
+    }
+}
+
+/// The path of the input file named `name`.
+pub fn path(name: impl AsRef<Path>) -> io::Result<PathBuf> {
+    Ok(input_dir()?.join(name))
+}
+
+/// Opens the input file named `name` for buffered reading.
+pub fn open(name: impl AsRef<Path>) -> io::Result<BufReader<fs::File>> {
+    let path = path(name)?;
+    fs::File::open(&path).map(BufReader::new).map_err(|e| {
+        let msg = format!("Couldn't open {}: {e}", path.display());
+        io::Error::new(e.kind(), msg)
+    })
+}
+
+/// Reads the whole input file named `name`.
+pub fn read_to_string(name: impl AsRef<Path>) -> io::Result<String> {
+    let path = path(name)?;
+    fs::read_to_string(&path).map_err(|e| {
+        let msg = format!("Couldn't read {}: {e}", path.display());
+        io::Error::new(e.kind(), msg)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_configured_input_dir() {
+        let root = env::temp_dir().join("aoc_util_test_configured_input_dir");
+        fs::create_dir_all(&root).unwrap();
+        assert_eq!(None, configured_input_dir(&root).unwrap());
+        fs::write(
+            root.join(CONFIG_FILE),
+            "# Where the inputs are\n\ninput_dir = \"inputs\"\n",
+        )
+        .unwrap();
+        assert_eq!(
+            Some(root.join("inputs")),
+            configured_input_dir(&root).unwrap()
+        );
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_find_workspace_root() {
+        let root = env::temp_dir().join("aoc_util_test_find_workspace_root");
+        let member = root.join("member");
+        fs::create_dir_all(member.join("src")).unwrap();
+        fs::write(
+            root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\"member\"]\n",
+        )
+        .unwrap();
+        fs::write(member.join("Cargo.toml"), "[package]\nname = \"member\"\n").unwrap();
+        assert_eq!(Some(root.clone()), find_workspace_root(&member.join("src")));
+        assert_eq!(Some(root.clone()), find_workspace_root(&root));
+        fs::remove_file(root.join("Cargo.toml")).unwrap();
+        assert_eq!(None, find_workspace_root(&member));
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -1,21 +1,131 @@
 //! Utilities that are general enough to be useful in many situations.
+//!
+//! Every module is behind a cargo feature of the same name, all of which are enabled by default.
+//! Depending on this crate with `default-features = false` and enabling only the modules that are
+//! needed avoids pulling in unused dependencies:
+//!
+//! - `aabb`: axis-aligned bounding boxes in any number of dimensions.
+//! - `a_star`: the A* search algorithm and a search from both ends between two known states.
+//! - `automata`: stepping cellular automata and going both ways through their generations.
+//! - `collections`: collection types that are not provided by the standard library.
+//! - `cycle`: finding where repeatedly stepped states start to repeat, to skip ahead many steps.
+//! - `dijkstra`: Dijkstra's shortest path algorithm, for searches without a good heuristic.
+//! - `error`: the error type returned by solvers.
+//! - `geometry`: points, directions, line segments, and polygons.
+//! - `grid`: grids of cells which are indexed by points.
+//! - `graph`: weighted graphs and algorithms over graphs, such as minimum cuts and cliques.
+//! - `input`: finding the puzzle inputs regardless of the directory that the solutions run in.
+//! - `limits`: limits on how much a search may do before it gives up, and statistics of what it
+//!   did.
+//! - `math`: greatest common divisors, least common multiples, the Chinese remainder theorem,
+//!   modular arithmetic, arithmetic which catches overflow, matrices, exact fractions for
+//!   solving linear systems, extrapolating polynomial sequences, the digits of numbers,
+//!   combinatorics, prime numbers, and writing numbers in any base.
+//! - `memory`: measuring how much heap memory a solver uses.
+//! - `nom_extended`: extensions to the [`nom`](https://docs.rs/nom) crate.
+//! - `progress`: reporting the progress of long-running solvers.
+//! - `registry`: the registry of solutions used by the `advent_of_code` binary.
+//! - `samples`: the example inputs from the puzzle descriptions.
+//! - `search`: breadth-first searches, flood fills, shortest paths across grids, the k shortest
+//!   paths to a goal, and beam searches.
+//! - `trace`: recording of the events in a solver's run so that two runs can be compared.
+//!
+//! The `parallel` feature isn't enabled by default. It adds a version of Dijkstra's algorithm that
+//! expands the states which are tied for the shortest distance in parallel with
+//! [`rayon`](https://docs.rs/rayon).
+//!
+//! None of the modules require a nightly compiler.
 
 #![warn(clippy::all)]
 #![warn(missing_copy_implementations, missing_docs, rust_2018_idioms)]
 #![deny(unsafe_op_in_unsafe_fn, missing_debug_implementations)]
 
 /// Utilities for axis-aligned bounding boxes.
+#[cfg(feature = "aabb")]
 pub mod aabb;
 
-/// A generic implementation of the A* search algorithm. Currently does not work correctly.
-#[doc(hidden)]
+/// A generic implementation of the A* search algorithm.
+#[cfg(feature = "a_star")]
 pub mod a_star;
 
+/// Steppers for cellular automata, and a history of their generations which can be stepped through
+/// in both directions, for debugging rules that produce the wrong generation.
+#[cfg(feature = "automata")]
+pub mod automata;
+
 /// Collection types that are not provided by the standard library.
+#[cfg(feature = "collections")]
 pub mod collections;
 
+/// Finding the cycles that states fall into when they're stepped over and over, so that the state
+/// after far more steps than could be taken one at a time can be found.
+#[cfg(feature = "cycle")]
+pub mod cycle;
+
+/// Dijkstra's algorithm for finding the shortest path to a goal when there's no good heuristic for
+/// an A* search.
+#[cfg(feature = "dijkstra")]
+pub mod dijkstra;
+
+/// The errors that solving a puzzle can produce.
+#[cfg(feature = "error")]
+pub mod error;
+
+/// An allocator which keeps track of how much heap memory is in use, for reporting how much each
+/// part of a puzzle needs.
+#[cfg(feature = "memory")]
+pub mod memory;
+
 /// Extensions to the `nom` crate.
+#[cfg(feature = "nom_extended")]
 pub mod nom_extended;
 
 /// Utilities dealing with geometry.
+#[cfg(feature = "geometry")]
 pub mod geometry;
+
+/// Grids of cells, such as the maps of puzzle inputs, which are indexed by points.
+#[cfg(feature = "grid")]
+pub mod grid;
+
+/// Weighted graphs, and algorithms over graphs which are given as lists of the edges from each
+/// node.
+#[cfg(feature = "graph")]
+pub mod graph;
+
+/// Locating the puzzle inputs.
+#[cfg(feature = "input")]
+pub mod input;
+
+/// Limits on the states, recursion depth, and memory that a search may use, and statistics of what
+/// searches did.
+#[cfg(feature = "limits")]
+pub mod limits;
+
+/// Number theory, such as the greatest common divisor, least common multiple, Chinese remainder
+/// theorem, and arithmetic modulo a number, along with arithmetic which catches overflow,
+/// matrices, exact fractions for solving systems of linear equations, polynomial extrapolation,
+/// the digits of numbers, counting and listing arrangements, prime numbers, and writing numbers
+/// in any base.
+#[cfg(feature = "math")]
+pub mod math;
+
+/// Reporting the progress of long-running tasks, such as searches, without cluttering the answers.
+#[cfg(feature = "progress")]
+pub mod progress;
+
+/// A registry of solutions which is populated by the crates that contain them.
+#[cfg(feature = "registry")]
+pub mod registry;
+
+/// Searches over states which are given by a function from each state to its neighbors.
+#[cfg(feature = "search")]
+pub mod search;
+
+/// The example inputs given in the puzzle descriptions, shared by the tests of every year.
+#[cfg(feature = "samples")]
+pub mod samples;
+
+/// Recording of the decisions that a solver makes so that two runs can be compared event by event.
+#[cfg(feature = "trace")]
+pub mod trace;
@@ -0,0 +1,235 @@
+use std::{
+    cell::Cell,
+    fmt::{self, Display, Formatter},
+    sync::Mutex,
+};
+
+use crate::error::{Limit, LimitExceeded};
+
+static DEFAULT: Mutex<Limits> = Mutex::new(Limits::NONE);
+
+thread_local! {
+    static LAST_STATS: Cell<Option<SearchStats>> = const { Cell::new(None) };
+}
+
+/// How much a search may do before it gives up with a [`LimitExceeded`] error instead of running
+/// out of memory or never finishing. Every limit is `None`, meaning unlimited, unless it's set.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Limits {
+    /// The most states that may be expanded.
+    pub max_states: Option<usize>,
+    /// The deepest that a recursive search may go.
+    pub max_depth: Option<usize>,
+    /// The most bytes that the states which are kept may take up, as estimated by the search.
+    pub max_memory: Option<usize>,
+}
+
+impl Limits {
+    /// No limits at all.
+    pub const NONE: Self = Self {
+        max_states: None,
+        max_depth: None,
+        max_memory: None,
+    };
+
+    /// These limits but with at most `max_states` states expanded.
+    pub fn with_max_states(self, max_states: usize) -> Self {
+        Self {
+            max_states: Some(max_states),
+            ..self
+        }
+    }
+
+    /// These limits but with recursion at most `max_depth` levels deep.
+    pub fn with_max_depth(self, max_depth: usize) -> Self {
+        Self {
+            max_depth: Some(max_depth),
+            ..self
+        }
+    }
+
+    /// These limits but with the states taking up at most about `max_memory` bytes.
+    pub fn with_max_memory(self, max_memory: usize) -> Self {
+        Self {
+            max_memory: Some(max_memory),
+            ..self
+        }
+    }
+
+    /// The limits that searches which aren't given any use, as set by [`set_default()`].
+    pub fn default_limits() -> Self {
+        *DEFAULT.lock().unwrap()
+    }
+
+    /// Starts keeping track of a search which is held to these limits.
+    pub fn budget(self) -> Budget {
+        Budget {
+            limits: self,
+            stats: SearchStats::default(),
+        }
+    }
+}
+
+/// Sets the limits that searches which aren't given any use, such as those from the command line.
+pub fn set_default(limits: Limits) {
+    *DEFAULT.lock().unwrap() = limits;
+}
+
+fn check(limit: Limit, max: Option<usize>, used: usize) -> Result<(), LimitExceeded> {
+    match max {
+        Some(max) if used > max => Err(LimitExceeded { limit, max }),
+        _ => Ok(()),
+    }
+}
+
+/// What a search did, for comparing how well different heuristics or data structures do on the
+/// same puzzle.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct SearchStats {
+    /// The number of states that were expanded.
+    pub expanded: usize,
+    /// The number of neighbors that were found while expanding states.
+    pub generated: usize,
+    /// The number of neighbors that were found which had already been reached at least as
+    /// cheaply, and so were thrown away.
+    pub duplicates: usize,
+    /// The most states that were waiting to be expanded at once.
+    pub max_frontier: usize,
+}
+
+impl SearchStats {
+    /// The fraction of the neighbors that were found which were duplicates, or 0 if none were
+    /// found.
+    pub fn duplicate_rate(&self) -> f64 {
+        if self.generated == 0 {
+            0.0
+        } else {
+            self.duplicates as f64 / self.generated as f64
+        }
+    }
+}
+
+impl Display for SearchStats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "expanded {} states, generated {} ({:.1}% duplicates), frontier peaked at {}",
+            self.expanded,
+            self.generated,
+            self.duplicate_rate() * 100.0,
+            self.max_frontier,
+        )
+    }
+}
+
+/// The statistics of the last search on this thread to finish, whether it found an answer or
+/// gave up, or `None` if no search has finished on this thread.
+pub fn last_stats() -> Option<SearchStats> {
+    LAST_STATS.get()
+}
+
+/// How much of its [`Limits`] a search has used so far. The search's [`SearchStats`] are recorded
+/// for [`last_stats()`] when this is dropped.
+#[derive(Clone, Debug)]
+pub struct Budget {
+    limits: Limits,
+    stats: SearchStats,
+}
+
+impl Budget {
+    /// Counts one more expanded state.
+    pub fn expand(&mut self) -> Result<(), LimitExceeded> {
+        self.stats.expanded += 1;
+        check(Limit::States, self.limits.max_states, self.stats.expanded)
+    }
+
+    /// The number of states that have been expanded.
+    pub fn states(&self) -> usize {
+        self.stats.expanded
+    }
+
+    /// Counts one more neighbor found, which is a `duplicate` if it had already been reached at
+    /// least as cheaply.
+    pub fn generate(&mut self, duplicate: bool) {
+        self.stats.generated += 1;
+        if duplicate {
+            self.stats.duplicates += 1;
+        }
+    }
+
+    /// Notes that `len` states are waiting to be expanded.
+    pub fn frontier(&mut self, len: usize) {
+        self.stats.max_frontier = self.stats.max_frontier.max(len);
+    }
+
+    /// What the search has done so far.
+    pub fn stats(&self) -> SearchStats {
+        self.stats
+    }
+
+    /// Checks that a recursive search which is `depth` levels deep may go on.
+    pub fn check_depth(&self, depth: usize) -> Result<(), LimitExceeded> {
+        check(Limit::Depth, self.limits.max_depth, depth)
+    }
+
+    /// Checks that a search which keeps `count` values of type `T` may go on. The estimate only
+    /// counts the values themselves, not any memory that they point to.
+    pub fn check_memory<T>(&self, count: usize) -> Result<(), LimitExceeded> {
+        let bytes = count.saturating_mul(std::mem::size_of::<T>());
+        check(Limit::Memory, self.limits.max_memory, bytes)
+    }
+}
+
+impl Drop for Budget {
+    fn drop(&mut self) {
+        LAST_STATS.set(Some(self.stats));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_budget() {
+        let mut budget = Limits::NONE.with_max_states(2).with_max_depth(3).budget();
+        assert!(budget.expand().is_ok());
+        assert!(budget.expand().is_ok());
+        assert_eq!(
+            Err(LimitExceeded {
+                limit: Limit::States,
+                max: 2
+            }),
+            budget.expand()
+        );
+        assert!(budget.check_depth(3).is_ok());
+        assert!(budget.check_depth(4).is_err());
+        assert!(budget.check_memory::<u64>(usize::MAX).is_ok());
+        let budget = Limits::NONE.with_max_memory(16).budget();
+        assert!(budget.check_memory::<u64>(2).is_ok());
+        assert!(budget.check_memory::<u64>(3).is_err());
+    }
+
+    #[test]
+    fn test_stats() {
+        let mut budget = Limits::NONE.budget();
+        budget.expand().unwrap();
+        budget.frontier(3);
+        budget.generate(false);
+        budget.generate(true);
+        budget.frontier(2);
+        let stats = SearchStats {
+            expanded: 1,
+            generated: 2,
+            duplicates: 1,
+            max_frontier: 3,
+        };
+        assert_eq!(stats, budget.stats());
+        drop(budget);
+        assert_eq!(Some(stats), last_stats());
+        assert_eq!(
+            "expanded 1 states, generated 2 (50.0% duplicates), frontier peaked at 3",
+            stats.to_string()
+        );
+    }
+}
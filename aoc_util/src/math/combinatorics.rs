@@ -0,0 +1,181 @@
+/// `n!`, the number of ways to order `n` things, or `None` if it's too large for a `u64`, which
+/// it is for any `n` greater than 20.
+pub fn checked_factorial(n: u64) -> Option<u64> {
+    (2..=n).try_fold(1u64, u64::checked_mul)
+}
+
+/// `n!`, the number of ways to order `n` things.
+///
+/// # Panics
+/// Panics if `n!` is too large for a `u64`, which it is for any `n` greater than 20.
+pub fn factorial(n: u64) -> u64 {
+    checked_factorial(n).unwrap_or_else(|| panic!("{n}! overflowed a u64"))
+}
+
+/// `n` choose `k`, the number of ways to pick `k` of `n` things without caring about their order,
+/// or `None` if it's too large for a `u64`. It's 0 if `k` is greater than `n`.
+pub fn checked_choose(n: u64, k: u64) -> Option<u64> {
+    if k > n {
+        return Some(0);
+    }
+    let k = k.min(n - k);
+    // Each partial product is itself a binomial coefficient, so the division is exact, and doing
+    // it in 128 bits keeps the multiplication from overflowing before the division.
+    let choose = (0..k).try_fold(1u128, |choose, i| {
+        Some(choose.checked_mul(u128::from(n - i))? / u128::from(i + 1))
+    })?;
+    u64::try_from(choose).ok()
+}
+
+/// `n` choose `k`, the number of ways to pick `k` of `n` things without caring about their order.
+/// It's 0 if `k` is greater than `n`.
+///
+/// # Panics
+/// Panics if `n` choose `k` is too large for a `u64`.
+pub fn choose(n: u64, k: u64) -> u64 {
+    checked_choose(n, k).unwrap_or_else(|| panic!("{n} choose {k} overflowed a u64"))
+}
+
+/// Gets an iterator over every ordering of `items`, such as the phase settings of the amplifiers
+/// of 2019 day 7. See [`Permutations`].
+pub fn permutations<T>(items: impl IntoIterator<Item = T>) -> Permutations<T>
+where
+    T: Clone,
+{
+    Permutations::new(items)
+}
+
+/// An iterator over every ordering of some items, which are generated by Heap's algorithm so that
+/// each ordering differs from the one before it by swapping two items. The first ordering is the
+/// order that the items were given in. Items which are equal are still treated as different, so
+/// `n` items always have `n!` orderings.
+///
+/// Each ordering is yielded as a new `Vec`. [`Permutations::next_slice()`] lends each one instead,
+/// which doesn't allocate.
+#[derive(Clone, Debug)]
+pub struct Permutations<T> {
+    items: Vec<T>,
+    /// Heap's algorithm's count of the swaps that have been made at each position.
+    counters: Vec<usize>,
+    /// The position which the next swap is made for.
+    position: usize,
+    started: bool,
+}
+
+impl<T> Permutations<T> {
+    /// Creates an iterator over every ordering of `items`.
+    pub fn new(items: impl IntoIterator<Item = T>) -> Self {
+        let items = items.into_iter().collect::<Vec<_>>();
+        Self {
+            counters: vec![0; items.len()],
+            items,
+            position: 1,
+            started: false,
+        }
+    }
+
+    /// Advances to the next ordering of the items and returns it, or returns `None` if every
+    /// ordering has been seen.
+    pub fn next_slice(&mut self) -> Option<&[T]> {
+        if !self.started {
+            self.started = true;
+            return Some(&self.items);
+        }
+        while self.position < self.items.len() {
+            let position = self.position;
+            if self.counters[position] < position {
+                if position % 2 == 0 {
+                    self.items.swap(0, position);
+                } else {
+                    self.items.swap(self.counters[position], position);
+                }
+                self.counters[position] += 1;
+                self.position = 1;
+                return Some(&self.items);
+            }
+            self.counters[position] = 0;
+            self.position += 1;
+        }
+        None
+    }
+}
+
+impl<T> Iterator for Permutations<T>
+where
+    T: Clone,
+{
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_slice().map(<[T]>::to_vec)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_factorial() {
+        assert_eq!(1, factorial(0));
+        assert_eq!(120, factorial(5));
+        assert_eq!(2432902008176640000, factorial(20));
+        assert_eq!(None, checked_factorial(21));
+    }
+
+    #[test]
+    #[should_panic = "21! overflowed a u64"]
+    fn test_factorial_overflow() {
+        factorial(21);
+    }
+
+    #[test]
+    fn test_choose() {
+        assert_eq!(10, choose(5, 2));
+        assert_eq!(1, choose(5, 0));
+        assert_eq!(1, choose(5, 5));
+        assert_eq!(0, choose(3, 5));
+        assert_eq!(118264581564861424, choose(60, 30));
+        // The largest which fits in a u64 would overflow partway through without 128 bits.
+        assert_eq!(Some(14226520737620288370), checked_choose(67, 33));
+        assert_eq!(None, checked_choose(68, 34));
+        assert_eq!(1 << 10, (0..=10).map(|k| choose(10, k)).sum::<u64>());
+    }
+
+    #[test]
+    fn test_permutations() {
+        assert_eq!(
+            vec![
+                vec![1, 2, 3],
+                vec![2, 1, 3],
+                vec![3, 1, 2],
+                vec![1, 3, 2],
+                vec![2, 3, 1],
+                vec![3, 2, 1],
+            ],
+            permutations([1, 2, 3]).collect::<Vec<_>>()
+        );
+        assert_eq!(vec![Vec::<u8>::new()], permutations([]).collect::<Vec<_>>());
+        let orderings = permutations(0..6).collect::<Vec<_>>();
+        assert_eq!(factorial(6) as usize, orderings.len());
+        assert_eq!(
+            orderings.len(),
+            orderings.iter().collect::<HashSet<_>>().len()
+        );
+        // Each ordering differs from the one before it in exactly two places.
+        assert!(orderings
+            .windows(2)
+            .all(|pair| { pair[0].iter().zip(&pair[1]).filter(|(a, b)| a != b).count() == 2 }));
+    }
+
+    #[test]
+    fn test_next_slice() {
+        let mut orderings = Permutations::new(['a', 'b']);
+        assert_eq!(Some(&['a', 'b'][..]), orderings.next_slice());
+        assert_eq!(Some(&['b', 'a'][..]), orderings.next_slice());
+        assert_eq!(None, orderings.next_slice());
+        assert_eq!(None, orderings.next_slice());
+    }
+}
@@ -0,0 +1,146 @@
+/// The decimal digits of `n`, from the most significant to the least significant. The only digit
+/// of 0 is 0.
+pub fn digits(n: u64) -> Vec<u8> {
+    let mut digits = Vec::with_capacity(count_digits(n) as usize);
+    let mut rest = n;
+    loop {
+        digits.push((rest % 10) as u8);
+        rest /= 10;
+        if rest == 0 {
+            break;
+        }
+    }
+    digits.reverse();
+    digits
+}
+
+/// The number of decimal digits of `n`, which is 1 for 0.
+pub fn count_digits(n: u64) -> u32 {
+    n.checked_ilog10().map_or(1, |log| log + 1)
+}
+
+/// The number whose decimal digits are `digits`, from the most significant to the least
+/// significant, or `None` if it's too large for a `u64`.
+///
+/// # Panics
+/// Panics if any of the digits is greater than 9.
+pub fn from_digits(digits: impl IntoIterator<Item = u8>) -> Option<u64> {
+    digits.into_iter().try_fold(0u64, |n, digit| {
+        assert!(digit < 10, "{digit} isn't a decimal digit");
+        n.checked_mul(10)?.checked_add(u64::from(digit))
+    })
+}
+
+/// The number whose decimal digits are those of `a` followed by those of `b`, such as the
+/// concatenation operator of 2024 day 7, or `None` if it's too large for a `u64`.
+pub fn checked_concat(a: u64, b: u64) -> Option<u64> {
+    a.checked_mul(10u64.checked_pow(count_digits(b))?)?
+        .checked_add(b)
+}
+
+/// The number whose decimal digits are those of `a` followed by those of `b`.
+///
+/// # Panics
+/// Panics if the number is too large for a `u64`.
+pub fn concat(a: u64, b: u64) -> u64 {
+    checked_concat(a, b).unwrap_or_else(|| panic!("Concatenating {a} and {b} overflowed a u64"))
+}
+
+/// Gets an iterator over the numbers whose decimal digits are a rearrangement of those of `n`,
+/// each once, in increasing order and starting from the least of them. Arrangements which start
+/// with 0 are included, so some of the numbers may have fewer digits than `n`.
+pub fn digit_permutations(n: u64) -> impl Iterator<Item = u64> {
+    let mut digits = digits(n);
+    digits.sort_unstable();
+    let mut next = Some(digits);
+    std::iter::from_fn(move || {
+        let current = next.take()?;
+        let mut following = current.clone();
+        if next_permutation(&mut following) {
+            next = Some(following);
+        }
+        from_digits(current.iter().copied())
+    })
+}
+
+/// Rearranges `values` into the next greater arrangement in lexicographic order. Returns false
+/// and leaves `values` unchanged if it's already the greatest.
+fn next_permutation(values: &mut [u8]) -> bool {
+    let Some(pivot) = values.windows(2).rposition(|pair| pair[0] < pair[1]) else {
+        return false;
+    };
+    let successor = values
+        .iter()
+        .rposition(|&value| value > values[pivot])
+        .expect("A later value is greater than the pivot");
+    values.swap(pivot, successor);
+    values[pivot + 1..].reverse();
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_digits() {
+        assert_eq!(vec![1, 2, 0, 3], digits(1203));
+        assert_eq!(vec![0], digits(0));
+        assert_eq!(20, digits(u64::MAX).len());
+        assert_eq!(
+            (1, 1, 4),
+            (count_digits(0), count_digits(9), count_digits(1000))
+        );
+        assert_eq!(20, count_digits(u64::MAX));
+        assert_eq!(Some(1203), from_digits([0, 1, 2, 0, 3]));
+        assert_eq!(Some(u64::MAX), from_digits(digits(u64::MAX)));
+        assert_eq!(None, from_digits([9; 20]));
+    }
+
+    #[test]
+    fn test_concat() {
+        assert_eq!(156, concat(15, 6));
+        assert_eq!(120, concat(12, 0));
+        assert_eq!(7, concat(0, 7));
+        assert_eq!(None, checked_concat(u64::MAX, 1));
+        // The calibration equation 7290: 6 8 6 15 of 2024 day 7 is true as 6 * 8 || 6 * 15.
+        assert_eq!(7290, concat(6 * 8, 6) * 15);
+    }
+
+    #[test]
+    fn test_digit_permutations() {
+        assert_eq!(
+            vec![123, 132, 213, 231, 312, 321],
+            digit_permutations(231).collect::<Vec<_>>()
+        );
+        // Repeated digits only give each number once, and leading zeros make shorter numbers.
+        assert_eq!(
+            vec![11, 101, 110],
+            digit_permutations(110).collect::<Vec<_>>()
+        );
+        assert_eq!(vec![0], digit_permutations(0).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_passwords() {
+        // The passwords of 2019 day 4 have six digits which never decrease and two adjacent
+        // digits which are the same, and in part 2 a pair which isn't part of a larger group.
+        let is_password = |password: u64| {
+            let digits = digits(password);
+            digits.len() == 6
+                && digits.windows(2).all(|pair| pair[0] <= pair[1])
+                && digits.windows(2).any(|pair| pair[0] == pair[1])
+        };
+        let has_exact_pair = |password: u64| {
+            digits(password)
+                .chunk_by(|a, b| a == b)
+                .any(|group| group.len() == 2)
+        };
+        assert!(is_password(111111));
+        assert!(!is_password(223450));
+        assert!(!is_password(123789));
+        assert!(has_exact_pair(112233));
+        assert!(!has_exact_pair(123444));
+        assert!(has_exact_pair(111122));
+    }
+}
@@ -0,0 +1,428 @@
+use std::ops::{Add, Index, IndexMut, Mul};
+
+use super::{Integer, Ratio};
+
+/// A matrix of numbers, such as the transitions of a linear recurrence like the population of
+/// lanternfish in 2021 day 6. Raising the matrix of one step to the `n`th power gives the matrix
+/// of `n` steps in a number of multiplications proportional to the logarithm of `n`, so a
+/// recurrence can be stepped far past where simulating it one step at a time would finish.
+///
+/// Matrices are indexed by `(row, column)`.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Matrix<T> {
+    rows: usize,
+    columns: usize,
+    /// The value at `(row, column)` is at `row * columns + column`.
+    values: Vec<T>,
+}
+
+impl<T> Matrix<T> {
+    /// Creates a matrix from its rows, from the top row to the bottom row. Returns `None` if the
+    /// rows aren't all the same length.
+    pub fn from_rows<I>(rows: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = Vec<T>>,
+    {
+        let mut rows = rows.into_iter().peekable();
+        let columns = rows.peek().map_or(0, Vec::len);
+        let mut row_count = 0;
+        let mut values = vec![];
+        for row in rows {
+            if row.len() != columns {
+                return None;
+            }
+            values.extend(row);
+            row_count += 1;
+        }
+        Some(Self {
+            rows: row_count,
+            columns,
+            values,
+        })
+    }
+
+    /// The number of rows in the matrix.
+    pub const fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// The number of columns in the matrix.
+    pub const fn columns(&self) -> usize {
+        self.columns
+    }
+
+    /// Returns true if and only if the matrix has as many rows as columns.
+    pub const fn is_square(&self) -> bool {
+        self.rows == self.columns
+    }
+
+    /// The values of row `row`, from left to right.
+    ///
+    /// # Panics
+    /// Panics if `row` is out of bounds.
+    pub fn row(&self, row: usize) -> &[T] {
+        assert!(
+            row < self.rows,
+            "Row {row} is outside of a matrix with {} rows",
+            self.rows
+        );
+        &self.values[row * self.columns..(row + 1) * self.columns]
+    }
+
+    fn offset(&self, (row, column): (usize, usize)) -> usize {
+        assert!(
+            row < self.rows && column < self.columns,
+            "{:?} is outside of a {}x{} matrix",
+            (row, column),
+            self.rows,
+            self.columns
+        );
+        row * self.columns + column
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Copy + Default,
+{
+    /// Creates a matrix with `rows` rows and `columns` columns whose values are all
+    /// [`T::default()`], which is 0 for numbers.
+    ///
+    /// [`T::default()`]: Default::default
+    pub fn new(rows: usize, columns: usize) -> Self {
+        Self {
+            rows,
+            columns,
+            values: vec![T::default(); rows * columns],
+        }
+    }
+
+    /// The matrix whose rows are this matrix's columns.
+    pub fn transpose(&self) -> Self {
+        let mut transpose = Self::new(self.columns, self.rows);
+        for row in 0..self.rows {
+            for column in 0..self.columns {
+                transpose[(column, row)] = self[(row, column)];
+            }
+        }
+        transpose
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Copy + Default + From<u8>,
+{
+    /// Creates the `size` by `size` matrix which is 1 along its diagonal and 0 everywhere else,
+    /// so multiplying by it changes nothing.
+    pub fn identity(size: usize) -> Self {
+        let mut identity = Self::new(size, size);
+        for i in 0..size {
+            identity[(i, i)] = T::from(1);
+        }
+        identity
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Copy + Default + Add<Output = T> + Mul<Output = T>,
+{
+    /// The product of this matrix and the column vector `vector`, such as the state of a linear
+    /// recurrence after the steps that this matrix represents.
+    ///
+    /// # Panics
+    /// Panics if `vector` doesn't have one value per column of the matrix.
+    pub fn mul_vector(&self, vector: &[T]) -> Vec<T> {
+        assert_eq!(
+            self.columns,
+            vector.len(),
+            "Can't multiply a {}x{} matrix by a vector of length {}",
+            self.rows,
+            self.columns,
+            vector.len()
+        );
+        (0..self.rows)
+            .map(|row| dot(self.row(row), vector.iter().copied()))
+            .collect()
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Copy + Default + From<u8> + Add<Output = T> + Mul<Output = T>,
+{
+    /// This matrix multiplied by itself `exponent` times, found by repeated squaring. The 0th power
+    /// is the identity matrix.
+    ///
+    /// # Panics
+    /// Panics if the matrix isn't square.
+    pub fn pow(&self, mut exponent: u64) -> Self {
+        assert!(
+            self.is_square(),
+            "Can't raise a {}x{} matrix to a power",
+            self.rows,
+            self.columns
+        );
+        let mut result = Self::identity(self.rows);
+        let mut base = self.clone();
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = &result * &base;
+            }
+            exponent >>= 1;
+            if exponent > 0 {
+                base = &base * &base;
+            }
+        }
+        result
+    }
+}
+
+impl<T> Matrix<Ratio<T>>
+where
+    T: Integer,
+{
+    /// Solves the system of linear equations whose coefficients are this matrix, so that the
+    /// vector `x` that's returned satisfies `self * x == rhs`, with Gaussian elimination. The
+    /// fractions are exact, so the solution doesn't drift the way that it would with
+    /// floating-point numbers, such as when finding where to throw the rock in 2023 day 24.
+    ///
+    /// Returns `None` if there isn't exactly one solution.
+    ///
+    /// # Panics
+    /// Panics if the matrix isn't square or `rhs` doesn't have one value per row of the matrix.
+    pub fn solve(&self, rhs: &[Ratio<T>]) -> Option<Vec<Ratio<T>>> {
+        assert!(
+            self.is_square(),
+            "Can't solve a system of {} equations in {} unknowns",
+            self.rows,
+            self.columns
+        );
+        assert_eq!(
+            self.rows,
+            rhs.len(),
+            "Can't solve a system of {} equations with {} right-hand sides",
+            self.rows,
+            rhs.len()
+        );
+        let size = self.rows;
+        // Each row of the system is followed by its right-hand side.
+        let mut rows = (0..size)
+            .map(|row| {
+                let mut row_values = self.row(row).to_vec();
+                row_values.push(rhs[row]);
+                row_values
+            })
+            .collect::<Vec<_>>();
+        for column in 0..size {
+            let pivot = (column..size).find(|&row| rows[row][column] != Ratio::ZERO)?;
+            rows.swap(column, pivot);
+            let scale = rows[column][column].recip();
+            for value in &mut rows[column][column..] {
+                *value = *value * scale;
+            }
+            let pivot_row = rows[column].clone();
+            for (row, row_values) in rows.iter_mut().enumerate() {
+                let factor = row_values[column];
+                if row == column || factor == Ratio::ZERO {
+                    continue;
+                }
+                for (value, &pivot_value) in
+                    row_values[column..].iter_mut().zip(&pivot_row[column..])
+                {
+                    *value = *value - factor * pivot_value;
+                }
+            }
+        }
+        Some(rows.into_iter().map(|row| row[size]).collect())
+    }
+}
+
+/// The sum of the products of the corresponding values of `a` and `b`.
+fn dot<T>(a: &[T], b: impl Iterator<Item = T>) -> T
+where
+    T: Copy + Default + Add<Output = T> + Mul<Output = T>,
+{
+    a.iter()
+        .zip(b)
+        .fold(T::default(), |sum, (&a, b)| sum + a * b)
+}
+
+impl<T> Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
+
+    fn index(&self, index: (usize, usize)) -> &T {
+        &self.values[self.offset(index)]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Matrix<T> {
+    fn index_mut(&mut self, index: (usize, usize)) -> &mut T {
+        let offset = self.offset(index);
+        &mut self.values[offset]
+    }
+}
+
+impl<T> Mul for &Matrix<T>
+where
+    T: Copy + Default + Add<Output = T> + Mul<Output = T>,
+{
+    type Output = Matrix<T>;
+
+    /// # Panics
+    /// Panics if the left matrix doesn't have as many columns as the right matrix has rows.
+    fn mul(self, other: Self) -> Matrix<T> {
+        assert_eq!(
+            self.columns, other.rows,
+            "Can't multiply a {}x{} matrix by a {}x{} matrix",
+            self.rows, self.columns, other.rows, other.columns
+        );
+        let mut product = Matrix::new(self.rows, other.columns);
+        for row in 0..self.rows {
+            for column in 0..other.columns {
+                product[(row, column)] =
+                    dot(self.row(row), (0..other.rows).map(|i| other[(i, column)]));
+            }
+        }
+        product
+    }
+}
+
+impl<T> Mul for Matrix<T>
+where
+    T: Copy + Default + Add<Output = T> + Mul<Output = T>,
+{
+    type Output = Self;
+
+    /// # Panics
+    /// Panics if the left matrix doesn't have as many columns as the right matrix has rows.
+    fn mul(self, other: Self) -> Self {
+        &self * &other
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::math::Rational;
+
+    #[test]
+    fn test_mul() {
+        let a = Matrix::from_rows([vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+        let b = Matrix::from_rows([vec![7, 8], vec![9, 10], vec![11, 12]]).unwrap();
+        let expected = Matrix::from_rows([vec![58, 64], vec![139, 154]]).unwrap();
+        assert_eq!(expected, &a * &b);
+        assert_eq!(expected.transpose(), b.transpose() * a.transpose());
+        assert_eq!(vec![14, 32], a.mul_vector(&[1, 2, 3]));
+        assert_eq!(&[4, 5, 6], a.row(1));
+        assert_eq!((2, 3), (a.rows(), a.columns()));
+        assert!(Matrix::from_rows([vec![1], vec![2, 3]]).is_none());
+    }
+
+    #[test]
+    #[should_panic = "Can't multiply a 2x3 matrix by a 2x3 matrix"]
+    fn test_mul_mismatched() {
+        let a = Matrix::<i32>::new(2, 3);
+        let _ = &a * &a;
+    }
+
+    #[test]
+    fn test_pow() {
+        // The nth power of this matrix holds the (n + 1)th, nth, and (n - 1)th Fibonacci numbers.
+        let fibonacci = Matrix::from_rows([vec![1u64, 1], vec![1, 0]]).unwrap();
+        assert_eq!(Matrix::identity(2), fibonacci.pow(0));
+        assert_eq!(fibonacci, fibonacci.pow(1));
+        assert_eq!(55, fibonacci.pow(10)[(0, 1)]);
+        assert_eq!(2880067194370816120, fibonacci.pow(90)[(0, 1)]);
+    }
+
+    #[test]
+    fn test_lanternfish() {
+        // The lanternfish of 2021 day 6. Each day, every fish's timer goes down by one, and each
+        // fish whose timer was 0 goes back to 6 and makes a new fish whose timer is 8.
+        let mut day = Matrix::<u64>::new(9, 9);
+        for timer in 1..9 {
+            day[(timer - 1, timer)] = 1;
+        }
+        day[(6, 0)] = 1;
+        day[(8, 0)] = 1;
+        let mut timers = vec![0; 9];
+        for timer in [3, 4, 3, 1, 2] {
+            timers[timer] += 1;
+        }
+        let count = |days| day.pow(days).mul_vector(&timers).into_iter().sum::<u64>();
+        assert_eq!(26, count(18));
+        assert_eq!(5934, count(80));
+        assert_eq!(26984457539, count(256));
+    }
+
+    #[test]
+    fn test_solve() {
+        let r = Rational::from;
+        // 2x + y = 3 and x - y = 3 / 2 meet at (3 / 2, 0).
+        let system = Matrix::from_rows([vec![r(2), r(1)], vec![r(1), r(-1)]]).unwrap();
+        assert_eq!(
+            Some(vec![Rational::new(3, 2), r(0)]),
+            system.solve(&[r(3), Rational::new(3, 2)])
+        );
+        // A pivot of 0 has to be swapped with a later row.
+        let system = Matrix::from_rows([vec![r(0), r(1)], vec![r(1), r(0)]]).unwrap();
+        assert_eq!(Some(vec![r(5), r(7)]), system.solve(&[r(7), r(5)]));
+        // Parallel lines never meet.
+        let system = Matrix::from_rows([vec![r(1), r(2)], vec![r(2), r(4)]]).unwrap();
+        assert_eq!(None, system.solve(&[r(1), r(1)]));
+    }
+
+    #[test]
+    fn test_hailstones() {
+        // The hailstones of 2023 day 24. A rock thrown from `p` with velocity `v` hits hailstone
+        // `i` if and only if `(p - p_i) × (v - v_i) = 0`. The only terms of that which aren't
+        // linear are `p × v`, which is the same for every hailstone, so subtracting the equation
+        // for hailstone 0 from those for hailstones 1 and 2 leaves six linear equations.
+        let hailstones: [([i64; 3], [i64; 3]); 3] = [
+            ([19, 13, 30], [-2, 1, -2]),
+            ([18, 19, 22], [-1, -1, -2]),
+            ([20, 25, 34], [-2, -2, -4]),
+        ];
+        let cross = |a: [i64; 3], b: [i64; 3]| {
+            [
+                a[1] * b[2] - a[2] * b[1],
+                a[2] * b[0] - a[0] * b[2],
+                a[0] * b[1] - a[1] * b[0],
+            ]
+        };
+        let sub = |a: [i64; 3], b: [i64; 3]| [a[0] - b[0], a[1] - b[1], a[2] - b[2]];
+        // The matrix which multiplies any `x` into `x × m`.
+        let cross_left = |m: [i64; 3]| [[0, m[2], -m[1]], [-m[2], 0, m[0]], [m[1], -m[0], 0]];
+        let mut rows = vec![];
+        let mut rhs = vec![];
+        let (p0, v0) = hailstones[0];
+        for &(p, v) in &hailstones[1..] {
+            // P × (v - v0) + (p - p0) × V = p × v - p0 × v0, where the rock is thrown from P with
+            // velocity V.
+            let (dv, dp) = (sub(v, v0), sub(p, p0));
+            let (on_p, on_v) = (cross_left(dv), cross_left(dp));
+            let constant = sub(cross(p, v), cross(p0, v0));
+            for axis in 0..3 {
+                let row = on_p[axis]
+                    .iter()
+                    .copied()
+                    .chain(on_v[axis].iter().map(|&coefficient| -coefficient))
+                    .map(Rational::from)
+                    .collect();
+                rows.push(row);
+                rhs.push(Rational::from(constant[axis]));
+            }
+        }
+        let system = Matrix::from_rows(rows).unwrap();
+        let rock = system
+            .solve(&rhs)
+            .unwrap()
+            .into_iter()
+            .map(|value| value.to_integer().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(vec![24, 13, 10, -3, 1, 2], rock);
+    }
+}
@@ -0,0 +1,683 @@
+/// Counting the ways to choose and order things, and listing the orders.
+pub mod combinatorics;
+pub use combinatorics::{choose, factorial, permutations, Permutations};
+/// The decimal digits of numbers, for puzzles about how numbers are written.
+pub mod digits;
+pub use digits::{concat, count_digits, digits};
+/// Matrices of numbers, for stepping linear recurrences many steps at once.
+pub mod matrix;
+pub use matrix::Matrix;
+/// Writing numbers in any base, including balanced bases with negative digits.
+pub mod numeral;
+pub use numeral::NumeralSystem;
+/// Fitting polynomials to sequences, for predicting values far past the end of the sequence.
+pub mod polynomial;
+pub use polynomial::{extrapolate, interpolate};
+/// Prime numbers, for sieving, testing, and factorizing numbers.
+pub mod primes;
+pub use primes::{factorize, is_prime, primes};
+/// Exact fractions, for arithmetic which floating-point numbers would round.
+pub mod ratio;
+pub use ratio::{Ratio, Rational};
+
+use std::{
+    any,
+    fmt::{self, Display, Formatter},
+    iter::{Product, Sum},
+    ops::{Add, Div, Mul, Neg, Rem, Sub},
+};
+
+/// The greatest common divisor of `a` and `b`, which is never negative. The greatest common
+/// divisor of 0 and any number is that number's absolute value.
+pub fn gcd<T>(mut a: T, mut b: T) -> T
+where
+    T: Copy + Default + PartialOrd + Rem<Output = T> + Sub<Output = T>,
+{
+    let zero = T::default();
+    while b != zero {
+        (a, b) = (b, a % b);
+    }
+    if a < zero {
+        zero - a
+    } else {
+        a
+    }
+}
+
+/// The least common multiple of `a` and `b`, such as the number of steps until two cycles with
+/// lengths `a` and `b` line up again. It's never negative, and it's 0 if either of them is 0.
+pub fn lcm<T>(a: T, b: T) -> T
+where
+    T: Copy
+        + Default
+        + PartialOrd
+        + Div<Output = T>
+        + Mul<Output = T>
+        + Rem<Output = T>
+        + Sub<Output = T>,
+{
+    let zero = T::default();
+    if a == zero || b == zero {
+        return zero;
+    }
+    let lcm = a / gcd(a, b) * b;
+    if lcm < zero {
+        zero - lcm
+    } else {
+        lcm
+    }
+}
+
+/// The least common multiple of every number in `values`, such as the number of steps until every
+/// ghost of 2023 day 8 is at the end of its cycle at once, or `None` if there are no numbers.
+pub fn lcm_of<T>(values: impl IntoIterator<Item = T>) -> Option<T>
+where
+    T: Copy
+        + Default
+        + PartialOrd
+        + Div<Output = T>
+        + Mul<Output = T>
+        + Rem<Output = T>
+        + Sub<Output = T>,
+{
+    values.into_iter().reduce(lcm)
+}
+
+/// The greatest common divisor `g` of `a` and `b` along with numbers `x` and `y` such that
+/// `a * x + b * y == g`, as found by the extended Euclidean algorithm. `g` is never negative.
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// Solves the system of congruences `x ≡ residue (mod modulus)` for each `(residue, modulus)` in
+/// `congruences` with the Chinese remainder theorem, such as finding the time when every bus of
+/// 2020 day 13 leaves at its offset. Residues may be negative or bigger than their moduli.
+///
+/// Returns the least solution `x` which isn't negative along with the modulus `m` that every
+/// solution is congruent to it modulo, which is the least common multiple of the moduli, or
+/// `None` if there's no solution. The moduli don't have to be coprime, but if any two of them
+/// share a factor then their residues must agree modulo that factor or there's no solution.
+///
+/// # Panics
+/// Panics if any modulus isn't positive.
+pub fn crt(congruences: impl IntoIterator<Item = (i128, i128)>) -> Option<(i128, i128)> {
+    congruences
+        .into_iter()
+        .try_fold((0, 1), |(x, m), (residue, modulus)| {
+            assert!(modulus > 0, "Modulus {modulus} isn't positive");
+            let residue = residue.rem_euclid(modulus);
+            // x + m * k ≡ residue (mod modulus) can only be solved for k if the difference is
+            // divisible by every factor that the moduli share.
+            let (g, m_inverse, _) = extended_gcd(m, modulus);
+            let difference = residue - x;
+            if difference % g != 0 {
+                return None;
+            }
+            let step = modulus / g;
+            let k = (difference / g % step * m_inverse).rem_euclid(step);
+            let lcm = m * step;
+            Some(((x + m * k).rem_euclid(lcm), lcm))
+        })
+}
+
+/// `base` raised to the power `exponent` modulo `modulus`, found by repeated squaring. The
+/// products are done in 128 bits, so they can't overflow.
+///
+/// # Panics
+/// Panics if `modulus` is 0.
+pub fn mod_pow(base: u64, mut exponent: u64, modulus: u64) -> u64 {
+    assert!(modulus > 0, "Modulus can't be 0");
+    let modulus = u128::from(modulus);
+    let mut base = u128::from(base) % modulus;
+    let mut result = 1 % modulus;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = result * base % modulus;
+        }
+        base = base * base % modulus;
+        exponent >>= 1;
+    }
+    result as u64
+}
+
+/// The number `x` such that `value * x ≡ 1 (mod modulus)` and `0 <= x < modulus`, or `None` if
+/// there isn't one because `value` and `modulus` share a factor.
+///
+/// # Panics
+/// Panics if `modulus` is 0.
+pub fn mod_inv(value: u64, modulus: u64) -> Option<u64> {
+    assert!(modulus > 0, "Modulus can't be 0");
+    let modulus = i128::from(modulus);
+    let (g, x, _) = extended_gcd(i128::from(value), modulus);
+    (g == 1).then(|| x.rem_euclid(modulus) as u64)
+}
+
+/// A number modulo some modulus, such as the position of a card in the deck of 2019 day 22.
+/// Arithmetic on these numbers is always reduced by the modulus, and products are done in 128
+/// bits, so composing shuffles of a huge deck many times over can't overflow.
+///
+/// Arithmetic between numbers with different moduli panics.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ModInt {
+    value: u64,
+    modulus: u64,
+}
+
+impl ModInt {
+    /// Creates the number congruent to `value` modulo `modulus`. `value` may be negative, such as
+    /// the size of a cut from the bottom of the deck.
+    ///
+    /// # Panics
+    /// Panics if `modulus` is 0.
+    pub fn new(value: i128, modulus: u64) -> Self {
+        assert!(modulus > 0, "Modulus can't be 0");
+        Self {
+            value: value.rem_euclid(i128::from(modulus)) as u64,
+            modulus,
+        }
+    }
+
+    /// The least number which isn't negative that this number is congruent to.
+    pub const fn value(self) -> u64 {
+        self.value
+    }
+
+    /// The modulus that this number is reduced by.
+    pub const fn modulus(self) -> u64 {
+        self.modulus
+    }
+
+    /// This number raised to the power `exponent`.
+    pub fn pow(self, exponent: u64) -> Self {
+        Self {
+            value: mod_pow(self.value, exponent, self.modulus),
+            ..self
+        }
+    }
+
+    /// The number which this number times is 1, or `None` if this number shares a factor with
+    /// the modulus.
+    pub fn inv(self) -> Option<Self> {
+        mod_inv(self.value, self.modulus).map(|value| Self { value, ..self })
+    }
+
+    fn check_modulus(self, other: Self) {
+        assert_eq!(
+            self.modulus, other.modulus,
+            "Can't do arithmetic on numbers with different moduli"
+        );
+    }
+}
+
+impl Display for ModInt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+impl Add for ModInt {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        self.check_modulus(other);
+        let sum = (u128::from(self.value) + u128::from(other.value)) % u128::from(self.modulus);
+        Self {
+            value: sum as u64,
+            ..self
+        }
+    }
+}
+
+impl Neg for ModInt {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            value: (self.modulus - self.value) % self.modulus,
+            ..self
+        }
+    }
+}
+
+impl Sub for ModInt {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self + -other
+    }
+}
+
+impl Mul for ModInt {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        self.check_modulus(other);
+        let product = u128::from(self.value) * u128::from(other.value) % u128::from(self.modulus);
+        Self {
+            value: product as u64,
+            ..self
+        }
+    }
+}
+
+/// Integers whose arithmetic can be checked for overflow, so that counts which get too large for
+/// their type are noticed instead of silently wrapping around in release builds.
+pub trait CheckedArithmetic: Copy + Display + Sized {
+    /// The number 0, which is the sum of no numbers.
+    const ZERO: Self;
+    /// The number 1, which is the product of no numbers.
+    const ONE: Self;
+
+    /// `self + other`, or `None` if that overflows.
+    fn checked_add(self, other: Self) -> Option<Self>;
+
+    /// `self - other`, or `None` if that overflows.
+    fn checked_sub(self, other: Self) -> Option<Self>;
+
+    /// `self * other`, or `None` if that overflows.
+    fn checked_mul(self, other: Self) -> Option<Self>;
+
+    /// `-self`, or `None` if that overflows.
+    fn checked_neg(self) -> Option<Self>;
+}
+
+/// Integers which can be the numerator and denominator of a [`Ratio`].
+pub trait Integer:
+    CheckedArithmetic + Default + Ord + Div<Output = Self> + Rem<Output = Self> + Sub<Output = Self>
+{
+}
+
+impl<T> Integer for T where
+    T: CheckedArithmetic
+        + Default
+        + Ord
+        + Div<Output = Self>
+        + Rem<Output = Self>
+        + Sub<Output = Self>
+{
+}
+
+/// Integers which can be multiplied into a type twice as wide, so that the product can't overflow.
+pub trait WideMul: Copy {
+    /// The type of the product.
+    type Wide;
+
+    /// `self * other` in a type which is wide enough to hold any product of two `Self`s.
+    fn wide_mul(self, other: Self) -> Self::Wide;
+}
+
+macro_rules! impl_checked_arithmetic {
+    ($($t:ty),*) => {
+        $(
+            impl CheckedArithmetic for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    <$t>::checked_add(self, other)
+                }
+
+                fn checked_sub(self, other: Self) -> Option<Self> {
+                    <$t>::checked_sub(self, other)
+                }
+
+                fn checked_mul(self, other: Self) -> Option<Self> {
+                    <$t>::checked_mul(self, other)
+                }
+
+                fn checked_neg(self) -> Option<Self> {
+                    <$t>::checked_neg(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_checked_arithmetic!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+macro_rules! impl_wide_mul {
+    ($($t:ty => $wide:ty),*) => {
+        $(
+            impl WideMul for $t {
+                type Wide = $wide;
+
+                fn wide_mul(self, other: Self) -> $wide {
+                    <$wide>::from(self) * <$wide>::from(other)
+                }
+            }
+        )*
+    };
+}
+
+impl_wide_mul!(
+    u8 => u16, u16 => u32, u32 => u64, u64 => u128,
+    i8 => i16, i16 => i32, i32 => i64, i64 => i128
+);
+
+/// The sum of `values`, or `None` if it overflows partway through.
+pub fn checked_sum<T>(values: impl IntoIterator<Item = T>) -> Option<T>
+where
+    T: CheckedArithmetic,
+{
+    values
+        .into_iter()
+        .try_fold(T::ZERO, CheckedArithmetic::checked_add)
+}
+
+/// The product of `values`, or `None` if it overflows partway through.
+pub fn checked_product<T>(values: impl IntoIterator<Item = T>) -> Option<T>
+where
+    T: CheckedArithmetic,
+{
+    values
+        .into_iter()
+        .try_fold(T::ONE, CheckedArithmetic::checked_mul)
+}
+
+/// Converts `value` to a narrower type, such as the answer to a puzzle whose intermediate values
+/// were computed in `i128` or `u128` to keep them from overflowing.
+///
+/// # Panics
+/// Panics if `value` doesn't fit in `T`.
+pub fn narrow<T, U>(value: U) -> T
+where
+    T: TryFrom<U>,
+    U: Copy + Display,
+{
+    T::try_from(value)
+        .unwrap_or_else(|_| panic!("{value} doesn't fit in a {}", any::type_name::<T>()))
+}
+
+/// An integer whose addition and multiplication always panic on overflow, even in release builds
+/// where plain arithmetic silently wraps around. The panic names the operands, so it's clear which
+/// count got too large. Wrapping a count in this while solving a puzzle is a quick way to find
+/// out whether a wrong answer comes from an overflow.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Checked<T>(pub T);
+
+impl<T> Display for Checked<T>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<T> Add for Checked<T>
+where
+    T: CheckedArithmetic,
+{
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        match self.0.checked_add(other.0) {
+            Some(sum) => Self(sum),
+            None => panic!(
+                "{} + {} overflowed a {}",
+                self.0,
+                other.0,
+                any::type_name::<T>()
+            ),
+        }
+    }
+}
+
+impl<T> Mul for Checked<T>
+where
+    T: CheckedArithmetic,
+{
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        match self.0.checked_mul(other.0) {
+            Some(product) => Self(product),
+            None => panic!(
+                "{} * {} overflowed a {}",
+                self.0,
+                other.0,
+                any::type_name::<T>()
+            ),
+        }
+    }
+}
+
+impl<T> Sum for Checked<T>
+where
+    T: CheckedArithmetic,
+{
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Self>,
+    {
+        iter.fold(Self(T::ZERO), Add::add)
+    }
+}
+
+impl<T> Product for Checked<T>
+where
+    T: CheckedArithmetic,
+{
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Self>,
+    {
+        iter.fold(Self(T::ONE), Mul::mul)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gcd() {
+        assert_eq!(6, gcd(48, 18));
+        assert_eq!(6, gcd(18u64, 48));
+        assert_eq!(1, gcd(17, 5));
+        assert_eq!(7, gcd(0, 7));
+        assert_eq!(7, gcd(-7, 0));
+        assert_eq!(4, gcd(-12i32, 8));
+        assert_eq!(0, gcd(0u8, 0));
+    }
+
+    #[test]
+    fn test_lcm() {
+        assert_eq!(36, lcm(12, 18));
+        assert_eq!(36, lcm(-12i64, 18));
+        assert_eq!(0, lcm(0, 5));
+        assert_eq!(Some(2520u128), lcm_of(1..=10));
+        assert_eq!(None, lcm_of(Vec::<u32>::new()));
+    }
+
+    #[test]
+    fn test_moon_periods() {
+        // The moons of the first example of 2019 day 12 return to where they started after 2772
+        // steps, since their x, y, and z coordinates repeat every 18, 28, and 44 steps.
+        assert_eq!(Some(2772), lcm_of([18, 28, 44]));
+    }
+
+    #[test]
+    fn test_extended_gcd() {
+        for (a, b) in [(240, 46), (-240, 46), (17, -5), (0, 9), (9, 0)] {
+            let (g, x, y) = extended_gcd(a, b);
+            assert_eq!(gcd(a, b), g);
+            assert_eq!(g, a * x + b * y);
+        }
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(Some((23, 105)), crt([(2, 3), (3, 5), (2, 7)]));
+        assert_eq!(Some((0, 1)), crt([]));
+        // Negative residues and residues bigger than their moduli are reduced first.
+        assert_eq!(Some((23, 105)), crt([(-1, 3), (-2, 5), (16, 7)]));
+        // Moduli which share a factor can be solved when their residues agree on it.
+        assert_eq!(Some((10, 12)), crt([(4, 6), (2, 4)]));
+        assert_eq!(None, crt([(1, 6), (2, 4)]));
+    }
+
+    #[test]
+    fn test_buses() {
+        // The buses of 2020 day 13, where bus 7 leaves at time t, bus 13 at t + 1, and so on.
+        let buses = [(0, 7), (1, 13), (4, 59), (6, 31), (7, 19)];
+        let (time, period) = crt(buses.map(|(offset, bus)| (-offset, bus))).unwrap();
+        assert_eq!(1068781, time);
+        assert_eq!(7 * 13 * 59 * 31 * 19, period);
+    }
+
+    #[test]
+    fn test_mod_pow() {
+        assert_eq!(445, mod_pow(4, 13, 497));
+        assert_eq!(1, mod_pow(7, 0, 13));
+        assert_eq!(0, mod_pow(7, 0, 1));
+        // The product of the squares would overflow 64 bits.
+        let modulus = 119315717514047;
+        assert_eq!(1, mod_pow(2, modulus - 1, modulus));
+    }
+
+    #[test]
+    fn test_mod_inv() {
+        assert_eq!(Some(4), mod_inv(3, 11));
+        assert_eq!(Some(1), mod_inv(12, 11));
+        assert_eq!(None, mod_inv(4, 10));
+        let modulus = 119315717514047;
+        let inverse = ModInt::new(2020, modulus).inv().unwrap();
+        assert_eq!(1, (inverse * ModInt::new(2020, modulus)).value());
+    }
+
+    #[test]
+    fn test_mod_int() {
+        let a = ModInt::new(-3, 10);
+        assert_eq!(7, a.value());
+        assert_eq!(10, a.modulus());
+        assert_eq!(ModInt::new(2, 10), a + ModInt::new(5, 10));
+        assert_eq!(ModInt::new(9, 10), ModInt::new(1, 10) - ModInt::new(2, 10));
+        assert_eq!(ModInt::new(9, 10), a * a);
+        assert_eq!(ModInt::new(0, 10), -ModInt::new(0, 10));
+        assert_eq!(ModInt::new(3, 10), a.pow(3));
+        assert_eq!(Some(ModInt::new(3, 10)), a.inv());
+        assert_eq!("7", a.to_string());
+    }
+
+    #[test]
+    #[should_panic = "different moduli"]
+    fn test_mod_int_different_moduli() {
+        let _ = ModInt::new(1, 10) + ModInt::new(1, 11);
+    }
+
+    #[test]
+    fn test_slam_shuffle() {
+        // The last example of 2019 day 22. Each technique moves the card at position `x` to
+        // `a * x + b`, so the whole shuffle is one such affine transform.
+        let shuffle = |deck_size: u64| {
+            let techniques = [
+                (-1, -1),
+                (1, 2),
+                (7, 0),
+                (1, -8),
+                (1, 4),
+                (7, 0),
+                (1, -3),
+                (9, 0),
+                (3, 0),
+                (1, 1),
+            ];
+            techniques.into_iter().fold(
+                (ModInt::new(1, deck_size), ModInt::new(0, deck_size)),
+                |(a, b), (c, d)| {
+                    let (c, d) = (ModInt::new(c, deck_size), ModInt::new(d, deck_size));
+                    (c * a, c * b + d)
+                },
+            )
+        };
+        let (a, b) = shuffle(10);
+        let mut deck = [0; 10];
+        for card in 0..10 {
+            deck[(a * ModInt::new(card, 10) + b).value() as usize] = card;
+        }
+        assert_eq!([9, 2, 5, 8, 1, 4, 7, 0, 3, 6], deck);
+
+        // Repeating the shuffle `n` times moves `x` to `a^n * x + b * (a^n - 1) / (a - 1)`, and the
+        // card that ends up at a position is found with the inverse.
+        let deck_size = 10007;
+        let (a, b) = shuffle(deck_size);
+        let times = 1000;
+        let a_n = a.pow(times);
+        let one = ModInt::new(1, deck_size);
+        let b_n = b * (a_n - one) * (a - one).inv().unwrap();
+        let mut position = ModInt::new(2019, deck_size);
+        for _ in 0..times {
+            position = a * position + b;
+        }
+        assert_eq!(position, a_n * ModInt::new(2019, deck_size) + b_n);
+        let card = (position - b_n) * a_n.inv().unwrap();
+        assert_eq!(2019, card.value());
+    }
+
+    #[test]
+    fn test_checked() {
+        assert_eq!(Some(255u8), checked_sum([100, 100, 55]));
+        assert_eq!(None, checked_sum([100u8, 100, 56]));
+        assert_eq!(Some(0), checked_sum(Vec::<i32>::new()));
+        assert_eq!(Some(1), checked_product(Vec::<u64>::new()));
+        assert_eq!(None, checked_product([1i64 << 32, 1 << 31]));
+        assert_eq!(Some(i64::MIN), checked_product([-(1i64 << 32), 1 << 31]));
+        assert_eq!(
+            u64::MAX as u128 * u64::MAX as u128,
+            u64::MAX.wide_mul(u64::MAX)
+        );
+        assert_eq!(16384, (-128i8).wide_mul(-128));
+        assert_eq!(Checked(6u32), [1, 2, 3].map(Checked).into_iter().product());
+        assert_eq!(Checked(6u32), [1, 2, 3].map(Checked).into_iter().sum());
+        assert_eq!(7u8, narrow(7i128));
+    }
+
+    #[test]
+    #[should_panic = "256 doesn't fit in a u8"]
+    fn test_narrow_too_large() {
+        narrow::<u8, _>(256u64);
+    }
+
+    #[test]
+    #[should_panic = "4294967295 + 1 overflowed a u32"]
+    fn test_checked_overflow() {
+        let _ = Checked(u32::MAX) + Checked(1);
+    }
+
+    #[test]
+    fn test_lanternfish() {
+        // The lanternfish of 2021 day 6, of which there are too many after 256 days to count in
+        // 32 bits.
+        fn count<T>(days: usize) -> Option<T>
+        where
+            T: CheckedArithmetic,
+        {
+            let mut timers = [T::ZERO; 9];
+            for timer in [3, 4, 3, 1, 2] {
+                timers[timer] = timers[timer].checked_add(T::ONE)?;
+            }
+            for _ in 0..days {
+                timers.rotate_left(1);
+                timers[6] = timers[6].checked_add(timers[8])?;
+            }
+            checked_sum(timers)
+        }
+        assert_eq!(Some(5934u32), count(80));
+        assert_eq!(None, count::<u32>(256));
+        assert_eq!(Some(26984457539u64), count(256));
+    }
+}
@@ -0,0 +1,210 @@
+/// A way of writing whole numbers as digits in a positional base, such as binary, hexadecimal, or
+/// the SNAFU numbers of 2022 day 25. Each character of the alphabet is a digit, and the value of
+/// each digit is one more than the value of the one before it.
+///
+/// The values of the digits start from 0 in most bases. In a balanced base, they start from a
+/// negative number instead, such as -2 in SNAFU numbers, whose digits are `=`, `-`, `0`, `1`, and
+/// `2`. Every number, including every negative number, can be written in a balanced base without
+/// a sign, so a sign is only written in a base whose digits start from 0.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NumeralSystem {
+    digits: Vec<char>,
+    /// The value of the first digit.
+    lowest: i64,
+}
+
+impl NumeralSystem {
+    /// Creates the base whose digits are the characters of `digits`, which are worth 0, 1, 2, and
+    /// so on.
+    ///
+    /// # Panics
+    /// Panics if there are fewer than 2 digits or a character appears twice.
+    pub fn new(digits: &str) -> Self {
+        Self::balanced(digits, 0)
+    }
+
+    /// Creates the base whose digits are the characters of `digits`, which are worth `lowest`,
+    /// `lowest + 1`, and so on.
+    ///
+    /// # Panics
+    /// Panics if there are fewer than 2 digits, a character appears twice, or 0 isn't the value
+    /// of a digit. Panics if the first digit is negative but the last digit isn't positive, since
+    /// then positive numbers couldn't be written.
+    pub fn balanced(digits: &str, lowest: i64) -> Self {
+        let digits = digits.chars().collect::<Vec<_>>();
+        assert!(digits.len() >= 2, "A base needs at least two digits");
+        for (i, digit) in digits.iter().enumerate() {
+            assert!(
+                !digits[i + 1..].contains(digit),
+                "The digit {digit:?} appears twice"
+            );
+        }
+        let highest = lowest + digits.len() as i64 - 1;
+        assert!(
+            lowest == 0 || (lowest < 0 && highest > 0),
+            "Can't write every number with digits from {lowest} to {highest}"
+        );
+        Self { digits, lowest }
+    }
+
+    /// Base 2, with the digits `0` and `1`.
+    pub fn binary() -> Self {
+        Self::new("01")
+    }
+
+    /// Base 10, with the digits `0` to `9`.
+    pub fn decimal() -> Self {
+        Self::new("0123456789")
+    }
+
+    /// Base 16, with the digits `0` to `9` and then `a` to `f`.
+    pub fn hexadecimal() -> Self {
+        Self::new("0123456789abcdef")
+    }
+
+    /// The balanced base 5 of 2022 day 25, whose digits `=`, `-`, `0`, `1`, and `2` are worth -2,
+    /// -1, 0, 1, and 2.
+    pub fn snafu() -> Self {
+        Self::balanced("=-012", -2)
+    }
+
+    /// The number of digits, which is the number that each place is worth that many times the
+    /// place after it.
+    pub fn base(&self) -> usize {
+        self.digits.len()
+    }
+
+    /// The value of `digit`, or `None` if it isn't a digit of this base.
+    pub fn value(&self, digit: char) -> Option<i64> {
+        self.digits
+            .iter()
+            .position(|&other| other == digit)
+            .map(|i| self.lowest + i as i64)
+    }
+
+    /// Writes `n` in this base, without any leading zeros. A negative number is written with a
+    /// leading `-` unless this base is balanced.
+    pub fn format(&self, n: i64) -> String {
+        if n < 0 && self.lowest == 0 {
+            // `n.unsigned_abs()` may not fit back in an `i64`, so its last digit is split off
+            // first.
+            let base = self.base() as i64;
+            let last = -(n % base);
+            let rest = -(n / base);
+            let mut written = String::from("-");
+            if rest != 0 {
+                written.push_str(&self.format(rest));
+            }
+            written.push(self.digits[last as usize]);
+            return written;
+        }
+        let base = self.base() as i128;
+        let mut rest = i128::from(n);
+        let mut digits = vec![];
+        loop {
+            let value = (rest - i128::from(self.lowest)).rem_euclid(base) + i128::from(self.lowest);
+            digits.push(self.digits[(value - i128::from(self.lowest)) as usize]);
+            rest = (rest - value) / base;
+            if rest == 0 {
+                break;
+            }
+        }
+        digits.into_iter().rev().collect()
+    }
+
+    /// Reads the number that `s` is written as in this base, or returns `None` if `s` has no
+    /// digits, has a character which isn't a digit, or is too large for an `i64`. A leading `-`
+    /// makes the number negative, unless this base is balanced or has `-` as a digit.
+    pub fn parse(&self, s: &str) -> Option<i64> {
+        let (negative, digits) = match s.strip_prefix('-') {
+            Some(digits) if self.lowest == 0 && self.value('-').is_none() => (true, digits),
+            _ => (false, s),
+        };
+        if digits.is_empty() {
+            return None;
+        }
+        // A number which fits in an `i64` may still have a prefix which doesn't, so the number is
+        // built up in 128 bits.
+        let base = self.base() as i128;
+        let n = digits.chars().try_fold(0i128, |n, digit| {
+            n.checked_mul(base)?
+                .checked_add(i128::from(self.value(digit)?))
+        })?;
+        i64::try_from(if negative { -n } else { n }).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_standard() {
+        let binary = NumeralSystem::binary();
+        assert_eq!("1101", binary.format(13));
+        assert_eq!("0", binary.format(0));
+        assert_eq!("-101", binary.format(-5));
+        assert_eq!(Some(13), binary.parse("01101"));
+        assert_eq!(Some(-5), binary.parse("-101"));
+        assert_eq!(None, binary.parse("102"));
+        assert_eq!(None, binary.parse(""));
+        assert_eq!(None, binary.parse("-"));
+        let hex = NumeralSystem::hexadecimal();
+        assert_eq!("-8000000000000000", hex.format(i64::MIN));
+        assert_eq!(Some(i64::MIN), hex.parse("-8000000000000000"));
+        assert_eq!(None, hex.parse("8000000000000000"));
+        assert_eq!(Some(i64::MAX), hex.parse(&hex.format(i64::MAX)));
+        assert_eq!("255", NumeralSystem::decimal().format(255));
+        assert_eq!(16, hex.base());
+    }
+
+    #[test]
+    fn test_custom_alphabet() {
+        // A base 3 whose digits are letters.
+        let letters = NumeralSystem::new("abc");
+        assert_eq!("bca", letters.format(15));
+        assert_eq!(Some(15), letters.parse("bca"));
+        assert_eq!(Some(2), letters.value('c'));
+        assert_eq!(None, letters.value('d'));
+    }
+
+    #[test]
+    fn test_balanced_ternary() {
+        let ternary = NumeralSystem::balanced("T01", -1);
+        assert_eq!("1T", ternary.format(2));
+        assert_eq!("T1", ternary.format(-2));
+        assert_eq!("0", ternary.format(0));
+        for n in -100..=100 {
+            assert_eq!(Some(n), ternary.parse(&ternary.format(n)));
+        }
+        assert_eq!(Some(i64::MIN), ternary.parse(&ternary.format(i64::MIN)));
+        assert_eq!(Some(i64::MAX), ternary.parse(&ternary.format(i64::MAX)));
+    }
+
+    #[test]
+    fn test_snafu() {
+        // The fuel requirements of 2022 day 25, which add up to 4890, or 2=-1=0 in SNAFU.
+        let snafu = NumeralSystem::snafu();
+        let requirements = [
+            "1=-0-2", "12111", "2=0=", "21", "2=01", "111", "20012", "112", "1=-1=", "1-12", "12",
+            "1=", "122",
+        ];
+        let values = requirements.map(|requirement| snafu.parse(requirement).unwrap());
+        assert_eq!(
+            [1747, 906, 198, 11, 201, 31, 1257, 32, 353, 107, 7, 3, 37],
+            values
+        );
+        let sum = values.iter().sum::<i64>();
+        assert_eq!(4890, sum);
+        assert_eq!("2=-1=0", snafu.format(sum));
+        assert_eq!("1121-1110-1=0", snafu.format(314159265));
+        assert_eq!("-", snafu.format(-1));
+        assert_eq!(Some(-3), snafu.parse("-2"));
+    }
+
+    #[test]
+    #[should_panic = "appears twice"]
+    fn test_repeated_digit() {
+        NumeralSystem::new("0120");
+    }
+}
@@ -0,0 +1,127 @@
+use super::Rational;
+
+/// The value at step `step` of the sequence whose first values are `values`, assuming that the
+/// sequence is a polynomial of degree less than the number of values, found with Newton's forward
+/// differences. `step` may be past the end of the values or negative, such as the next and
+/// previous values of the histories of 2023 day 9.
+///
+/// The values don't have to be for consecutive inputs, only for evenly spaced ones. In 2023 day
+/// 21, the number of plots that can be reached in `65 + 131 * n` steps is a quadratic in `n`, so
+/// its values for `n` of 0, 1, and 2 give its value for `n` of 202300.
+///
+/// The answer is exact, since a polynomial which is an integer at consecutive integers is an
+/// integer at every integer.
+///
+/// # Panics
+/// Panics if `values` is empty.
+pub fn extrapolate(values: &[i128], step: i128) -> i128 {
+    assert!(!values.is_empty(), "Can't extrapolate from no values");
+    let mut differences = values.to_vec();
+    let mut value = 0;
+    // The binomial coefficient `step` choose `degree`, which is always an integer.
+    let mut choose = 1;
+    for degree in 0..values.len() {
+        value += differences[0] * choose;
+        choose = choose * (step - degree as i128) / (degree as i128 + 1);
+        differences = differences
+            .windows(2)
+            .map(|pair| pair[1] - pair[0])
+            .collect();
+        if differences.iter().all(|&difference| difference == 0) {
+            break;
+        }
+    }
+    value
+}
+
+/// The value at `x` of the polynomial of least degree which passes through every one of `points`,
+/// found with Lagrange's formula. Unlike [`extrapolate()`], the points may be anywhere, so the
+/// answer may be a fraction.
+///
+/// # Panics
+/// Panics if `points` is empty or two of them have the same x-coordinate.
+pub fn interpolate(points: &[(i128, i128)], x: i128) -> Rational {
+    assert!(!points.is_empty(), "Can't interpolate from no points");
+    points
+        .iter()
+        .enumerate()
+        .map(|(i, &(x_i, y_i))| {
+            points.iter().enumerate().filter(|&(j, _)| j != i).fold(
+                Rational::from(y_i),
+                |term, (_, &(x_j, _))| {
+                    assert_ne!(x_i, x_j, "Two points have the x-coordinate {x_i}");
+                    term * Rational::new(x - x_j, x_i - x_j)
+                },
+            )
+        })
+        .fold(Rational::ZERO, |sum, term| sum + term)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extrapolate() {
+        assert_eq!(7, extrapolate(&[7], 100));
+        assert_eq!(-4, extrapolate(&[2, 4], -3));
+        // The squares, from 0 squared.
+        assert_eq!(10000, extrapolate(&[0, 1, 4, 9], 100));
+        assert_eq!(25, extrapolate(&[0, 1, 4, 9], -5));
+        // A cubic which only fits with all four values.
+        let cubic = |n: i128| n * n * n - 2 * n + 1;
+        let values = [0, 1, 2, 3].map(cubic);
+        assert_eq!(cubic(1000), extrapolate(&values, 1000));
+        assert_eq!(cubic(-7), extrapolate(&values, -7));
+    }
+
+    #[test]
+    fn test_histories() {
+        // The histories of 2023 day 9, whose next values add up to 114 and whose previous values
+        // add up to 2.
+        let histories = [
+            [0, 3, 6, 9, 12, 15],
+            [1, 3, 6, 10, 15, 21],
+            [10, 13, 16, 21, 30, 45],
+        ];
+        let next = histories.map(|history| extrapolate(&history, history.len() as i128));
+        assert_eq!([18, 28, 68], next);
+        assert_eq!(114, next.iter().sum::<i128>());
+        let previous = histories.map(|history| extrapolate(&history, -1));
+        assert_eq!([-3, 0, 5], previous);
+    }
+
+    #[test]
+    fn test_garden_plots() {
+        // In 2023 day 21, the number of plots reached in 65 + 131 * n steps is a quadratic in n,
+        // so its values for n of 0, 1, and 2 give its value for 26501365 steps. The quadratic
+        // depends on the input, so this one is made up.
+        let plots = |n: i128| 14655 * n * n + 14757 * n + 3699;
+        let values = [0, 1, 2].map(plots);
+        assert_eq!(plots(202300), extrapolate(&values, 202300));
+        assert_eq!(
+            Rational::from(plots(202300)),
+            interpolate(
+                &[(65, values[0]), (196, values[1]), (327, values[2])],
+                26501365
+            )
+        );
+    }
+
+    #[test]
+    fn test_interpolate() {
+        // The line through (0, 0) and (2, 1) is at 1 / 2 at 1.
+        assert_eq!(Rational::new(1, 2), interpolate(&[(0, 0), (2, 1)], 1));
+        assert_eq!(
+            Rational::from(16),
+            interpolate(&[(-1, 1), (1, 1), (3, 9)], 4)
+        );
+        assert_eq!(Rational::from(5), interpolate(&[(3, 5)], -10));
+    }
+
+    #[test]
+    #[should_panic = "Two points have the x-coordinate 1"]
+    fn test_interpolate_repeated() {
+        interpolate(&[(1, 1), (1, 2)], 0);
+    }
+}
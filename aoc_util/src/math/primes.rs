@@ -0,0 +1,208 @@
+use std::ops::Range;
+
+use super::mod_pow;
+
+/// The number of numbers that [`primes()`] sieves at a time.
+const SEGMENT_SIZE: u64 = 1 << 16;
+
+/// The prime numbers less than `limit`, in increasing order, found with the sieve of Eratosthenes.
+pub fn primes_below(limit: u64) -> Vec<u64> {
+    let limit = usize::try_from(limit).expect("Limit is too large to sieve");
+    let mut is_prime = vec![true; limit];
+    let mut primes = vec![];
+    for n in 2..limit {
+        if is_prime[n] {
+            primes.push(n as u64);
+            for multiple in (n * n..limit).step_by(n) {
+                is_prime[multiple] = false;
+            }
+        }
+    }
+    primes
+}
+
+/// The prime numbers in `range`, in increasing order, found with a segmented sieve, so that only
+/// the primes up to the square root of the end of the range and the numbers in the range itself
+/// are kept in memory. This suits ranges which are short but far from 0.
+pub fn primes_in(range: Range<u64>) -> Vec<u64> {
+    let Range { start, end } = range;
+    if start >= end {
+        return vec![];
+    }
+    let start = start.max(2);
+    let len = usize::try_from(end.saturating_sub(start)).expect("Range is too long to sieve");
+    let mut is_prime = vec![true; len];
+    for prime in primes_below((end - 1).isqrt() + 1) {
+        // Multiples which are less than the square of the prime were crossed off by a smaller
+        // prime, and the prime itself mustn't be crossed off.
+        let first = (prime * prime).max(start.div_ceil(prime) * prime);
+        for multiple in (first..end).step_by(prime as usize) {
+            is_prime[(multiple - start) as usize] = false;
+        }
+    }
+    is_prime
+        .into_iter()
+        .zip(start..)
+        .filter_map(|(is_prime, n)| is_prime.then_some(n))
+        .collect()
+}
+
+/// Gets an iterator over every prime number in increasing order, which sieves the numbers a
+/// segment at a time as they're needed.
+pub fn primes() -> impl Iterator<Item = u64> {
+    (0..).flat_map(|segment| primes_in(segment * SEGMENT_SIZE..(segment + 1) * SEGMENT_SIZE))
+}
+
+/// Returns true if and only if `n` is prime, found with the Miller-Rabin test. The bases that it
+/// tests are enough for the test to be exact for every `u64`.
+pub fn is_prime(n: u64) -> bool {
+    const BASES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+    if n < 2 {
+        return false;
+    }
+    if let Some(&base) = BASES.iter().find(|&&base| n % base == 0) {
+        return n == base;
+    }
+    // n - 1 = odd * 2^twos
+    let twos = (n - 1).trailing_zeros();
+    let odd = (n - 1) >> twos;
+    BASES.iter().all(|&base| {
+        let mut x = mod_pow(base, odd, n);
+        if x == 1 || x == n - 1 {
+            return true;
+        }
+        for _ in 1..twos {
+            x = mod_pow(x, 2, n);
+            if x == n - 1 {
+                return true;
+            }
+        }
+        false
+    })
+}
+
+/// The prime factors of `n` in increasing order, each with the number of times that it divides
+/// `n`, found by trial division. 1 has no prime factors.
+///
+/// # Panics
+/// Panics if `n` is 0.
+pub fn factorize(mut n: u64) -> Vec<(u64, u32)> {
+    assert!(n > 0, "0 has no factorization");
+    let mut factors = vec![];
+    let candidates = std::iter::once(2).chain((3..).step_by(2));
+    for candidate in candidates {
+        if candidate > n / candidate {
+            break;
+        }
+        let mut exponent = 0;
+        while n % candidate == 0 {
+            n /= candidate;
+            exponent += 1;
+        }
+        if exponent > 0 {
+            factors.push((candidate, exponent));
+        }
+    }
+    if n > 1 {
+        factors.push((n, 1));
+    }
+    factors
+}
+
+/// Every number which divides `n`, in increasing order.
+///
+/// # Panics
+/// Panics if `n` is 0.
+pub fn divisors(n: u64) -> Vec<u64> {
+    let mut divisors = vec![1];
+    for (prime, exponent) in factorize(n) {
+        let smaller = divisors.len();
+        let mut power = 1;
+        for _ in 0..exponent {
+            power *= prime;
+            for i in 0..smaller {
+                divisors.push(divisors[i] * power);
+            }
+        }
+    }
+    divisors.sort_unstable();
+    divisors
+}
+
+/// The sum of every number which divides `n`, including 1 and `n` itself, such as the value that
+/// the disassembled loop of 2018 day 19 leaves in register 0.
+///
+/// # Panics
+/// Panics if `n` is 0.
+pub fn sum_of_divisors(n: u64) -> u64 {
+    factorize(n)
+        .into_iter()
+        .map(|(prime, exponent)| (0..=exponent).map(|power| prime.pow(power)).sum::<u64>())
+        .product()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sieves() {
+        let below_50 = vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47];
+        assert_eq!(below_50, primes_below(50));
+        assert_eq!(below_50, primes_in(0..50));
+        assert_eq!(vec![2, 3], primes_in(2..4));
+        assert_eq!(Vec::<u64>::new(), primes_in(24..29));
+        assert_eq!(
+            vec![1_000_000_007, 1_000_000_009],
+            primes_in(1_000_000_000..1_000_000_010)
+        );
+        assert_eq!(Vec::<u64>::new(), primes_in(10..10));
+        assert_eq!(
+            below_50,
+            primes().take_while(|&p| p < 50).collect::<Vec<_>>()
+        );
+        // The 10001st prime, which crosses several segments.
+        assert_eq!(Some(104743), primes().nth(10000));
+        assert_eq!(
+            primes_below(200_000),
+            primes().take_while(|&p| p < 200_000).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_is_prime() {
+        let sieved = primes_below(10_000);
+        assert!((0..10_000).all(|n| is_prime(n) == sieved.binary_search(&n).is_ok()));
+        assert!(is_prime(1_000_000_007));
+        assert!(is_prime(18_446_744_073_709_551_557));
+        // A strong pseudoprime to the first several bases.
+        assert!(!is_prime(3_215_031_751));
+        assert!(!is_prime(u64::MAX));
+    }
+
+    #[test]
+    fn test_factorize() {
+        assert_eq!(Vec::<(u64, u32)>::new(), factorize(1));
+        assert_eq!(vec![(2, 3), (3, 2), (5, 1)], factorize(360));
+        assert_eq!(vec![(1_000_000_007, 1)], factorize(1_000_000_007));
+        assert_eq!(vec![(2, 63)], factorize(1 << 63));
+        assert_eq!(vec![1, 2, 3, 4, 6, 12], divisors(12));
+        assert_eq!(vec![1], divisors(1));
+    }
+
+    #[test]
+    fn test_sum_of_divisors() {
+        for n in 1..2000 {
+            assert_eq!(
+                (1..=n).filter(|d| n % d == 0).sum::<u64>(),
+                sum_of_divisors(n)
+            );
+        }
+        // The number that the loop of 2018 day 19 sums the divisors of is 836 in some inputs.
+        assert_eq!(1680, sum_of_divisors(836));
+        assert_eq!(
+            divisors(10_551_236).into_iter().sum::<u64>(),
+            sum_of_divisors(10_551_236)
+        );
+    }
+}
@@ -0,0 +1,337 @@
+use std::{
+    cmp::Ordering,
+    fmt::{self, Display, Formatter},
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+use super::{gcd, Integer};
+
+/// Unwraps the result of checked arithmetic on the parts of a [`Ratio`], since a wrong answer from
+/// a silent overflow is worse than a panic.
+fn checked<T>(value: Option<T>) -> T {
+    value.expect("Ratio arithmetic overflowed")
+}
+
+/// An exact fraction of two integers of type `T`, such as the coordinates of the intersections of
+/// the paths of the hailstones of 2023 day 24, which floating-point numbers would round. It's
+/// always in lowest terms with a positive denominator, so equal fractions have equal parts.
+///
+/// # Panics
+/// Arithmetic panics if an intermediate value overflows a `T` instead of wrapping around.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Ratio<T> {
+    numerator: T,
+    denominator: T,
+}
+
+/// A fraction of two `i128`s, which is wide enough for most puzzles.
+pub type Rational = Ratio<i128>;
+
+impl<T> Ratio<T>
+where
+    T: Integer,
+{
+    /// The number 0.
+    pub const ZERO: Self = Self {
+        numerator: T::ZERO,
+        denominator: T::ONE,
+    };
+    /// The number 1.
+    pub const ONE: Self = Self {
+        numerator: T::ONE,
+        denominator: T::ONE,
+    };
+
+    /// Creates the fraction `numerator / denominator` in lowest terms.
+    ///
+    /// # Panics
+    /// Panics if `denominator` is 0.
+    pub fn new(numerator: T, denominator: T) -> Self {
+        assert!(denominator != T::ZERO, "Denominator can't be 0");
+        let divisor = gcd(numerator, denominator);
+        let (numerator, denominator) = (numerator / divisor, denominator / divisor);
+        if denominator < T::ZERO {
+            Self {
+                numerator: checked(numerator.checked_neg()),
+                denominator: checked(denominator.checked_neg()),
+            }
+        } else {
+            Self {
+                numerator,
+                denominator,
+            }
+        }
+    }
+
+    /// Creates the fraction `value / 1`.
+    pub const fn from_integer(value: T) -> Self {
+        Self {
+            numerator: value,
+            denominator: T::ONE,
+        }
+    }
+
+    /// The numerator of the fraction in lowest terms, which has the sign of the fraction.
+    pub const fn numerator(self) -> T {
+        self.numerator
+    }
+
+    /// The denominator of the fraction in lowest terms, which is always positive.
+    pub const fn denominator(self) -> T {
+        self.denominator
+    }
+
+    /// Returns true if and only if the fraction is a whole number.
+    pub fn is_integer(self) -> bool {
+        self.denominator == T::ONE
+    }
+
+    /// The fraction as a whole number, or `None` if it isn't one.
+    pub fn to_integer(self) -> Option<T> {
+        self.is_integer().then_some(self.numerator)
+    }
+
+    /// The greatest whole number which isn't greater than the fraction.
+    pub fn floor(self) -> T {
+        let quotient = self.numerator / self.denominator;
+        if self.numerator % self.denominator < T::ZERO {
+            checked(quotient.checked_sub(T::ONE))
+        } else {
+            quotient
+        }
+    }
+
+    /// The least whole number which isn't less than the fraction.
+    pub fn ceil(self) -> T {
+        let quotient = self.numerator / self.denominator;
+        if self.numerator % self.denominator > T::ZERO {
+            checked(quotient.checked_add(T::ONE))
+        } else {
+            quotient
+        }
+    }
+
+    /// The fraction which this fraction times is 1.
+    ///
+    /// # Panics
+    /// Panics if the fraction is 0.
+    pub fn recip(self) -> Self {
+        assert!(self.numerator != T::ZERO, "0 has no reciprocal");
+        Self::new(self.denominator, self.numerator)
+    }
+}
+
+impl<T> Default for Ratio<T>
+where
+    T: Integer,
+{
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl<T> Display for Ratio<T>
+where
+    T: Integer,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)
+        }
+    }
+}
+
+macro_rules! impl_from_int_for_ratio {
+    ($($t:ty => $($from:ty),*);*) => {
+        $($(
+            impl From<$from> for Ratio<$t> {
+                fn from(value: $from) -> Self {
+                    Self::from_integer(<$t>::from(value))
+                }
+            }
+        )*)*
+    };
+}
+
+impl_from_int_for_ratio!(
+    i8 => i8;
+    i16 => i8, i16, u8;
+    i32 => i8, i16, i32, u8, u16;
+    i64 => i8, i16, i32, i64, u8, u16, u32;
+    i128 => i8, i16, i32, i64, i128, u8, u16, u32, u64
+);
+
+impl<T> PartialOrd for Ratio<T>
+where
+    T: Integer,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for Ratio<T>
+where
+    T: Integer,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        // The denominators are positive, so multiplying both sides by them keeps the order.
+        let left = checked(self.numerator.checked_mul(other.denominator));
+        let right = checked(other.numerator.checked_mul(self.denominator));
+        left.cmp(&right)
+    }
+}
+
+impl<T> Neg for Ratio<T>
+where
+    T: Integer,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            numerator: checked(self.numerator.checked_neg()),
+            ..self
+        }
+    }
+}
+
+impl<T> Add for Ratio<T>
+where
+    T: Integer,
+{
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        // Using the least common denominator keeps the intermediate values small.
+        let divisor = gcd(self.denominator, other.denominator);
+        let left = checked(self.numerator.checked_mul(other.denominator / divisor));
+        let right = checked(other.numerator.checked_mul(self.denominator / divisor));
+        let denominator = checked((self.denominator / divisor).checked_mul(other.denominator));
+        Self::new(checked(left.checked_add(right)), denominator)
+    }
+}
+
+impl<T> Sub for Ratio<T>
+where
+    T: Integer,
+{
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self + -other
+    }
+}
+
+impl<T> Mul for Ratio<T>
+where
+    T: Integer,
+{
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        // Cancelling before multiplying keeps the intermediate values small.
+        let left = gcd(self.numerator, other.denominator);
+        let right = gcd(other.numerator, self.denominator);
+        Self::new(
+            checked((self.numerator / left).checked_mul(other.numerator / right)),
+            checked((self.denominator / right).checked_mul(other.denominator / left)),
+        )
+    }
+}
+
+impl<T> Div for Ratio<T>
+where
+    T: Integer,
+{
+    type Output = Self;
+
+    /// # Panics
+    /// Panics if `other` is 0.
+    fn div(self, other: Self) -> Self {
+        #[allow(clippy::suspicious_arithmetic_impl)]
+        let quotient = self * other.recip();
+        quotient
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        let half = Rational::new(2, 4);
+        assert_eq!((1, 2), (half.numerator(), half.denominator()));
+        assert_eq!(Rational::new(-1, 2), Rational::new(3, -6));
+        assert_eq!(Rational::ZERO, Rational::new(0, -5));
+        assert_eq!(Some(-3), Rational::new(9, -3).to_integer());
+        assert_eq!(None, half.to_integer());
+        assert_eq!("-1/2", Rational::new(1, -2).to_string());
+        assert_eq!("4", Rational::from(4).to_string());
+    }
+
+    #[test]
+    #[should_panic = "Denominator can't be 0"]
+    fn test_new_zero_denominator() {
+        Rational::new(1, 0);
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let (half, third) = (Rational::new(1, 2), Rational::new(1, 3));
+        assert_eq!(Rational::new(5, 6), half + third);
+        assert_eq!(Rational::new(1, 6), half - third);
+        assert_eq!(Rational::new(-1, 6), third - half);
+        assert_eq!(Rational::new(1, 6), half * third);
+        assert_eq!(Rational::new(3, 2), half / third);
+        assert_eq!(Rational::from(3), third.recip());
+        assert!(third < half);
+        assert!(-half < third - half);
+        assert_eq!(
+            Rational::ONE,
+            Rational::new(1, 10).recip() * Rational::new(1, 10)
+        );
+    }
+
+    #[test]
+    fn test_rounding() {
+        assert_eq!(
+            (2, 3),
+            (Rational::new(5, 2).floor(), Rational::new(5, 2).ceil())
+        );
+        assert_eq!(
+            (-3, -2),
+            (Rational::new(-5, 2).floor(), Rational::new(-5, 2).ceil())
+        );
+        assert_eq!(
+            (4, 4),
+            (Rational::from(4).floor(), Rational::from(4).ceil())
+        );
+    }
+
+    #[test]
+    fn test_narrow_parts() {
+        let third = Ratio::<i32>::new(2, 6);
+        assert_eq!(Ratio::from(1), third + third + third);
+        assert_eq!(Ratio::new(1i8, 3), Ratio::new(-1, -3));
+        assert_eq!("1/3", third.to_string());
+    }
+
+    #[test]
+    #[should_panic = "overflowed"]
+    fn test_overflow() {
+        let _ = Ratio::<i8>::from_integer(100) + Ratio::from_integer(100);
+    }
+
+    #[test]
+    fn test_no_drift() {
+        // Adding a tenth ten times is exactly 1, unlike with floating-point numbers.
+        let tenth = Rational::new(1, 10);
+        let sum = (0..10).fold(Rational::ZERO, |sum, _| sum + tenth);
+        assert_eq!(Rational::ONE, sum);
+        assert_ne!(1.0, (0..10).fold(0.0, |sum, _| sum + 0.1));
+    }
+}
@@ -0,0 +1,135 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    fmt::{self, Display, Formatter},
+    sync::atomic::{AtomicBool, AtomicUsize, Ordering},
+};
+
+static ACTIVE: AtomicBool = AtomicBool::new(false);
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+/// An allocator which keeps track of how much heap memory is in use so that the peak usage of a
+/// solver can be measured with [`peak_since()`]. It allocates with [`System`] and only works once a
+/// binary has installed it as its global allocator:
+///
+/// ```ignore
+/// #[global_allocator]
+/// static ALLOCATOR: TrackingAllocator = TrackingAllocator;
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TrackingAllocator;
+
+impl TrackingAllocator {
+    fn grew(by: usize) {
+        ACTIVE.store(true, Ordering::Relaxed);
+        let current = CURRENT.fetch_add(by, Ordering::Relaxed) + by;
+        PEAK.fetch_max(current, Ordering::Relaxed);
+    }
+
+    fn shrank(by: usize) {
+        CURRENT.fetch_sub(by, Ordering::Relaxed);
+    }
+}
+
+// SAFETY: Every call is passed straight on to `System`, which upholds the contract of
+// `GlobalAlloc`. The bookkeeping doesn't allocate.
+unsafe impl GlobalAlloc for TrackingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        // SAFETY: The caller upholds the contract of `alloc()`.
+        let ptr = unsafe { System.alloc(layout) };
+        if !ptr.is_null() {
+            Self::grew(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        // SAFETY: The caller upholds the contract of `alloc_zeroed()`.
+        let ptr = unsafe { System.alloc_zeroed(layout) };
+        if !ptr.is_null() {
+            Self::grew(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        // SAFETY: The caller upholds the contract of `dealloc()`.
+        unsafe { System.dealloc(ptr, layout) };
+        Self::shrank(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        // SAFETY: The caller upholds the contract of `realloc()`.
+        let new_ptr = unsafe { System.realloc(ptr, layout, new_size) };
+        if !new_ptr.is_null() {
+            if new_size > layout.size() {
+                Self::grew(new_size - layout.size());
+            } else {
+                Self::shrank(layout.size() - new_size);
+            }
+        }
+        new_ptr
+    }
+}
+
+/// Starts measuring the peak heap usage from now, and returns how much heap memory is in use now so
+/// that it can be passed to [`peak_since()`].
+pub fn reset_peak() -> usize {
+    let current = CURRENT.load(Ordering::Relaxed);
+    PEAK.store(current, Ordering::Relaxed);
+    current
+}
+
+/// The most heap memory that has been in use at once since [`reset_peak()`] was last called, not
+/// counting the `base` bytes which were already in use before. Returns `None` if
+/// [`TrackingAllocator`] isn't the global allocator.
+pub fn peak_since(base: usize) -> Option<usize> {
+    ACTIVE
+        .load(Ordering::Relaxed)
+        .then(|| PEAK.load(Ordering::Relaxed).saturating_sub(base))
+}
+
+/// A number of bytes, displayed in the largest binary unit that keeps it at least 1.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Bytes(pub usize);
+
+impl Display for Bytes {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+        if self.0 < 1024 {
+            return write!(f, "{} B", self.0);
+        }
+        let mut size = self.0 as f64 / 1024.0;
+        let mut unit = 0;
+        while size >= 1024.0 && unit + 1 < UNITS.len() {
+            size /= 1024.0;
+            unit += 1;
+        }
+        write!(f, "{size:.1} {}", UNITS[unit])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[global_allocator]
+    static ALLOCATOR: TrackingAllocator = TrackingAllocator;
+
+    #[test]
+    fn test_peak() {
+        let base = reset_peak();
+        let big = vec![0_u8; 1 << 20];
+        drop(big);
+        // Other tests may be allocating at the same time, so the peak can only be bounded below.
+        assert!(peak_since(base).unwrap() >= 1 << 20);
+    }
+
+    #[test]
+    fn test_display_bytes() {
+        assert_eq!("1000 B", Bytes(1000).to_string());
+        assert_eq!("1.5 KiB", Bytes(1536).to_string());
+        assert_eq!("3.0 MiB", Bytes(3 << 20).to_string());
+        assert_eq!("2048.0 TiB", Bytes(1 << 51).to_string());
+    }
+}
@@ -0,0 +1,181 @@
+use std::ops::RangeInclusive;
+
+use nom::{
+    bytes::complete as bytes, character::complete as character, combinator as comb, sequence,
+    IResult,
+};
+
+#[cfg(feature = "aabb")]
+use crate::aabb::{Aabb, BoundingBox};
+#[cfg(feature = "aabb")]
+use crate::geometry::Point;
+use crate::geometry::{Point2D, Point3D};
+
+/// Recognizes the comma between two coordinates and any spaces after it.
+fn separator(s: &str) -> IResult<&str, &str> {
+    sequence::terminated(bytes::tag(","), character::space0)(s)
+}
+
+/// Parses a point written as `x,y`, such as the corners of the rock paths of 2022 day 14, with
+/// `coord` parsing each coordinate. There may be spaces after the comma.
+pub fn point2d<'s, T, F>(coord: F) -> impl FnMut(&'s str) -> IResult<&'s str, Point2D<T>>
+where
+    F: FnMut(&'s str) -> IResult<&'s str, T> + Copy,
+{
+    comb::map(
+        sequence::separated_pair(coord, separator, coord),
+        |(x, y)| Point2D::at(x, y),
+    )
+}
+
+/// Parses a point written as `<x=1, y=2, z=3>`, such as the moons of 2019 day 12, with `coord`
+/// parsing each coordinate. There may be spaces after each `=` and comma.
+pub fn labeled_point3d<'s, T, F>(coord: F) -> impl FnMut(&'s str) -> IResult<&'s str, Point3D<T>>
+where
+    F: FnMut(&'s str) -> IResult<&'s str, T> + Copy,
+{
+    let labeled = move |label| {
+        sequence::preceded(sequence::pair(bytes::tag(label), character::space0), coord)
+    };
+    comb::map(
+        sequence::delimited(
+            bytes::tag("<"),
+            sequence::tuple((
+                labeled("x="),
+                sequence::preceded(separator, labeled("y=")),
+                sequence::preceded(separator, labeled("z=")),
+            )),
+            bytes::tag(">"),
+        ),
+        |(x, y, z)| Point3D::at(x, y, z),
+    )
+}
+
+/// Parses the range of coordinates along an axis written as `x=5..7`, or as `x=3` for a range
+/// which only includes one coordinate, along with the name of the axis, which is one of `x`,
+/// `y`, and `z`.
+pub fn labeled_range(s: &str) -> IResult<&str, (char, RangeInclusive<i64>)> {
+    sequence::separated_pair(
+        character::one_of("xyz"),
+        bytes::tag("="),
+        comb::map(
+            sequence::pair(
+                character::i64,
+                comb::opt(sequence::preceded(bytes::tag(".."), character::i64)),
+            ),
+            |(start, end)| start..=end.unwrap_or(start),
+        ),
+    )(s)
+}
+
+/// Parses a rectangle written as the ranges of its x- and y-coordinates in either order, such as
+/// `x=495, y=2..7` or `y=7, x=495..501` for the veins of clay of 2018 day 17.
+#[cfg(feature = "aabb")]
+pub fn rectangle(s: &str) -> IResult<&str, BoundingBox<i64, 2>> {
+    comb::map_opt(
+        sequence::separated_pair(labeled_range, separator, labeled_range),
+        |ranges| {
+            let (x, y) = match ranges {
+                (('x', x), ('y', y)) | (('y', y), ('x', x)) => (x, y),
+                _ => return None,
+            };
+            Some(BoundingBox::new(
+                Point::at([*x.start(), *y.start()]),
+                Point::at([*x.end(), *y.end()]),
+            ))
+        },
+    )(s)
+}
+
+/// Parses a box written as the ranges of its x-, y-, and z-coordinates in any order, such as
+/// `x=10..12,y=10..12,z=10..12` for the cuboids of 2021 day 22.
+#[cfg(feature = "aabb")]
+pub fn aabb(s: &str) -> IResult<&str, Aabb> {
+    comb::map_opt(
+        sequence::tuple((
+            labeled_range,
+            sequence::preceded(separator, labeled_range),
+            sequence::preceded(separator, labeled_range),
+        )),
+        |(a, b, c)| {
+            let mut ranges = [a, b, c];
+            ranges.sort_unstable_by_key(|&(axis, _)| axis);
+            match ranges {
+                [('x', x), ('y', y), ('z', z)] => Some(Aabb {
+                    min_x: *x.start(),
+                    max_x: *x.end(),
+                    min_y: *y.start(),
+                    max_y: *y.end(),
+                    min_z: *z.start(),
+                    max_z: *z.end(),
+                }),
+                _ => None,
+            }
+        },
+    )(s)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_point2d() {
+        let mut parser = point2d(character::u32);
+        assert_eq!(
+            Ok((" -> 498,6", Point2D::at(498, 4))),
+            parser("498,4 -> 498,6")
+        );
+        assert_eq!(Ok(("", Point2D::at(1, 6))), parser("1, 6"));
+        assert!(parser("1;6").is_err());
+        assert!(point2d(character::u32)("-1,6").is_err());
+        assert_eq!(
+            Ok(("", Point2D::at(-1, 6))),
+            point2d(character::i64)("-1,6")
+        );
+    }
+
+    #[test]
+    fn test_labeled_point3d() {
+        let mut parser = labeled_point3d(character::i16);
+        assert_eq!(Ok(("", Point3D::at(-1, 0, 2))), parser("<x=-1, y=0, z=2>"));
+        assert_eq!(
+            Ok(("\n", Point3D::at(2, -10, -7))),
+            parser("<x= 2, y=-10, z=-7>\n")
+        );
+        assert!(parser("<y=0, x=-1, z=2>").is_err());
+    }
+
+    #[test]
+    fn test_labeled_range() {
+        assert_eq!(Ok(("", ('x', 5..=7))), labeled_range("x=5..7"));
+        assert_eq!(Ok((", y=1", ('z', -3..=-3))), labeled_range("z=-3, y=1"));
+        assert!(labeled_range("w=1..2").is_err());
+    }
+
+    #[test]
+    fn test_rectangle() {
+        let vein = BoundingBox::new(Point::at([495, 2]), Point::at([495, 7]));
+        assert_eq!(Ok(("", vein)), rectangle("x=495, y=2..7"));
+        let vein = BoundingBox::new(Point::at([495, 7]), Point::at([501, 7]));
+        assert_eq!(Ok(("", vein)), rectangle("y=7, x=495..501"));
+        let target = BoundingBox::new(Point::at([20, -10]), Point::at([30, -5]));
+        assert_eq!(Ok(("", target)), rectangle("x=20..30, y=-10..-5"));
+        assert!(rectangle("x=1, x=2").is_err());
+    }
+
+    #[test]
+    fn test_aabb() {
+        let cuboid = Aabb {
+            min_x: -20,
+            max_x: 26,
+            min_y: -36,
+            max_y: 17,
+            min_z: -47,
+            max_z: 7,
+        };
+        assert_eq!(Ok(("", cuboid)), aabb("x=-20..26,y=-36..17,z=-47..7"));
+        assert_eq!(Ok(("", cuboid)), aabb("z=-47..7, x=-20..26, y=-36..17"));
+        assert!(aabb("x=1..2,y=1..2,y=1..2").is_err());
+    }
+}
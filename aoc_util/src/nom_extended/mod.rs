@@ -1,5 +1,12 @@
 use nom::{character::complete as character, combinator, multi, IResult};
 
+#[doc(hidden)]
+pub use nom;
+
+/// Parsers for the ways that puzzles write coordinates, points, and boxes.
+#[cfg(feature = "geometry")]
+pub mod coords;
+
 /// Recognizes both `\n` and `\r\n`.
 #[deprecated = "Use character::line_ending"]
 pub fn newline(s: &str) -> IResult<&str, &str> {
@@ -129,7 +136,7 @@ macro_rules! impl_from_str_for_nom_parse {
             type Err = String;
 
             fn from_str(s: &str) -> Result<Self, Self::Err> {
-                use ::nom::{combinator, Finish};
+                use $crate::nom_extended::nom::{combinator, Finish};
 
                 combinator::complete(combinator::all_consuming(Self::nom_parse))(s)
                     .finish()
@@ -0,0 +1,213 @@
+use std::{
+    io::{self, Write},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static REPORTER: Mutex<Option<Box<dyn Report + Send>>> = Mutex::new(None);
+
+/// The least time between two updates of a task which are passed on to the reporter, so that a
+/// task can report every step without slowing down.
+const INTERVAL: Duration = Duration::from_millis(100);
+
+/// Shows the progress of the tasks that solvers run, such as with a progress bar.
+pub trait Report {
+    /// Shows that the task `task` has done `done` steps out of `total`, if the total is known.
+    fn update(&mut self, task: &str, done: u64, total: Option<u64>);
+
+    /// Shows that the task `task` has ended after `done` steps.
+    fn finish(&mut self, task: &str, done: u64);
+}
+
+/// Starts passing the progress of every task to `reporter`, replacing any previous reporter.
+pub fn report_to(reporter: impl Report + Send + 'static) {
+    *REPORTER.lock().unwrap() = Some(Box::new(reporter));
+    ENABLED.store(true, Ordering::Release);
+}
+
+/// Stops reporting progress.
+pub fn stop() {
+    ENABLED.store(false, Ordering::Release);
+    REPORTER.lock().unwrap().take();
+}
+
+fn with_reporter(f: impl FnOnce(&mut dyn Report)) {
+    if let Some(reporter) = REPORTER.lock().unwrap().as_mut() {
+        f(reporter.as_mut());
+    }
+}
+
+/// The progress of one task of a solver, such as a search. Reporting progress does nothing unless
+/// a reporter has been set with [`report_to()`], so solvers can always report it without cluttering
+/// their output. The task is finished when this is dropped.
+#[derive(Debug)]
+pub struct Progress {
+    task: &'static str,
+    done: u64,
+    total: Option<u64>,
+    last_update: Option<Instant>,
+}
+
+impl Progress {
+    /// Starts the task `task`, which takes `total` steps if that's known in advance.
+    pub fn new(task: &'static str, total: Option<u64>) -> Self {
+        let mut progress = Self {
+            task,
+            done: 0,
+            total,
+            last_update: None,
+        };
+        progress.set(0);
+        progress
+    }
+
+    /// Counts one more step as done.
+    pub fn inc(&mut self) {
+        self.set(self.done + 1);
+    }
+
+    /// Counts `done` steps as done in all.
+    pub fn set(&mut self, done: u64) {
+        self.done = done;
+        if !ENABLED.load(Ordering::Acquire) {
+            return;
+        }
+        let now = Instant::now();
+        if self
+            .last_update
+            .is_some_and(|last_update| now - last_update < INTERVAL)
+        {
+            return;
+        }
+        self.last_update = Some(now);
+        with_reporter(|reporter| reporter.update(self.task, self.done, self.total));
+    }
+
+    /// The number of steps that have been done.
+    pub fn done(&self) -> u64 {
+        self.done
+    }
+}
+
+impl Drop for Progress {
+    fn drop(&mut self) {
+        if ENABLED.load(Ordering::Acquire) {
+            with_reporter(|reporter| reporter.finish(self.task, self.done));
+        }
+    }
+}
+
+/// A reporter which draws a progress bar for the current task on stderr and erases it once the
+/// task is finished, so that the bar doesn't end up mixed in with the answers.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Bar {
+    /// The length of the line that was last drawn, so that it can be erased.
+    drawn: usize,
+}
+
+impl Bar {
+    /// The number of characters that the bar itself takes up.
+    const WIDTH: u64 = 30;
+
+    /// Creates a reporter which hasn't drawn anything yet.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The line which shows that `task` has done `done` steps out of `total`.
+    pub fn render(task: &str, done: u64, total: Option<u64>) -> String {
+        match total {
+            Some(total) if total > 0 => {
+                let done = done.min(total);
+                let filled = (done * Self::WIDTH / total) as usize;
+                format!(
+                    "{task}: [{}{}] {}% ({done}/{total})",
+                    "#".repeat(filled),
+                    ".".repeat(Self::WIDTH as usize - filled),
+                    done * 100 / total,
+                )
+            }
+            _ => format!("{task}: {done}"),
+        }
+    }
+
+    fn draw(&mut self, line: &str) {
+        let mut stderr = io::stderr().lock();
+        // Progress is only a convenience, so failing to show it shouldn't stop the solver.
+        let _ = write!(stderr, "\r{line:<0$}\r", self.drawn.max(line.len()));
+        let _ = stderr.flush();
+        self.drawn = line.len();
+    }
+}
+
+impl Report for Bar {
+    fn update(&mut self, task: &str, done: u64, total: Option<u64>) {
+        self.draw(&Self::render(task, done, total));
+    }
+
+    fn finish(&mut self, _task: &str, _done: u64) {
+        self.draw("");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::Arc;
+
+    #[derive(Debug, Default)]
+    struct Recorder(Arc<Mutex<Vec<String>>>);
+
+    impl Report for Recorder {
+        fn update(&mut self, task: &str, done: u64, total: Option<u64>) {
+            let event = format!("update {task} {done} {total:?}");
+            self.0.lock().unwrap().push(event);
+        }
+
+        fn finish(&mut self, task: &str, done: u64) {
+            self.0.lock().unwrap().push(format!("finish {task} {done}"));
+        }
+    }
+
+    #[test]
+    fn test_progress() {
+        let events = Arc::new(Mutex::new(vec![]));
+        Progress::new("before", None).inc();
+        report_to(Recorder(Arc::clone(&events)));
+        let mut progress = Progress::new("search", Some(10));
+        progress.inc();
+        progress.set(5);
+        assert_eq!(5, progress.done());
+        drop(progress);
+        stop();
+        Progress::new("after", None).inc();
+        // Other tests may be reporting their own tasks at the same time.
+        let events = events.lock().unwrap();
+        let events = events
+            .iter()
+            .map(String::as_str)
+            .filter(|event| event.contains(" search ") || event.contains(" before "))
+            .collect::<Vec<_>>();
+        // Only the first update is sure to get through since the rest come soon after it.
+        assert_eq!(Some(&"update search 0 Some(10)"), events.first());
+        assert_eq!(Some(&"finish search 5"), events.last());
+    }
+
+    #[test]
+    fn test_render() {
+        assert_eq!(
+            "search: [###############...............] 50% (5/10)",
+            Bar::render("search", 5, Some(10))
+        );
+        assert_eq!(
+            "search: [##############################] 100% (12/12)",
+            Bar::render("search", 15, Some(12))
+        );
+        assert_eq!("search: 1234", Bar::render("search", 1234, None));
+    }
+}
@@ -0,0 +1,681 @@
+use std::{
+    fmt::{self, Debug, Display, Formatter},
+    io::{self, BufRead},
+    panic::{self, AssertUnwindSafe},
+    time::{Duration, Instant},
+};
+
+use crate::{error::Error, input, memory};
+
+#[doc(hidden)]
+pub use linkme;
+
+/// The answer to one part of a puzzle.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Answer {
+    /// An answer which is a whole number.
+    Number(i128),
+    /// An answer which is anything other than a whole number.
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Number(n) => write!(f, "{n}"),
+            Self::Text(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+macro_rules! impl_from_number_for_answer {
+    ($($t:ty)+) => ($(
+        impl From<$t> for Answer {
+            fn from(n: $t) -> Self {
+                Self::Number(n as i128)
+            }
+        }
+    )+)
+}
+
+impl_from_number_for_answer!(u8 u16 u32 u64 usize i8 i16 i32 i64 i128 isize);
+
+impl Answer {
+    /// The answer which is displayed as `answer`. An answer which is displayed as a whole number
+    /// is a [`Answer::Number`], the same as if it had been converted from that number.
+    pub fn from_display(answer: impl Display) -> Self {
+        let answer = answer.to_string();
+        match answer.parse::<i128>() {
+            Ok(n) if n.to_string() == answer => Self::Number(n),
+            _ => Self::Text(answer),
+        }
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Self::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Self::Text(s.to_string())
+    }
+}
+
+/// A function which solves one part of a day's puzzle from the puzzle input.
+pub type Part = fn(&mut dyn BufRead) -> io::Result<Answer>;
+
+/// A function which takes the whole puzzle input as a `&str`, leaving reading the input to the
+/// runner.
+pub trait InputFn {
+    /// What the function returns.
+    type Output;
+
+    /// Calls the function with `input` as the puzzle input.
+    fn call_with_str(&self, input: &str) -> Self::Output;
+
+    /// Calls the function with the puzzle input that's read from `input`.
+    fn call_with_reader(&self, input: &mut dyn BufRead) -> io::Result<Self::Output>;
+}
+
+impl<F, R> InputFn for F
+where
+    F: Fn(&str) -> R,
+{
+    type Output = R;
+
+    fn call_with_str(&self, input: &str) -> R {
+        self(input)
+    }
+
+    fn call_with_reader(&self, input: &mut dyn BufRead) -> io::Result<R> {
+        let mut buf = String::new();
+        input.read_to_string(&mut buf)?;
+        Ok(self(&buf))
+    }
+}
+
+/// A day's puzzle input after it has been parsed, from which either part of the puzzle can be
+/// solved without reading the input again.
+pub trait Solver {
+    /// The parsed puzzle input, so that it can be checked before debugging the solution.
+    fn model(&self) -> &dyn Debug;
+
+    /// Solves the first part of the puzzle.
+    fn part1(&self) -> io::Result<Answer>;
+
+    /// Solves the second part of the puzzle.
+    fn part2(&self) -> io::Result<Answer>;
+}
+
+/// A parsed puzzle input along with the functions which solve each part of the puzzle from it.
+#[derive(Clone, Copy, Debug)]
+pub struct Parsed<M> {
+    /// The parsed puzzle input.
+    pub model: M,
+    /// The solution to the first part of the puzzle.
+    pub part1: fn(&M) -> io::Result<Answer>,
+    /// The solution to the second part of the puzzle.
+    pub part2: fn(&M) -> io::Result<Answer>,
+}
+
+impl<M> Solver for Parsed<M>
+where
+    M: Debug,
+{
+    fn model(&self) -> &dyn Debug {
+        &self.model
+    }
+
+    fn part1(&self) -> io::Result<Answer> {
+        (self.part1)(&self.model)
+    }
+
+    fn part2(&self) -> io::Result<Answer> {
+        (self.part2)(&self.model)
+    }
+}
+
+/// The way that a registered day produces its answers.
+#[derive(Clone, Copy, Debug)]
+pub enum Runner {
+    /// The day solves each part separately from the contents of `YYYY_DD.txt`.
+    Parts {
+        /// The solution to the first part of the puzzle.
+        part1: Part,
+        /// The solution to the second part of the puzzle.
+        part2: Part,
+    },
+    /// The day parses the contents of `YYYY_DD.txt` once and solves both parts from the result.
+    Parsed(fn(&mut dyn BufRead) -> io::Result<Box<dyn Solver>>),
+}
+
+/// A solution to both parts of a day's puzzle.
+pub trait Solution {
+    /// Solves the first part of the puzzle from the puzzle input.
+    fn part1(&self, input: &mut dyn BufRead) -> io::Result<Answer>;
+
+    /// Solves the second part of the puzzle from the puzzle input.
+    fn part2(&self, input: &mut dyn BufRead) -> io::Result<Answer>;
+}
+
+/// The answer to one part of a puzzle along with how long it took to find.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Timed {
+    /// The part of the puzzle that was solved.
+    pub part: u32,
+    /// The answer to that part.
+    pub answer: Answer,
+    /// How long it took to find the answer, not counting reading the input.
+    pub elapsed: Duration,
+    /// The most heap memory beyond what was already in use that was in use at once while finding
+    /// the answer, or `None` unless the allocations are being tracked by [`TrackingAllocator`].
+    ///
+    /// [`TrackingAllocator`]: crate::memory::TrackingAllocator
+    pub peak_memory: Option<usize>,
+}
+
+/// A single day's puzzle as registered by [`register_day!`].
+///
+/// [`register_day!`]: crate::register_day
+#[derive(Clone, Copy, Debug)]
+pub struct Day {
+    /// The year that the puzzle was released in.
+    pub year: u32,
+    /// The day of December that the puzzle was released on.
+    pub day: u32,
+    /// The title of the puzzle.
+    pub title: &'static str,
+    /// How to produce the answers to the puzzle.
+    pub runner: Runner,
+}
+
+impl Day {
+    /// The name of the file that the input for this day is read from.
+    pub fn input_file(&self) -> String {
+        input_file(self.year, self.day)
+    }
+
+    /// Runs both parts of this day's puzzle, printing the answers to stdout.
+    pub fn run(&self) -> io::Result<()> {
+        self.run_timed().map(|_| ())
+    }
+
+    /// Runs both parts of this day's puzzle like [`run()`](Self::run), and returns their answers
+    /// along with how long each took to find and how much memory each used. The time and memory
+    /// that a day which parses its input once takes to parse it are included in both parts. A panic
+    /// while solving the puzzle is returned as an [`Error::Panicked`].
+    pub fn run_timed(&self) -> io::Result<Vec<Timed>> {
+        let _span = tracing::info_span!("day", year = self.year, day = self.day).entered();
+        let mut answers = Vec::with_capacity(2);
+        match self.runner {
+            Runner::Parts { part1, part2 } => {
+                for (part_num, part) in [(1, part1), (2, part2)] {
+                    let mut input = input::open(self.input_file())?;
+                    let _span = tracing::info_span!("part", part = part_num).entered();
+                    let base = memory::reset_peak();
+                    let start = Instant::now();
+                    let answer = catch_panic(Some(part_num), || part(&mut input))?;
+                    let elapsed = start.elapsed();
+                    println!("Year {} Day {} Part {part_num}", self.year, self.day);
+                    println!("{answer}");
+                    answers.push(Timed {
+                        part: part_num,
+                        answer,
+                        elapsed,
+                        peak_memory: memory::peak_since(base),
+                    });
+                }
+            }
+            Runner::Parsed(parse) => {
+                let mut input = input::open(self.input_file())?;
+                let base = memory::reset_peak();
+                let start = Instant::now();
+                let solver = tracing::info_span!("parse")
+                    .in_scope(|| catch_panic(None, || parse(&mut input)))?;
+                let parse_elapsed = start.elapsed();
+                let parse_peak = memory::peak_since(base);
+                for part_num in [1, 2] {
+                    let _span = tracing::info_span!("part", part = part_num).entered();
+                    memory::reset_peak();
+                    let start = Instant::now();
+                    let answer = catch_panic(Some(part_num), || {
+                        if part_num == 1 {
+                            solver.part1()
+                        } else {
+                            solver.part2()
+                        }
+                    })?;
+                    let elapsed = parse_elapsed + start.elapsed();
+                    println!("Year {} Day {} Part {part_num}", self.year, self.day);
+                    println!("{answer}");
+                    answers.push(Timed {
+                        part: part_num,
+                        answer,
+                        elapsed,
+                        peak_memory: memory::peak_since(base).max(parse_peak),
+                    });
+                }
+            }
+        }
+        Ok(answers)
+    }
+}
+
+impl Solution for Day {
+    fn part1(&self, input: &mut dyn BufRead) -> io::Result<Answer> {
+        match self.runner {
+            Runner::Parts { part1, .. } => part1(input),
+            Runner::Parsed(parse) => parse(input)?.part1(),
+        }
+    }
+
+    fn part2(&self, input: &mut dyn BufRead) -> io::Result<Answer> {
+        match self.runner {
+            Runner::Parts { part2, .. } => part2(input),
+            Runner::Parsed(parse) => parse(input)?.part2(),
+        }
+    }
+}
+
+impl Day {
+    /// Parses `input` without solving either part of the puzzle. Only days which parse their input
+    /// once can be parsed separately.
+    pub fn parse(&self, input: &mut dyn BufRead) -> io::Result<Box<dyn Solver>> {
+        match self.runner {
+            Runner::Parsed(parse) => parse(input),
+            Runner::Parts { .. } => {
+                let msg = format!(
+                    "Year {} day {} doesn't parse its input separately from solving it",
+                    self.year, self.day
+                );
+                Err(io::Error::new(io::ErrorKind::Unsupported, msg))
+            }
+        }
+    }
+}
+
+/// Calls `f`, which solves part `part` of a puzzle, turning a panic into an [`Error::Panicked`] so
+/// that one broken day doesn't stop the rest from running.
+fn catch_panic<T>(part: Option<u32>, f: impl FnOnce() -> io::Result<T>) -> io::Result<T> {
+    panic::catch_unwind(AssertUnwindSafe(f))
+        .unwrap_or_else(|payload| Err(Error::panicked(part, payload.as_ref()).into()))
+}
+
+/// The name of the file that the input for day `day` of year `year` is read from. The file is found
+/// in [`input::input_dir()`].
+pub fn input_file(year: u32, day: u32) -> String {
+    format!("{year}_{day:02}.txt")
+}
+
+/// Every day that has been registered by any linked crate, in no particular order.
+#[linkme::distributed_slice]
+pub static DAYS: [Day];
+
+/// Finds the registered solution to the puzzle released on day `day` of year `year`.
+pub fn find(year: u32, day: u32) -> Option<&'static Day> {
+    DAYS.iter()
+        .find(|entry| entry.year == year && entry.day == day)
+}
+
+/// All registered days of year `year`, sorted by day.
+pub fn days_in(year: u32) -> Vec<&'static Day> {
+    let mut days = DAYS
+        .iter()
+        .filter(|entry| entry.year == year)
+        .collect::<Vec<_>>();
+    days.sort_unstable_by_key(|entry| entry.day);
+    days
+}
+
+/// Every year which has at least one registered day, in order.
+pub fn years() -> Vec<u32> {
+    let mut years = DAYS.iter().map(|entry| entry.year).collect::<Vec<_>>();
+    years.sort_unstable();
+    years.dedup();
+    years
+}
+
+/// Runs the registered solution to the puzzle released on day `day` of year `year`.
+pub fn run_day(year: u32, day: u32) -> io::Result<()> {
+    match find(year, day) {
+        Some(entry) => entry.run(),
+        None => {
+            let msg = format!("Invalid day: {day}");
+            Err(io::Error::new(io::ErrorKind::InvalidInput, msg))
+        }
+    }
+}
+
+/// Parses `input` as the puzzle input for day `day` of year `year` without solving the puzzle.
+pub fn parse(year: u32, day: u32, input: &mut dyn BufRead) -> io::Result<Box<dyn Solver>> {
+    match find(year, day) {
+        Some(entry) => entry.parse(input),
+        None => {
+            let msg = format!("No solution for year {year} day {day}");
+            Err(io::Error::new(io::ErrorKind::NotFound, msg))
+        }
+    }
+}
+
+/// Solves part `part` of the puzzle released on day `day` of year `year` using `input` as the
+/// puzzle input.
+pub fn solve(year: u32, day: u32, part: u32, input: &mut dyn BufRead) -> io::Result<Answer> {
+    let entry = find(year, day).ok_or_else(|| {
+        let msg = format!("No solution for year {year} day {day}");
+        io::Error::new(io::ErrorKind::NotFound, msg)
+    })?;
+    match part {
+        1 => entry.part1(input),
+        2 => entry.part2(input),
+        _ => {
+            let msg = format!("Invalid part: {part}");
+            Err(io::Error::new(io::ErrorKind::InvalidInput, msg))
+        }
+    }
+}
+
+/// Adds a day's solution to [`DAYS`] so that it can be found by [`find()`] without any central
+/// list of days needing to be updated.
+///
+/// The six-argument form registers a function which parses the puzzle input into a model and a
+/// function for each part which solves that part from a reference to the model, so that the input
+/// is only read and parsed once. The model must implement [`Debug`] so that it can be inspected.
+/// The five-argument form registers separate functions for each part, each of which takes the
+/// puzzle input directly.
+///
+/// Functions which take the puzzle input take it as a `&str`, as described by [`InputFn`]. The
+/// parts return a `Result` of anything that implements [`Display`], which is converted into an
+/// [`Answer`] by [`Answer::from_display()`].
+///
+/// Every function may fail with either an [`io::Error`] or an [`Error`], which is converted into
+/// an [`io::Error`] that still holds it.
+///
+/// [`Error`]: crate::error::Error
+///
+/// ```rust.ignore
+/// fn parse(input: &str) -> error::Result<Inventory> { ... }
+/// fn part1(inventory: &Inventory) -> error::Result<u32> { ... }
+/// fn part2(inventory: &Inventory) -> error::Result<u32> { ... }
+///
+/// aoc_util::register_day!(2022, 1, parse, part1, part2, "Calorie Counting");
+/// ```
+#[macro_export]
+macro_rules! register_day {
+    (
+        $year:literal,
+        $day:literal,
+        $parse:path,
+        $part1:path,
+        $part2:path,
+        $title:literal $(,)?
+    ) => {
+        #[$crate::registry::linkme::distributed_slice($crate::registry::DAYS)]
+        #[linkme(crate = $crate::registry::linkme)]
+        static REGISTERED_DAY: $crate::registry::Day = $crate::registry::Day {
+            year: $year,
+            day: $day,
+            title: $title,
+            runner: $crate::registry::Runner::Parsed(|input| {
+                let solver: ::std::boxed::Box<dyn $crate::registry::Solver> =
+                    ::std::boxed::Box::new($crate::registry::Parsed {
+                        model: $crate::registry::InputFn::call_with_reader(&$parse, input)?
+                            .map_err(::std::io::Error::from)?,
+                        part1: |model| {
+                            $part1(model)
+                                .map($crate::registry::Answer::from_display)
+                                .map_err(::std::io::Error::from)
+                        },
+                        part2: |model| {
+                            $part2(model)
+                                .map($crate::registry::Answer::from_display)
+                                .map_err(::std::io::Error::from)
+                        },
+                    });
+                ::std::result::Result::Ok(solver)
+            }),
+        };
+    };
+    ($year:literal, $day:literal, $part1:path, $part2:path, $title:literal $(,)?) => {
+        #[$crate::registry::linkme::distributed_slice($crate::registry::DAYS)]
+        #[linkme(crate = $crate::registry::linkme)]
+        static REGISTERED_DAY: $crate::registry::Day = $crate::registry::Day {
+            year: $year,
+            day: $day,
+            title: $title,
+            runner: $crate::registry::Runner::Parts {
+                part1: |input| {
+                    $crate::registry::InputFn::call_with_reader(&$part1, input)?
+                        .map($crate::registry::Answer::from_display)
+                        .map_err(::std::io::Error::from)
+                },
+                part2: |input| {
+                    $crate::registry::InputFn::call_with_reader(&$part2, input)?
+                        .map($crate::registry::Answer::from_display)
+                        .map_err(::std::io::Error::from)
+                },
+            },
+        };
+    };
+}
+
+/// Registers a day's solution like [`register_day!`] and adds a test which checks the answers that
+/// it gives for the example inputs from the puzzle description, which are read with
+/// [`samples::get()`] or, if the example is given a name, [`samples::get_named()`].
+///
+/// ```rust.ignore
+/// aoc_util::solution! {
+///     year: 2022,
+///     day: 9,
+///     title: "Rope Bridge",
+///     part1: part1,
+///     part2: part2,
+///     examples: [
+///         part1 => 13,
+///         part2 => 1,
+///         part2("large") => 36,
+///     ],
+/// }
+/// ```
+///
+/// A `parse` entry before `part1` registers a day which parses its input once, as with the
+/// six-argument form of [`register_day!`]. Attributes written before `examples`, such as
+/// `#[ignore]`, are applied to the generated test.
+///
+/// [`register_day!`]: crate::register_day
+/// [`samples::get()`]: crate::samples::get
+/// [`samples::get_named()`]: crate::samples::get_named
+#[macro_export]
+macro_rules! solution {
+    (
+        year: $year:literal,
+        day: $day:literal,
+        // Forwarded as a token tree because `register_day!` can't tell that a `literal` fragment
+        // isn't a path, which would make it mistake five arguments for six.
+        title: $title:tt,
+        parse: $parse:path,
+        part1: $part1:path,
+        part2: $part2:path
+        $(, $(#[$attr:meta])* examples: $examples:tt)?
+        $(,)?
+    ) => {
+        $crate::register_day!($year, $day, $parse, $part1, $part2, $title);
+        $($crate::solution!(
+            @examples [$(#[$attr])*] [$parse], $year, $day, $part1, $part2, $examples
+        );)?
+    };
+    (
+        year: $year:literal,
+        day: $day:literal,
+        title: $title:tt,
+        part1: $part1:path,
+        part2: $part2:path
+        $(, $(#[$attr:meta])* examples: $examples:tt)?
+        $(,)?
+    ) => {
+        $crate::register_day!($year, $day, $part1, $part2, $title);
+        $($crate::solution!(
+            @examples [$(#[$attr])*] [], $year, $day, $part1, $part2, $examples
+        );)?
+    };
+    (
+        @examples
+        [$(#[$attr:meta])*]
+        $parse:tt,
+        $year:literal,
+        $day:literal,
+        $part1:path,
+        $part2:path,
+        [$($part:ident $(($name:literal))? => $expected:expr),* $(,)?]
+    ) => {
+        #[cfg(test)]
+        #[test]
+        $(#[$attr])*
+        fn test_examples() {
+            $({
+                let input = $crate::solution!(@sample $year, $day $(, $name)?)
+                    .expect("Failed to read the example");
+                let actual = $crate::solution!(@solve $part, $parse, $part1, $part2, input);
+                assert_eq!(
+                    actual.expect("Failed to solve the example"),
+                    $expected,
+                    "Wrong answer to {} of the example {}",
+                    stringify!($part),
+                    stringify!($($name)?),
+                );
+            })*
+        }
+    };
+    (@sample $year:literal, $day:literal) => {
+        $crate::samples::get($year, $day)
+    };
+    (@sample $year:literal, $day:literal, $name:literal) => {
+        $crate::samples::get_named($year, $day, $name)
+    };
+    (@solve part1, $parse:tt, $part1:path, $part2:path, $input:ident) => {
+        $crate::solution!(@call $parse $part1, $input)
+    };
+    (@solve part2, $parse:tt, $part1:path, $part2:path, $input:ident) => {
+        $crate::solution!(@call $parse $part2, $input)
+    };
+    (@call [] $part:path, $input:ident) => {
+        $crate::registry::InputFn::call_with_str(&$part, &$input)
+    };
+    (@call [$parse:path] $part:path, $input:ident) => {
+        $part(
+            &$crate::registry::InputFn::call_with_str(&$parse, &$input)
+                .expect("Failed to parse the example"),
+        )
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn part1(input: &str) -> io::Result<usize> {
+        Ok(input.lines().count())
+    }
+
+    fn part2(input: &str) -> io::Result<String> {
+        Ok(input.chars().rev().collect())
+    }
+
+    crate::register_day!(1, 1, part1, part2, "Registry Test");
+
+    #[test]
+    fn test_find() {
+        let entry = find(1, 1).expect("Test day was not registered");
+        assert_eq!("Registry Test", entry.title);
+        match entry.runner {
+            Runner::Parts { part1, part2 } => {
+                assert_eq!(
+                    Answer::Number(2),
+                    part1(&mut "ab\ncd\n".as_bytes()).unwrap()
+                );
+                assert_eq!(Answer::from("dcba"), part2(&mut "abcd".as_bytes()).unwrap());
+            }
+            _ => panic!("Test day was registered without separate parts"),
+        }
+        assert!(find(1, 2).is_none());
+        assert_eq!(1, days_in(1).len());
+        assert_eq!(vec![1, 2], years());
+    }
+
+    #[test]
+    fn test_catch_panic() {
+        assert_eq!(3, catch_panic(Some(1), || Ok(3)).unwrap());
+        let e = catch_panic(Some(2), || -> io::Result<()> { panic!("Bad input") }).unwrap_err();
+        assert_eq!("Part 2 panicked: Bad input", e.to_string());
+    }
+
+    mod parsed {
+        use super::*;
+
+        #[derive(Debug)]
+        struct Numbers(Vec<u32>);
+
+        fn parse(input: &str) -> io::Result<Numbers> {
+            input
+                .lines()
+                .map(|line| {
+                    line.parse()
+                        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+                })
+                .collect::<io::Result<_>>()
+                .map(Numbers)
+        }
+
+        fn part1(numbers: &Numbers) -> io::Result<u32> {
+            Ok(numbers.0.iter().sum())
+        }
+
+        fn part2(numbers: &Numbers) -> io::Result<u32> {
+            Ok(numbers.0.iter().product())
+        }
+
+        crate::register_day!(2, 1, parse, part1, part2, "Parsed Registry Test");
+    }
+
+    #[test]
+    fn test_solve_parsed() {
+        let input = "2\n3\n4\n";
+        assert_eq!(
+            Answer::Number(9),
+            solve(2, 1, 1, &mut input.as_bytes()).unwrap()
+        );
+        assert_eq!(
+            Answer::Number(24),
+            solve(2, 1, 2, &mut input.as_bytes()).unwrap()
+        );
+        assert!(solve(2, 1, 1, &mut "two\n".as_bytes()).is_err());
+    }
+
+    #[test]
+    fn test_parse() {
+        let solver = parse(2, 1, &mut "2\n3\n".as_bytes()).unwrap();
+        assert_eq!("Numbers([2, 3])", format!("{:?}", solver.model()));
+        let e = parse(1, 1, &mut "".as_bytes()).err().unwrap();
+        assert_eq!(io::ErrorKind::Unsupported, e.kind());
+    }
+
+    #[test]
+    fn test_solve() {
+        let answer = solve(1, 1, 1, &mut "ab\ncd\n".as_bytes()).unwrap();
+        assert_eq!("2", answer.to_string());
+        let answer = solve(1, 1, 2, &mut "abcd".as_bytes()).unwrap();
+        assert_eq!("dcba", answer.to_string());
+        assert!(solve(1, 1, 3, &mut "".as_bytes()).is_err());
+        assert!(solve(1, 2, 1, &mut "".as_bytes()).is_err());
+    }
+
+    #[test]
+    fn test_answer_from_display() {
+        assert_eq!(Answer::Number(-12), Answer::from_display(-12));
+        assert_eq!(Answer::Number(34), Answer::from_display("34"));
+        assert_eq!(Answer::from("034"), Answer::from_display("034"));
+        assert_eq!(Answer::from("EHZ"), Answer::from_display("EHZ"));
+    }
+}
@@ -0,0 +1,79 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::input;
+
+/// The directory in the workspace root that holds the example inputs from the puzzle descriptions.
+/// Each example is named like the puzzle input that it stands in for, such as `2022_04.txt`, and
+/// any further examples for the same day add a suffix, such as `2022_09_large.txt`.
+pub const SAMPLES_DIR: &str = "test_data";
+
+/// Finds the directory that holds the example inputs.
+pub fn dir() -> io::Result<PathBuf> {
+    match input::workspace_root() {
+        Some(root) => Ok(root.join(SAMPLES_DIR)),
+        None => {
+            let msg = format!("Couldn't find the workspace which contains {SAMPLES_DIR}");
+            Err(io::Error::new(io::ErrorKind::NotFound, msg))
+        }
+    }
+}
+
+fn read(name: String) -> io::Result<String> {
+    read_in(&dir()?, name)
+}
+
+fn read_in(dir: &Path, name: String) -> io::Result<String> {
+    let path = dir.join(name);
+    fs::read_to_string(&path).map_err(|e| {
+        let msg = format!("Couldn't read sample {}: {e}", path.display());
+        io::Error::new(e.kind(), msg)
+    })
+}
+
+/// Reads the example input given in the description of the puzzle released on day `day` of year
+/// `year`.
+pub fn get(year: u32, day: u32) -> io::Result<String> {
+    read(file_name(year, day, None))
+}
+
+/// Reads the example input named `name` for the puzzle released on day `day` of year `year`, for
+/// days whose description gives more than one example.
+pub fn get_named(year: u32, day: u32, name: &str) -> io::Result<String> {
+    read(file_name(year, day, Some(name)))
+}
+
+/// The name of the file that holds the example input named `name`, or the only example input if
+/// there's no name, for the puzzle released on day `day` of year `year`.
+fn file_name(year: u32, day: u32, name: Option<&str>) -> String {
+    match name {
+        Some(name) => format!("{year}_{day:02}_{name}.txt"),
+        None => format!("{year}_{day:02}.txt"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_in() {
+        let dir = std::env::temp_dir().join("aoc_util_test_read_in");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("2022_02.txt"), "A Y\nB X\nC Z\n").unwrap();
+        fs::write(dir.join("2022_09_large.txt"), "R 5\nU 8\n").unwrap();
+        assert_eq!(
+            "A Y\nB X\nC Z\n",
+            read_in(&dir, file_name(2022, 2, None)).unwrap()
+        );
+        assert_eq!(
+            "R 5\nU 8\n",
+            read_in(&dir, file_name(2022, 9, Some("large"))).unwrap()
+        );
+        let e = read_in(&dir, file_name(1990, 1, None)).unwrap_err();
+        assert_eq!(io::ErrorKind::NotFound, e.kind());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,95 @@
+use std::cmp::Reverse;
+
+/// The best state that a beam search from `start` finds, where `successors` gives the states that
+/// can be reached from a state in one step and `score` gives how good a state is, higher being
+/// better.
+///
+/// The search goes one step at a time and only keeps the `width` best states of each step, so it
+/// can miss the best state when the way to it starts out looking worse than `width` others. A
+/// wider beam misses less but takes longer. States which are tied for the same score are kept or
+/// returned smallest first, so that the answer doesn't depend on the order that `successors` gives
+/// them in.
+///
+/// The search stops once no state of a step has any successors, so every path through the states
+/// must come to an end, such as by keeping track of the time that is left in each state.
+///
+/// # Panics
+/// Panics if `width` is 0.
+pub fn beam_search<S, V, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut score: impl FnMut(&S) -> V,
+    width: usize,
+) -> S
+where
+    S: Clone + Ord,
+    V: Ord,
+    I: IntoIterator<Item = S>,
+{
+    assert!(width > 0, "A beam search needs a width of at least 1");
+    let mut best = (score(&start), start.clone());
+    let mut beam = vec![start];
+    while !beam.is_empty() {
+        let mut next = beam.iter().flat_map(&mut successors).collect::<Vec<_>>();
+        next.sort_unstable();
+        next.dedup();
+        let mut next = next
+            .into_iter()
+            .map(|state| (Reverse(score(&state)), state))
+            .collect::<Vec<_>>();
+        // Sorting the whole step keeps ties in order of the states themselves.
+        next.sort_unstable();
+        next.truncate(width);
+        beam.clear();
+        for (Reverse(value), state) in next {
+            if value > best.0 {
+                best = (value, state.clone());
+            }
+            beam.push(state);
+        }
+    }
+    best.1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The score of each state of a tree where the best leaf is below the worse of the first two
+    /// branches.
+    fn score(state: &&str) -> u32 {
+        match *state {
+            "a" => 5,
+            "b" => 1,
+            "aa" => 6,
+            "ba" => 10,
+            "bb" => 10,
+            _ => 0,
+        }
+    }
+
+    fn successors(state: &&'static str) -> Vec<&'static str> {
+        match *state {
+            "" => vec!["b", "a"],
+            "a" => vec!["aa"],
+            "b" => vec!["bb", "ba"],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn test_beam_search() {
+        assert_eq!("aa", beam_search("", successors, score, 1));
+        // Both leaves below "b" are as good as each other, so the smaller one is chosen.
+        assert_eq!("ba", beam_search("", successors, score, 2));
+        assert_eq!("ba", beam_search("", successors, score, 100));
+        assert_eq!("aa", beam_search("a", successors, score, 1));
+    }
+
+    #[test]
+    fn test_start_is_best() {
+        // Every step loses one point, so stopping straight away is best.
+        let successors = |&steps: &u32| (steps < 5).then_some(steps + 1);
+        assert_eq!(0, beam_search(0, successors, |&steps| Reverse(steps), 3));
+    }
+}
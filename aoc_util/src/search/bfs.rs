@@ -0,0 +1,280 @@
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    hash::Hash,
+};
+
+/// The fewest moves that it takes to get from `start` to a state for which `is_goal` returns
+/// `true`, where `neighbors` gives the states that can be reached from a state in one move, along
+/// with the states along the way, starting with `start` and ending with the goal. Returns `None`
+/// if no goal can be reached.
+pub fn shortest_path<T, I>(
+    start: T,
+    mut neighbors: impl FnMut(&T) -> I,
+    mut is_goal: impl FnMut(&T) -> bool,
+) -> Option<(usize, Vec<T>)>
+where
+    T: Clone + Eq + Hash,
+    I: IntoIterator<Item = T>,
+{
+    let mut parents: HashMap<T, Option<T>> = HashMap::from([(start.clone(), None)]);
+    let mut queue = VecDeque::from([start]);
+    while let Some(state) = queue.pop_front() {
+        if is_goal(&state) {
+            let mut path = vec![state];
+            while let Some(Some(parent)) = parents.get(&path[path.len() - 1]) {
+                path.push(parent.clone());
+            }
+            path.reverse();
+            return Some((path.len() - 1, path));
+        }
+        for neighbor in neighbors(&state) {
+            if !parents.contains_key(&neighbor) {
+                parents.insert(neighbor.clone(), Some(state.clone()));
+                queue.push_back(neighbor);
+            }
+        }
+    }
+    None
+}
+
+/// The fewest moves that it takes to get from `start` to every state that can be reached from it,
+/// where `neighbors` gives the states that can be reached from a state in one move.
+pub fn distances<T, I>(start: T, mut neighbors: impl FnMut(&T) -> I) -> HashMap<T, usize>
+where
+    T: Clone + Eq + Hash,
+    I: IntoIterator<Item = T>,
+{
+    let mut distances = HashMap::from([(start.clone(), 0)]);
+    let mut queue = VecDeque::from([(start, 0)]);
+    while let Some((state, distance)) = queue.pop_front() {
+        for neighbor in neighbors(&state) {
+            if !distances.contains_key(&neighbor) {
+                distances.insert(neighbor.clone(), distance + 1);
+                queue.push_back((neighbor, distance + 1));
+            }
+        }
+    }
+    distances
+}
+
+/// Every state that can be reached from any of `starts`, including the starts themselves, where
+/// `neighbors` gives the states that can be reached from a state in one move. This is a flood fill
+/// when the states are the tiles of a grid.
+pub fn reachable<T, I>(
+    starts: impl IntoIterator<Item = T>,
+    mut neighbors: impl FnMut(&T) -> I,
+) -> HashSet<T>
+where
+    T: Clone + Eq + Hash,
+    I: IntoIterator<Item = T>,
+{
+    let mut reached = HashSet::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if reached.insert(start.clone()) {
+            queue.push_back(start);
+        }
+    }
+    while let Some(state) = queue.pop_front() {
+        for neighbor in neighbors(&state) {
+            if reached.insert(neighbor.clone()) {
+                queue.push_back(neighbor);
+            }
+        }
+    }
+    reached
+}
+
+/// Like [`shortest_path()`] except that each move costs either 0 or 1, such as when stepping onto
+/// an open tile is free but breaking through a wall isn't, and `neighbors` gives the cost of each
+/// move along with the state that it reaches. Returns the least total cost of reaching a goal along
+/// with the states along the way. This is faster than Dijkstra's algorithm since the states are
+/// kept in a deque instead of a heap, with the states reached by free moves at the front.
+///
+/// # Panics
+/// Panics if a move costs more than 1.
+pub fn zero_one_shortest_path<T, I>(
+    start: T,
+    mut neighbors: impl FnMut(&T) -> I,
+    mut is_goal: impl FnMut(&T) -> bool,
+) -> Option<(usize, Vec<T>)>
+where
+    T: Clone + Eq + Hash,
+    I: IntoIterator<Item = (usize, T)>,
+{
+    let mut costs = HashMap::from([(start.clone(), 0)]);
+    let mut parents: HashMap<T, T> = HashMap::new();
+    let mut queue = VecDeque::from([(0, start)]);
+    while let Some((cost, state)) = queue.pop_front() {
+        if costs.get(&state).is_some_and(|&best| best < cost) {
+            // The state was reached more cheaply after this entry was queued.
+            continue;
+        }
+        if is_goal(&state) {
+            let mut path = vec![state];
+            while let Some(parent) = parents.get(&path[path.len() - 1]) {
+                path.push(parent.clone());
+            }
+            path.reverse();
+            return Some((cost, path));
+        }
+        for (step, neighbor) in neighbors(&state) {
+            assert!(step <= 1, "A move in a 0-1 BFS can't cost {step}");
+            let neighbor_cost = cost + step;
+            if costs
+                .get(&neighbor)
+                .is_some_and(|&best| best <= neighbor_cost)
+            {
+                continue;
+            }
+            costs.insert(neighbor.clone(), neighbor_cost);
+            parents.insert(neighbor.clone(), state.clone());
+            if step == 0 {
+                queue.push_front((neighbor_cost, neighbor));
+            } else {
+                queue.push_back((neighbor_cost, neighbor));
+            }
+        }
+    }
+    None
+}
+
+/// Like [`distances()`] except that each move costs either 0 or 1 and `neighbors` gives the cost
+/// of each move along with the state that it reaches, as in [`zero_one_shortest_path()`].
+///
+/// # Panics
+/// Panics if a move costs more than 1.
+pub fn zero_one_distances<T, I>(start: T, mut neighbors: impl FnMut(&T) -> I) -> HashMap<T, usize>
+where
+    T: Clone + Eq + Hash,
+    I: IntoIterator<Item = (usize, T)>,
+{
+    let mut costs = HashMap::from([(start.clone(), 0)]);
+    let mut queue = VecDeque::from([(0, start)]);
+    while let Some((cost, state)) = queue.pop_front() {
+        if costs[&state] < cost {
+            continue;
+        }
+        for (step, neighbor) in neighbors(&state) {
+            assert!(step <= 1, "A move in a 0-1 BFS can't cost {step}");
+            let neighbor_cost = cost + step;
+            if costs
+                .get(&neighbor)
+                .is_some_and(|&best| best <= neighbor_cost)
+            {
+                continue;
+            }
+            costs.insert(neighbor.clone(), neighbor_cost);
+            if step == 0 {
+                queue.push_front((neighbor_cost, neighbor));
+            } else {
+                queue.push_back((neighbor_cost, neighbor));
+            }
+        }
+    }
+    costs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GRID: &str = "\
+        ..#..\n\
+        .##.#\n\
+        ...#.\n\
+        #....\n";
+
+    /// The open tiles next to `(x, y)` in `GRID`.
+    fn open_neighbors(&(x, y): &(usize, usize)) -> Vec<(usize, usize)> {
+        let rows = GRID.lines().collect::<Vec<_>>();
+        [
+            (x.wrapping_sub(1), y),
+            (x + 1, y),
+            (x, y.wrapping_sub(1)),
+            (x, y + 1),
+        ]
+        .into_iter()
+        .filter(|&(x, y)| rows.get(y).and_then(|row| row.as_bytes().get(x)) == Some(&b'.'))
+        .collect()
+    }
+
+    #[test]
+    fn test_shortest_path() {
+        let (distance, path) = shortest_path((0, 0), open_neighbors, |&tile| tile == (4, 3))
+            .expect("The corners are connected");
+        assert_eq!(7, distance);
+        assert_eq!(8, path.len());
+        assert_eq!(Some(&(0, 0)), path.first());
+        assert_eq!(Some(&(4, 3)), path.last());
+        assert_eq!(
+            None,
+            shortest_path((0, 0), open_neighbors, |&tile| tile == (3, 0))
+        );
+    }
+
+    #[test]
+    fn test_distances() {
+        let distances = distances((0, 0), open_neighbors);
+        assert_eq!(Some(&0), distances.get(&(0, 0)));
+        assert_eq!(Some(&4), distances.get(&(1, 3)));
+        assert_eq!(Some(&8), distances.get(&(4, 2)));
+        assert_eq!(None, distances.get(&(3, 1)));
+    }
+
+    #[test]
+    fn test_reachable() {
+        assert_eq!(
+            HashSet::from([(3, 0), (4, 0), (3, 1)]),
+            reachable([(4, 0)], open_neighbors)
+        );
+        assert_eq!(14, reachable([(0, 0), (3, 0)], open_neighbors).len());
+    }
+
+    /// The tiles next to `(x, y)` in `GRID` along with the number of walls that have to be broken
+    /// to step onto them.
+    fn walls_to_break(&(x, y): &(usize, usize)) -> Vec<(usize, (usize, usize))> {
+        let rows = GRID.lines().collect::<Vec<_>>();
+        [
+            (x.wrapping_sub(1), y),
+            (x + 1, y),
+            (x, y.wrapping_sub(1)),
+            (x, y + 1),
+        ]
+        .into_iter()
+        .filter_map(|(x, y)| {
+            let tile = rows.get(y)?.as_bytes().get(x)?;
+            Some((usize::from(*tile == b'#'), (x, y)))
+        })
+        .collect()
+    }
+
+    #[test]
+    fn test_zero_one_shortest_path() {
+        let (cost, path) = zero_one_shortest_path((0, 0), walls_to_break, |&tile| tile == (4, 3))
+            .expect("Every tile can be reached by breaking walls");
+        assert_eq!(0, cost);
+        assert_eq!(Some(&(4, 3)), path.last());
+        // The corner which is walled off can only be reached by breaking one wall.
+        let (cost, path) = zero_one_shortest_path((0, 0), walls_to_break, |&tile| tile == (3, 0))
+            .expect("Every tile can be reached by breaking walls");
+        assert_eq!(1, cost);
+        assert_eq!(Some(&(0, 0)), path.first());
+        assert_eq!(
+            1,
+            path.iter()
+                .filter(|&&(x, y)| GRID.lines().nth(y).unwrap().as_bytes()[x] == b'#')
+                .count()
+        );
+    }
+
+    #[test]
+    fn test_zero_one_distances() {
+        let distances = zero_one_distances((0, 0), walls_to_break);
+        assert_eq!(20, distances.len());
+        assert_eq!(Some(&0), distances.get(&(4, 2)));
+        assert_eq!(Some(&1), distances.get(&(4, 0)));
+        assert_eq!(Some(&1), distances.get(&(4, 1)));
+        assert_eq!(Some(&1), distances.get(&(2, 1)));
+    }
+}
@@ -0,0 +1,203 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap},
+    ops::Add,
+};
+
+/// The steps that can be taken from a cell to the cells next to it, as `(dx, dy)`, in the order
+/// right, down, left, up so that the opposite of step `i` is step `(i + 2) % 4`.
+const STEPS: [(isize, isize); 4] = [(1, 0), (0, 1), (-1, 0), (0, -1)];
+
+/// How a path across a grid may move, apart from which cells it may enter. A path never turns
+/// straight back the way it came.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct GridMoves {
+    /// The fewest steps that a path must take in one direction before it may turn or end.
+    pub min_straight: usize,
+    /// The most steps that a path may take in one direction before it has to turn.
+    pub max_straight: usize,
+}
+
+impl GridMoves {
+    /// Moves which may turn after any step.
+    pub const ANY: Self = Self {
+        min_straight: 1,
+        max_straight: usize::MAX,
+    };
+
+    /// Moves which have to go at least `min` and at most `max` steps in one direction at a time,
+    /// such as the crucibles of 2023 day 17.
+    pub const fn straight(min: usize, max: usize) -> Self {
+        Self {
+            min_straight: min,
+            max_straight: max,
+        }
+    }
+}
+
+impl Default for GridMoves {
+    fn default() -> Self {
+        Self::ANY
+    }
+}
+
+/// Where a path across a grid is, which way it's heading, and how many steps it has taken that
+/// way.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+struct Heading {
+    cell: (usize, usize),
+    /// The index into [`STEPS`] of the last step, or `None` before the first step.
+    step: Option<usize>,
+    straight: usize,
+}
+
+/// The cheapest path across `grid` from the cell `start` to the cell `goal`, where cells are given
+/// as `(x, y)` so that `grid[y][x]` is the cell at `(x, y)`. `cost` gives the cost of entering a
+/// cell, or `None` if the cell can't be entered, such as when it's a wall, and `moves` restricts
+/// how far the path may go in a straight line. The path is returned with its cost and the cells
+/// along it, starting with `start` and ending with `goal`. Returns `None` if `goal` can't be
+/// reached.
+///
+/// Every cost must be at least `D::default()`.
+pub fn grid_shortest_path<T, D>(
+    grid: &[Vec<T>],
+    start: (usize, usize),
+    goal: (usize, usize),
+    mut cost: impl FnMut(&T) -> Option<D>,
+    moves: GridMoves,
+) -> Option<(D, Vec<(usize, usize)>)>
+where
+    D: Add<Output = D> + Clone + Default + Ord,
+{
+    let initial = Heading {
+        cell: start,
+        step: None,
+        straight: 0,
+    };
+    // The cheapest cost that has been found to each heading and the heading that it came from.
+    let mut best = HashMap::from([(initial, (D::default(), None))]);
+    let mut queue = BinaryHeap::from([Reverse((D::default(), initial))]);
+    while let Some(Reverse((distance, heading))) = queue.pop() {
+        if best[&heading].0 < distance {
+            continue;
+        }
+        if heading.cell == goal
+            && (heading.step.is_none() || heading.straight >= moves.min_straight)
+        {
+            let mut path = vec![];
+            let mut current = Some(heading);
+            while let Some(heading) = current {
+                path.push(heading.cell);
+                current = best[&heading].1;
+            }
+            path.reverse();
+            return Some((distance, path));
+        }
+        for (step, (dx, dy)) in STEPS.into_iter().enumerate() {
+            let straight = match heading.step {
+                None => 1,
+                Some(last) if last == step => heading.straight + 1,
+                Some(last) if (last + 2) % 4 == step => continue,
+                Some(_) if heading.straight < moves.min_straight => continue,
+                Some(_) => 1,
+            };
+            if straight > moves.max_straight {
+                continue;
+            }
+            let (x, y) = heading.cell;
+            let (Some(x), Some(y)) = (x.checked_add_signed(dx), y.checked_add_signed(dy)) else {
+                continue;
+            };
+            let Some(step_cost) = grid.get(y).and_then(|row| row.get(x)).and_then(&mut cost) else {
+                continue;
+            };
+            let next = Heading {
+                cell: (x, y),
+                step: Some(step),
+                straight,
+            };
+            let next_distance = distance.clone() + step_cost;
+            if best
+                .get(&next)
+                .is_some_and(|(known, _)| known <= &next_distance)
+            {
+                continue;
+            }
+            best.insert(next, (next_distance.clone(), Some(heading)));
+            queue.push(Reverse((next_distance, next)));
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(grid: &str) -> Vec<Vec<u32>> {
+        grid.lines()
+            .map(|line| line.chars().map(|c| c.to_digit(10).unwrap()).collect())
+            .collect()
+    }
+
+    /// The heat loss of entering each block of the example city from 2023 day 17.
+    const CITY: &str = "\
+        2413432311323\n\
+        3215453535623\n\
+        3255245654254\n\
+        3446585845452\n\
+        4546657867536\n\
+        1438598798454\n\
+        4457876987766\n\
+        3637877979653\n\
+        4654967986887\n\
+        4564679986453\n\
+        1224686865563\n\
+        2546548887735\n\
+        4322674655533\n";
+
+    #[test]
+    fn test_crucibles() {
+        let city = parse(CITY);
+        let corner = (12, 12);
+        let heat_loss = |&loss: &u32| Some(loss);
+        let (loss, path) =
+            grid_shortest_path(&city, (0, 0), corner, heat_loss, GridMoves::straight(1, 3))
+                .unwrap();
+        assert_eq!(102, loss);
+        assert_eq!((0, 0), path[0]);
+        assert_eq!(corner, path[path.len() - 1]);
+        assert_eq!(
+            loss,
+            path[1..].iter().map(|&(x, y)| city[y][x]).sum::<u32>()
+        );
+        let ultra = GridMoves::straight(4, 10);
+        assert_eq!(
+            Some(94),
+            grid_shortest_path(&city, (0, 0), corner, heat_loss, ultra).map(|(loss, _)| loss)
+        );
+        let city = parse("111111111111\n999999999991\n999999999991\n999999999991\n999999999991\n");
+        assert_eq!(
+            Some(71),
+            grid_shortest_path(&city, (0, 0), (11, 4), heat_loss, ultra).map(|(loss, _)| loss)
+        );
+    }
+
+    #[test]
+    fn test_walls() {
+        let maze = ["S..#", ".#.#", ".#..", "...#"].map(|line| line.chars().collect::<Vec<_>>());
+        let open = |&tile: &char| (tile != '#').then_some(1);
+        assert_eq!(
+            Some((5, vec![(0, 0), (1, 0), (2, 0), (2, 1), (2, 2), (3, 2)])),
+            grid_shortest_path(&maze, (0, 0), (3, 2), open, GridMoves::ANY)
+        );
+        assert_eq!(
+            None,
+            grid_shortest_path(&maze, (0, 0), (3, 0), open, GridMoves::ANY)
+        );
+        assert_eq!(
+            Some((0, vec![(0, 0)])),
+            grid_shortest_path(&maze, (0, 0), (0, 0), open, GridMoves::ANY)
+        );
+    }
+}
@@ -0,0 +1,199 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet},
+    hash::Hash,
+    ops::Add,
+};
+
+/// The `k` shortest paths from `start` to a state for which `is_goal` returns `true`, shortest
+/// first, where `neighbors` gives the states that can be reached from a state in one move along
+/// with the cost of each move. Each path is returned with its distance and the states along it,
+/// starting with `start` and ending with the goal. If fewer than `k` paths reach a goal, every one
+/// of them is returned.
+///
+/// This is Dijkstra's algorithm except that each state may be expanded up to `k` times instead of
+/// once, so a path may go through a state more than once. Paths stop at the first goal that they
+/// reach. Every cost must be at least `D::default()`.
+pub fn k_shortest_paths<S, D, I>(
+    start: S,
+    mut neighbors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+    k: usize,
+) -> Vec<(D, Vec<S>)>
+where
+    S: Clone + Eq + Hash,
+    D: Add<Output = D> + Clone + Default + Ord,
+    I: IntoIterator<Item = (D, S)>,
+{
+    // Every path that has been queued, as its last state and the path that it extends.
+    let mut steps = vec![(start, None)];
+    let mut queue = BinaryHeap::from([Reverse((D::default(), 0))]);
+    let mut expansions = HashMap::<S, usize>::new();
+    let mut paths = vec![];
+    while let Some(Reverse((distance, step))) = queue.pop() {
+        if paths.len() == k {
+            break;
+        }
+        let state = steps[step].0.clone();
+        let expanded = expansions.entry(state.clone()).or_default();
+        // The `k` shortest paths through a state can only extend its `k` shortest paths.
+        if *expanded == k {
+            continue;
+        }
+        *expanded += 1;
+        if is_goal(&state) {
+            let mut path = vec![];
+            let mut current = Some(step);
+            while let Some(step) = current {
+                path.push(steps[step].0.clone());
+                current = steps[step].1;
+            }
+            path.reverse();
+            paths.push((distance, path));
+            continue;
+        }
+        for (cost, neighbor) in neighbors(&state) {
+            steps.push((neighbor, Some(step)));
+            queue.push(Reverse((distance.clone() + cost, steps.len() - 1)));
+        }
+    }
+    paths
+}
+
+/// The distance of the shortest paths from `start` to a goal and how many different paths have
+/// that distance, where `neighbors` and `is_goal` are as in [`k_shortest_paths()`]. Paths which
+/// end at different goals are counted separately. Returns `None` if no goal can be reached.
+///
+/// Every cost must be greater than `D::default()`, since otherwise there could be infinitely
+/// many shortest paths.
+pub fn count_shortest_paths<S, D, I>(
+    start: S,
+    mut neighbors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<(D, u64)>
+where
+    S: Clone + Eq + Hash + Ord,
+    D: Add<Output = D> + Clone + Default + Ord,
+    I: IntoIterator<Item = (D, S)>,
+{
+    // The shortest distance to each state which has been reached and the number of paths which
+    // have that distance.
+    let mut best = HashMap::from([(start.clone(), (D::default(), 1_u64))]);
+    let mut expanded = HashSet::new();
+    let mut queue = BinaryHeap::from([Reverse((D::default(), start))]);
+    let mut goals = None;
+    while let Some(Reverse((distance, state))) = queue.pop() {
+        if goals
+            .as_ref()
+            .is_some_and(|(goal_distance, _)| &distance > goal_distance)
+        {
+            break;
+        }
+        if !expanded.insert(state.clone()) {
+            continue;
+        }
+        let count = best[&state].1;
+        if is_goal(&state) {
+            let (_, total) = goals.get_or_insert((distance, 0));
+            *total += count;
+            continue;
+        }
+        for (cost, neighbor) in neighbors(&state) {
+            let neighbor_distance = distance.clone() + cost;
+            match best.get_mut(&neighbor) {
+                Some((known, paths)) if *known == neighbor_distance => *paths += count,
+                Some((known, _)) if *known < neighbor_distance => {}
+                _ => {
+                    best.insert(neighbor.clone(), (neighbor_distance.clone(), count));
+                    queue.push(Reverse((neighbor_distance, neighbor)));
+                }
+            }
+        }
+    }
+    goals
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A diamond from `'s'` to `'t'`, where going through `'a'` costs 2 and going through `'b'`
+    /// or `'c'` costs 3.
+    fn diamond(state: &char) -> Vec<(u32, char)> {
+        match state {
+            's' => vec![(1, 'a'), (1, 'b'), (2, 'c')],
+            'a' => vec![(1, 't')],
+            'b' => vec![(2, 't')],
+            'c' => vec![(1, 't')],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn test_k_shortest_paths() {
+        let paths = k_shortest_paths('s', diamond, |&state| state == 't', 2);
+        assert_eq!(2, paths.len());
+        assert_eq!((2, vec!['s', 'a', 't']), paths[0]);
+        assert_eq!(3, paths[1].0);
+        let paths = k_shortest_paths('s', diamond, |&state| state == 't', 5);
+        assert_eq!(
+            vec![2, 3, 3],
+            paths
+                .iter()
+                .map(|(distance, _)| *distance)
+                .collect::<Vec<_>>()
+        );
+        assert!(k_shortest_paths('a', diamond, |&state| state == 's', 1).is_empty());
+    }
+
+    #[test]
+    fn test_k_shortest_paths_with_cycle() {
+        // Going around the loop between 0 and 1 gives arbitrarily long paths.
+        let neighbors = |&state: &u32| match state {
+            0 => vec![(1, 1)],
+            1 => vec![(1, 0), (5, 2)],
+            _ => vec![],
+        };
+        let paths = k_shortest_paths(0, neighbors, |&state| state == 2, 3);
+        assert_eq!(
+            vec![
+                (6, vec![0, 1, 2]),
+                (8, vec![0, 1, 0, 1, 2]),
+                (10, vec![0, 1, 0, 1, 0, 1, 2])
+            ],
+            paths
+        );
+    }
+
+    #[test]
+    fn test_count_shortest_paths() {
+        assert_eq!(
+            Some((2, 1)),
+            count_shortest_paths('s', diamond, |&state| state == 't')
+        );
+        assert_eq!(
+            Some((3, 2)),
+            count_shortest_paths(
+                's',
+                |state| diamond(state).into_iter().filter(|&(_, next)| next != 'a'),
+                |&state| state == 't'
+            )
+        );
+        assert_eq!(
+            None,
+            count_shortest_paths('t', diamond, |&state| state == 's')
+        );
+        // Every path right and down across a 3x3 grid of moves is as short as every other.
+        let grid = |&(x, y): &(u32, u32)| {
+            [(x + 1, y), (x, y + 1)]
+                .into_iter()
+                .filter(|&(x, y)| x <= 3 && y <= 3)
+                .map(|next| (1, next))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            Some((6, 20)),
+            count_shortest_paths((0, 0), grid, |&state| state == (3, 3))
+        );
+    }
+}
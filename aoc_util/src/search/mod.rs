@@ -0,0 +1,16 @@
+/// Beam searches for the best state of an optimization puzzle whose states are too many to search
+/// exactly.
+pub mod beam;
+
+/// Breadth-first searches for when every move costs the same, such as stepping between the tiles
+/// of a grid, or costs either nothing or one.
+pub mod bfs;
+
+/// Shortest paths across grids of cells with different costs, where a path may have to turn or go
+/// straight after a number of steps.
+pub mod grid;
+pub use grid::{grid_shortest_path, GridMoves};
+
+/// Searches for more than just one shortest path when moves have different costs, such as the
+/// second best path or how many paths are tied for the best.
+pub mod k_shortest;
@@ -0,0 +1,135 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    fs::File,
+    io::{self, BufRead, LineWriter, Write},
+    path::Path,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex,
+    },
+};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static SINK: Mutex<Option<Box<dyn Write + Send>>> = Mutex::new(None);
+
+/// Starts recording events to `writer`, replacing any previous destination.
+pub fn record_to(writer: impl Write + Send + 'static) {
+    *SINK.lock().unwrap() = Some(Box::new(writer));
+    ENABLED.store(true, Ordering::Release);
+}
+
+/// Starts recording events to the file at `path`, replacing any previous destination. Each event
+/// is written as soon as it's recorded so that the trace is complete even if the solver panics.
+pub fn record_to_file(path: impl AsRef<Path>) -> io::Result<()> {
+    record_to(LineWriter::new(File::create(path)?));
+    Ok(())
+}
+
+/// Stops recording events and flushes every event that has been recorded.
+pub fn finish() -> io::Result<()> {
+    ENABLED.store(false, Ordering::Release);
+    match SINK.lock().unwrap().take() {
+        Some(mut writer) => writer.flush(),
+        None => Ok(()),
+    }
+}
+
+/// Whether events are currently being recorded. Solvers can check this before doing any work
+/// which is only needed to describe an event.
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Acquire)
+}
+
+/// Records an event of kind `kind`, such as `expand` or `choose`, described by `detail`. Does
+/// nothing unless events are being recorded, so `detail` should usually be built with
+/// `format_args!` to avoid formatting it when it won't be used.
+///
+/// Events are written one per line so that two recordings can be compared with [`compare()`].
+pub fn event(kind: &str, detail: impl Display) {
+    if !is_enabled() {
+        return;
+    }
+    if let Some(writer) = SINK.lock().unwrap().as_mut() {
+        let detail = detail.to_string().replace('\n', "\\n");
+        // A trace is a debugging aid, so failing to write one shouldn't stop the solver.
+        let _ = writeln!(writer, "{kind}\t{detail}");
+    }
+}
+
+/// The first point at which two traces differ.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Divergence {
+    /// The 1-based index of the first event that differs.
+    pub index: usize,
+    /// The event from the first trace, or `None` if the first trace ended first.
+    pub left: Option<String>,
+    /// The event from the second trace, or `None` if the second trace ended first.
+    pub right: Option<String>,
+}
+
+impl Display for Divergence {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let end = "<end of trace>";
+        writeln!(f, "Traces diverge at event {}", self.index)?;
+        writeln!(f, "< {}", self.left.as_deref().unwrap_or(end))?;
+        write!(f, "> {}", self.right.as_deref().unwrap_or(end))
+    }
+}
+
+/// Finds the first event at which the traces `left` and `right` differ, if any.
+pub fn compare(left: impl BufRead, right: impl BufRead) -> io::Result<Option<Divergence>> {
+    let mut left = left.lines();
+    let mut right = right.lines();
+    for index in 1.. {
+        match (left.next().transpose()?, right.next().transpose()?) {
+            (None, None) => return Ok(None),
+            (left, right) if left != right => {
+                return Ok(Some(Divergence { index, left, right }));
+            }
+            _ => {}
+        }
+    }
+    unreachable!("Traces have more than usize::MAX events")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record() {
+        let path = std::env::temp_dir().join("aoc_util_test_record_trace.txt");
+        event("expand", "before recording");
+        record_to_file(&path).unwrap();
+        event("expand", format_args!("{:?}", (1, 2)));
+        event("choose", "two\nlines");
+        finish().unwrap();
+        event("expand", "after recording");
+        let trace = std::fs::read_to_string(&path).unwrap();
+        assert_eq!("expand\t(1, 2)\nchoose\ttwo\\nlines\n", trace);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_compare() {
+        let trace = "expand\ta\nexpand\tb\n";
+        assert_eq!(None, compare(trace.as_bytes(), trace.as_bytes()).unwrap());
+        let expected = Divergence {
+            index: 2,
+            left: Some("expand\tb".to_string()),
+            right: Some("expand\tc".to_string()),
+        };
+        let actual = compare(trace.as_bytes(), "expand\ta\nexpand\tc\n".as_bytes()).unwrap();
+        assert_eq!(Some(expected), actual);
+        let expected = Divergence {
+            index: 3,
+            left: None,
+            right: Some("choose\td".to_string()),
+        };
+        let actual = compare(
+            trace.as_bytes(),
+            (trace.to_string() + "choose\td\n").as_bytes(),
+        );
+        assert_eq!(Some(expected), actual.unwrap());
+    }
+}
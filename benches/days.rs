@@ -0,0 +1,74 @@
+//! Benchmarks every registered day which has an example input in `test_data`, so that performance
+//! regressions in any of them are visible and not just in the days that `golden` times.
+//!
+//! Days which parse their input once are benchmarked as `YYYY-DD/parse`, `YYYY-DD/part1` and
+//! `YYYY-DD/part2`, where the parts are solved from an input which has already been parsed. Days
+//! which solve each part from the raw input can only be benchmarked as `YYYY-DD/part1` and
+//! `YYYY-DD/part2`. Parts which haven't been solved yet or which fail on the example are skipped.
+//!
+//! Run with `cargo bench --bench days`. Pass a filter such as `-- 2021-23` to benchmark only some
+//! of the days.
+
+use std::io;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use aoc_util::registry::{self, Day, Runner};
+
+// Make sure that every year's days are linked into the benchmark so that they get registered.
+use advent_of_code as _;
+
+/// Whether `f` succeeds on the example input, so that puzzles which can't be solved yet aren't
+/// benchmarked.
+fn succeeds<T>(day: &Day, name: &str, f: impl FnOnce() -> io::Result<T>) -> bool {
+    match f() {
+        Ok(_) => true,
+        Err(e) => {
+            eprintln!("Skipping year {} day {} {name}: {e}", day.year, day.day);
+            false
+        }
+    }
+}
+
+fn bench_day(c: &mut Criterion, day: &Day, sample: &str) {
+    let mut group = c.benchmark_group(format!("{}-{:02}", day.year, day.day));
+    match day.runner {
+        Runner::Parts { part1, part2 } => {
+            for (name, part) in [("part1", part1), ("part2", part2)] {
+                if succeeds(day, name, || part(&mut sample.as_bytes())) {
+                    group.bench_function(name, |b| b.iter(|| part(&mut sample.as_bytes())));
+                }
+            }
+        }
+        Runner::Parsed(parse) => {
+            let solver = match parse(&mut sample.as_bytes()) {
+                Ok(solver) => solver,
+                Err(e) => {
+                    eprintln!("Skipping year {} day {}: {e}", day.year, day.day);
+                    return;
+                }
+            };
+            group.bench_function("parse", |b| b.iter(|| parse(&mut sample.as_bytes())));
+            if succeeds(day, "part1", || solver.part1()) {
+                group.bench_function("part1", |b| b.iter(|| solver.part1()));
+            }
+            if succeeds(day, "part2", || solver.part2()) {
+                group.bench_function("part2", |b| b.iter(|| solver.part2()));
+            }
+        }
+    }
+    group.finish();
+}
+
+fn bench_days(c: &mut Criterion) {
+    for year in registry::years() {
+        for day in registry::days_in(year) {
+            if let Ok(sample) = aoc_util::samples::get(day.year, day.day) {
+                bench_day(c, day, &sample);
+            }
+        }
+    }
+}
+
+criterion_group!(benches, bench_days);
+criterion_main!(benches);
@@ -0,0 +1,233 @@
+//! Times one representative hard day from each year on its sample input and compares the times
+//! against the baseline stored in `benches/golden_baseline.txt`, so that performance regressions
+//! caused by changes to the shared utilities are visible.
+//!
+//! Run with `cargo bench --bench golden`. Pass `-- --save-baseline` to replace the stored baseline
+//! with the times from the current run, and commit the result along with the change that made it
+//! necessary. Each time is stored relative to the time of a fixed calibration workload measured in
+//! the same run, so that the baseline can be compared against on any machine.
+
+use std::{
+    collections::HashMap,
+    env, fs,
+    hint::black_box,
+    io,
+    path::PathBuf,
+    time::{Duration, Instant},
+};
+
+use aoc_util::registry::{self, Runner};
+
+// Make sure that every year's days are linked into the benchmark so that they get registered.
+use advent_of_code as _;
+
+/// How many times each case is run. The median time is reported.
+const ITERATIONS: usize = 10;
+
+/// How much slower than the baseline a case has to be, relative to the calibration workload, before
+/// it's reported as a regression.
+const TOLERANCE: f64 = 0.25;
+
+/// How many numbers the calibration workload hashes and sorts.
+const CALIBRATION_SIZE: u64 = 200_000;
+
+/// A day to time.
+struct Case {
+    year: u32,
+    day: u32,
+    /// The parts to time.
+    parts: &'static [u32],
+}
+
+// The hardest puzzles of 2018 (15), 2019 (18), 2020 (23), and 2022 (19) haven't been solved yet
+// and 2023 isn't part of this workspace, so the hardest day which has been solved stands in for
+// each of them until they are.
+const CASES: &[Case] = &[
+    Case {
+        year: 2018,
+        day: 4,
+        parts: &[1, 2],
+    },
+    Case {
+        year: 2019,
+        day: 14,
+        parts: &[1, 2],
+    },
+    Case {
+        year: 2020,
+        day: 17,
+        parts: &[1, 2],
+    },
+    Case {
+        year: 2021,
+        day: 23,
+        parts: &[1],
+    },
+    Case {
+        year: 2022,
+        day: 12,
+        parts: &[1, 2],
+    },
+];
+
+fn bench_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("benches")
+}
+
+fn baseline_file() -> PathBuf {
+    bench_dir().join("golden_baseline.txt")
+}
+
+/// Reads the baseline as a map from `(year, day)` to the median time of that case relative to the
+/// calibration workload.
+fn read_baseline() -> io::Result<HashMap<(u32, u32), f64>> {
+    let baseline = match fs::read_to_string(baseline_file()) {
+        Ok(baseline) => baseline,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(HashMap::new()),
+        Err(e) => return Err(e),
+    };
+    baseline
+        .lines()
+        .filter(|line| !line.starts_with('#') && !line.trim().is_empty())
+        .map(|line| {
+            let invalid = || {
+                let msg = format!("Invalid baseline entry: {line:?}");
+                io::Error::new(io::ErrorKind::InvalidData, msg)
+            };
+            match line.split_whitespace().collect::<Vec<_>>()[..] {
+                [year, day, relative] => Ok((
+                    (
+                        year.parse().map_err(|_| invalid())?,
+                        day.parse().map_err(|_| invalid())?,
+                    ),
+                    relative.parse().map_err(|_| invalid())?,
+                )),
+                _ => Err(invalid()),
+            }
+        })
+        .collect()
+}
+
+fn write_baseline(times: &[(&Case, f64)]) -> io::Result<()> {
+    let mut baseline = String::from(
+        "# Generated by `cargo bench --bench golden -- --save-baseline`.\n\
+         # year day median-time-relative-to-calibration\n",
+    );
+    for (case, relative) in times {
+        baseline += &format!("{} {} {relative:.6}\n", case.year, case.day);
+    }
+    fs::write(baseline_file(), baseline)
+}
+
+/// A fixed workload of hashing and sorting, which stands in for the speed of the machine so that
+/// the times of the cases can be compared between machines.
+fn calibrate() {
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    let mut numbers = (0..CALIBRATION_SIZE)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        })
+        .collect::<Vec<_>>();
+    let counts = numbers.iter().fold(HashMap::new(), |mut acc, n| {
+        *acc.entry(n % 1024).or_insert(0u32) += 1;
+        acc
+    });
+    numbers.sort_unstable();
+    black_box((numbers, counts));
+}
+
+/// The median time of `case` relative to the median time of the calibration workload. The two are
+/// run alternately so that anything else which is slowing the machine down affects both equally,
+/// and a case which is much faster than the calibration workload is run repeatedly in each sample
+/// so that it isn't swamped by the resolution of the clock.
+fn relative_time(case: &Case) -> io::Result<f64> {
+    let start = Instant::now();
+    calibrate();
+    let calibration = start.elapsed();
+    let start = Instant::now();
+    run_once(case)?;
+    let once = start.elapsed();
+    let repetitions = (calibration.as_secs_f64() / once.as_secs_f64()).clamp(1.0, 1000.0) as u32;
+
+    let mut case_times = Vec::with_capacity(ITERATIONS);
+    let mut calibration_times = Vec::with_capacity(ITERATIONS);
+    for _ in 0..ITERATIONS {
+        let start = Instant::now();
+        calibrate();
+        calibration_times.push(start.elapsed());
+        let start = Instant::now();
+        for _ in 0..repetitions {
+            run_once(case)?;
+        }
+        case_times.push(start.elapsed() / repetitions);
+    }
+    let median = |times: &mut Vec<Duration>| {
+        times.sort_unstable();
+        times[ITERATIONS / 2].as_secs_f64()
+    };
+    Ok(median(&mut case_times) / median(&mut calibration_times))
+}
+
+fn run_once(case: &Case) -> io::Result<()> {
+    let day = registry::find(case.year, case.day).ok_or_else(|| {
+        let msg = format!("Year {} day {} isn't registered", case.year, case.day);
+        io::Error::new(io::ErrorKind::NotFound, msg)
+    })?;
+    match day.runner {
+        Runner::Parts { part1, part2 } => {
+            for &part_num in case.parts {
+                let part = if part_num == 1 { part1 } else { part2 };
+                part(&mut aoc_util::input::open(day.input_file())?)?;
+            }
+            Ok(())
+        }
+        Runner::Parsed(parse) => {
+            let solver = parse(&mut aoc_util::input::open(day.input_file())?)?;
+            for &part_num in case.parts {
+                if part_num == 1 {
+                    solver.part1()?;
+                } else {
+                    solver.part2()?;
+                }
+            }
+            Ok(())
+        }
+    }
+}
+
+fn main() -> io::Result<()> {
+    let save_baseline = env::args().any(|arg| arg == "--save-baseline");
+    let baseline = read_baseline()?;
+    env::set_var(aoc_util::input::INPUT_DIR_VAR, aoc_util::samples::dir()?);
+    let mut times = Vec::with_capacity(CASES.len());
+    for case in CASES {
+        times.push((case, relative_time(case)?));
+    }
+    println!();
+    let mut regressions = 0;
+    for &(case, relative) in &times {
+        print!("Year {} Day {:>2}: {relative:>10.6}x", case.year, case.day);
+        match baseline.get(&(case.year, case.day)) {
+            Some(&expected) => {
+                let change = relative / expected - 1.0;
+                print!(" (baseline {expected:.6}x, {:+.1}%)", change * 100.0);
+                if change > TOLERANCE {
+                    regressions += 1;
+                    print!(" REGRESSION");
+                }
+                println!();
+            }
+            None => println!(" (no baseline)"),
+        }
+    }
+    if save_baseline {
+        write_baseline(&times)?;
+        println!("Saved the baseline to {}", baseline_file().display());
+    } else if regressions > 0 {
+        println!("{regressions} of {} cases regressed", times.len());
+    }
+    Ok(())
+}
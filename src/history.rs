@@ -0,0 +1,258 @@
+use std::{
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+    process::Command,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use aoc_util::{input, registry::Timed};
+
+/// The name of the file in the workspace root that the history of runs is stored in.
+pub const HISTORY_FILE: &str = ".aoc_history";
+
+/// The path of [`HISTORY_FILE`], as found by [`input::workspace_file()`].
+pub fn history_file() -> io::Result<PathBuf> {
+    input::workspace_file(HISTORY_FILE)
+}
+
+/// One part of one day's puzzle having been solved.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Record {
+    /// When the part was solved.
+    pub time: SystemTime,
+    /// The year that the puzzle was released in.
+    pub year: u32,
+    /// The day of December that the puzzle was released on.
+    pub day: u32,
+    /// The part of the puzzle that was solved.
+    pub part: u32,
+    /// How long it took to find the answer.
+    pub elapsed: Duration,
+    /// The commit that the solution was built from, as described by `git describe --dirty`.
+    pub commit: String,
+    /// The answer that was found.
+    pub answer: String,
+}
+
+impl Record {
+    /// The records of the parts of day `day` of year `year` which were solved at time `time` by the
+    /// code in commit `commit`.
+    pub fn from_timed(
+        year: u32,
+        day: u32,
+        timed: &[Timed],
+        commit: &str,
+        time: SystemTime,
+    ) -> Vec<Self> {
+        timed
+            .iter()
+            .map(|timed| Self {
+                time,
+                year,
+                day,
+                part: timed.part,
+                elapsed: timed.elapsed,
+                commit: commit.to_string(),
+                answer: timed.answer.to_string(),
+            })
+            .collect()
+    }
+
+    /// Parses a line of the form `time year day part nanoseconds commit answer`, where the answer
+    /// has its backslashes and newlines escaped.
+    fn parse_line(line: &str) -> Option<Self> {
+        let mut fields = line.splitn(7, ' ');
+        let time = UNIX_EPOCH + Duration::from_secs(fields.next()?.parse().ok()?);
+        let mut number = || fields.next()?.parse::<u32>().ok();
+        let (year, day, part) = (number()?, number()?, number()?);
+        let elapsed = Duration::from_nanos(fields.next()?.parse().ok()?);
+        let commit = fields.next()?.to_string();
+        let answer = unescape(fields.next()?)?;
+        Some(Self {
+            time,
+            year,
+            day,
+            part,
+            elapsed,
+            commit,
+            answer,
+        })
+    }
+
+    fn to_line(&self) -> String {
+        format!(
+            "{} {} {} {} {} {} {}\n",
+            self.time
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs(),
+            self.year,
+            self.day,
+            self.part,
+            self.elapsed.as_nanos(),
+            self.commit,
+            escape(&self.answer),
+        )
+    }
+}
+
+fn escape(answer: &str) -> String {
+    answer.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+fn unescape(answer: &str) -> Option<String> {
+    let mut unescaped = String::with_capacity(answer.len());
+    let mut chars = answer.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next()? {
+                '\\' => unescaped.push('\\'),
+                'n' => unescaped.push('\n'),
+                _ => return None,
+            },
+            c => unescaped.push(c),
+        }
+    }
+    Some(unescaped)
+}
+
+/// Reads the records stored in the file at `path`, oldest first. If there is no such file, there
+/// are no records.
+pub fn load(path: impl AsRef<Path>) -> io::Result<Vec<Record>> {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(e) => return Err(e),
+    };
+    contents
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            Record::parse_line(line).ok_or_else(|| {
+                let msg = format!("Invalid history record: {line:?}");
+                io::Error::new(io::ErrorKind::InvalidData, msg)
+            })
+        })
+        .collect()
+}
+
+/// Adds `records` to the end of the file at `path`, creating it if it doesn't exist yet.
+pub fn append(path: impl AsRef<Path>, records: &[Record]) -> io::Result<()> {
+    let contents = records.iter().map(Record::to_line).collect::<String>();
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)?
+        .write_all(contents.as_bytes())
+}
+
+/// The commit that the working directory is at, with a `-dirty` suffix if it has uncommitted
+/// changes, or `unknown` if it isn't in a git repository.
+pub fn current_commit() -> String {
+    Command::new("git")
+        .args(["describe", "--always", "--dirty"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map(|commit| commit.trim().to_string())
+        .filter(|commit| !commit.is_empty())
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+/// Describes how the time taken to solve each part changed over the runs in `records`, which are
+/// oldest first. If `year` or `day` is given, only the parts of that year or day are included.
+pub fn trends(records: &[Record], year: Option<u32>, day: Option<u32>) -> String {
+    let mut records = records
+        .iter()
+        .filter(|record| year.is_none_or(|year| record.year == year))
+        .filter(|record| day.is_none_or(|day| record.day == day))
+        .collect::<Vec<_>>();
+    // The sort is stable, so each part's runs stay oldest first.
+    records.sort_by_key(|record| (record.year, record.day, record.part));
+    let mut trends = String::new();
+    for (i, record) in records.iter().enumerate() {
+        let previous = i.checked_sub(1).map(|i| records[i]).filter(|previous| {
+            (previous.year, previous.day, previous.part) == (record.year, record.day, record.part)
+        });
+        if previous.is_none() {
+            trends += &format!(
+                "Year {} Day {} Part {}\n",
+                record.year, record.day, record.part
+            );
+        }
+        trends += &format!("  {:<16} {:>12.3?}", record.commit, record.elapsed);
+        if let Some(previous) = previous {
+            let change = record.elapsed.as_secs_f64() / previous.elapsed.as_secs_f64() - 1.0;
+            trends += &format!(" ({:+.1}%)", change * 100.0);
+            if previous.answer != record.answer {
+                trends += &format!(" answer changed to {:?}", record.answer);
+            }
+        }
+        trends.push('\n');
+    }
+    trends
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use aoc_util::registry::Answer;
+
+    fn record(part: u32, millis: u64, commit: &str, answer: &str) -> Record {
+        Record {
+            time: UNIX_EPOCH + Duration::from_secs(1_700_000_000),
+            year: 2022,
+            day: 10,
+            part,
+            elapsed: Duration::from_millis(millis),
+            commit: commit.to_string(),
+            answer: answer.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_append_and_load() {
+        let timed = [Timed {
+            part: 2,
+            answer: Answer::from("#.\n\\#"),
+            elapsed: Duration::from_micros(1500),
+            peak_memory: None,
+        }];
+        let time = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let records = Record::from_timed(2022, 10, &timed, "abc1234-dirty", time);
+        assert_eq!(1, records.len());
+        assert_eq!("#.\n\\#", records[0].answer);
+        let path = std::env::temp_dir().join("aoc_test_append_and_load_history");
+        let _ = fs::remove_file(&path);
+        append(&path, &records).unwrap();
+        append(&path, &[record(1, 3, "def5678", "13140")]).unwrap();
+        let loaded = load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(2, loaded.len());
+        assert_eq!(records[0], loaded[0]);
+        assert_eq!(record(1, 3, "def5678", "13140"), loaded[1]);
+    }
+
+    #[test]
+    fn test_trends() {
+        let records = [
+            record(1, 10, "abc1234", "13140"),
+            record(2, 20, "abc1234", "#."),
+            record(1, 5, "def5678", "13140"),
+            record(1, 6, "0123abc", "13141"),
+        ];
+        assert_eq!(
+            "Year 2022 Day 10 Part 1\n\
+            \x20 abc1234              10.000ms\n\
+            \x20 def5678               5.000ms (-50.0%)\n\
+            \x20 0123abc               6.000ms (+20.0%) answer changed to \"13141\"\n\
+            Year 2022 Day 10 Part 2\n\
+            \x20 abc1234              20.000ms\n",
+            trends(&records, Some(2022), None)
+        );
+        assert_eq!("", trends(&records, None, Some(11)));
+    }
+}
@@ -0,0 +1,82 @@
+use std::{
+    fs,
+    io::{self, ErrorKind},
+    path::{Path, PathBuf},
+};
+
+use sha2::{Digest, Sha256};
+
+/// Whether a cached input still matches the input that was originally saved.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Integrity {
+    /// The input matches the recorded hash.
+    Intact,
+    /// No hash was recorded for the input, so it can't be checked.
+    Unverified,
+    /// The input doesn't match the recorded hash. This usually means that it was truncated while
+    /// being copied into place.
+    Corrupted,
+}
+
+/// Calculates the SHA-256 of `contents` as a lowercase hexadecimal string.
+pub fn sha256(contents: impl AsRef<[u8]>) -> String {
+    format!("{:x}", Sha256::digest(contents))
+}
+
+/// The file that the hash of the input at `path` is stored in.
+pub fn hash_file(path: impl AsRef<Path>) -> PathBuf {
+    let mut hash_file = path.as_ref().as_os_str().to_owned();
+    hash_file.push(".sha256");
+    hash_file.into()
+}
+
+/// Records the hash of `contents` as the expected hash of the input at `path`.
+pub fn record_hash(path: impl AsRef<Path>, contents: impl AsRef<[u8]>) -> io::Result<()> {
+    fs::write(hash_file(path), sha256(contents) + "\n")
+}
+
+/// Checks whether the input at `path` matches its recorded hash.
+pub fn check(path: impl AsRef<Path>) -> io::Result<Integrity> {
+    let expected = match fs::read_to_string(hash_file(&path)) {
+        Ok(expected) => expected,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Integrity::Unverified),
+        Err(e) => return Err(e),
+    };
+    let actual = match fs::read(&path) {
+        Ok(actual) => actual,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Integrity::Corrupted),
+        Err(e) => return Err(e),
+    };
+    if sha256(actual) == expected.trim() {
+        Ok(Integrity::Intact)
+    } else {
+        Ok(Integrity::Corrupted)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sha256() {
+        assert_eq!(
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad",
+            sha256("abc")
+        );
+    }
+
+    #[test]
+    fn test_check() {
+        let path = std::env::temp_dir().join("aoc_test_check_integrity.txt");
+        fs::write(&path, "1\n2\n3\n").unwrap();
+        assert_eq!(Integrity::Unverified, check(&path).unwrap());
+        record_hash(&path, "1\n2\n3\n").unwrap();
+        assert_eq!(Integrity::Intact, check(&path).unwrap());
+        fs::write(&path, "1\n2\n").unwrap();
+        assert_eq!(Integrity::Corrupted, check(&path).unwrap());
+        fs::remove_file(&path).unwrap();
+        assert_eq!(Integrity::Corrupted, check(&path).unwrap());
+        fs::remove_file(hash_file(&path)).unwrap();
+    }
+}
@@ -1,28 +1,133 @@
 //! This crate aggregates my solutions to all [advent of code](https://adventofcode.com/) problems.
 
 #![warn(rust_2018_idioms)]
-#![feature(box_patterns)]
-#![feature(coroutines, coroutine_trait)]
-#![feature(hash_extract_if)]
-#![feature(step_trait)]
 
-use std::io;
+use std::{
+    io,
+    time::{Duration, Instant, SystemTime},
+};
 
+use aoc_util::registry;
 use extended_io as eio;
 
-mod year_2018;
-mod year_2019;
+pub use aoc_util::registry::{Answer, Day, Timed};
 
-fn run_year(year: u32, day: Option<u32>) -> io::Result<()> {
-    let day_prompt = move || day.ok_or(()).or_else(|_| eio::prompt("Enter day to run: "));
-    match year {
-        2018 => year_2018::run_day(day_prompt()?),
-        2019 => year_2019::run_day(day_prompt()?),
-        2020 => aoc_2020::run_day(day_prompt()?),
-        2021 => aoc_2021::run_day(day_prompt()?),
-        2022 => aoc_2022::run_day(day_prompt()?),
-        _ => unimplemented!("Year {}", year),
+/// The answers and timings of past runs, so that it can be seen whether a change sped a day up.
+pub mod history;
+
+/// Verification of the puzzle inputs that are cached on disk.
+pub mod input;
+
+/// Communication with the advent of code server.
+pub mod network;
+
+/// Generation of the boilerplate for a new day.
+pub mod scaffold;
+
+/// A function which lists the registered days of one year.
+type YearDays = fn() -> Vec<&'static Day>;
+
+/// The registered days of the year whose crate is `$krate`, or `None` if the crate was left out of
+/// the build by turning off the feature `$feature`.
+macro_rules! year {
+    ($feature:literal, $krate:ident) => {{
+        #[cfg(feature = $feature)]
+        let days: Option<YearDays> = Some($krate::days);
+        #[cfg(not(feature = $feature))]
+        let days: Option<YearDays> = None;
+        days
+    }};
+}
+
+/// The registered days of each year. Going through these instead of straight to the registry makes
+/// sure that every year's crate is linked in, since nothing else refers to them.
+const YEARS: [(u32, Option<YearDays>); 6] = [
+    (2018, year!("yr2018", aoc_2018)),
+    (2019, year!("yr2019", aoc_2019)),
+    (2020, year!("yr2020", aoc_2020)),
+    (2021, year!("yr2021", aoc_2021)),
+    (2022, year!("yr2022", aoc_2022)),
+    (2024, year!("yr2024", aoc_2024)),
+];
+
+/// Every day which has a registered solution, sorted by year and then by day. If `year` is given,
+/// only the days of that year are included. Years whose features are turned off have no days.
+pub fn days(year: Option<u32>) -> Vec<&'static Day> {
+    YEARS
+        .iter()
+        .filter_map(|&(_, days)| days)
+        .flat_map(|days| days())
+        .filter(|day| year.is_none_or(|year| day.year == year))
+        .collect()
+}
+
+/// Fails if the solutions to year `year` were left out of the build by turning off its feature, so
+/// that it isn't mistaken for a year which hasn't been solved.
+pub fn check_year(year: u32) -> io::Result<()> {
+    match YEARS.iter().find(|&&(other, _)| other == year) {
+        Some((_, None)) => {
+            let msg = format!("Year {year} wasn't compiled in; build with the yr{year} feature");
+            Err(io::Error::new(io::ErrorKind::Unsupported, msg))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Runs the solution to the puzzle released on day `day` of year `year`, printing its answers, and
+/// returns how long it took along with each part's answer, time and peak memory. The answers and
+/// how long each part took are added to the history in [`history::history_file()`].
+pub fn run_day(year: u32, day: u32) -> io::Result<(Duration, Vec<Timed>)> {
+    check_year(year)?;
+    let entry = registry::find(year, day).ok_or_else(|| {
+        let msg = format!("Invalid day: {day}");
+        io::Error::new(io::ErrorKind::InvalidInput, msg)
+    })?;
+    verify_input(year, day)?;
+    let start = Instant::now();
+    let timed = entry.run_timed()?;
+    let elapsed = start.elapsed();
+    if !timed.is_empty() {
+        let commit = history::current_commit();
+        let records = history::Record::from_timed(year, day, &timed, &commit, SystemTime::now());
+        history::append(history::history_file()?, &records)?;
     }
+    Ok((elapsed, timed))
+}
+
+/// Refuses to run a day whose input doesn't match the input that was downloaded for it, offering
+/// to download it again instead.
+fn verify_input(year: u32, day: u32) -> io::Result<()> {
+    let path = aoc_util::input::path(registry::input_file(year, day))?;
+    if input::check(&path)? != input::Integrity::Corrupted {
+        return Ok(());
+    }
+    eprintln!(
+        "{} doesn't match the input that was downloaded for it. It may be truncated.",
+        path.display()
+    );
+    #[cfg(feature = "network")]
+    {
+        let answer: String = eio::prompt("Download it again? [y/N] ")?;
+        if answer.eq_ignore_ascii_case("y") {
+            let server = network::HttpServer::from_stored_session()?;
+            return network::download_input(&server, year, day, &path);
+        }
+    }
+    let msg = format!("Corrupted input file {}", path.display());
+    Err(io::Error::new(io::ErrorKind::InvalidData, msg))
+}
+
+/// Solves part `part` of the puzzle released on day `day` of year `year` with `input` as the puzzle
+/// input, returning the answer instead of printing it.
+pub fn solve(year: u32, day: u32, part: u32, input: &str) -> io::Result<Answer> {
+    registry::solve(year, day, part, &mut input.as_bytes())
+}
+
+/// Parses `input` as the puzzle input for day `day` of year `year` and pretty-prints the result,
+/// so that it can be checked that the input was understood before debugging the solution.
+pub fn inspect(year: u32, day: u32, input: &str) -> io::Result<String> {
+    let solver = registry::parse(year, day, &mut input.as_bytes())?;
+    Ok(format!("{:#?}", solver.model()))
 }
 
 /// The entry point for my solutions to advent of code.
@@ -31,5 +136,57 @@ pub fn run(year: Option<u32>, day: Option<u32>) -> io::Result<()> {
         Some(year) => year,
         None => eio::prompt("Enter the year to run: ")?,
     };
-    run_year(year, day)
+    check_year(year)?;
+    if days(Some(year)).is_empty() {
+        let msg = format!("No solutions for year {year}");
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, msg));
+    }
+    let day = match day {
+        Some(day) => day,
+        None => eio::prompt("Enter day to run: ")?,
+    };
+    run_day(year, day).map(|_| ())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "yr2022")]
+    fn test_solve() {
+        let input = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n";
+        assert_eq!(Answer::Number(11000), solve(2022, 1, 1, input).unwrap());
+        assert_eq!(Answer::Number(21000), solve(2022, 1, 2, input).unwrap());
+        let e = solve(2019, 26, 1, input).unwrap_err();
+        assert_eq!(io::ErrorKind::NotFound, e.kind());
+    }
+
+    #[test]
+    #[cfg(feature = "yr2022")]
+    fn test_inspect() {
+        let input = aoc_util::samples::get(2022, 12).unwrap();
+        let model = inspect(2022, 12, &input).unwrap();
+        assert!(model.starts_with("Map {\n    width: 8,\n    height: 5,\n"));
+        let e = inspect(2022, 1, &input).unwrap_err();
+        assert_eq!(io::ErrorKind::Unsupported, e.kind());
+    }
+
+    #[test]
+    #[cfg(all(
+        feature = "yr2018",
+        feature = "yr2019",
+        feature = "yr2020",
+        feature = "yr2021",
+        feature = "yr2022",
+        feature = "yr2024"
+    ))]
+    fn test_days() {
+        assert_eq!(25, days(Some(2022)).len());
+        let all = days(None);
+        assert_eq!(126, all.len());
+        assert!(all
+            .windows(2)
+            .all(|pair| (pair[0].year, pair[0].day) < (pair[1].year, pair[1].day)));
+    }
 }
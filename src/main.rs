@@ -1,12 +1,32 @@
 //! An executable wrapper around (my) advent of code solutions.
 use advent_of_code as aoc;
+#[cfg(feature = "network")]
+use extended_io as eio;
 
+use aoc_util::{
+    error::Error,
+    limits::{self, Limits},
+    memory, progress,
+    registry::Timed,
+    trace,
+};
 use clap::{App, Arg};
+use tracing_subscriber::prelude::*;
 
-use std::io;
+use std::{
+    fs::File,
+    io::{self, BufReader, IsTerminal},
+    process,
+    time::Duration,
+};
+
+/// Tracks allocations so that `--time` can report how much memory each part used.
+#[cfg(feature = "memory")]
+#[global_allocator]
+static ALLOCATOR: memory::TrackingAllocator = memory::TrackingAllocator;
 
 fn app() -> App<'static> {
-    App::new("Advent of Code")
+    let app = App::new("Advent of Code")
         .version("0.1.0")
         .author("Kevin M. <dragonrider7225@gmail.com>")
         .about("Runs one day of one year of the Advent of Code <adventofcode.com>")
@@ -17,7 +37,8 @@ fn app() -> App<'static> {
                 .long("year")
                 .takes_value(true)
                 .value_name("YEAR")
-                .possible_values(["2018", "2019", "2020", "2021", "2022"])
+                .validator(|s| s.parse::<u32>())
+                .global(true)
                 .help("Selects the year to run"),
         )
         .arg(
@@ -30,15 +51,404 @@ fn app() -> App<'static> {
                     "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14",
                     "15", "16", "17", "18", "19", "20", "21", "22", "23", "24", "25",
                 ])
+                .global(true)
                 .help("Selects the day to run"),
         )
+        .arg(
+            Arg::new("record-trace")
+                .long("record-trace")
+                .takes_value(true)
+                .value_name("FILE")
+                .help("Records the events that the solvers report to FILE"),
+        )
+        .arg(
+            Arg::new("trace")
+                .long("trace")
+                .takes_value(true)
+                .value_name("FILE")
+                .help("Writes where the time went to FILE as a Chrome trace, such as for Perfetto"),
+        )
+        .arg(
+            Arg::new("max-states")
+                .long("max-states")
+                .takes_value(true)
+                .value_name("COUNT")
+                .validator(|s| s.parse::<usize>())
+                .help("Makes searches give up after expanding COUNT states"),
+        )
+        .arg(
+            Arg::new("max-depth")
+                .long("max-depth")
+                .takes_value(true)
+                .value_name("DEPTH")
+                .validator(|s| s.parse::<usize>())
+                .help("Makes recursive searches give up beyond DEPTH levels deep"),
+        )
+        .arg(
+            Arg::new("max-memory")
+                .long("max-memory")
+                .takes_value(true)
+                .value_name("MIB")
+                .validator(|s| s.parse::<usize>())
+                .help("Makes searches give up once their states take up about MIB mebibytes"),
+        )
+        .arg(
+            Arg::new("all")
+                .long("all")
+                .conflicts_with("day")
+                .help("Runs every solved day of the selected year, or of all years"),
+        )
+        .arg(
+            Arg::new("time")
+                .long("time")
+                .help("Reports how long each day and part took to run"),
+        )
+        .subcommand(
+            App::new("list")
+                .about("Lists the days which have been solved, optionally only for one year"),
+        )
+        .subcommand(
+            App::new("history")
+                .about("Shows how long each part took in past runs, optionally only for one day"),
+        )
+        .subcommand(
+            App::new("inspect")
+                .about("Parses the input for the selected day and prints what it was parsed into"),
+        )
+        .subcommand(
+            App::new("new-day")
+                .about("Creates the selected day from the template and registers it")
+                .arg(
+                    Arg::new("title")
+                        .long("title")
+                        .takes_value(true)
+                        .value_name("TITLE")
+                        .default_value("????")
+                        .help("The title of the puzzle"),
+                ),
+        )
+        .subcommand(
+            App::new("compare-traces")
+                .about("Finds the first event at which two recorded traces differ")
+                .arg(Arg::new("left").required(true).value_name("LEFT"))
+                .arg(Arg::new("right").required(true).value_name("RIGHT")),
+        );
+    #[cfg(feature = "network")]
+    let app = app
+        .arg(
+            Arg::new("fetch")
+                .long("fetch")
+                .requires_all(&["year", "day"])
+                .help("Downloads the input for the selected day before running it"),
+        )
+        .subcommand(
+            App::new("login")
+                .about("Checks and saves the session cookie used to talk to the server")
+                .arg(
+                    Arg::new("session")
+                        .takes_value(true)
+                        .value_name("SESSION")
+                        .help("The value of the `session` cookie; prompted for if omitted"),
+                ),
+        )
+        .subcommand(
+            App::new("submit")
+                .about("Submits an answer for the selected day unless it's known to be wrong")
+                .arg(
+                    Arg::new("part")
+                        .required(true)
+                        .possible_values(["1", "2"])
+                        .help("The part of the puzzle that the answer is for"),
+                )
+                .arg(
+                    Arg::new("answer")
+                        .required(true)
+                        .help("The answer to submit"),
+                ),
+        );
+    app
+}
+
+/// Prints every solved day of `year`, or of every year if `year` is `None`.
+fn list(year: Option<u32>) {
+    for day in aoc::days(year) {
+        println!("{} Day {:>2}: {}", day.year, day.day, day.title);
+    }
+}
+
+/// Whether `e` was caused by running a solution which hasn't been written yet.
+fn is_unimplemented(e: &io::Error) -> bool {
+    matches!(
+        e.get_ref().and_then(|e| e.downcast_ref::<Error>()),
+        Some(Error::Unimplemented { .. })
+    )
+}
+
+/// Whether `e` was caused by a solver panicking.
+fn is_panicked(e: &io::Error) -> bool {
+    matches!(
+        e.get_ref().and_then(|e| e.downcast_ref::<Error>()),
+        Some(Error::Panicked { .. })
+    )
+}
+
+/// Whether `e` was caused by a search giving up because it reached one of its limits.
+fn is_limit_exceeded(e: &io::Error) -> bool {
+    matches!(
+        e.get_ref().and_then(|e| e.downcast_ref::<Error>()),
+        Some(Error::LimitExceeded(_))
+    )
+}
+
+/// Runs every solved day of `year`, or of every year if `year` is `None`. Days whose input hasn't
+/// been downloaded, which haven't been solved yet, or which reach the limits of their searches are
+/// skipped. Days which panic are listed once every other day has been run.
+fn run_all(year: Option<u32>, time: bool) -> io::Result<()> {
+    if let Some(year) = year {
+        aoc::check_year(year)?;
+    }
+    let mut total = Duration::ZERO;
+    let mut panicked = vec![];
+    let mut year_span = None;
+    for day in aoc::days(year) {
+        if year_span.as_ref().map(|&(span_year, _)| span_year) != Some(day.year) {
+            // The previous year's span has to be exited before the next one is entered.
+            drop(year_span.take());
+            let span = tracing::info_span!("year", year = day.year).entered();
+            year_span = Some((day.year, span));
+        }
+        if !aoc_util::input::path(day.input_file())?.exists() {
+            eprintln!("Skipping year {} day {}: no input", day.year, day.day);
+            continue;
+        }
+        let (elapsed, timed) = match aoc::run_day(day.year, day.day) {
+            Ok(run) => run,
+            Err(e) if is_unimplemented(&e) || is_limit_exceeded(&e) => {
+                eprintln!("Skipping year {} day {}: {e}", day.year, day.day);
+                continue;
+            }
+            Err(e) if is_panicked(&e) => {
+                panicked.push((day, e));
+                continue;
+            }
+            Err(e) => return Err(e),
+        };
+        total += elapsed;
+        if time {
+            println!("Year {} Day {} took {elapsed:.3?}", day.year, day.day);
+            print_parts(&timed);
+        }
+    }
+    if time {
+        println!("Total: {total:.3?}");
+    }
+    if panicked.is_empty() {
+        return Ok(());
+    }
+    eprintln!("{} of the days panicked:", panicked.len());
+    for (day, e) in &panicked {
+        eprintln!("  Year {} Day {}: {e}", day.year, day.day);
+    }
+    let msg = format!("{} of the days panicked", panicked.len());
+    Err(io::Error::new(io::ErrorKind::Other, msg))
+}
+
+/// Prints how long each part took and, if allocations are being tracked, the most heap memory that
+/// it used at once.
+fn print_parts(timed: &[Timed]) {
+    for timed in timed {
+        print!("  Part {} took {:.3?}", timed.part, timed.elapsed);
+        if let Some(peak_memory) = timed.peak_memory {
+            print!(", peaking at {}", memory::Bytes(peak_memory));
+        }
+        println!();
+    }
+}
+
+/// Prints how long each part of the selected days took in each recorded run.
+fn history(year: Option<u32>, day: Option<u32>) -> io::Result<()> {
+    let records = aoc::history::load(aoc::history::history_file()?)?;
+    let trends = aoc::history::trends(&records, year, day);
+    if trends.is_empty() {
+        println!("No runs have been recorded");
+    } else {
+        print!("{trends}");
+    }
+    Ok(())
+}
+
+/// Prints the model that the input for day `day` of year `year` is parsed into.
+fn inspect(year: Option<u32>, day: Option<u32>) -> io::Result<()> {
+    let (year, day) = year.zip(day).ok_or_else(|| {
+        let msg = "Inspecting an input requires both --year and --day".to_string();
+        io::Error::new(io::ErrorKind::InvalidInput, msg)
+    })?;
+    let input = aoc_util::input::read_to_string(aoc_util::registry::input_file(year, day))?;
+    println!("{}", aoc::inspect(year, day, &input)?);
+    Ok(())
+}
+
+/// Creates day `day` of year `year` from the template.
+fn new_day(year: Option<u32>, day: Option<u32>, title: &str) -> io::Result<()> {
+    let (year, day) = year.zip(day).ok_or_else(|| {
+        let msg = "Creating a day requires both --year and --day".to_string();
+        io::Error::new(io::ErrorKind::InvalidInput, msg)
+    })?;
+    let root = aoc_util::input::workspace_root().ok_or_else(|| {
+        let msg = "Couldn't find the workspace to create the day in".to_string();
+        io::Error::new(io::ErrorKind::NotFound, msg)
+    })?;
+    let path = aoc::scaffold::new_day(&root, year, day, title)?;
+    println!("Created {}", path.display());
+    Ok(())
+}
+
+/// Reports the first difference between the traces in the files at `left` and `right`.
+fn compare_traces(left: &str, right: &str) -> io::Result<()> {
+    let open = |path| File::open(path).map(BufReader::new);
+    match trace::compare(open(left)?, open(right)?)? {
+        Some(divergence) => {
+            println!("{divergence}");
+            process::exit(1)
+        }
+        None => {
+            println!("Traces are identical");
+            Ok(())
+        }
+    }
+}
+
+/// Saves the session cookie if the server accepts it.
+#[cfg(feature = "network")]
+fn login(session: Option<&str>) -> io::Result<()> {
+    let session = match session {
+        Some(session) => session.trim().to_string(),
+        None => eio::prompt::<String>("Session cookie: ")?
+            .trim()
+            .to_string(),
+    };
+    if !aoc::network::HttpServer::new(session.clone()).validate()? {
+        let msg = "The server rejected the session cookie".to_string();
+        return Err(io::Error::new(io::ErrorKind::PermissionDenied, msg));
+    }
+    aoc::network::session::store(&session)?;
+    println!("Logged in");
+    Ok(())
+}
+
+/// Submits `answer` as the answer to part `part` of day `day` of year `year`, keeping track of
+/// which answers have been rejected so that they aren't submitted again.
+#[cfg(feature = "network")]
+fn submit(year: Option<u32>, day: Option<u32>, part: u32, answer: &str) -> io::Result<()> {
+    use aoc::network::{self, Guesses, Verdict};
+
+    let (year, day) = year.zip(day).ok_or_else(|| {
+        let msg = "Submitting an answer requires both --year and --day".to_string();
+        io::Error::new(io::ErrorKind::InvalidInput, msg)
+    })?;
+    let server = network::HttpServer::from_stored_session()?;
+    let guesses_file = network::guesses_file()?;
+    let mut guesses = Guesses::load(&guesses_file)?;
+    let verdict = network::guesses::submit(&server, &mut guesses, year, day, part, answer);
+    guesses.save(&guesses_file)?;
+    match verdict? {
+        Verdict::Correct => println!("{answer} is correct"),
+        Verdict::TooHigh => println!("{answer} is too high"),
+        Verdict::TooLow => println!("{answer} is too low"),
+        Verdict::Incorrect => println!("{answer} is incorrect"),
+        Verdict::Wait(wait) => println!("Answer not checked; wait {}s", wait.as_secs()),
+        Verdict::AlreadySolved => println!("Part {part} has already been solved"),
+    }
+    Ok(())
 }
 
 fn main() -> io::Result<()> {
     let matches = app().get_matches();
+    if let Some(matches) = matches.subcommand_matches("compare-traces") {
+        return compare_traces(
+            matches.value_of("left").unwrap(),
+            matches.value_of("right").unwrap(),
+        );
+    }
+    #[cfg(feature = "network")]
+    if let Some(matches) = matches.subcommand_matches("login") {
+        return login(matches.value_of("session"));
+    }
+    #[cfg(feature = "network")]
+    if let Some(submit_matches) = matches.subcommand_matches("submit") {
+        let year = matches.value_of("year").and_then(|s| s.parse::<u32>().ok());
+        let day = matches.value_of("day").and_then(|s| s.parse::<u32>().ok());
+        let part = submit_matches.value_of_t_or_exit::<u32>("part");
+        return submit(year, day, part, submit_matches.value_of("answer").unwrap());
+    }
     let year = matches.value_of("year").and_then(|s| s.parse::<u32>().ok());
     let day = matches.value_of("day").and_then(|s| s.parse::<u32>().ok());
-    aoc::run(year, day)
+    if matches.subcommand_matches("list").is_some() {
+        list(year);
+        return Ok(());
+    }
+    if matches.subcommand_matches("history").is_some() {
+        return history(year, day);
+    }
+    if matches.subcommand_matches("inspect").is_some() {
+        return inspect(year, day);
+    }
+    if let Some(new_day_matches) = matches.subcommand_matches("new-day") {
+        return new_day(year, day, new_day_matches.value_of("title").unwrap());
+    }
+    #[cfg(feature = "network")]
+    if let (true, Some(year), Some(day)) = (matches.is_present("fetch"), year, day) {
+        let server = aoc::network::HttpServer::from_stored_session()?;
+        aoc::network::download_input(
+            &server,
+            year,
+            day,
+            aoc_util::input::path(aoc_util::registry::input_file(year, day))?,
+        )?;
+    }
+    let limit = |name| matches.value_of(name).and_then(|s| s.parse::<usize>().ok());
+    limits::set_default(Limits {
+        max_states: limit("max-states"),
+        max_depth: limit("max-depth"),
+        max_memory: limit("max-memory").map(|mib| mib.saturating_mul(1 << 20)),
+    });
+    let chrome_trace = matches.value_of("trace").map(|path| {
+        let (layer, guard) = tracing_chrome::ChromeLayerBuilder::new()
+            .file(path)
+            .include_args(true)
+            .build();
+        tracing_subscriber::registry().with(layer).init();
+        guard
+    });
+    if let Some(path) = matches.value_of("record-trace") {
+        trace::record_to_file(path)?;
+    }
+    // A progress bar would only clutter a log that stderr is redirected to.
+    if io::stderr().is_terminal() {
+        progress::report_to(progress::Bar::new());
+    }
+    let time = matches.is_present("time");
+    let result = match (matches.is_present("all"), year, day) {
+        (true, _, _) => run_all(year, time),
+        (false, Some(year), Some(day)) if time => {
+            aoc::run_day(year, day).map(|(elapsed, timed)| {
+                println!("Took {elapsed:.3?}");
+                print_parts(&timed);
+            })
+        }
+        (false, _, _) => aoc::run(year, day),
+    };
+    progress::stop();
+    // Dropping the guard writes out the rest of the Chrome trace.
+    drop(chrome_trace);
+    trace::finish()?;
+    match result {
+        Err(e) if is_limit_exceeded(&e) => {
+            eprintln!("{e}");
+            process::exit(2)
+        }
+        result => result,
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,86 @@
+use std::{
+    cell::RefCell,
+    collections::{HashMap, VecDeque},
+    io,
+};
+
+use super::{Response, Server, Verdict};
+
+/// A [`Server`] which serves canned inputs and responses without touching the network.
+#[derive(Debug, Default)]
+pub(crate) struct FakeServer {
+    inputs: HashMap<(u32, u32), String>,
+    responses: RefCell<VecDeque<Response>>,
+    submissions: RefCell<Vec<(u32, u32, u32, String)>>,
+}
+
+impl FakeServer {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Serves `input` as the input for day `day` of year `year`.
+    pub(crate) fn with_input(mut self, year: u32, day: u32, input: &str) -> Self {
+        self.inputs.insert((year, day), input.to_string());
+        self
+    }
+
+    /// Responds to the next submission which doesn't already have a response with `verdict`.
+    pub(crate) fn with_verdict(self, verdict: Verdict) -> Self {
+        self.with_response(verdict.into())
+    }
+
+    /// Responds to the next submission which doesn't already have a response with `response`.
+    pub(crate) fn with_response(self, response: Response) -> Self {
+        self.responses.borrow_mut().push_back(response);
+        self
+    }
+
+    /// Every submission that has been made so far as `(year, day, part, answer)`.
+    pub(crate) fn submissions(&self) -> Vec<(u32, u32, u32, String)> {
+        self.submissions.borrow().clone()
+    }
+}
+
+impl Server for FakeServer {
+    fn fetch_input(&self, year: u32, day: u32) -> io::Result<String> {
+        self.inputs.get(&(year, day)).cloned().ok_or_else(|| {
+            let msg = format!("No input for year {year} day {day}");
+            io::Error::new(io::ErrorKind::NotFound, msg)
+        })
+    }
+
+    fn submit_answer(&self, year: u32, day: u32, part: u32, answer: &str) -> io::Result<Response> {
+        self.submissions
+            .borrow_mut()
+            .push((year, day, part, answer.to_string()));
+        self.responses.borrow_mut().pop_front().ok_or_else(|| {
+            let msg = "No response left to give".to_string();
+            io::Error::new(io::ErrorKind::UnexpectedEof, msg)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_submit_answer() {
+        let server = FakeServer::new()
+            .with_verdict(Verdict::TooLow)
+            .with_verdict(Verdict::Correct);
+        assert_eq!(
+            Verdict::TooLow,
+            server.submit_answer(2022, 1, 1, "5").unwrap().verdict
+        );
+        assert_eq!(
+            Verdict::Correct,
+            server.submit_answer(2022, 1, 1, "7").unwrap().verdict
+        );
+        assert!(server.submit_answer(2022, 1, 2, "9").is_err());
+        let expected = [(1, "5"), (1, "7"), (2, "9")]
+            .map(|(part, answer)| (2022, 1, part, answer.to_string()));
+        assert_eq!(expected.to_vec(), server.submissions());
+    }
+}
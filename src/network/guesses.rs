@@ -0,0 +1,307 @@
+use std::{
+    collections::HashMap,
+    fmt::{self, Display, Formatter},
+    fs, io,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use aoc_util::input;
+
+use super::{Response, Server, Verdict};
+
+/// The name of the file in the workspace root that the guesses are stored in.
+pub const GUESSES_FILE: &str = ".aoc_guesses";
+
+/// The path of [`GUESSES_FILE`], as found by [`input::workspace_file()`].
+pub fn guesses_file() -> io::Result<PathBuf> {
+    input::workspace_file(GUESSES_FILE)
+}
+
+/// Identifies one part of one day's puzzle as `(year, day, part)`.
+type PartId = (u32, u32, u32);
+
+/// Everything that is known about the answers that have been submitted for one part of a puzzle.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+struct PartGuesses {
+    /// The answers which the server has judged, along with its verdicts.
+    verdicts: Vec<(String, Verdict)>,
+    /// The earliest time that the server will accept another answer.
+    locked_until: Option<SystemTime>,
+}
+
+/// The reason that an answer should not be submitted.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Refusal {
+    /// The part has already been solved with the contained answer.
+    Solved(String),
+    /// The exact same answer was already judged incorrect.
+    Repeated(Verdict),
+    /// The answer is at least as high as the contained answer, which was too high.
+    AtLeast(String),
+    /// The answer is at most as low as the contained answer, which was too low.
+    AtMost(String),
+    /// The server won't accept another answer until the contained time.
+    Locked(SystemTime),
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Solved(answer) => write!(f, "Already solved with {answer}"),
+            Self::Repeated(verdict) => write!(f, "Already submitted and got {verdict:?}"),
+            Self::AtLeast(answer) => write!(f, "{answer} was already too high"),
+            Self::AtMost(answer) => write!(f, "{answer} was already too low"),
+            Self::Locked(until) => {
+                let wait = until
+                    .duration_since(SystemTime::now())
+                    .unwrap_or_default()
+                    .as_secs();
+                write!(f, "The server won't accept an answer for another {wait}s")
+            }
+        }
+    }
+}
+
+/// A record of the answers that have been submitted and how the server responded to them.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Guesses {
+    parts: HashMap<PartId, PartGuesses>,
+}
+
+impl Guesses {
+    /// Creates an empty record.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reads the record stored in the file at `path`. If there is no such file, the record is
+    /// empty.
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Self::new()),
+            Err(e) => return Err(e),
+        };
+        let mut guesses = Self::new();
+        for line in contents.lines().filter(|line| !line.is_empty()) {
+            guesses.parse_line(line).ok_or_else(|| {
+                let msg = format!("Invalid guess record: {line:?}");
+                io::Error::new(io::ErrorKind::InvalidData, msg)
+            })?;
+        }
+        Ok(guesses)
+    }
+
+    /// Parses a line of the form `year day part kind value` and adds it to the record.
+    fn parse_line(&mut self, line: &str) -> Option<()> {
+        let mut fields = line.splitn(5, ' ');
+        let mut number = || fields.next()?.parse::<u32>().ok();
+        let id = (number()?, number()?, number()?);
+        let kind = fields.next()?;
+        let value = fields.next()?.to_string();
+        let part = self.parts.entry(id).or_default();
+        let verdict = match kind {
+            "wait" => {
+                let until = UNIX_EPOCH + Duration::from_secs(value.parse().ok()?);
+                part.locked_until = Some(until);
+                return Some(());
+            }
+            "correct" => Verdict::Correct,
+            "too_high" => Verdict::TooHigh,
+            "too_low" => Verdict::TooLow,
+            "incorrect" => Verdict::Incorrect,
+            _ => return None,
+        };
+        part.verdicts.push((value, verdict));
+        Some(())
+    }
+
+    /// Writes the record to the file at `path`.
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let mut ids = self.parts.keys().collect::<Vec<_>>();
+        ids.sort_unstable();
+        let mut contents = String::new();
+        for &id @ (year, day, part) in ids {
+            let guesses = &self.parts[&id];
+            for (answer, verdict) in &guesses.verdicts {
+                let kind = match verdict {
+                    Verdict::Correct => "correct",
+                    Verdict::TooHigh => "too_high",
+                    Verdict::TooLow => "too_low",
+                    Verdict::Incorrect => "incorrect",
+                    Verdict::Wait(_) | Verdict::AlreadySolved => continue,
+                };
+                contents += &format!("{year} {day} {part} {kind} {answer}\n");
+            }
+            if let Some(until) = guesses.locked_until {
+                let until = until.duration_since(UNIX_EPOCH).unwrap_or_default();
+                contents += &format!("{year} {day} {part} wait {}\n", until.as_secs());
+            }
+        }
+        fs::write(path, contents)
+    }
+
+    /// Checks whether `answer` is worth submitting for part `part` of day `day` of year `year` at
+    /// time `now`.
+    pub fn check(
+        &self,
+        year: u32,
+        day: u32,
+        part: u32,
+        answer: &str,
+        now: SystemTime,
+    ) -> Result<(), Refusal> {
+        let guesses = match self.parts.get(&(year, day, part)) {
+            Some(guesses) => guesses,
+            None => return Ok(()),
+        };
+        let numeric = answer.parse::<i128>().ok();
+        for (guess, verdict) in &guesses.verdicts {
+            match (verdict, numeric.zip(guess.parse::<i128>().ok())) {
+                (Verdict::Correct, _) => return Err(Refusal::Solved(guess.clone())),
+                _ if guess == answer => return Err(Refusal::Repeated(*verdict)),
+                (Verdict::TooHigh, Some((answer, guess_value))) if answer >= guess_value => {
+                    return Err(Refusal::AtLeast(guess.clone()));
+                }
+                (Verdict::TooLow, Some((answer, guess_value))) if answer <= guess_value => {
+                    return Err(Refusal::AtMost(guess.clone()));
+                }
+                _ => {}
+            }
+        }
+        match guesses.locked_until {
+            Some(until) if until > now => Err(Refusal::Locked(until)),
+            _ => Ok(()),
+        }
+    }
+
+    /// Records the server's response to `answer` having been submitted at time `now`.
+    pub fn record(
+        &mut self,
+        year: u32,
+        day: u32,
+        part: u32,
+        answer: &str,
+        response: impl Into<Response>,
+        now: SystemTime,
+    ) {
+        let Response { verdict, cooldown } = response.into();
+        let guesses = self.parts.entry((year, day, part)).or_default();
+        match verdict {
+            Verdict::AlreadySolved => return,
+            Verdict::Wait(_) => {}
+            verdict => guesses.verdicts.push((answer.to_string(), verdict)),
+        }
+        guesses.locked_until = cooldown.map(|cooldown| now + cooldown);
+    }
+}
+
+/// Submits `answer` to `server` unless `guesses` shows that it can't be correct or that the server
+/// won't accept it yet, and records the server's verdict in `guesses`.
+pub fn submit(
+    server: &dyn Server,
+    guesses: &mut Guesses,
+    year: u32,
+    day: u32,
+    part: u32,
+    answer: &str,
+) -> io::Result<Verdict> {
+    let now = SystemTime::now();
+    guesses
+        .check(year, day, part, answer, now)
+        .map_err(|refusal| io::Error::new(io::ErrorKind::InvalidInput, refusal.to_string()))?;
+    let response = server.submit_answer(year, day, part, answer)?;
+    guesses.record(year, day, part, answer, response, now);
+    Ok(response.verdict)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::network::FakeServer;
+
+    #[test]
+    fn test_refuses_dominated_answers() {
+        let now = SystemTime::now();
+        let mut guesses = Guesses::new();
+        guesses.record(2022, 1, 1, "100", Verdict::TooHigh, now);
+        guesses.record(2022, 1, 1, "10", Verdict::TooLow, now);
+        guesses.record(2022, 1, 1, "50", Verdict::Incorrect, now);
+        assert_eq!(
+            Err(Refusal::AtLeast("100".to_string())),
+            guesses.check(2022, 1, 1, "150", now)
+        );
+        assert_eq!(
+            Err(Refusal::AtMost("10".to_string())),
+            guesses.check(2022, 1, 1, "9", now)
+        );
+        assert_eq!(
+            Err(Refusal::Repeated(Verdict::Incorrect)),
+            guesses.check(2022, 1, 1, "50", now)
+        );
+        assert_eq!(Ok(()), guesses.check(2022, 1, 1, "51", now));
+        assert_eq!(Ok(()), guesses.check(2022, 1, 2, "150", now));
+    }
+
+    #[test]
+    fn test_respects_lockout() {
+        let now = SystemTime::now();
+        let mut guesses = Guesses::new();
+        let wait = Duration::from_secs(60);
+        guesses.record(2022, 1, 1, "7", Verdict::Wait(wait), now);
+        assert_eq!(
+            Err(Refusal::Locked(now + wait)),
+            guesses.check(2022, 1, 1, "8", now)
+        );
+        assert_eq!(Ok(()), guesses.check(2022, 1, 1, "8", now + wait));
+    }
+
+    #[test]
+    fn test_wrong_answer_locks_out() {
+        let now = SystemTime::now();
+        let mut guesses = Guesses::new();
+        let response = Response::from_body(include_str!("wrong_answer.html")).unwrap();
+        guesses.record(2022, 1, 1, "7", response, now);
+        let wait = Duration::from_secs(60);
+        assert_eq!(
+            Err(Refusal::Locked(now + wait)),
+            guesses.check(2022, 1, 1, "8", now)
+        );
+        assert_eq!(Ok(()), guesses.check(2022, 1, 1, "8", now + wait));
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let now = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let mut guesses = Guesses::new();
+        guesses.record(2022, 1, 1, "100", Verdict::TooHigh, now);
+        guesses.record(2022, 1, 1, "abc", Verdict::Incorrect, now);
+        guesses.record(
+            2022,
+            1,
+            1,
+            "99",
+            Verdict::Wait(Duration::from_secs(60)),
+            now,
+        );
+        guesses.record(2022, 1, 2, "42", Verdict::Correct, now);
+        let path = std::env::temp_dir().join("aoc_test_save_and_load_guesses");
+        guesses.save(&path).unwrap();
+        assert_eq!(guesses, Guesses::load(&path).unwrap());
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_submit() {
+        let server = FakeServer::new().with_verdict(Verdict::TooLow);
+        let mut guesses = Guesses::new();
+        assert_eq!(
+            Verdict::TooLow,
+            submit(&server, &mut guesses, 2022, 1, 1, "5").unwrap()
+        );
+        assert!(submit(&server, &mut guesses, 2022, 1, 1, "4").is_err());
+        assert_eq!(1, server.submissions().len());
+    }
+}
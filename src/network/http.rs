@@ -0,0 +1,73 @@
+use std::{fs, io, path::Path};
+
+use super::{session, Response, Server};
+
+const BASE_URL: &str = "https://adventofcode.com";
+const USER_AGENT: &str =
+    "github.com/dragonrider7225/advent_of_code_rust by dragonrider7225@gmail.com";
+
+/// The real Advent of Code server, accessed with the session cookie of a logged-in user.
+#[derive(Clone, Debug)]
+pub struct HttpServer {
+    session: String,
+}
+
+impl HttpServer {
+    /// Creates a connection to the server which authenticates with `session`.
+    pub fn new(session: String) -> Self {
+        Self { session }
+    }
+
+    /// Creates a connection to the server which authenticates with the session cookie stored in
+    /// the file at `path`.
+    pub fn from_session_file(path: impl AsRef<Path>) -> io::Result<Self> {
+        Ok(Self::new(fs::read_to_string(path)?.trim().to_string()))
+    }
+
+    /// Creates a connection to the server which authenticates with the session cookie found by
+    /// [`session::load()`].
+    pub fn from_stored_session() -> io::Result<Self> {
+        Ok(Self::new(session::load()?))
+    }
+
+    /// Checks whether the server accepts the session cookie by requesting an input, which can
+    /// only be seen by a logged-in user.
+    pub fn validate(&self) -> io::Result<bool> {
+        match self.fetch_input(2015, 1) {
+            Ok(_) => Ok(true),
+            Err(e) => match e.get_ref().and_then(|e| e.downcast_ref::<ureq::Error>()) {
+                Some(ureq::Error::Status(400 | 401 | 403 | 500, _)) => Ok(false),
+                _ => Err(e),
+            },
+        }
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+}
+
+fn to_io_error(e: ureq::Error) -> io::Error {
+    io::Error::new(io::ErrorKind::Other, e)
+}
+
+impl Server for HttpServer {
+    fn fetch_input(&self, year: u32, day: u32) -> io::Result<String> {
+        ureq::get(&format!("{BASE_URL}/{year}/day/{day}/input"))
+            .set("Cookie", &self.cookie())
+            .set("User-Agent", USER_AGENT)
+            .call()
+            .map_err(to_io_error)?
+            .into_string()
+    }
+
+    fn submit_answer(&self, year: u32, day: u32, part: u32, answer: &str) -> io::Result<Response> {
+        let body = ureq::post(&format!("{BASE_URL}/{year}/day/{day}/answer"))
+            .set("Cookie", &self.cookie())
+            .set("User-Agent", USER_AGENT)
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])
+            .map_err(to_io_error)?
+            .into_string()?;
+        Response::from_body(&body)
+    }
+}
@@ -0,0 +1,263 @@
+use std::{fs, io, path::Path, time::Duration};
+
+use crate::input;
+
+#[cfg(feature = "network")]
+mod http;
+#[cfg(feature = "network")]
+pub use http::HttpServer;
+
+/// A record of submitted answers which prevents resubmitting answers that are known to be wrong.
+pub mod guesses;
+pub use guesses::{guesses_file, Guesses, GUESSES_FILE};
+
+/// Storage for the session cookie that identifies the logged-in user to the server.
+#[cfg(feature = "network")]
+pub mod session;
+
+#[cfg(test)]
+mod fake;
+#[cfg(test)]
+pub(crate) use fake::FakeServer;
+
+/// The server's response to a submitted answer.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Verdict {
+    /// The answer was correct.
+    Correct,
+    /// The answer was incorrect and greater than the correct answer.
+    TooHigh,
+    /// The answer was incorrect and less than the correct answer.
+    TooLow,
+    /// The answer was incorrect and the server gave no hint about why.
+    Incorrect,
+    /// The answer was not checked because an answer was submitted too recently. Another answer
+    /// may be submitted after the contained duration has passed.
+    Wait(Duration),
+    /// The answer was not checked because the part has already been solved.
+    AlreadySolved,
+}
+
+impl Verdict {
+    /// Determines the verdict from the body of the server's response to a submission.
+    pub fn from_response(body: &str) -> io::Result<Self> {
+        if body.contains("That's the right answer") {
+            Ok(Self::Correct)
+        } else if body.contains("your answer is too high") {
+            Ok(Self::TooHigh)
+        } else if body.contains("your answer is too low") {
+            Ok(Self::TooLow)
+        } else if body.contains("That's not the right answer") {
+            Ok(Self::Incorrect)
+        } else if body.contains("You gave an answer too recently") {
+            Ok(Self::Wait(
+                parse_wait(body).unwrap_or(Duration::from_secs(60)),
+            ))
+        } else if body.contains("Did you already complete it?") {
+            Ok(Self::AlreadySolved)
+        } else {
+            let msg = "Unrecognized response to submission".to_string();
+            Err(io::Error::new(io::ErrorKind::InvalidData, msg))
+        }
+    }
+}
+
+/// Everything that the server's response to a submission says about the submitted answer.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Response {
+    /// How the server judged the answer.
+    pub verdict: Verdict,
+    /// How long the server will refuse further answers for, if it said.
+    pub cooldown: Option<Duration>,
+}
+
+impl Response {
+    /// Determines the verdict and cooldown from the body of the server's response to a
+    /// submission.
+    pub fn from_body(body: &str) -> io::Result<Self> {
+        let verdict = Verdict::from_response(body)?;
+        let cooldown = match verdict {
+            Verdict::Wait(wait) => Some(wait),
+            Verdict::Correct | Verdict::AlreadySolved => None,
+            Verdict::TooHigh | Verdict::TooLow | Verdict::Incorrect => parse_cooldown(body),
+        };
+        Ok(Self { verdict, cooldown })
+    }
+}
+
+impl From<Verdict> for Response {
+    fn from(verdict: Verdict) -> Self {
+        let cooldown = match verdict {
+            Verdict::Wait(wait) => Some(wait),
+            _ => None,
+        };
+        Self { verdict, cooldown }
+    }
+}
+
+/// Extracts the cooldown after a wrong answer from text of the form `Please wait one minute before
+/// trying again` or `please wait 5 minutes before trying again`.
+fn parse_cooldown(body: &str) -> Option<Duration> {
+    let end = body.find(" before trying again")?;
+    let start = body[..end].rfind("lease wait ")? + "lease wait ".len();
+    let (value, unit) = body[start..end].split_once(' ')?;
+    let value = match value {
+        "a" | "an" | "one" => 1,
+        value => value.parse::<u64>().ok()?,
+    };
+    match unit.strip_suffix('s').unwrap_or(unit) {
+        "hour" => value.checked_mul(3600).map(Duration::from_secs),
+        "minute" => value.checked_mul(60).map(Duration::from_secs),
+        "second" => Some(Duration::from_secs(value)),
+        _ => None,
+    }
+}
+
+/// Extracts the remaining wait from text of the form `You have 4m 39s left to wait`.
+fn parse_wait(body: &str) -> Option<Duration> {
+    let end = body.find(" left to wait")?;
+    let start = body[..end].rfind("You have ")? + "You have ".len();
+    body[start..end]
+        .split_whitespace()
+        .try_fold(0u64, |acc, piece| {
+            let (i, unit) = piece.char_indices().next_back()?;
+            let value = piece[..i].parse::<u64>().ok()?;
+            let seconds = match unit {
+                'h' => value.checked_mul(3600)?,
+                'm' => value.checked_mul(60)?,
+                's' => value,
+                _ => return None,
+            };
+            acc.checked_add(seconds)
+        })
+        .map(Duration::from_secs)
+}
+
+/// A source of puzzle inputs and a judge of submitted answers.
+pub trait Server {
+    /// Gets the puzzle input for day `day` of year `year`.
+    fn fetch_input(&self, year: u32, day: u32) -> io::Result<String>;
+
+    /// Submits `answer` as the answer to part `part` of day `day` of year `year`.
+    fn submit_answer(&self, year: u32, day: u32, part: u32, answer: &str) -> io::Result<Response>;
+}
+
+/// Downloads the puzzle input for day `day` of year `year` from `server` and writes it to `path`.
+/// The hash of the input is recorded so that later runs can detect if the file has been damaged.
+pub fn download_input(
+    server: &dyn Server,
+    year: u32,
+    day: u32,
+    path: impl AsRef<Path>,
+) -> io::Result<()> {
+    let input = server.fetch_input(year, day)?;
+    fs::write(&path, &input)?;
+    input::record_hash(path, input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verdict_from_response() {
+        let cases = [
+            (
+                "That's the right answer!  You are one gold star closer.",
+                Verdict::Correct,
+            ),
+            (
+                "That's not the right answer; your answer is too high.",
+                Verdict::TooHigh,
+            ),
+            (
+                "That's not the right answer; your answer is too low.",
+                Verdict::TooLow,
+            ),
+            (
+                "That's not the right answer.  If you're stuck, ...",
+                Verdict::Incorrect,
+            ),
+            (
+                "You gave an answer too recently; ...  You have 4m 39s left to wait.",
+                Verdict::Wait(Duration::from_secs(279)),
+            ),
+            (
+                "You don't seem to be solving the right level.  Did you already complete it?",
+                Verdict::AlreadySolved,
+            ),
+        ];
+        for (body, expected) in cases {
+            assert_eq!(expected, Verdict::from_response(body).unwrap());
+        }
+        assert!(Verdict::from_response("<html></html>").is_err());
+    }
+
+    #[test]
+    fn test_parse_wait() {
+        assert_eq!(
+            Some(Duration::from_secs(3879)),
+            parse_wait("You have 1h 4m 39s left to wait.")
+        );
+        assert_eq!(None, parse_wait("You have 4m 39é left to wait."));
+        assert_eq!(None, parse_wait("You have m left to wait."));
+        assert_eq!(
+            None,
+            parse_wait("You have 18446744073709551615h left to wait.")
+        );
+        assert_eq!(
+            None,
+            parse_wait("You have 18446744073709551615s 1s left to wait.")
+        );
+    }
+
+    #[test]
+    fn test_parse_cooldown() {
+        assert_eq!(
+            Some(Duration::from_secs(300)),
+            parse_cooldown("Please wait 5 minutes before trying again.")
+        );
+        assert_eq!(
+            Some(Duration::from_secs(60)),
+            parse_cooldown("Please wait one minute before trying again.")
+        );
+        assert_eq!(
+            None,
+            parse_cooldown("Please wait 18446744073709551615 hours before trying again.")
+        );
+    }
+
+    #[test]
+    fn test_response_from_wrong_answer_page() {
+        let body = include_str!("wrong_answer.html");
+        let expected = Response {
+            verdict: Verdict::TooLow,
+            cooldown: Some(Duration::from_secs(60)),
+        };
+        assert_eq!(expected, Response::from_body(body).unwrap());
+        let body = "That's not the right answer.  ...  Because you have guessed incorrectly 4 \
+                    times on this puzzle, please wait 5 minutes before trying again.";
+        let expected = Response {
+            verdict: Verdict::Incorrect,
+            cooldown: Some(Duration::from_secs(300)),
+        };
+        assert_eq!(expected, Response::from_body(body).unwrap());
+        let body = "That's the right answer!  You are one gold star closer.";
+        assert_eq!(
+            Response::from(Verdict::Correct),
+            Response::from_body(body).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_download_input() {
+        let server = FakeServer::new().with_input(2022, 1, "1000\n2000\n");
+        let path = std::env::temp_dir().join("aoc_test_download_input.txt");
+        download_input(&server, 2022, 1, &path).unwrap();
+        assert_eq!("1000\n2000\n", fs::read_to_string(&path).unwrap());
+        assert_eq!(input::Integrity::Intact, input::check(&path).unwrap());
+        fs::remove_file(&path).unwrap();
+        fs::remove_file(input::hash_file(&path)).unwrap();
+        assert!(download_input(&server, 2022, 2, &path).is_err());
+    }
+}
@@ -0,0 +1,112 @@
+use std::{env, fs, io, path::PathBuf};
+
+use aoc_util::input;
+
+/// The environment variable that the session cookie is read from, if it's set.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// The name of the file in the workspace root that the session cookie is read from when it isn't
+/// found anywhere else.
+pub const SESSION_FILE: &str = ".aoc_session";
+
+/// The path of [`SESSION_FILE`], as found by [`input::workspace_file()`].
+pub fn session_file() -> io::Result<PathBuf> {
+    input::workspace_file(SESSION_FILE)
+}
+
+#[cfg(feature = "keyring")]
+const KEYRING_SERVICE: &str = "advent_of_code";
+#[cfg(feature = "keyring")]
+const KEYRING_USER: &str = "session";
+
+#[cfg(feature = "keyring")]
+fn keyring_entry() -> io::Result<keyring::Entry> {
+    keyring::Entry::new(KEYRING_SERVICE, KEYRING_USER)
+        .map_err(|e| io::Error::new(io::ErrorKind::Other, e))
+}
+
+/// Reads the session cookie from the OS keyring.
+#[cfg(feature = "keyring")]
+fn load_from_keyring() -> io::Result<Option<String>> {
+    match keyring_entry()?.get_password() {
+        Ok(session) => Ok(Some(session)),
+        Err(keyring::Error::NoEntry) => Ok(None),
+        Err(e) => Err(io::Error::new(io::ErrorKind::Other, e)),
+    }
+}
+
+#[cfg(not(feature = "keyring"))]
+fn load_from_keyring() -> io::Result<Option<String>> {
+    Ok(None)
+}
+
+/// Finds the session cookie of the logged-in user. The cookie is taken from the first of
+/// [`SESSION_VAR`], the OS keyring (if the `keyring` feature is enabled), and [`SESSION_FILE`]
+/// which contains it.
+pub fn load() -> io::Result<String> {
+    load_with_env(|name| env::var(name).ok())
+}
+
+/// Finds the session cookie like [`load()`], but looks up environment variables with `var`.
+fn load_with_env(var: impl Fn(&str) -> Option<String>) -> io::Result<String> {
+    if let Some(session) = var(SESSION_VAR).filter(|session| !session.trim().is_empty()) {
+        return Ok(session.trim().to_string());
+    }
+    if let Some(session) = load_from_keyring()? {
+        return Ok(session);
+    }
+    match fs::read_to_string(session_file()?) {
+        Ok(session) => Ok(session.trim().to_string()),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            let msg = format!("Not logged in: set {SESSION_VAR} or run the login subcommand");
+            Err(io::Error::new(io::ErrorKind::NotFound, msg))
+        }
+        Err(e) => Err(e),
+    }
+}
+
+/// Saves `session` so that later calls to [`load()`] will find it. The session is stored in the OS
+/// keyring if the `keyring` feature is enabled and in [`SESSION_FILE`] otherwise.
+#[cfg(feature = "keyring")]
+pub fn store(session: &str) -> io::Result<()> {
+    keyring_entry()?
+        .set_password(session)
+        .map_err(|e| io::Error::new(io::ErrorKind::Other, e))
+}
+
+/// Saves `session` so that later calls to [`load()`] will find it. The session is stored in the OS
+/// keyring if the `keyring` feature is enabled and in [`SESSION_FILE`] otherwise.
+#[cfg(not(feature = "keyring"))]
+pub fn store(session: &str) -> io::Result<()> {
+    use std::io::Write;
+
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+
+        options.mode(0o600);
+    }
+    let mut file = options.open(session_file()?)?;
+    // The mode above only applies when the file is created, so a session file which already
+    // existed with looser permissions has to be restricted before the cookie is written to it.
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+
+        file.set_permissions(fs::Permissions::from_mode(0o600))?;
+    }
+    writeln!(file, "{session}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load_from_env() {
+        let var = |name: &str| (name == SESSION_VAR).then(|| " 53616c7465645f5f\n".to_string());
+        assert_eq!("53616c7465645f5f", load_with_env(var).unwrap());
+    }
+}
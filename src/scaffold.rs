@@ -0,0 +1,114 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+/// The template that new days are made from. It's the first day of `year_frame` so that there's
+/// only one template to keep up to date.
+const DAY_TEMPLATE: &str = include_str!("../year_frame/day_1/mod.rs");
+
+/// The source of a new day `day` of year `year` titled `title`, with stubs for the parser and both
+/// parts and an ignored example test whose answers are placeholders, so that the test fails until
+/// the example and its answers are filled in.
+pub fn day_source(year: u32, day: u32, title: &str) -> String {
+    DAY_TEMPLATE
+        .replace("????,", &format!("{year},"))
+        .replace("day: 1,", &format!("day: {day},"))
+        .replace("\"????\"", &format!("{title:?}"))
+}
+
+/// Adds the declaration of the module of day `day` to `lib`, the source of a year's `lib.rs`,
+/// keeping the days in order. Returns `None` if the module is already declared.
+fn declare_day(lib: &str, day: u32) -> Option<String> {
+    let declaration = format!("mod day_{day};");
+    let mut lines = lib.lines().collect::<Vec<_>>();
+    if lines.iter().any(|line| line.trim() == declaration) {
+        return None;
+    }
+    let declared_day = |line: &str| {
+        line.trim()
+            .strip_prefix("mod day_")?
+            .strip_suffix(';')?
+            .parse::<u32>()
+            .ok()
+    };
+    if let Some(idx) = lines
+        .iter()
+        .rposition(|line| declared_day(line).is_some_and(|other| other < day))
+    {
+        lines.insert(idx + 1, &declaration);
+    } else if let Some(idx) = lines.iter().position(|line| declared_day(line).is_some()) {
+        lines.insert(idx, &declaration);
+    } else {
+        // The first day to be declared goes in its own paragraph after the imports.
+        let idx = lines
+            .iter()
+            .rposition(|line| line.starts_with("use "))
+            .map_or(0, |idx| idx + 1);
+        lines.splice(idx..idx, ["", &declaration]);
+    }
+    Some(lines.join("\n") + "\n")
+}
+
+/// Creates the module of day `day` in the crate of year `year` in the workspace at `root` and
+/// declares it in the crate's `lib.rs`, which registers it. Also creates an empty file for the
+/// example input if there isn't one yet, for the example to be copied into. Returns the path of the
+/// new module.
+pub fn new_day(root: &Path, year: u32, day: u32, title: &str) -> io::Result<PathBuf> {
+    let src = root.join(format!("aoc_{year}")).join("src");
+    let lib_path = src.join("lib.rs");
+    if !lib_path.exists() {
+        let msg = format!("There's no crate for {year} at {}", lib_path.display());
+        return Err(io::Error::new(io::ErrorKind::NotFound, msg));
+    }
+    let day_dir = src.join(format!("day_{day}"));
+    let day_path = day_dir.join("mod.rs");
+    if day_path.exists() {
+        let msg = format!("{} already exists", day_path.display());
+        return Err(io::Error::new(io::ErrorKind::AlreadyExists, msg));
+    }
+    fs::create_dir_all(&day_dir)?;
+    fs::write(&day_path, day_source(year, day, title))?;
+    if let Some(lib) = declare_day(&fs::read_to_string(&lib_path)?, day) {
+        fs::write(&lib_path, lib)?;
+    }
+    let sample_path = root
+        .join(aoc_util::samples::SAMPLES_DIR)
+        .join(aoc_util::registry::input_file(year, day));
+    if !sample_path.exists() {
+        fs::write(&sample_path, "")?;
+    }
+    Ok(day_path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_day_source() {
+        let source = day_source(2023, 7, "Camel Cards");
+        assert!(source.contains("year: 2023,\n    day: 7,\n    title: \"Camel Cards\",\n"));
+        assert!(source.contains("year: 2023,\n        day: 7,\n        part: Some(2),\n"));
+        assert!(source.contains("#[ignore]\n    examples: [\n        part1 => 0,\n"));
+        assert!(!source.contains("????"));
+    }
+
+    #[test]
+    fn test_declare_day() {
+        let lib = "use aoc_util::registry::Day;\n\nmod day_1;\nmod day_2;\n\nmod day_10;\n";
+        assert_eq!(
+            Some("use aoc_util::registry::Day;\n\nmod day_1;\nmod day_2;\nmod day_3;\n\nmod day_10;\n"),
+            declare_day(lib, 3).as_deref()
+        );
+        assert_eq!(
+            Some("use aoc_util::registry::Day;\n\nmod day_1;\nmod day_2;\n\nmod day_10;\nmod day_11;\n"),
+            declare_day(lib, 11).as_deref()
+        );
+        assert_eq!(None, declare_day(lib, 2));
+        assert_eq!(
+            Some("use aoc_util::registry::Day;\n\nmod day_1;\n\npub fn days() {}\n"),
+            declare_day("use aoc_util::registry::Day;\n\npub fn days() {}\n", 1).as_deref()
+        );
+    }
+}
@@ -1,5 +0,0 @@
-use std::io;
-
-pub(super) fn run() -> io::Result<()> {
-    unimplemented!()
-}
@@ -1,13 +0,0 @@
-use std::io;
-
-pub(super) fn run() -> io::Result<()> {
-    {
-        println!("Year 2019 Day 17 Part 1");
-        println!("Unimplemented");
-    }
-    {
-        println!("Year 2019 Day 17 Part 2");
-        println!("Unimplemented");
-    }
-    Ok(())
-}
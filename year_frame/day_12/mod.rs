@@ -1,30 +1,41 @@
-use std::{
-    fs::File,
-    io::{self, BufRead, BufReader},
-};
+use aoc_util::error::{self, Error};
 
-fn part1(_input: &mut dyn BufRead) -> io::Result<()> {
-    todo!("Year ???? Day 12 Part 1")
+/// The puzzle input after it has been parsed.
+#[derive(Clone, Debug)]
+struct Model;
+
+fn parse(_input: &str) -> error::Result<Model> {
+    Ok(Model)
+}
+
+fn part1(_model: &Model) -> error::Result<u32> {
+    Err(Error::Unimplemented {
+        year: ????,
+        day: 12,
+        part: Some(1),
+    })
 }
 
-fn part2(_input: &mut dyn BufRead) -> io::Result<()> {
-    todo!("Year ???? Day 12 Part 2")
+fn part2(_model: &Model) -> error::Result<u32> {
+    Err(Error::Unimplemented {
+        year: ????,
+        day: 12,
+        part: Some(2),
+    })
 }
 
-pub(super) fn run() -> io::Result<()> {
-    {
-        println!("Year ???? Day 12 Part 1");
-        println!(
-            "{:?}",
-            part1(&mut BufReader::new(File::open("????_12.txt")?))?
-        );
-    }
-    {
-        println!("Year ???? Day 12 Part 2");
-        println!(
-            "{:?}",
-            part2(&mut BufReader::new(File::open("????_12.txt")?))?
-        );
-    }
-    Ok(())
+aoc_util::solution! {
+    year: ????,
+    day: 12,
+    title: "????",
+    parse: parse,
+    part1: part1,
+    part2: part2,
+    // TODO: Copy the example from the puzzle description into its file in `test_data`, replace
+    // these answers with the ones that the description gives for it, and remove the `#[ignore]`.
+    #[ignore]
+    examples: [
+        part1 => 0,
+        part2 => 0,
+    ],
 }